@@ -0,0 +1,78 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    errors::BecoError,
+    organisation::organisation::parse_date,
+    proto::beco::{AccessRecordResponse, ListAccessLogRequest},
+    requests::user_request::FieldReference,
+};
+
+// a read of someone else's fields, recorded by the user node that served it. Reads are not
+// validated so the log is kept by the sst outside of the user's merkle tree
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct AccessRecord {
+    pub id: String,
+    pub user_id: String,
+    pub viewer_id: String,
+    pub fields: Vec<FieldReference>,
+    pub read_at: DateTime<Utc>,
+    // the peer id of the serving node, set by the sst from the message that carried the record
+    #[serde(default)]
+    pub node: String,
+}
+
+impl AccessRecord {
+    pub fn new(
+        user_id: String,
+        viewer_id: String,
+        fields: Vec<FieldReference>,
+        read_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            user_id,
+            viewer_id,
+            fields,
+            read_at,
+            node: "".into(),
+        }
+    }
+}
+
+impl From<AccessRecord> for AccessRecordResponse {
+    fn from(val: AccessRecord) -> Self {
+        AccessRecordResponse {
+            id: val.id,
+            viewer_id: val.viewer_id,
+            fields: val.fields.into_iter().map(|field| field.into()).collect(),
+            read_at: val.read_at.to_rfc3339(),
+            node: val.node,
+        }
+    }
+}
+
+// the validated form of ListAccessLogRequest
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct ListAccessLog {
+    pub user_id: String,
+    pub calling_user: String,
+    pub since: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<ListAccessLogRequest> for ListAccessLog {
+    type Error = BecoError;
+
+    fn try_from(request: ListAccessLogRequest) -> Result<Self, Self::Error> {
+        let since_result = parse_date(&request.since, None);
+        if let Err(err) = since_result {
+            return Err(err);
+        }
+        Ok(Self {
+            user_id: request.user_id,
+            calling_user: request.calling_user,
+            since: since_result.unwrap(),
+        })
+    }
+}
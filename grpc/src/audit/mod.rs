@@ -0,0 +1 @@
+pub mod access_record;
\ No newline at end of file
@@ -1,13 +1,18 @@
-use std::{fmt::Debug, marker::PhantomData, hash::Hash};
+use std::{collections::BTreeMap, fmt::Debug, marker::PhantomData, hash::Hash};
 
+use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
 use tonic::Code;
 
 use crate::{
     enums::{blockchain::Blockchain, value_reference::ValueReference},
     errors::BecoError,
-    permissions::model::PermissionModel,
-    proto::beco::{AddAccountRequest, ChainResponse, WalletResponse},
+    permissions::{
+        model::{GrantRole, PermissionModel},
+        policy::AccessPolicy,
+        visibility::Visibility,
+    },
+    proto::beco::{AddAccountRequest, ChainResponse, RemoveAccountRequest, WalletResponse},
     traits::value::Values,
     user::public_user::PublicUser,
 };
@@ -17,7 +22,11 @@ where
     T: Values<A> + Clone + Debug + Into<PublicKey> + Hash,
 {
     pub chain: Blockchain,
+    // grants here reach every key, creating keys needs editor rights on it
     pub keys: PermissionModel<Vec<T>>,
+    // grants on a single key keyed by its alias, only added once something is granted on the key
+    #[serde(default)]
+    pub key_permissions: BTreeMap<String, PermissionModel<()>>,
     phantom_type: PhantomData<A>,
 }
 
@@ -29,32 +38,69 @@ where
         Self {
             chain,
             keys: PermissionModel::new(owner_id, vec![], "keys".into(), ValueReference::CHAIN_HEYS),
+            key_permissions: BTreeMap::new(),
             phantom_type: PhantomData,
         }
     }
 
+    fn owner(&self) -> PublicUser {
+        PublicUser::new(self.keys.owner_id().clone(), None, None, None, vec![])
+    }
+
+    // every key regardless of who is asking, callers filter by the rights on each key
+    pub fn all_keys(&self) -> Vec<T> {
+        self.keys.value(&self.owner()).unwrap_or(vec![])
+    }
+
+    pub fn has_key(&self, alias: &String) -> bool {
+        self.all_keys().iter().any(|key| &key.alias() == alias)
+    }
+
     pub fn does_alias_exist(&self, alias: String, user: &PublicUser) -> bool {
-        self.keys
-            .value(user)
-            .unwrap_or(vec![])
-            .iter()
-            .find(|&key| key.alias() == alias)
-            .is_some()
+        self.has_key(&alias) && self.can_view_key(&alias, user, &Utc::now())
+    }
+
+    // a key's own visibility wins over the one set on the chain keys, a PRIVATE key is hidden
+    // from viewers of every key
+    pub fn can_view_key(&self, alias: &str, user: &PublicUser, at: &DateTime<Utc>) -> bool {
+        let key_permission_option = self.key_permissions.get(alias);
+        if key_permission_option.is_none() {
+            return self.keys.can_view(user, at);
+        }
+        let key_permission = key_permission_option.unwrap();
+        match key_permission.visibility() {
+            Visibility::PUBLIC => true,
+            Visibility::SHARED => {
+                self.keys.can_view(user, at) || key_permission.can_view(user, at)
+            }
+            Visibility::PRIVATE => self.can_edit_key(alias, user, at),
+        }
+    }
+
+    // editors of a key can sign with it and remove it
+    pub fn can_edit_key(&self, alias: &str, user: &PublicUser, at: &DateTime<Utc>) -> bool {
+        PermissionModel::is_owner_or_editor(&self.keys, user, at)
+            || self
+                .key_permissions
+                .get(alias)
+                .is_some_and(|key_permission| {
+                    PermissionModel::is_owner_or_editor(key_permission, user, at)
+                })
     }
 
     pub fn propose(
         &self,
         request: AddAccountRequest,
         calling_user: &PublicUser,
+        at: &DateTime<Utc>,
     ) -> Result<(), BecoError> {
-        let does_alias_exist = self.does_alias_exist(request.alias.clone(), calling_user);
-        if does_alias_exist {
+        if self.has_key(&request.alias) {
             return Err(BecoError {
                 message: "Alias already exists".into(),
                 status: Code::AlreadyExists,
             });
         }
-        if !PermissionModel::is_owner_or_editor(&self.keys, calling_user) {
+        if !PermissionModel::is_owner_or_editor(&self.keys, calling_user, at) {
             return Err(BecoError {
                 message: "User does not have permission to create a new key".into(),
                 status: Code::PermissionDenied,
@@ -63,12 +109,291 @@ where
         Ok(())
     }
 
+    pub fn propose_remove(
+        &self,
+        alias: &String,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        if !self.has_key(alias) {
+            return Err(BecoError {
+                message: format!("Key not found: {alias}"),
+                status: Code::NotFound,
+            });
+        }
+        if !self.can_edit_key(alias, calling_user, at) {
+            return Err(BecoError {
+                message: "User does not have permission to remove this key".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        Ok(())
+    }
+
+    // the grants on the key go with it
+    pub fn remove(
+        &mut self,
+        alias: &String,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        self.propose_remove(alias, calling_user, at)?;
+        let owner = self.owner();
+        let keys = self.keys.value_mut(&owner, at).unwrap();
+        keys.retain(|key| &key.alias() != alias);
+        self.key_permissions.remove(alias);
+        Ok(())
+    }
+
+    fn key_permission_mut(
+        &mut self,
+        alias: &String,
+    ) -> Result<&mut PermissionModel<()>, BecoError> {
+        if !self.has_key(alias) {
+            return Err(BecoError {
+                message: format!("Key not found: {alias}"),
+                status: Code::NotFound,
+            });
+        }
+        let owner_id = self.keys.owner_id().clone();
+        Ok(self.key_permissions.entry(alias.clone()).or_insert_with(|| {
+            PermissionModel::new(owner_id, (), alias.clone(), ValueReference::CHAIN_HEYS)
+        }))
+    }
+
+    // alias is None for a grant on every key
+    pub fn add_viewer_until(
+        &mut self,
+        alias: &Option<String>,
+        viewer_id: String,
+        calling_user: &PublicUser,
+        expires: Option<DateTime<Utc>>,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        if alias.is_none() {
+            return self.keys.add_viewer_until(viewer_id, calling_user, expires, at);
+        }
+        let key_permission_result = self.key_permission_mut(alias.as_ref().unwrap());
+        if let Err(err) = key_permission_result {
+            return Err(err);
+        }
+        key_permission_result
+            .unwrap()
+            .add_viewer_until(viewer_id, calling_user, expires, at)
+    }
+
+    pub fn add_editor_until(
+        &mut self,
+        alias: &Option<String>,
+        editor_id: String,
+        calling_user: &PublicUser,
+        expires: Option<DateTime<Utc>>,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        if alias.is_none() {
+            return self
+                .keys
+                .add_editor_until(editor_id, calling_user, expires, at);
+        }
+        let key_permission_result = self.key_permission_mut(alias.as_ref().unwrap());
+        if let Err(err) = key_permission_result {
+            return Err(err);
+        }
+        key_permission_result
+            .unwrap()
+            .add_editor_until(editor_id, calling_user, expires, at)
+    }
+
+    pub fn set_group(
+        &mut self,
+        alias: &Option<String>,
+        group_id: &String,
+        role: GrantRole,
+        grant: bool,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        if alias.is_none() {
+            return self.keys.set_group(group_id, role, grant, calling_user, at);
+        }
+        let key_permission_result = self.key_permission_mut(alias.as_ref().unwrap());
+        if let Err(err) = key_permission_result {
+            return Err(err);
+        }
+        key_permission_result
+            .unwrap()
+            .set_group(group_id, role, grant, calling_user, at)
+    }
+
+    pub fn revoke(
+        &mut self,
+        alias: &Option<String>,
+        user_id: &str,
+        role: GrantRole,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        match alias {
+            None => self.keys.revoke(user_id, role, calling_user, at),
+            Some(alias) => {
+                let key_permission_option = self.key_permissions.get_mut(alias);
+                if key_permission_option.is_none() {
+                    return Err(BecoError {
+                        message: format!("Key not found: {alias}"),
+                        status: Code::NotFound,
+                    });
+                }
+                key_permission_option
+                    .unwrap()
+                    .revoke(user_id, role, calling_user, at)
+            }
+        }
+    }
+
+    pub fn set_policies(
+        &mut self,
+        alias: &Option<String>,
+        policies: Vec<AccessPolicy>,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        if alias.is_none() {
+            return self.keys.set_policies(policies, calling_user);
+        }
+        let key_permission_result = self.key_permission_mut(alias.as_ref().unwrap());
+        if let Err(err) = key_permission_result {
+            return Err(err);
+        }
+        key_permission_result.unwrap().set_policies(policies, calling_user)
+    }
+
+    pub fn set_visibility(
+        &mut self,
+        alias: &Option<String>,
+        visibility: Visibility,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        if alias.is_none() {
+            return self.keys.set_visibility(visibility, calling_user);
+        }
+        let key_permission_result = self.key_permission_mut(alias.as_ref().unwrap());
+        if let Err(err) = key_permission_result {
+            return Err(err);
+        }
+        key_permission_result.unwrap().set_visibility(visibility, calling_user)
+    }
+
+    pub fn expires(
+        &self,
+        alias: &Option<String>,
+        user_id: &String,
+        role: GrantRole,
+    ) -> Option<DateTime<Utc>> {
+        match alias {
+            None => self.keys.expires(user_id, role),
+            Some(alias) => self
+                .key_permissions
+                .get(alias)
+                .and_then(|key_permission| key_permission.expires(user_id, role)),
+        }
+    }
+
+    pub fn next_expiry(&self) -> Option<DateTime<Utc>> {
+        std::iter::once(self.keys.next_expiry())
+            .chain(
+                self.key_permissions
+                    .values()
+                    .map(|key_permission| key_permission.next_expiry()),
+            )
+            .flatten()
+            .min()
+    }
+
+    // the alias is None for grants on every key
+    pub fn expired_grants(&self, at: &DateTime<Utc>) -> Vec<(Option<String>, String, GrantRole)> {
+        let mut grants: Vec<(Option<String>, String, GrantRole)> = self
+            .keys
+            .expired_grants(at)
+            .into_iter()
+            .map(|(user_id, role)| (None, user_id, role))
+            .collect();
+        for (alias, key_permission) in self.key_permissions.iter() {
+            for (user_id, role) in key_permission.expired_grants(at) {
+                grants.push((Some(alias.clone()), user_id, role));
+            }
+        }
+        grants
+    }
+
+    pub fn revoke_expired(
+        &mut self,
+        alias: &Option<String>,
+        user_id: &String,
+        role: GrantRole,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        match alias {
+            None => self.keys.revoke_expired(user_id, role, at),
+            Some(alias) => {
+                let key_permission_option = self.key_permissions.get_mut(alias);
+                if key_permission_option.is_none() {
+                    return Err(BecoError {
+                        message: format!("Key not found: {alias}"),
+                        status: Code::NotFound,
+                    });
+                }
+                key_permission_option.unwrap().revoke_expired(user_id, role, at)
+            }
+        }
+    }
+
     pub fn as_public(&self, calling_user: &PublicUser) -> PublicChainCustody {
-        let keys = self.keys.value(calling_user).unwrap_or(vec![]);
+        let at = Utc::now();
         PublicChainCustody {
             chain: self.chain.clone(),
-            keys: keys.iter().map(|key| key.clone().into()).collect(),
+            keys: self
+                .all_keys()
+                .into_iter()
+                .filter(|key| self.can_view_key(&key.alias(), calling_user, &at))
+                .map(|key| key.into())
+                .collect(),
+        }
+    }
+}
+
+// the validated form of RemoveAccountRequest
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct RemoveAccount {
+    pub user_id: String,
+    pub calling_user: String,
+    pub blockchain: Blockchain,
+    pub alias: String,
+    pub expected_sequence: Option<u64>,
+}
+
+impl TryFrom<RemoveAccountRequest> for RemoveAccount {
+    type Error = BecoError;
+
+    fn try_from(request: RemoveAccountRequest) -> Result<Self, Self::Error> {
+        let blockchain: Blockchain = request.blockchain.into();
+        if blockchain == Blockchain::UNSPECIFIED {
+            return Err(BecoError {
+                message: "No blockchain provided".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        if request.alias.is_empty() {
+            return Err(BecoError {
+                message: "No alias provided".into(),
+                status: Code::InvalidArgument,
+            });
         }
+        Ok(Self {
+            user_id: request.user_id,
+            calling_user: request.calling_user,
+            blockchain,
+            alias: request.alias,
+            expected_sequence: request.expected_sequence,
+        })
     }
 }
 
@@ -1,10 +1,13 @@
 #![cfg(test)]
 
-use xrpl::constants::CryptoAlgorithm;
+use chrono::Utc;
+use tonic::Code;
+use xrpl::{constants::CryptoAlgorithm, core::keypairs::is_valid_message};
 
 use crate::{
     chain::chain_custody::ChainCustody,
     enums::blockchain::Blockchain,
+    permissions::{model::GrantRole, visibility::Visibility},
     traits::key::Key,
     user::public_user::PublicUser,
     xrpl::{XRPLKey, XRPLKeyValues}, proto::beco::{AddAccountRequest, Blockchain as RequestBlockchain},
@@ -23,8 +26,8 @@ fn create_new_key() {
     let public_user = PublicUser::new("blah blah blah".into(), None, None, None, vec![]);
     let mut chain_custody: ChainCustody<XRPLKey, XRPLKeyValues> =
         ChainCustody::new(Blockchain::XRPL, public_user.id.clone());
-    let request = AddAccountRequest { alias: "test".into(), blockchain: RequestBlockchain::Xrpl.into(), calling_user: "".into(), user_id: "".into() };
-    let _ = chain_custody.create(Some(CryptoAlgorithm::ED25519), request, &public_user);
+    let request = AddAccountRequest { alias: "test".into(), blockchain: RequestBlockchain::Xrpl.into(), calling_user: "".into(), user_id: "".into(), expected_sequence: None };
+    let _ = chain_custody.create(Some(CryptoAlgorithm::ED25519), request, &public_user, &Utc::now());
     assert_eq!(chain_custody.keys.value(&public_user).unwrap().len(), 1);
 }
 
@@ -33,8 +36,8 @@ fn does_alias_exist_true() {
     let public_user = PublicUser::new("blah blah blah".into(), None, None, None, vec![]);
     let mut chain_custody: ChainCustody<XRPLKey, XRPLKeyValues> =
         ChainCustody::new(Blockchain::XRPL, public_user.id.clone());
-    let request = AddAccountRequest { alias: "test".into(), blockchain: RequestBlockchain::Xrpl.into(), calling_user: "".into(), user_id: "".into() };
-    let _ = chain_custody.create(Some(CryptoAlgorithm::ED25519), request, &public_user);
+    let request = AddAccountRequest { alias: "test".into(), blockchain: RequestBlockchain::Xrpl.into(), calling_user: "".into(), user_id: "".into(), expected_sequence: None };
+    let _ = chain_custody.create(Some(CryptoAlgorithm::ED25519), request, &public_user, &Utc::now());
     let does_exist = chain_custody.does_alias_exist("test".into(), &public_user);
     assert_eq!(does_exist, true);
 }
@@ -44,8 +47,137 @@ fn does_alias_exist_false() {
     let public_user = PublicUser::new("blah blah blah".into(), None, None, None, vec![]);
     let mut chain_custody: ChainCustody<XRPLKey, XRPLKeyValues> =
         ChainCustody::new(Blockchain::XRPL, public_user.id.clone());
-    let request = AddAccountRequest { alias: "test".into(), blockchain: RequestBlockchain::Xrpl.into(), calling_user: "".into(), user_id: "".into() };
-    let _ = chain_custody.create(Some(CryptoAlgorithm::ED25519), request, &public_user);
+    let request = AddAccountRequest { alias: "test".into(), blockchain: RequestBlockchain::Xrpl.into(), calling_user: "".into(), user_id: "".into(), expected_sequence: None };
+    let _ = chain_custody.create(Some(CryptoAlgorithm::ED25519), request, &public_user, &Utc::now());
     let does_exist = chain_custody.does_alias_exist("nope".into(), &public_user);
     assert_eq!(does_exist, false);
 }
+
+#[test]
+fn public_keys_visible_to_everyone() {
+    let public_user = PublicUser::new("blah blah blah".into(), None, None, None, vec![]);
+    let stranger = PublicUser::new("stranger".into(), None, None, None, vec![]);
+    let mut chain_custody: ChainCustody<XRPLKey, XRPLKeyValues> =
+        ChainCustody::new(Blockchain::XRPL, public_user.id.clone());
+    let request = AddAccountRequest { alias: "test".into(), blockchain: RequestBlockchain::Xrpl.into(), calling_user: "".into(), user_id: "".into(), expected_sequence: None };
+    let _ = chain_custody.create(Some(CryptoAlgorithm::ED25519), request, &public_user, &Utc::now());
+    assert!(chain_custody.as_public(&stranger).keys.is_empty());
+
+    chain_custody.keys.set_visibility(Visibility::PUBLIC, &public_user).unwrap();
+
+    let keys = chain_custody.as_public(&stranger).keys;
+    assert_eq!(keys.len(), 1);
+    assert_eq!(keys[0].alias, "test");
+}
+
+fn custody_with_keys(
+    owner: &PublicUser,
+    aliases: Vec<&str>,
+) -> ChainCustody<XRPLKey, XRPLKeyValues> {
+    let mut chain_custody: ChainCustody<XRPLKey, XRPLKeyValues> =
+        ChainCustody::new(Blockchain::XRPL, owner.id.clone());
+    for alias in aliases {
+        let request = AddAccountRequest { alias: alias.into(), blockchain: RequestBlockchain::Xrpl.into(), calling_user: "".into(), user_id: "".into(), expected_sequence: None };
+        chain_custody.create(Some(CryptoAlgorithm::ED25519), request, owner, &Utc::now()).unwrap();
+    }
+    chain_custody
+}
+
+#[test]
+fn key_viewer_only_sees_that_key() {
+    let public_user = PublicUser::new("blah blah blah".into(), None, None, None, vec![]);
+    let employer = PublicUser::new("employer".into(), None, None, None, vec![]);
+    let mut chain_custody = custody_with_keys(&public_user, vec!["payments", "savings"]);
+
+    chain_custody
+        .add_viewer_until(
+            &Some("payments".into()),
+            employer.id.clone(),
+            &public_user,
+            None,
+            &Utc::now(),
+        )
+        .unwrap();
+
+    let keys = chain_custody.as_public(&employer).keys;
+    assert_eq!(keys.len(), 1);
+    assert_eq!(keys[0].alias, "payments");
+    assert_eq!(chain_custody.as_public(&public_user).keys.len(), 2);
+    assert!(chain_custody.does_alias_exist("payments".into(), &employer));
+    assert!(!chain_custody.does_alias_exist("savings".into(), &employer));
+}
+
+#[test]
+fn private_key_hidden_from_viewers_of_every_key() {
+    let public_user = PublicUser::new("blah blah blah".into(), None, None, None, vec![]);
+    let viewer = PublicUser::new("viewer".into(), None, None, None, vec![]);
+    let mut chain_custody = custody_with_keys(&public_user, vec!["payments", "savings"]);
+    chain_custody
+        .add_viewer_until(&None, viewer.id.clone(), &public_user, None, &Utc::now())
+        .unwrap();
+
+    chain_custody
+        .set_visibility(&Some("savings".into()), Visibility::PRIVATE, &public_user)
+        .unwrap();
+
+    let keys = chain_custody.as_public(&viewer).keys;
+    assert_eq!(keys.len(), 1);
+    assert_eq!(keys[0].alias, "payments");
+}
+
+#[test]
+fn key_editor_signs_and_removes_only_that_key() {
+    let public_user = PublicUser::new("blah blah blah".into(), None, None, None, vec![]);
+    let mut accountant = PublicUser::new("accountant".into(), None, None, None, vec![]);
+    accountant.groups = vec!["payroll".into()];
+    let mut chain_custody = custody_with_keys(&public_user, vec!["payments", "savings"]);
+    let at = Utc::now();
+    chain_custody
+        .set_group(
+            &Some("payments".into()),
+            &"payroll".into(),
+            GrantRole::EDITOR,
+            true,
+            &public_user,
+            &at,
+        )
+        .unwrap();
+
+    let (public_key, signature) = chain_custody
+        .sign("payments", b"invoice", &accountant, &at)
+        .unwrap();
+    assert!(is_valid_message(b"invoice", &signature, &public_key));
+    let denied = chain_custody.sign("savings", b"invoice", &accountant, &at);
+    assert_eq!(denied.unwrap_err().status, Code::PermissionDenied);
+
+    let denied = chain_custody.remove(&"savings".into(), &accountant, &at);
+    assert_eq!(denied.unwrap_err().status, Code::PermissionDenied);
+    chain_custody.remove(&"payments".into(), &accountant, &at).unwrap();
+    assert!(!chain_custody.has_key(&"payments".into()));
+    assert!(chain_custody.key_permissions.is_empty());
+    assert_eq!(chain_custody.as_public(&public_user).keys.len(), 1);
+}
+
+#[test]
+fn key_editor_grant_signs_only_with_that_key() {
+    let public_user = PublicUser::new("blah blah blah".into(), None, None, None, vec![]);
+    let accountant = PublicUser::new("accountant".into(), None, None, None, vec![]);
+    let mut chain_custody = custody_with_keys(&public_user, vec!["payments", "savings"]);
+    let at = Utc::now();
+    chain_custody
+        .add_editor_until(
+            &Some("payments".into()),
+            accountant.id.clone(),
+            &public_user,
+            None,
+            &at,
+        )
+        .unwrap();
+
+    let (public_key, signature) = chain_custody
+        .sign("payments", b"invoice", &accountant, &at)
+        .unwrap();
+    assert!(is_valid_message(b"invoice", &signature, &public_key));
+    let denied = chain_custody.sign("savings", b"invoice", &accountant, &at);
+    assert_eq!(denied.unwrap_err().status, Code::PermissionDenied);
+}
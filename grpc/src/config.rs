@@ -7,4 +7,8 @@ pub struct Config {
 
     #[envconfig(from = "EXTERNAL_GRPC_PORT", default = "9000")]
     pub external_grpc_port: u16,
+
+    // public key of the sst, national identifiers are sealed to it before they are gossiped
+    #[envconfig(from = "SST_IDENTITY_PUBLIC_KEY")]
+    pub sst_identity_public_key: Option<String>,
 }
\ No newline at end of file
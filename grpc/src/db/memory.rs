@@ -0,0 +1,530 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use tokio::sync::RwLock;
+use tonic::Code;
+
+use crate::{
+    audit::access_record::AccessRecord,
+    errors::BecoError,
+    merkle::{
+        frontier::MerkleFrontier,
+        transaction::{GroupTransaction, OrganisationTransaction, Transaction},
+    },
+    group::group::Group,
+    organisation::organisation::Organisation,
+    user::user::User,
+};
+
+use super::storage::{CommittedRequest, Storage};
+
+#[derive(Debug, Clone)]
+struct StoredUser {
+    details: Value,
+    sequence: i64,
+    next_expiry: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone)]
+struct StoredTransaction {
+    id: i64,
+    transaction: Value,
+    user_id: String,
+    sequence: i64,
+    merkle_root_hex: String,
+    merkle_frontier: MerkleFrontier,
+    request_id: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct StoredLeaf {
+    content: [u8; 32],
+    user_id: String,
+    transaction_id: i64,
+}
+
+#[derive(Debug, Clone)]
+struct StoredOrganisation {
+    details: Value,
+    sequence: i64,
+    merkle_frontier: MerkleFrontier,
+}
+
+#[derive(Debug, Clone)]
+struct StoredOrganisationTransaction {
+    transaction: Value,
+    organisation_id: String,
+    sequence: i64,
+    request_id: String,
+}
+
+#[derive(Debug, Clone)]
+struct StoredGroupTransaction {
+    transaction: Value,
+    group_id: String,
+    sequence: i64,
+    request_id: String,
+}
+
+#[derive(Debug, Default)]
+pub struct MemoryStorage {
+    users: RwLock<HashMap<String, StoredUser>>,
+    transactions: RwLock<Vec<StoredTransaction>>,
+    leaves: RwLock<Vec<StoredLeaf>>,
+    national_identifiers: RwLock<HashMap<String, String>>,
+    organisations: RwLock<HashMap<String, StoredOrganisation>>,
+    organisation_transactions: RwLock<Vec<StoredOrganisationTransaction>>,
+    groups: RwLock<HashMap<String, Value>>,
+    group_transactions: RwLock<Vec<StoredGroupTransaction>>,
+    access_records: RwLock<Vec<AccessRecord>>,
+}
+
+impl MemoryStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub async fn transaction_count(&self, user_id: &String) -> usize {
+        self.transactions
+            .read()
+            .await
+            .iter()
+            .filter(|transaction| &transaction.user_id == user_id)
+            .count()
+    }
+
+    pub async fn merkle_root_hex(&self, user_id: &String) -> Option<String> {
+        self.transactions
+            .read()
+            .await
+            .iter()
+            .filter(|transaction| &transaction.user_id == user_id)
+            .max_by_key(|transaction| transaction.sequence)
+            .map(|transaction| transaction.merkle_root_hex.clone())
+    }
+
+    pub async fn organisation_transaction_count(&self, organisation_id: &String) -> usize {
+        self.organisation_transactions
+            .read()
+            .await
+            .iter()
+            .filter(|transaction| &transaction.organisation_id == organisation_id)
+            .count()
+    }
+}
+
+#[tonic::async_trait]
+impl Storage for MemoryStorage {
+    async fn load_user(&self, user_id: &str) -> Result<User, BecoError> {
+        let users = self.users.read().await;
+        let stored_user_option = users.get(user_id);
+        if stored_user_option.is_none() {
+            return Err(BecoError {
+                message: format!("User not found: {user_id}"),
+                status: Code::NotFound,
+            });
+        }
+        Ok(serde_json::from_value(stored_user_option.unwrap().details.clone()).unwrap())
+    }
+
+    async fn load_merkle(&self, user_id: &str) -> Result<Option<MerkleFrontier>, BecoError> {
+        Ok(self
+            .transactions
+            .read()
+            .await
+            .iter()
+            .filter(|transaction| transaction.user_id == user_id)
+            .max_by_key(|transaction| transaction.sequence)
+            .map(|transaction| transaction.merkle_frontier.clone()))
+    }
+
+    async fn load_transactions(
+        &self,
+        user_id: &str,
+        from_sequence: i64,
+        to_sequence: i64,
+    ) -> Result<Vec<Transaction>, BecoError> {
+        let mut transactions: Vec<StoredTransaction> = self
+            .transactions
+            .read()
+            .await
+            .iter()
+            .filter(|transaction| {
+                transaction.user_id == user_id
+                    && transaction.sequence >= from_sequence
+                    && transaction.sequence <= to_sequence
+            })
+            .cloned()
+            .collect();
+        transactions.sort_by_key(|transaction| transaction.sequence);
+        Ok(transactions
+            .into_iter()
+            .map(|transaction| serde_json::from_value(transaction.transaction).unwrap())
+            .collect())
+    }
+
+    async fn load_transaction_for_request(
+        &self,
+        request_id: &str,
+    ) -> Result<Option<(Transaction, String)>, BecoError> {
+        Ok(self
+            .transactions
+            .read()
+            .await
+            .iter()
+            .find(|transaction| transaction.request_id.as_deref() == Some(request_id))
+            .map(|transaction| {
+                (
+                    serde_json::from_value(transaction.transaction.clone()).unwrap(),
+                    transaction.merkle_root_hex.clone(),
+                )
+            }))
+    }
+
+    async fn load_user_ids(&self) -> Result<Vec<String>, BecoError> {
+        Ok(self.users.read().await.keys().cloned().collect())
+    }
+
+    async fn load_due_user_ids(&self, at: &DateTime<Utc>) -> Result<Vec<String>, BecoError> {
+        let users = self.users.read().await;
+        let mut due: Vec<(&DateTime<Utc>, &String)> = users
+            .iter()
+            .filter_map(|(user_id, user)| Some((user.next_expiry.as_ref()?, user_id)))
+            .filter(|(next_expiry, _)| *next_expiry <= at)
+            .collect();
+        due.sort();
+        Ok(due.into_iter().map(|(_, user_id)| user_id.clone()).collect())
+    }
+
+    async fn save_next_expiry(
+        &self,
+        user_id: &str,
+        next_expiry: Option<DateTime<Utc>>,
+    ) -> Result<(), BecoError> {
+        if let Some(user) = self.users.write().await.get_mut(user_id) {
+            user.next_expiry = next_expiry;
+        }
+        Ok(())
+    }
+
+    async fn user_for_blind_index(&self, blind_index: &str) -> Result<Option<String>, BecoError> {
+        Ok(self
+            .national_identifiers
+            .read()
+            .await
+            .get(blind_index)
+            .cloned())
+    }
+
+    async fn load_transaction_rows(&self, user_id: &str) -> Result<Vec<(i64, Value)>, BecoError> {
+        let mut transactions: Vec<StoredTransaction> = self
+            .transactions
+            .read()
+            .await
+            .iter()
+            .filter(|transaction| transaction.user_id == user_id)
+            .cloned()
+            .collect();
+        transactions.sort_by_key(|transaction| transaction.sequence);
+        Ok(transactions
+            .into_iter()
+            .map(|transaction| (transaction.id, transaction.transaction))
+            .collect())
+    }
+
+    async fn rebuild_user(
+        &self,
+        user_id: &str,
+        serialised_user: &Value,
+        sequence: i64,
+        next_expiry: Option<DateTime<Utc>>,
+        leaves: Vec<(i64, [u8; 32], MerkleFrontier)>,
+    ) -> Result<(), BecoError> {
+        self.users.write().await.insert(
+            user_id.to_string(),
+            StoredUser {
+                details: serialised_user.clone(),
+                sequence,
+                next_expiry,
+            },
+        );
+        let mut transactions = self.transactions.write().await;
+        let mut stored_leaves = self.leaves.write().await;
+        stored_leaves.retain(|leaf| leaf.user_id != user_id);
+        for (transaction_id, content, frontier) in leaves {
+            stored_leaves.push(StoredLeaf {
+                content,
+                user_id: user_id.to_string(),
+                transaction_id,
+            });
+            let transaction_option = transactions
+                .iter_mut()
+                .find(|transaction| transaction.id == transaction_id);
+            if let Some(transaction) = transaction_option {
+                transaction.merkle_root_hex = frontier.root_hex().unwrap_or_default();
+                transaction.merkle_frontier = frontier;
+            }
+        }
+        Ok(())
+    }
+
+    async fn save_user_request_and_merkle(
+        &self,
+        user_id: &str,
+        serialised_user: &Value,
+        request: CommittedRequest<'_>,
+        next_expiry: Option<DateTime<Utc>>,
+        frontier: &MerkleFrontier,
+        blind_index: Option<&String>,
+    ) -> Result<(), BecoError> {
+        let CommittedRequest {
+            request_id,
+            sequence,
+            serialised_transaction,
+            leaf,
+            root,
+        } = request;
+        // take every lock up front so the write is all or nothing, like the postgres transaction
+        let mut users = self.users.write().await;
+        let mut transactions = self.transactions.write().await;
+        let mut stored_leaves = self.leaves.write().await;
+        let mut national_identifiers = self.national_identifiers.write().await;
+
+        let is_duplicate_sequence = transactions
+            .iter()
+            .any(|transaction| transaction.user_id == user_id && transaction.sequence == sequence);
+        let is_duplicate_request = transactions
+            .iter()
+            .any(|transaction| transaction.request_id.as_deref() == Some(request_id));
+        if is_duplicate_request {
+            return Err(BecoError {
+                message: format!("Request {request_id} has already been committed"),
+                status: Code::AlreadyExists,
+            });
+        }
+        if is_duplicate_sequence {
+            return Err(BecoError {
+                message: format!("Sequence {sequence} already exists for user {user_id}"),
+                status: Code::AlreadyExists,
+            });
+        }
+        if blind_index.is_some() && national_identifiers.contains_key(blind_index.unwrap()) {
+            return Err(BecoError {
+                message: "National identifier is already registered".into(),
+                status: Code::AlreadyExists,
+            });
+        }
+        users.insert(
+            user_id.to_string(),
+            StoredUser {
+                details: serialised_user.clone(),
+                sequence,
+                next_expiry,
+            },
+        );
+        let transaction_id = transactions.len() as i64 + 1;
+        transactions.push(StoredTransaction {
+            id: transaction_id,
+            transaction: serialised_transaction.clone(),
+            user_id: user_id.to_string(),
+            sequence,
+            merkle_root_hex: root.to_string(),
+            merkle_frontier: frontier.clone(),
+            request_id: Some(request_id.to_string()),
+        });
+        stored_leaves.push(StoredLeaf {
+            content: leaf,
+            user_id: user_id.to_string(),
+            transaction_id,
+        });
+        if let Some(blind_index) = blind_index {
+            national_identifiers.insert(blind_index.clone(), user_id.to_string());
+        }
+        Ok(())
+    }
+
+    async fn load_organisation(
+        &self,
+        organisation_id: &str,
+    ) -> Result<Option<(Organisation, MerkleFrontier)>, BecoError> {
+        Ok(self
+            .organisations
+            .read()
+            .await
+            .get(organisation_id)
+            .map(|stored_organisation| {
+                (
+                    serde_json::from_value(stored_organisation.details.clone()).unwrap(),
+                    stored_organisation.merkle_frontier.clone(),
+                )
+            }))
+    }
+
+    async fn load_organisations_for_member(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<Organisation>, BecoError> {
+        Ok(self
+            .organisations
+            .read()
+            .await
+            .values()
+            .map(|stored_organisation| {
+                serde_json::from_value::<Organisation>(stored_organisation.details.clone()).unwrap()
+            })
+            .filter(|organisation| {
+                organisation
+                    .memberships()
+                    .iter()
+                    .any(|membership| membership.user_id == user_id)
+            })
+            .collect())
+    }
+
+    async fn load_organisation_transaction_for_request(
+        &self,
+        request_id: &str,
+    ) -> Result<Option<OrganisationTransaction>, BecoError> {
+        Ok(self
+            .organisation_transactions
+            .read()
+            .await
+            .iter()
+            .find(|transaction| transaction.request_id == request_id)
+            .map(|transaction| serde_json::from_value(transaction.transaction.clone()).unwrap()))
+    }
+
+    async fn save_organisation_request_and_merkle(
+        &self,
+        organisation_id: &str,
+        serialised_organisation: &Value,
+        request: CommittedRequest<'_>,
+        frontier: &MerkleFrontier,
+    ) -> Result<(), BecoError> {
+        let CommittedRequest {
+            request_id,
+            sequence,
+            serialised_transaction,
+            ..
+        } = request;
+        let mut organisations = self.organisations.write().await;
+        let mut transactions = self.organisation_transactions.write().await;
+
+        let is_duplicate = transactions.iter().any(|transaction| {
+            transaction.request_id == request_id
+                || (transaction.organisation_id == organisation_id
+                    && transaction.sequence == sequence)
+        });
+        if is_duplicate {
+            return Err(BecoError {
+                message: format!("Request {request_id} has already been committed"),
+                status: Code::AlreadyExists,
+            });
+        }
+        organisations.insert(
+            organisation_id.to_string(),
+            StoredOrganisation {
+                details: serialised_organisation.clone(),
+                sequence,
+                merkle_frontier: frontier.clone(),
+            },
+        );
+        transactions.push(StoredOrganisationTransaction {
+            transaction: serialised_transaction.clone(),
+            organisation_id: organisation_id.to_string(),
+            sequence,
+            request_id: request_id.to_string(),
+        });
+        Ok(())
+    }
+    async fn load_group(&self, group_id: &str) -> Result<Option<Group>, BecoError> {
+        Ok(self
+            .groups
+            .read()
+            .await
+            .get(group_id)
+            .map(|details| serde_json::from_value(details.clone()).unwrap()))
+    }
+
+    async fn load_groups_for_member(&self, user_id: &str) -> Result<Vec<Group>, BecoError> {
+        Ok(self
+            .groups
+            .read()
+            .await
+            .values()
+            .map(|details| serde_json::from_value::<Group>(details.clone()).unwrap())
+            .filter(|group| group.members().contains(user_id))
+            .collect())
+    }
+
+    async fn load_group_transaction_for_request(
+        &self,
+        request_id: &str,
+    ) -> Result<Option<GroupTransaction>, BecoError> {
+        Ok(self
+            .group_transactions
+            .read()
+            .await
+            .iter()
+            .find(|transaction| transaction.request_id == request_id)
+            .map(|transaction| serde_json::from_value(transaction.transaction.clone()).unwrap()))
+    }
+
+    async fn save_group_request(
+        &self,
+        group_id: &str,
+        serialised_group: &Value,
+        serialised_transaction: &Value,
+        request_id: &str,
+        sequence: i64,
+    ) -> Result<(), BecoError> {
+        let mut groups = self.groups.write().await;
+        let mut transactions = self.group_transactions.write().await;
+
+        let is_duplicate = transactions.iter().any(|transaction| {
+            transaction.request_id == request_id
+                || (transaction.group_id == group_id && transaction.sequence == sequence)
+        });
+        if is_duplicate {
+            return Err(BecoError {
+                message: format!("Request {request_id} has already been committed"),
+                status: Code::AlreadyExists,
+            });
+        }
+        groups.insert(group_id.to_string(), serialised_group.clone());
+        transactions.push(StoredGroupTransaction {
+            transaction: serialised_transaction.clone(),
+            group_id: group_id.to_string(),
+            sequence,
+            request_id: request_id.to_string(),
+        });
+        Ok(())
+    }
+    async fn save_access_record(&self, record: &AccessRecord) -> Result<(), BecoError> {
+        let mut access_records = self.access_records.write().await;
+        if access_records.iter().any(|saved| saved.id == record.id) {
+            return Ok(());
+        }
+        access_records.push(record.clone());
+        Ok(())
+    }
+
+    async fn load_access_records(
+        &self,
+        user_id: &str,
+        since: Option<DateTime<Utc>>,
+    ) -> Result<Vec<AccessRecord>, BecoError> {
+        let mut records: Vec<AccessRecord> = self
+            .access_records
+            .read()
+            .await
+            .iter()
+            .filter(|record| record.user_id == user_id)
+            .filter(|record| since.is_none_or(|since| record.read_at >= since))
+            .cloned()
+            .collect();
+        records.sort_by_key(|record| record.read_at);
+        Ok(records)
+    }
+}
@@ -0,0 +1,68 @@
+// Migrations are embedded in the sst binary and applied in version order at startup.
+// Never edit a migration once it has been released, add a new one instead.
+
+pub struct Migration {
+    pub version: i32,
+    pub name: &'static str,
+    pub sql: &'static str,
+}
+
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "initial_schema",
+        sql: include_str!("sql/001_initial_schema.sql"),
+    },
+    Migration {
+        version: 2,
+        name: "history_indexes",
+        sql: include_str!("sql/002_history_indexes.sql"),
+    },
+    Migration {
+        version: 3,
+        name: "merkle_frontier",
+        sql: include_str!("sql/003_merkle_frontier.sql"),
+    },
+    Migration {
+        version: 4,
+        name: "transaction_request_id",
+        sql: include_str!("sql/004_transaction_request_id.sql"),
+    },
+    Migration {
+        version: 5,
+        name: "national_identifier",
+        sql: include_str!("sql/005_national_identifier.sql"),
+    },
+    Migration {
+        version: 6,
+        name: "organisation",
+        sql: include_str!("sql/006_organisation.sql"),
+    },
+    Migration {
+        version: 7,
+        name: "grantee_ids",
+        sql: include_str!("sql/007_grantee_ids.sql"),
+    },
+    Migration {
+        version: 8,
+        name: "group",
+        sql: include_str!("sql/008_group.sql"),
+    },
+    Migration {
+        version: 9,
+        name: "access_log",
+        sql: include_str!("sql/009_access_log.sql"),
+    },
+    Migration {
+        version: 10,
+        name: "next_expiry",
+        sql: include_str!("sql/010_next_expiry.sql"),
+    },
+];
+
+pub fn pending(applied: &[i32]) -> Vec<&'static Migration> {
+    MIGRATIONS
+        .iter()
+        .filter(|migration| !applied.contains(&migration.version))
+        .collect()
+}
@@ -0,0 +1,7 @@
+----------- INDEXES -----------
+
+CREATE INDEX IF NOT EXISTS transaction_user_sequence_idx
+    ON personal.transaction (user_id, sequence_number);
+
+CREATE INDEX IF NOT EXISTS leaf_user_created_at_idx
+    ON personal.leaf (user_id, created_at);
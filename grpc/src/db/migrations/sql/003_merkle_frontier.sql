@@ -0,0 +1,6 @@
+----------- MERKLE FRONTIER -----------
+
+-- right edge of each user's merkle tree after the transaction was appended, so the tree can be
+-- extended without reading every leaf. Older rows stay NULL and are rebuilt from personal.leaf
+ALTER TABLE personal.transaction
+    ADD COLUMN IF NOT EXISTS merkle_frontier JSONB;
@@ -0,0 +1,9 @@
+----------- REQUEST ID -----------
+
+-- id of the process request that produced the transaction. Gossipsub can deliver the same
+-- request more than once, the unique index stops it being committed twice
+ALTER TABLE personal.transaction
+    ADD COLUMN IF NOT EXISTS request_id VARCHAR(32);
+
+CREATE UNIQUE INDEX IF NOT EXISTS transaction_request_id_key
+    ON personal.transaction (request_id);
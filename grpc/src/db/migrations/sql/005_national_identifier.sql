@@ -0,0 +1,13 @@
+----------- NATIONAL IDENTIFIER -----------
+
+-- keyed, salted blind index of each user's national identifier. The raw identifier is never
+-- stored, the primary key rejects a second user with the same identifier
+CREATE TABLE IF NOT EXISTS personal.national_identifier (
+    blind_index VARCHAR(64) PRIMARY KEY,
+    user_id UUID NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT fk_user
+        FOREIGN KEY(user_id)
+        REFERENCES personal.user(id)
+        ON DELETE NO ACTION
+);
@@ -0,0 +1,32 @@
+----------- ORGANISATION -----------
+
+-- organisations have their own transaction log and merkle tree, like users
+CREATE TABLE IF NOT EXISTS personal.organisation (
+    id UUID PRIMARY KEY,
+    details JSONB NOT NULL,
+    sequence_number BIGINT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- finds the organisations a user is or was a member of
+CREATE INDEX IF NOT EXISTS organisation_memberships_idx
+    ON personal.organisation USING GIN ((details->'memberships') jsonb_path_ops);
+
+CREATE TABLE IF NOT EXISTS personal.organisation_transaction (
+    id BIGSERIAL PRIMARY KEY,
+    transaction JSONB NOT NULL,
+    organisation_id UUID NOT NULL,
+    sequence_number BIGINT NOT NULL,
+    merkle_root_hex VARCHAR(100) NOT NULL,
+    merkle_frontier JSONB NOT NULL,
+    request_id VARCHAR(32) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT fk_organisation
+        FOREIGN KEY(organisation_id)
+        REFERENCES personal.organisation(id)
+        ON DELETE NO ACTION,
+    CONSTRAINT organisation_transaction_sequence_key
+        UNIQUE (organisation_id, sequence_number),
+    CONSTRAINT organisation_transaction_request_id_key
+        UNIQUE (request_id)
+);
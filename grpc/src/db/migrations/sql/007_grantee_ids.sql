@@ -0,0 +1,39 @@
+----------- GRANTEE IDS -----------
+
+-- permission models kept a copy of each viewer and editor, they now only keep the grantee's id.
+-- Transactions are left alone as their json is part of the merkle tree, the sst reads both
+CREATE OR REPLACE FUNCTION personal.grantee_ids(details JSONB) RETURNS JSONB AS $$
+DECLARE
+    result JSONB;
+BEGIN
+    IF jsonb_typeof(details) = 'array' THEN
+        SELECT COALESCE(jsonb_agg(personal.grantee_ids(element.value) ORDER BY element.ordinality), '[]'::JSONB)
+            INTO result
+            FROM jsonb_array_elements(details) WITH ORDINALITY AS element;
+        RETURN result;
+    END IF;
+    IF jsonb_typeof(details) <> 'object' THEN
+        RETURN details;
+    END IF;
+    IF details ? 'owner_id' AND details ? 'viewers' AND details ? 'editors' THEN
+        RETURN details || jsonb_build_object(
+            'viewers', (
+                SELECT COALESCE(jsonb_agg(COALESCE(grantee.value->'id', grantee.value) ORDER BY grantee.ordinality), '[]'::JSONB)
+                FROM jsonb_array_elements(details->'viewers') WITH ORDINALITY AS grantee
+            ),
+            'editors', (
+                SELECT COALESCE(jsonb_agg(COALESCE(grantee.value->'id', grantee.value) ORDER BY grantee.ordinality), '[]'::JSONB)
+                FROM jsonb_array_elements(details->'editors') WITH ORDINALITY AS grantee
+            )
+        );
+    END IF;
+    SELECT COALESCE(jsonb_object_agg(field.key, personal.grantee_ids(field.value)), '{}'::JSONB)
+        INTO result
+        FROM jsonb_each(details) AS field;
+    RETURN result;
+END;
+$$ LANGUAGE plpgsql IMMUTABLE;
+
+UPDATE personal.user SET details = personal.grantee_ids(details);
+
+DROP FUNCTION personal.grantee_ids(JSONB);
@@ -0,0 +1,30 @@
+----------- GROUP -----------
+
+-- named sets of users that can be granted a role on fields, owned by a user or an organisation
+CREATE TABLE IF NOT EXISTS personal.group (
+    id UUID PRIMARY KEY,
+    details JSONB NOT NULL,
+    sequence_number BIGINT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- resolves the groups of a calling user when its grants are checked
+CREATE INDEX IF NOT EXISTS group_members_idx
+    ON personal.group USING GIN ((details->'members') jsonb_path_ops);
+
+CREATE TABLE IF NOT EXISTS personal.group_transaction (
+    id BIGSERIAL PRIMARY KEY,
+    transaction JSONB NOT NULL,
+    group_id UUID NOT NULL,
+    sequence_number BIGINT NOT NULL,
+    request_id VARCHAR(32) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT fk_group
+        FOREIGN KEY(group_id)
+        REFERENCES personal.group(id)
+        ON DELETE NO ACTION,
+    CONSTRAINT group_transaction_sequence_key
+        UNIQUE (group_id, sequence_number),
+    CONSTRAINT group_transaction_request_id_key
+        UNIQUE (request_id)
+);
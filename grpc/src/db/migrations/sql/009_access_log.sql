@@ -0,0 +1,17 @@
+----------- ACCESS LOG -----------
+
+-- reads of a user's fields by someone else, shipped by the user node that served them
+CREATE TABLE IF NOT EXISTS personal.access_log (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL,
+    details JSONB NOT NULL,
+    read_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT fk_user
+        FOREIGN KEY(user_id)
+        REFERENCES personal.user(id)
+        ON DELETE NO ACTION
+);
+
+CREATE INDEX IF NOT EXISTS access_log_user_read_at_idx
+    ON personal.access_log (user_id, read_at);
@@ -0,0 +1,11 @@
+----------- NEXT EXPIRY -----------
+
+-- the earliest deadline the sst sweeps a user for, NULL when it has none. Existing users start
+-- due so the first sweep works theirs out
+ALTER TABLE personal.user ADD COLUMN IF NOT EXISTS next_expiry TIMESTAMP WITH TIME ZONE;
+
+UPDATE personal.user SET next_expiry = 'epoch';
+
+CREATE INDEX IF NOT EXISTS user_next_expiry_idx
+    ON personal.user (next_expiry)
+    WHERE next_expiry IS NOT NULL;
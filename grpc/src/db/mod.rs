@@ -1,6 +1,12 @@
 mod queries;
 mod config;
+pub mod memory;
+pub mod migrations;
+pub mod storage;
 
+mod tests;
+
+use chrono::{DateTime, Utc};
 use config::Config as db_config;
 use deadpool_postgres::{Config, Object, Pool, PoolConfig, Runtime};
 use envconfig::Envconfig;
@@ -9,7 +15,19 @@ use tokio_postgres::{NoTls, Statement};
 use tonic::Code;
 use uuid::Uuid;
 
-use crate::{errors::BecoError, user::user::User};
+use crate::{
+    audit::access_record::AccessRecord,
+    errors::BecoError,
+    merkle::{
+        frontier::MerkleFrontier,
+        transaction::{GroupTransaction, OrganisationTransaction, Transaction},
+    },
+    group::group::Group,
+    organisation::organisation::Organisation,
+    user::user::User,
+};
+
+use self::storage::{CommittedRequest, Storage};
 
 pub struct DB {
     pool: Pool,
@@ -33,14 +51,67 @@ impl DB {
         Self { pool, config: clone_config }
     }
 
-    pub async fn load_user(&self, user_id: &String) -> Result<User, BecoError> {
-        let client = self.pool.get().await.unwrap();
-        self.load_user_using_client(user_id, &client).await
+    // applies every embedded migration that has not been recorded yet and returns their versions
+    pub async fn migrate(&self) -> Result<Vec<i32>, BecoError> {
+        let mut client = self.pool.get().await.unwrap();
+
+        // the advisory lock stops two sst nodes migrating at the same time, it is taken before the
+        // version table is created so they do not race on that either
+        let db_transaction = client.transaction().await.unwrap();
+        let lock_result = db_transaction.execute(queries::migration::LOCK, &[]).await;
+        if let Err(err) = lock_result {
+            return Err(err.into());
+        }
+        let create_table_result = db_transaction
+            .batch_execute(queries::migration::CREATE_TABLE)
+            .await;
+        if let Err(err) = create_table_result {
+            return Err(err.into());
+        }
+        let rows_result = db_transaction.query(queries::migration::SELECT, &[]).await;
+        if let Err(err) = rows_result {
+            return Err(err.into());
+        }
+        let applied: Vec<i32> = rows_result
+            .unwrap()
+            .iter()
+            .map(|row| row.get::<&str, i32>("version"))
+            .collect();
+
+        let mut newly_applied: Vec<i32> = vec![];
+        for migration in migrations::pending(&applied) {
+            let migration_result = db_transaction.batch_execute(migration.sql).await;
+            if let Err(err) = migration_result {
+                return Err(BecoError {
+                    message: format!(
+                        "Failed to apply migration {} ({}): {err}",
+                        migration.version, migration.name
+                    ),
+                    status: Code::Internal,
+                });
+            }
+            let insert_result = db_transaction
+                .execute(
+                    queries::migration::INSERT,
+                    &[&migration.version, &migration.name],
+                )
+                .await;
+            if let Err(err) = insert_result {
+                return Err(err.into());
+            }
+            newly_applied.push(migration.version);
+        }
+
+        let commit_result = db_transaction.commit().await;
+        if let Err(err) = commit_result {
+            return Err(err.into());
+        }
+        Ok(newly_applied)
     }
 
     pub async fn load_user_using_client(
         &self,
-        user_id: &String,
+        user_id: &str,
         client: &Object,
     ) -> Result<User, BecoError> {
         let user_uuid = Uuid::parse_str(&user_id).unwrap();
@@ -56,46 +127,397 @@ impl DB {
         Ok(serde_json::from_value(row.get("details")).unwrap())
     }
 
-    pub async fn load_merkle(&self, user_id: &String) -> Result<Vec<[u8; 32]>, BecoError> {
-        let client = self.pool.get().await.unwrap();
-        self.load_merkle_with_client(user_id, &client).await
-    }
-
     pub async fn load_merkle_with_client(
         &self,
-        user_id: &String,
+        user_id: &str,
         client: &Object,
-    ) -> Result<Vec<[u8; 32]>, BecoError> {
+    ) -> Result<Option<MerkleFrontier>, BecoError> {
+        let user_uuid_result = Uuid::parse_str(user_id);
+        if user_uuid_result.is_err() {
+            return Err(BecoError {
+                message: "Invalid user ID".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        let user_uuid = user_uuid_result.unwrap();
         let select_merkle_statement = client
             .prepare_cached(queries::merkle::SELECT)
             .await
             .unwrap();
         let row_result = client
-            .query_one(&select_merkle_statement, &[&user_id])
+            .query_opt(&select_merkle_statement, &[&user_uuid])
             .await;
         if row_result.is_err() {
             return Err(row_result.unwrap_err().into());
         }
-        let row = row_result.unwrap();
-        let leaves: Vec<&[u8]> = row.get::<&str, Vec<&[u8]>>("leaves");
-        let merkle_leaves: Vec<[u8; 32]> = leaves
+        let row_option = row_result.unwrap();
+        if row_option.is_none() {
+            return Ok(None);
+        }
+        let frontier_option = row_option
+            .unwrap()
+            .get::<&str, Option<Value>>("merkle_frontier");
+        if let Some(frontier) = frontier_option {
+            let frontier_result = serde_json::from_value(frontier);
+            if frontier_result.is_err() {
+                return Err(BecoError {
+                    message: "Failed to deserialize the merkle frontier".into(),
+                    status: Code::Internal,
+                });
+            }
+            return Ok(Some(frontier_result.unwrap()));
+        }
+
+        // transactions written before the frontier was stored, rebuild it from the leaves once
+        let select_leaves_statement = client
+            .prepare_cached(queries::merkle::SELECT_LEAVES)
+            .await
+            .unwrap();
+        let rows_result = client
+            .query(&select_leaves_statement, &[&user_uuid])
+            .await;
+        if let Err(err) = rows_result {
+            return Err(err.into());
+        }
+        let leaves: Vec<[u8; 32]> = rows_result
+            .unwrap()
             .iter()
-            .map(|&leaf| leaf[0..32].try_into().unwrap())
+            .map(|row| row.get::<&str, &[u8]>("content")[0..32].try_into().unwrap())
             .collect();
-        Ok(merkle_leaves)
+        Ok(Some(MerkleFrontier::from_leaves(&leaves)))
+    }
+
+    async fn prepare_statements(queries: Vec<&str>, client: &Object) -> Vec<Statement> {
+        let mut statements: Vec<Statement> = vec![];
+        for query in queries {
+            let statement = client.prepare_cached(query).await.unwrap();
+            statements.push(statement);
+        }
+
+        statements
+    }
+}
+
+#[tonic::async_trait]
+impl Storage for DB {
+    async fn load_user(&self, user_id: &str) -> Result<User, BecoError> {
+        let client = self.pool.get().await.unwrap();
+        self.load_user_using_client(user_id, &client).await
+    }
+
+    async fn load_merkle(&self, user_id: &str) -> Result<Option<MerkleFrontier>, BecoError> {
+        let client = self.pool.get().await.unwrap();
+        self.load_merkle_with_client(user_id, &client).await
+    }
+
+    async fn load_transactions(
+        &self,
+        user_id: &str,
+        from_sequence: i64,
+        to_sequence: i64,
+    ) -> Result<Vec<Transaction>, BecoError> {
+        let user_uuid_result = Uuid::parse_str(user_id);
+        if user_uuid_result.is_err() {
+            return Err(BecoError {
+                message: "Invalid user ID".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        let user_uuid = user_uuid_result.unwrap();
+        let client = self.pool.get().await.unwrap();
+        let select_transactions_statement = client
+            .prepare_cached(queries::transaction::SELECT_RANGE)
+            .await
+            .unwrap();
+        let rows_result = client
+            .query(
+                &select_transactions_statement,
+                &[&user_uuid, &from_sequence, &to_sequence],
+            )
+            .await;
+        if let Err(err) = rows_result {
+            return Err(err.into());
+        }
+        let mut transactions: Vec<Transaction> = vec![];
+        for row in rows_result.unwrap() {
+            let transaction_result = serde_json::from_value(row.get("transaction"));
+            if transaction_result.is_err() {
+                return Err(BecoError {
+                    message: "Failed to deserialize the transaction".into(),
+                    status: Code::Internal,
+                });
+            }
+            transactions.push(transaction_result.unwrap());
+        }
+        Ok(transactions)
+    }
+
+    async fn load_transaction_for_request(
+        &self,
+        request_id: &str,
+    ) -> Result<Option<(Transaction, String)>, BecoError> {
+        let client = self.pool.get().await.unwrap();
+        let select_transaction_statement = client
+            .prepare_cached(queries::transaction::SELECT_BY_REQUEST_ID)
+            .await
+            .unwrap();
+        let row_result = client
+            .query_opt(&select_transaction_statement, &[&request_id])
+            .await;
+        if let Err(err) = row_result {
+            return Err(err.into());
+        }
+        let row_option = row_result.unwrap();
+        if row_option.is_none() {
+            return Ok(None);
+        }
+        let row = row_option.unwrap();
+        let transaction_result = serde_json::from_value(row.get("transaction"));
+        if transaction_result.is_err() {
+            return Err(BecoError {
+                message: "Failed to deserialize the transaction".into(),
+                status: Code::Internal,
+            });
+        }
+        Ok(Some((
+            transaction_result.unwrap(),
+            row.get::<&str, String>("merkle_root_hex"),
+        )))
+    }
+
+    async fn user_for_blind_index(
+        &self,
+        blind_index: &str,
+    ) -> Result<Option<String>, BecoError> {
+        let client = self.pool.get().await.unwrap();
+        let select_user_id_statement = client
+            .prepare_cached(queries::national_identifier::SELECT_USER_ID)
+            .await
+            .unwrap();
+        let row_result = client
+            .query_opt(&select_user_id_statement, &[&blind_index])
+            .await;
+        if let Err(err) = row_result {
+            return Err(err.into());
+        }
+        Ok(row_result
+            .unwrap()
+            .map(|row| row.get::<&str, Uuid>("user_id").to_string()))
+    }
+
+    async fn load_user_ids(&self) -> Result<Vec<String>, BecoError> {
+        let client = self.pool.get().await.unwrap();
+        let select_ids_statement = client
+            .prepare_cached(queries::user::SELECT_IDS)
+            .await
+            .unwrap();
+        let rows_result = client.query(&select_ids_statement, &[]).await;
+        if let Err(err) = rows_result {
+            return Err(err.into());
+        }
+        Ok(rows_result
+            .unwrap()
+            .iter()
+            .map(|row| row.get::<&str, Uuid>("id").to_string())
+            .collect())
+    }
+
+    async fn load_due_user_ids(&self, at: &DateTime<Utc>) -> Result<Vec<String>, BecoError> {
+        let client = self.pool.get().await.unwrap();
+        let select_due_ids_statement = client
+            .prepare_cached(queries::user::SELECT_DUE_IDS)
+            .await
+            .unwrap();
+        let rows_result = client
+            .query(&select_due_ids_statement, &[&at.to_rfc3339()])
+            .await;
+        if let Err(err) = rows_result {
+            return Err(err.into());
+        }
+        Ok(rows_result
+            .unwrap()
+            .iter()
+            .map(|row| row.get::<&str, Uuid>("id").to_string())
+            .collect())
+    }
+
+    async fn save_next_expiry(
+        &self,
+        user_id: &str,
+        next_expiry: Option<DateTime<Utc>>,
+    ) -> Result<(), BecoError> {
+        let user_uuid_result = Uuid::parse_str(user_id);
+        if user_uuid_result.is_err() {
+            return Err(BecoError {
+                message: "Invalid user ID".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        let client = self.pool.get().await.unwrap();
+        let update_next_expiry_statement = client
+            .prepare_cached(queries::user::UPDATE_NEXT_EXPIRY)
+            .await
+            .unwrap();
+        let next_expiry = next_expiry.map(|next_expiry| next_expiry.to_rfc3339());
+        let update_result = client
+            .execute(
+                &update_next_expiry_statement,
+                &[&user_uuid_result.unwrap(), &next_expiry],
+            )
+            .await;
+        if let Err(err) = update_result {
+            return Err(err.into());
+        }
+        Ok(())
+    }
+
+    async fn load_transaction_rows(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<(i64, Value)>, BecoError> {
+        let user_uuid_result = Uuid::parse_str(user_id);
+        if user_uuid_result.is_err() {
+            return Err(BecoError {
+                message: "Invalid user ID".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        let user_uuid = user_uuid_result.unwrap();
+        let client = self.pool.get().await.unwrap();
+        let select_transactions_statement = client
+            .prepare_cached(queries::transaction::SELECT_FOR_USER)
+            .await
+            .unwrap();
+        let rows_result = client
+            .query(&select_transactions_statement, &[&user_uuid])
+            .await;
+        if let Err(err) = rows_result {
+            return Err(err.into());
+        }
+        Ok(rows_result
+            .unwrap()
+            .iter()
+            .map(|row| (row.get::<&str, i64>("id"), row.get::<&str, Value>("transaction")))
+            .collect())
     }
 
-    pub async fn save_user_request_and_merkle(
+    // overwrites the stored snapshot, replaces every leaf of the user and rewrites the root and
+    // frontier of each transaction in one transaction
+    async fn rebuild_user(
         &self,
-        user_id: &String,
+        user_id: &str,
         serialised_user: &Value,
-        serialised_transaction: &Value,
         sequence: i64,
-        root: &String,
-        leaves: Vec<[u8; 32]>,
+        next_expiry: Option<DateTime<Utc>>,
+        leaves: Vec<(i64, [u8; 32], MerkleFrontier)>,
     ) -> Result<(), BecoError> {
         let mut client = self.pool.get().await.unwrap();
 
+        let queries = vec![
+            queries::user::INSERT,
+            queries::user::UPDATE,
+            queries::leaf::DELETE_FOR_USER,
+            queries::leaf::INSERT,
+            queries::transaction::UPDATE_MERKLE,
+        ];
+        let statements = DB::prepare_statements(queries, &client).await;
+        let [insert_user_statement, update_user_statement, delete_leaves_statement, insert_leaf_statement, update_merkle_statement] =
+            &statements[..]
+        else {
+            return Err(BecoError {
+                message: "Failed to prepare statements".into(),
+                status: Code::Internal,
+            });
+        };
+
+        let user_uuid = Uuid::parse_str(user_id).unwrap();
+        let next_expiry = next_expiry.map(|next_expiry| next_expiry.to_rfc3339());
+        let existing_user_result = self.load_user_using_client(user_id, &client).await;
+
+        let db_transaction = client.transaction().await.unwrap();
+
+        let user_upsert_result = if existing_user_result.is_err() {
+            db_transaction
+                .query(
+                    insert_user_statement,
+                    &[&user_uuid, &serialised_user, &sequence, &next_expiry],
+                )
+                .await
+        } else {
+            db_transaction
+                .query(
+                    update_user_statement,
+                    &[&user_uuid, &serialised_user, &sequence, &next_expiry],
+                )
+                .await
+        };
+        if let Err(err) = user_upsert_result {
+            return Err(err.into());
+        }
+
+        let delete_leaves_result = db_transaction
+            .query(delete_leaves_statement, &[&user_uuid])
+            .await;
+        if let Err(err) = delete_leaves_result {
+            return Err(err.into());
+        }
+
+        for (transaction_id, leaf, frontier) in leaves {
+            let leaf_content: &[u8] = &leaf;
+            let insert_leaf_result = db_transaction
+                .query(
+                    insert_leaf_statement,
+                    &[&leaf_content, &user_uuid, &transaction_id],
+                )
+                .await;
+            if let Err(err) = insert_leaf_result {
+                return Err(err.into());
+            }
+            let serialised_frontier = serde_json::to_value(&frontier).unwrap();
+            let update_merkle_result = db_transaction
+                .query(
+                    update_merkle_statement,
+                    &[&transaction_id, &frontier.root_hex(), &serialised_frontier],
+                )
+                .await;
+            if let Err(err) = update_merkle_result {
+                return Err(err.into());
+            }
+        }
+
+        let commit_result = db_transaction.commit().await;
+        if let Err(err) = commit_result {
+            return Err(err.into());
+        }
+        Ok(())
+    }
+
+    async fn save_user_request_and_merkle(
+        &self,
+        user_id: &str,
+        serialised_user: &Value,
+        request: CommittedRequest<'_>,
+        next_expiry: Option<DateTime<Utc>>,
+        frontier: &MerkleFrontier,
+        blind_index: Option<&String>,
+    ) -> Result<(), BecoError> {
+        let CommittedRequest {
+            request_id,
+            sequence,
+            serialised_transaction,
+            leaf,
+            root,
+        } = request;
+        let serialised_frontier_result = serde_json::to_value(frontier);
+        if serialised_frontier_result.is_err() {
+            return Err(BecoError {
+                message: "Failed to serialize the merkle frontier".into(),
+                status: Code::Internal,
+            });
+        }
+        let serialised_frontier = serialised_frontier_result.unwrap();
+        let mut client = self.pool.get().await.unwrap();
+
         // prepare statements
 
         let queries = vec![
@@ -103,9 +525,10 @@ impl DB {
             queries::user::UPDATE,
             queries::transaction::INSERT,
             queries::leaf::INSERT,
+            queries::national_identifier::INSERT,
         ];
         let statements = DB::prepare_statements(queries, &client).await;
-        let [insert_user_statement, update_user_statement, insert_transaction_statement, insert_leaf_statement] =
+        let [insert_user_statement, update_user_statement, insert_transaction_statement, insert_leaf_statement, insert_national_identifier_statement] =
             &statements[..]
         else {
             return Err(BecoError {
@@ -117,6 +540,7 @@ impl DB {
         // check if user exists
 
         let user_uuid = Uuid::parse_str(user_id).unwrap();
+        let next_expiry = next_expiry.map(|next_expiry| next_expiry.to_rfc3339());
         let existing_user_result = self.load_user_using_client(user_id, &client).await;
 
         // start transaction
@@ -129,14 +553,14 @@ impl DB {
             db_transaction
                 .query(
                     insert_user_statement,
-                    &[&user_uuid, &serialised_user, &sequence],
+                    &[&user_uuid, &serialised_user, &sequence, &next_expiry],
                 )
                 .await
         } else {
             db_transaction
                 .query(
                     update_user_statement,
-                    &[&user_uuid, &serialised_user, &sequence],
+                    &[&user_uuid, &serialised_user, &sequence, &next_expiry],
                 )
                 .await
         };
@@ -151,7 +575,14 @@ impl DB {
         let transaction_insert_result = db_transaction
             .query(
                 insert_transaction_statement,
-                &[&serialised_transaction, &user_uuid, &sequence, &root],
+                &[
+                    &serialised_transaction,
+                    &user_uuid,
+                    &sequence,
+                    &root,
+                    &serialised_frontier,
+                    &request_id,
+                ],
             )
             .await;
         if transaction_insert_result.is_err() {
@@ -171,7 +602,7 @@ impl DB {
 
         // insert leaf
 
-        let latest_leaf: &[u8] = leaves.last().unwrap();
+        let latest_leaf: &[u8] = &leaf;
         let insert_leaf_result = db_transaction
             .query(
                 insert_leaf_statement,
@@ -183,6 +614,20 @@ impl DB {
             return Err(insert_leaf_result.unwrap_err().into());
         }
 
+        // insert national identifier blind index, the primary key rejects duplicates
+
+        if let Some(blind_index) = blind_index {
+            let insert_national_identifier_result = db_transaction
+                .query(
+                    insert_national_identifier_statement,
+                    &[blind_index, &user_uuid],
+                )
+                .await;
+            if let Err(err) = insert_national_identifier_result {
+                return Err(err.into());
+            }
+        }
+
         // commit
 
         let commit_result = db_transaction.commit().await;
@@ -192,13 +637,435 @@ impl DB {
         Ok(())
     }
 
-    async fn prepare_statements(queries: Vec<&str>, client: &Object) -> Vec<Statement> {
-        let mut statements: Vec<Statement> = vec![];
-        for query in queries {
-            let statement = client.prepare_cached(query).await.unwrap();
-            statements.push(statement);
+    async fn load_organisation(
+        &self,
+        organisation_id: &str,
+    ) -> Result<Option<(Organisation, MerkleFrontier)>, BecoError> {
+        let organisation_uuid_result = Uuid::parse_str(organisation_id);
+        if organisation_uuid_result.is_err() {
+            return Err(BecoError {
+                message: "Invalid organisation ID".into(),
+                status: Code::InvalidArgument,
+            });
         }
+        let organisation_uuid = organisation_uuid_result.unwrap();
+        let client = self.pool.get().await.unwrap();
+        let queries = vec![
+            queries::organisation::SELECT,
+            queries::organisation::SELECT_MERKLE,
+        ];
+        let statements = DB::prepare_statements(queries, &client).await;
+        let [select_organisation_statement, select_merkle_statement] = &statements[..] else {
+            return Err(BecoError {
+                message: "Failed to prepare statements".into(),
+                status: Code::Internal,
+            });
+        };
+        let organisation_row_result = client
+            .query_opt(select_organisation_statement, &[&organisation_uuid])
+            .await;
+        if let Err(err) = organisation_row_result {
+            return Err(err.into());
+        }
+        let organisation_row_option = organisation_row_result.unwrap();
+        if organisation_row_option.is_none() {
+            return Ok(None);
+        }
+        let organisation_result =
+            serde_json::from_value(organisation_row_option.unwrap().get("details"));
+        if organisation_result.is_err() {
+            return Err(BecoError {
+                message: "Failed to deserialize the organisation".into(),
+                status: Code::Internal,
+            });
+        }
+        let merkle_row_result = client
+            .query_opt(select_merkle_statement, &[&organisation_uuid])
+            .await;
+        if let Err(err) = merkle_row_result {
+            return Err(err.into());
+        }
+        let frontier = merkle_row_result
+            .unwrap()
+            .and_then(|row| serde_json::from_value(row.get("merkle_frontier")).ok())
+            .unwrap_or_default();
+        Ok(Some((organisation_result.unwrap(), frontier)))
+    }
 
-        statements
+    async fn load_organisations_for_member(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<Organisation>, BecoError> {
+        let client = self.pool.get().await.unwrap();
+        let select_organisations_statement = client
+            .prepare_cached(queries::organisation::SELECT_FOR_MEMBER)
+            .await
+            .unwrap();
+        let rows_result = client
+            .query(&select_organisations_statement, &[&user_id])
+            .await;
+        if let Err(err) = rows_result {
+            return Err(err.into());
+        }
+        let mut organisations: Vec<Organisation> = vec![];
+        for row in rows_result.unwrap() {
+            let organisation_result = serde_json::from_value(row.get("details"));
+            if organisation_result.is_err() {
+                return Err(BecoError {
+                    message: "Failed to deserialize the organisation".into(),
+                    status: Code::Internal,
+                });
+            }
+            organisations.push(organisation_result.unwrap());
+        }
+        Ok(organisations)
+    }
+
+    async fn load_organisation_transaction_for_request(
+        &self,
+        request_id: &str,
+    ) -> Result<Option<OrganisationTransaction>, BecoError> {
+        let client = self.pool.get().await.unwrap();
+        let select_transaction_statement = client
+            .prepare_cached(queries::organisation::SELECT_TRANSACTION_BY_REQUEST_ID)
+            .await
+            .unwrap();
+        let row_result = client
+            .query_opt(&select_transaction_statement, &[&request_id])
+            .await;
+        if let Err(err) = row_result {
+            return Err(err.into());
+        }
+        let row_option = row_result.unwrap();
+        if row_option.is_none() {
+            return Ok(None);
+        }
+        let transaction_result = serde_json::from_value(row_option.unwrap().get("transaction"));
+        if transaction_result.is_err() {
+            return Err(BecoError {
+                message: "Failed to deserialize the transaction".into(),
+                status: Code::Internal,
+            });
+        }
+        Ok(Some(transaction_result.unwrap()))
+    }
+
+    async fn save_organisation_request_and_merkle(
+        &self,
+        organisation_id: &str,
+        serialised_organisation: &Value,
+        request: CommittedRequest<'_>,
+        frontier: &MerkleFrontier,
+    ) -> Result<(), BecoError> {
+        let CommittedRequest {
+            request_id,
+            sequence,
+            serialised_transaction,
+            root,
+            ..
+        } = request;
+        let serialised_frontier_result = serde_json::to_value(frontier);
+        if serialised_frontier_result.is_err() {
+            return Err(BecoError {
+                message: "Failed to serialize the merkle frontier".into(),
+                status: Code::Internal,
+            });
+        }
+        let serialised_frontier = serialised_frontier_result.unwrap();
+        let organisation_uuid = Uuid::parse_str(organisation_id).unwrap();
+        let mut client = self.pool.get().await.unwrap();
+
+        // prepare statements
+
+        let queries = vec![
+            queries::organisation::INSERT,
+            queries::organisation::UPDATE,
+            queries::organisation::INSERT_TRANSACTION,
+        ];
+        let statements = DB::prepare_statements(queries, &client).await;
+        let [insert_organisation_statement, update_organisation_statement, insert_transaction_statement] =
+            &statements[..]
+        else {
+            return Err(BecoError {
+                message: "Failed to prepare statements".into(),
+                status: Code::Internal,
+            });
+        };
+
+        // start transaction
+
+        let db_transaction = client.transaction().await.unwrap();
+
+        // insert or update organisation, it is new on its first transaction
+
+        let organisation_upsert_result = if sequence == 1 {
+            db_transaction
+                .execute(
+                    insert_organisation_statement,
+                    &[&organisation_uuid, &serialised_organisation, &sequence],
+                )
+                .await
+        } else {
+            db_transaction
+                .execute(
+                    update_organisation_statement,
+                    &[&organisation_uuid, &serialised_organisation, &sequence],
+                )
+                .await
+        };
+        if let Err(err) = organisation_upsert_result {
+            return Err(err.into());
+        }
+
+        // insert transaction
+
+        let transaction_insert_result = db_transaction
+            .execute(
+                insert_transaction_statement,
+                &[
+                    &serialised_transaction,
+                    &organisation_uuid,
+                    &sequence,
+                    &root,
+                    &serialised_frontier,
+                    &request_id,
+                ],
+            )
+            .await;
+        if let Err(err) = transaction_insert_result {
+            return Err(err.into());
+        }
+
+        // commit
+
+        let commit_result = db_transaction.commit().await;
+        if let Err(err) = commit_result {
+            return Err(err.into());
+        }
+        Ok(())
+    }
+    async fn load_group(&self, group_id: &str) -> Result<Option<Group>, BecoError> {
+        let group_uuid_result = Uuid::parse_str(group_id);
+        if group_uuid_result.is_err() {
+            return Err(BecoError {
+                message: "Invalid group ID".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        let client = self.pool.get().await.unwrap();
+        let select_group_statement = client
+            .prepare_cached(queries::group::SELECT)
+            .await
+            .unwrap();
+        let row_result = client
+            .query_opt(&select_group_statement, &[&group_uuid_result.unwrap()])
+            .await;
+        if let Err(err) = row_result {
+            return Err(err.into());
+        }
+        let row_option = row_result.unwrap();
+        if row_option.is_none() {
+            return Ok(None);
+        }
+        let group_result = serde_json::from_value(row_option.unwrap().get("details"));
+        if group_result.is_err() {
+            return Err(BecoError {
+                message: "Failed to deserialize the group".into(),
+                status: Code::Internal,
+            });
+        }
+        Ok(Some(group_result.unwrap()))
+    }
+    async fn load_groups_for_member(&self, user_id: &str) -> Result<Vec<Group>, BecoError> {
+        let client = self.pool.get().await.unwrap();
+        let select_groups_statement = client
+            .prepare_cached(queries::group::SELECT_FOR_MEMBER)
+            .await
+            .unwrap();
+        let rows_result = client.query(&select_groups_statement, &[&user_id]).await;
+        if let Err(err) = rows_result {
+            return Err(err.into());
+        }
+        let mut groups: Vec<Group> = vec![];
+        for row in rows_result.unwrap() {
+            let group_result = serde_json::from_value(row.get("details"));
+            if group_result.is_err() {
+                return Err(BecoError {
+                    message: "Failed to deserialize the group".into(),
+                    status: Code::Internal,
+                });
+            }
+            groups.push(group_result.unwrap());
+        }
+        Ok(groups)
+    }
+    async fn load_group_transaction_for_request(
+        &self,
+        request_id: &str,
+    ) -> Result<Option<GroupTransaction>, BecoError> {
+        let client = self.pool.get().await.unwrap();
+        let select_transaction_statement = client
+            .prepare_cached(queries::group::SELECT_TRANSACTION_BY_REQUEST_ID)
+            .await
+            .unwrap();
+        let row_result = client
+            .query_opt(&select_transaction_statement, &[&request_id])
+            .await;
+        if let Err(err) = row_result {
+            return Err(err.into());
+        }
+        let row_option = row_result.unwrap();
+        if row_option.is_none() {
+            return Ok(None);
+        }
+        let transaction_result = serde_json::from_value(row_option.unwrap().get("transaction"));
+        if transaction_result.is_err() {
+            return Err(BecoError {
+                message: "Failed to deserialize the transaction".into(),
+                status: Code::Internal,
+            });
+        }
+        Ok(Some(transaction_result.unwrap()))
+    }
+    async fn save_group_request(
+        &self,
+        group_id: &str,
+        serialised_group: &Value,
+        serialised_transaction: &Value,
+        request_id: &str,
+        sequence: i64,
+    ) -> Result<(), BecoError> {
+        let group_uuid = Uuid::parse_str(group_id).unwrap();
+        let mut client = self.pool.get().await.unwrap();
+
+        // prepare statements
+
+        let queries = vec![
+            queries::group::INSERT,
+            queries::group::UPDATE,
+            queries::group::INSERT_TRANSACTION,
+        ];
+        let statements = DB::prepare_statements(queries, &client).await;
+        let [insert_group_statement, update_group_statement, insert_transaction_statement] =
+            &statements[..]
+        else {
+            return Err(BecoError {
+                message: "Failed to prepare statements".into(),
+                status: Code::Internal,
+            });
+        };
+
+        // start transaction
+
+        let db_transaction = client.transaction().await.unwrap();
+
+        // insert or update group, it is new on its first transaction
+
+        let group_upsert_result = if sequence == 1 {
+            db_transaction
+                .execute(
+                    insert_group_statement,
+                    &[&group_uuid, &serialised_group, &sequence],
+                )
+                .await
+        } else {
+            db_transaction
+                .execute(
+                    update_group_statement,
+                    &[&group_uuid, &serialised_group, &sequence],
+                )
+                .await
+        };
+        if let Err(err) = group_upsert_result {
+            return Err(err.into());
+        }
+
+        // insert transaction
+
+        let transaction_insert_result = db_transaction
+            .execute(
+                insert_transaction_statement,
+                &[&serialised_transaction, &group_uuid, &sequence, &request_id],
+            )
+            .await;
+        if let Err(err) = transaction_insert_result {
+            return Err(err.into());
+        }
+
+        // commit
+
+        let commit_result = db_transaction.commit().await;
+        if let Err(err) = commit_result {
+            return Err(err.into());
+        }
+        Ok(())
+    }
+    async fn save_access_record(&self, record: &AccessRecord) -> Result<(), BecoError> {
+        let record_uuid_result = Uuid::parse_str(&record.id);
+        let user_uuid_result = Uuid::parse_str(&record.user_id);
+        if record_uuid_result.is_err() || user_uuid_result.is_err() {
+            return Err(BecoError {
+                message: "Invalid access record ID".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        let client = self.pool.get().await.unwrap();
+        let insert_statement = client
+            .prepare_cached(queries::access_log::INSERT)
+            .await
+            .unwrap();
+        let serialised_record = serde_json::to_value(record).unwrap();
+        let insert_result = client
+            .execute(
+                &insert_statement,
+                &[
+                    &record_uuid_result.unwrap(),
+                    &user_uuid_result.unwrap(),
+                    &serialised_record,
+                    &record.read_at.to_rfc3339(),
+                ],
+            )
+            .await;
+        if let Err(err) = insert_result {
+            return Err(err.into());
+        }
+        Ok(())
+    }
+    async fn load_access_records(
+        &self,
+        user_id: &str,
+        since: Option<DateTime<Utc>>,
+    ) -> Result<Vec<AccessRecord>, BecoError> {
+        let user_uuid_result = Uuid::parse_str(user_id);
+        if user_uuid_result.is_err() {
+            return Err(BecoError {
+                message: "Invalid user ID".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        let since = since.map_or("-infinity".to_string(), |since| since.to_rfc3339());
+        let client = self.pool.get().await.unwrap();
+        let select_statement = client
+            .prepare_cached(queries::access_log::SELECT_FOR_USER)
+            .await
+            .unwrap();
+        let rows_result = client
+            .query(&select_statement, &[&user_uuid_result.unwrap(), &since])
+            .await;
+        if let Err(err) = rows_result {
+            return Err(err.into());
+        }
+        let mut records: Vec<AccessRecord> = vec![];
+        for row in rows_result.unwrap() {
+            let record_result = serde_json::from_value(row.get("details"));
+            if record_result.is_err() {
+                return Err(BecoError {
+                    message: "Failed to deserialize the access record".into(),
+                    status: Code::Internal,
+                });
+            }
+            records.push(record_result.unwrap());
+        }
+        Ok(records)
     }
 }
@@ -0,0 +1,3 @@
+// read_at is bound as RFC 3339 text, an unbounded since is passed as -infinity
+pub const INSERT: &str = "INSERT INTO personal.access_log (id, user_id, details, read_at) VALUES ($1, $2, $3, $4::text::timestamptz) ON CONFLICT (id) DO NOTHING;";
+pub const SELECT_FOR_USER: &str = "SELECT details FROM personal.access_log WHERE user_id=$1 AND read_at>=$2::text::timestamptz ORDER BY read_at ASC;";
\ No newline at end of file
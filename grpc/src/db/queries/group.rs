@@ -0,0 +1,6 @@
+pub const SELECT: &str = "SELECT details FROM personal.group WHERE id=$1;";
+pub const INSERT: &str = "INSERT INTO personal.group (id, details, sequence_number) VALUES ($1, $2, $3);";
+pub const UPDATE: &str = "UPDATE personal.group SET details=$2, sequence_number=$3 WHERE id=$1;";
+pub const SELECT_FOR_MEMBER: &str = "SELECT details FROM personal.group WHERE details->'members' @> jsonb_build_array($1::text) ORDER BY created_at ASC;";
+pub const INSERT_TRANSACTION: &str = "INSERT INTO personal.group_transaction (transaction, group_id, sequence_number, request_id) VALUES ($1, $2, $3, $4);";
+pub const SELECT_TRANSACTION_BY_REQUEST_ID: &str = "SELECT transaction FROM personal.group_transaction WHERE request_id=$1;";
\ No newline at end of file
@@ -1 +1,2 @@
-pub const INSERT: &str = "INSERT INTO personal.leaf (content, user_id, transaction_id) VALUES ($1, $2, $3);";
\ No newline at end of file
+pub const INSERT: &str = "INSERT INTO personal.leaf (content, user_id, transaction_id) VALUES ($1, $2, $3);";
+pub const DELETE_FOR_USER: &str = "DELETE FROM personal.leaf WHERE user_id=$1;";
\ No newline at end of file
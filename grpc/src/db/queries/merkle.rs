@@ -1,17 +1,2 @@
-pub const SELECT: &str = "SELECT
-t.merkle_root_hex,
-array_agg(leaves.content) AS leaves
-FROM personal.user u
-INNER JOIN personal.transaction t
-ON t.user_id = u.id
-INNER JOIN (
-   SELECT
-     content,
-     user_id
- FROM
- personal.leaf
- ORDER BY created_at ASC
-) leaves
-ON leaves.user_id = u.id
-WHERE u.id=$1
-GROUP BY t.merkle_root_hex;";
\ No newline at end of file
+pub const SELECT: &str = "SELECT merkle_frontier FROM personal.transaction WHERE user_id=$1 ORDER BY sequence_number DESC LIMIT 1;";
+pub const SELECT_LEAVES: &str = "SELECT l.content FROM personal.leaf l INNER JOIN personal.transaction t ON t.id = l.transaction_id WHERE l.user_id=$1 ORDER BY t.sequence_number ASC;";
\ No newline at end of file
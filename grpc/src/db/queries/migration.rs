@@ -0,0 +1,9 @@
+pub const CREATE_TABLE: &str = "CREATE SCHEMA IF NOT EXISTS migration;
+CREATE TABLE IF NOT EXISTS migration.version (
+    version INTEGER PRIMARY KEY,
+    name VARCHAR(100) NOT NULL,
+    applied_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);";
+pub const LOCK: &str = "SELECT pg_advisory_xact_lock(hashtext('migration.version'));";
+pub const SELECT: &str = "SELECT version FROM migration.version ORDER BY version ASC;";
+pub const INSERT: &str = "INSERT INTO migration.version (version, name) VALUES ($1, $2);";
@@ -1,4 +1,9 @@
+pub mod access_log;
+pub mod group;
 pub mod leaf;
 pub mod merkle;
+pub mod migration;
+pub mod national_identifier;
+pub mod organisation;
 pub mod transaction;
 pub mod user;
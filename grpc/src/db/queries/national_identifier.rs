@@ -0,0 +1,2 @@
+pub const INSERT: &str = "INSERT INTO personal.national_identifier (blind_index, user_id) VALUES ($1, $2);";
+pub const SELECT_USER_ID: &str = "SELECT user_id FROM personal.national_identifier WHERE blind_index=$1;";
\ No newline at end of file
@@ -0,0 +1,7 @@
+pub const SELECT: &str = "SELECT details FROM personal.organisation WHERE id=$1;";
+pub const INSERT: &str = "INSERT INTO personal.organisation (id, details, sequence_number) VALUES ($1, $2, $3);";
+pub const UPDATE: &str = "UPDATE personal.organisation SET details=$2, sequence_number=$3 WHERE id=$1;";
+pub const SELECT_FOR_MEMBER: &str = "SELECT details FROM personal.organisation WHERE details->'memberships' @> jsonb_build_array(jsonb_build_object('user_id', $1::text)) ORDER BY created_at ASC;";
+pub const SELECT_MERKLE: &str = "SELECT merkle_frontier FROM personal.organisation_transaction WHERE organisation_id=$1 ORDER BY sequence_number DESC LIMIT 1;";
+pub const INSERT_TRANSACTION: &str = "INSERT INTO personal.organisation_transaction (transaction, organisation_id, sequence_number, merkle_root_hex, merkle_frontier, request_id) VALUES ($1, $2, $3, $4, $5, $6);";
+pub const SELECT_TRANSACTION_BY_REQUEST_ID: &str = "SELECT transaction FROM personal.organisation_transaction WHERE request_id=$1;";
\ No newline at end of file
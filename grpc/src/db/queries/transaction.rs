@@ -1 +1,5 @@
-pub const INSERT: &str = "INSERT INTO personal.transaction (transaction, user_id, sequence_number, merkle_root_hex) VALUES ($1, $2, $3, $4) RETURNING id;";
\ No newline at end of file
+pub const INSERT: &str = "INSERT INTO personal.transaction (transaction, user_id, sequence_number, merkle_root_hex, merkle_frontier, request_id) VALUES ($1, $2, $3, $4, $5, $6) RETURNING id;";
+pub const SELECT_RANGE: &str = "SELECT transaction FROM personal.transaction WHERE user_id=$1 AND sequence_number BETWEEN $2 AND $3 ORDER BY sequence_number ASC;";
+pub const SELECT_FOR_USER: &str = "SELECT id, transaction FROM personal.transaction WHERE user_id=$1 ORDER BY sequence_number ASC;";
+pub const UPDATE_MERKLE: &str = "UPDATE personal.transaction SET merkle_root_hex=$2, merkle_frontier=$3 WHERE id=$1;";
+pub const SELECT_BY_REQUEST_ID: &str = "SELECT transaction, merkle_root_hex FROM personal.transaction WHERE request_id=$1;";
\ No newline at end of file
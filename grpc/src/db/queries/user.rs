@@ -1,3 +1,7 @@
+// next_expiry is bound as RFC 3339 text like the access log, NULL when the user has none
 pub const SELECT: &str = "SELECT id, details, sequence_number FROM personal.user WHERE id=$1";
-pub const INSERT: &str = "INSERT INTO personal.user (id, details, sequence_number) VALUES ($1, $2, $3);";
-pub const UPDATE: &str = "UPDATE personal.user SET details=$2, sequence_number=$3 WHERE id=$1;";
\ No newline at end of file
+pub const INSERT: &str = "INSERT INTO personal.user (id, details, sequence_number, next_expiry) VALUES ($1, $2, $3, $4::text::timestamptz);";
+pub const UPDATE: &str = "UPDATE personal.user SET details=$2, sequence_number=$3, next_expiry=$4::text::timestamptz WHERE id=$1;";
+pub const SELECT_IDS: &str = "SELECT id FROM personal.user ORDER BY created_at ASC;";
+pub const SELECT_DUE_IDS: &str = "SELECT id FROM personal.user WHERE next_expiry<=$1::text::timestamptz ORDER BY next_expiry ASC;";
+pub const UPDATE_NEXT_EXPIRY: &str = "UPDATE personal.user SET next_expiry=$2::text::timestamptz WHERE id=$1;";
\ No newline at end of file
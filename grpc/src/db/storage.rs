@@ -0,0 +1,311 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use crate::{
+    audit::access_record::AccessRecord,
+    errors::BecoError,
+    merkle::{
+        frontier::MerkleFrontier,
+        transaction::{GroupTransaction, OrganisationTransaction, Transaction},
+    },
+    group::group::Group,
+    organisation::organisation::Organisation,
+    user::user::User,
+};
+
+// a committed request and the leaf it appended, written alongside the user or organisation
+pub struct CommittedRequest<'a> {
+    pub request_id: &'a str,
+    pub sequence: i64,
+    pub serialised_transaction: &'a Value,
+    pub leaf: [u8; 32],
+    pub root: &'a str,
+}
+
+// Everything the SST needs to persist. Postgres is the production backend (`DB`),
+// `MemoryStorage` keeps the same guarantees in memory for tests.
+#[tonic::async_trait]
+pub trait Storage: Send + Sync {
+    async fn load_user(&self, user_id: &str) -> Result<User, BecoError>;
+
+    // the frontier after the latest transaction, None when the user has no transactions yet
+    async fn load_merkle(&self, user_id: &str) -> Result<Option<MerkleFrontier>, BecoError>;
+
+    async fn load_transactions(
+        &self,
+        user_id: &str,
+        from_sequence: i64,
+        to_sequence: i64,
+    ) -> Result<Vec<Transaction>, BecoError>;
+
+    // the transaction and merkle root committed for a process request, if it was committed already
+    async fn load_transaction_for_request(
+        &self,
+        request_id: &str,
+    ) -> Result<Option<(Transaction, String)>, BecoError>;
+
+    async fn load_user_ids(&self) -> Result<Vec<String>, BecoError>;
+
+    // users whose next expiry has passed at `at`, the earliest first
+    async fn load_due_user_ids(&self, at: &DateTime<Utc>) -> Result<Vec<String>, BecoError>;
+
+    // corrects the next expiry of a user without a change, e.g. one stored before it was kept
+    async fn save_next_expiry(
+        &self,
+        user_id: &str,
+        next_expiry: Option<DateTime<Utc>>,
+    ) -> Result<(), BecoError>;
+
+    // the user already registered with a national identifier blind index, if any
+    async fn user_for_blind_index(&self, blind_index: &str) -> Result<Option<String>, BecoError>;
+
+    async fn load_transaction_rows(&self, user_id: &str) -> Result<Vec<(i64, Value)>, BecoError>;
+
+    // each leaf carries its transaction id and the frontier once that leaf was appended
+    async fn rebuild_user(
+        &self,
+        user_id: &str,
+        serialised_user: &Value,
+        sequence: i64,
+        next_expiry: Option<DateTime<Utc>>,
+        leaves: Vec<(i64, [u8; 32], MerkleFrontier)>,
+    ) -> Result<(), BecoError>;
+
+    async fn save_user_request_and_merkle(
+        &self,
+        user_id: &str,
+        serialised_user: &Value,
+        request: CommittedRequest<'_>,
+        next_expiry: Option<DateTime<Utc>>,
+        frontier: &MerkleFrontier,
+        blind_index: Option<&String>,
+    ) -> Result<(), BecoError>;
+    // the organisation and the frontier after its latest transaction, None if it does not exist
+    async fn load_organisation(
+        &self,
+        organisation_id: &str,
+    ) -> Result<Option<(Organisation, MerkleFrontier)>, BecoError>;
+
+    // every organisation the user is or was a member of
+    async fn load_organisations_for_member(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<Organisation>, BecoError>;
+
+    async fn load_organisation_transaction_for_request(
+        &self,
+        request_id: &str,
+    ) -> Result<Option<OrganisationTransaction>, BecoError>;
+
+    async fn save_organisation_request_and_merkle(
+        &self,
+        organisation_id: &str,
+        serialised_organisation: &Value,
+        request: CommittedRequest<'_>,
+        frontier: &MerkleFrontier,
+    ) -> Result<(), BecoError>;
+
+    async fn load_group(&self, group_id: &str) -> Result<Option<Group>, BecoError>;
+
+    // every group the user is currently a member of
+    async fn load_groups_for_member(&self, user_id: &str) -> Result<Vec<Group>, BecoError>;
+
+    async fn load_group_transaction_for_request(
+        &self,
+        request_id: &str,
+    ) -> Result<Option<GroupTransaction>, BecoError>;
+
+    async fn save_group_request(
+        &self,
+        group_id: &str,
+        serialised_group: &Value,
+        serialised_transaction: &Value,
+        request_id: &str,
+        sequence: i64,
+    ) -> Result<(), BecoError>;
+
+    // a record that was already saved is ignored, gossipsub can deliver it more than once
+    async fn save_access_record(&self, record: &AccessRecord) -> Result<(), BecoError>;
+
+    // oldest first, since is inclusive
+    async fn load_access_records(
+        &self,
+        user_id: &str,
+        since: Option<DateTime<Utc>>,
+    ) -> Result<Vec<AccessRecord>, BecoError>;
+}
+
+// allows a storage to be shared, e.g. inspected by a test while the SST owns it
+#[tonic::async_trait]
+impl<T: Storage + ?Sized> Storage for Arc<T> {
+    async fn load_user(&self, user_id: &str) -> Result<User, BecoError> {
+        (**self).load_user(user_id).await
+    }
+
+    async fn load_merkle(&self, user_id: &str) -> Result<Option<MerkleFrontier>, BecoError> {
+        (**self).load_merkle(user_id).await
+    }
+
+    async fn load_transactions(
+        &self,
+        user_id: &str,
+        from_sequence: i64,
+        to_sequence: i64,
+    ) -> Result<Vec<Transaction>, BecoError> {
+        (**self)
+            .load_transactions(user_id, from_sequence, to_sequence)
+            .await
+    }
+
+    async fn load_transaction_for_request(
+        &self,
+        request_id: &str,
+    ) -> Result<Option<(Transaction, String)>, BecoError> {
+        (**self).load_transaction_for_request(request_id).await
+    }
+
+    async fn load_user_ids(&self) -> Result<Vec<String>, BecoError> {
+        (**self).load_user_ids().await
+    }
+
+    async fn load_due_user_ids(&self, at: &DateTime<Utc>) -> Result<Vec<String>, BecoError> {
+        (**self).load_due_user_ids(at).await
+    }
+
+    async fn save_next_expiry(
+        &self,
+        user_id: &str,
+        next_expiry: Option<DateTime<Utc>>,
+    ) -> Result<(), BecoError> {
+        (**self).save_next_expiry(user_id, next_expiry).await
+    }
+
+    async fn user_for_blind_index(&self, blind_index: &str) -> Result<Option<String>, BecoError> {
+        (**self).user_for_blind_index(blind_index).await
+    }
+
+    async fn load_transaction_rows(&self, user_id: &str) -> Result<Vec<(i64, Value)>, BecoError> {
+        (**self).load_transaction_rows(user_id).await
+    }
+
+    async fn rebuild_user(
+        &self,
+        user_id: &str,
+        serialised_user: &Value,
+        sequence: i64,
+        next_expiry: Option<DateTime<Utc>>,
+        leaves: Vec<(i64, [u8; 32], MerkleFrontier)>,
+    ) -> Result<(), BecoError> {
+        (**self)
+            .rebuild_user(user_id, serialised_user, sequence, next_expiry, leaves)
+            .await
+    }
+
+    async fn save_user_request_and_merkle(
+        &self,
+        user_id: &str,
+        serialised_user: &Value,
+        request: CommittedRequest<'_>,
+        next_expiry: Option<DateTime<Utc>>,
+        frontier: &MerkleFrontier,
+        blind_index: Option<&String>,
+    ) -> Result<(), BecoError> {
+        (**self)
+            .save_user_request_and_merkle(
+                user_id,
+                serialised_user,
+                request,
+                next_expiry,
+                frontier,
+                blind_index,
+            )
+            .await
+    }
+
+    async fn load_organisation(
+        &self,
+        organisation_id: &str,
+    ) -> Result<Option<(Organisation, MerkleFrontier)>, BecoError> {
+        (**self).load_organisation(organisation_id).await
+    }
+
+    async fn load_organisations_for_member(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<Organisation>, BecoError> {
+        (**self).load_organisations_for_member(user_id).await
+    }
+
+    async fn load_organisation_transaction_for_request(
+        &self,
+        request_id: &str,
+    ) -> Result<Option<OrganisationTransaction>, BecoError> {
+        (**self)
+            .load_organisation_transaction_for_request(request_id)
+            .await
+    }
+
+    async fn save_organisation_request_and_merkle(
+        &self,
+        organisation_id: &str,
+        serialised_organisation: &Value,
+        request: CommittedRequest<'_>,
+        frontier: &MerkleFrontier,
+    ) -> Result<(), BecoError> {
+        (**self)
+            .save_organisation_request_and_merkle(
+                organisation_id,
+                serialised_organisation,
+                request,
+                frontier,
+            )
+            .await
+    }
+
+    async fn load_group(&self, group_id: &str) -> Result<Option<Group>, BecoError> {
+        (**self).load_group(group_id).await
+    }
+
+    async fn load_groups_for_member(&self, user_id: &str) -> Result<Vec<Group>, BecoError> {
+        (**self).load_groups_for_member(user_id).await
+    }
+
+    async fn load_group_transaction_for_request(
+        &self,
+        request_id: &str,
+    ) -> Result<Option<GroupTransaction>, BecoError> {
+        (**self).load_group_transaction_for_request(request_id).await
+    }
+
+    async fn save_group_request(
+        &self,
+        group_id: &str,
+        serialised_group: &Value,
+        serialised_transaction: &Value,
+        request_id: &str,
+        sequence: i64,
+    ) -> Result<(), BecoError> {
+        (**self)
+            .save_group_request(
+                group_id,
+                serialised_group,
+                serialised_transaction,
+                request_id,
+                sequence,
+            )
+            .await
+    }
+    async fn save_access_record(&self, record: &AccessRecord) -> Result<(), BecoError> {
+        (**self).save_access_record(record).await
+    }
+
+    async fn load_access_records(
+        &self,
+        user_id: &str,
+        since: Option<DateTime<Utc>>,
+    ) -> Result<Vec<AccessRecord>, BecoError> {
+        (**self).load_access_records(user_id, since).await
+    }
+}
@@ -0,0 +1,28 @@
+#![cfg(all(test, feature = "sst"))]
+
+use crate::db::migrations::{pending, MIGRATIONS};
+
+#[test]
+fn migrations_are_ordered_and_unique() {
+    let versions: Vec<i32> = MIGRATIONS
+        .iter()
+        .map(|migration| migration.version)
+        .collect();
+    let mut sorted = versions.clone();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(versions, sorted);
+    assert_eq!(versions.first(), Some(&1));
+}
+
+#[test]
+fn pending_skips_applied() {
+    let remaining = pending(&[1]);
+    assert!(remaining.iter().all(|migration| migration.version != 1));
+    assert_eq!(remaining.len(), MIGRATIONS.len() - 1);
+}
+
+#[test]
+fn pending_none_applied() {
+    assert_eq!(pending(&[]).len(), MIGRATIONS.len());
+}
@@ -0,0 +1 @@
+mod migrations;
\ No newline at end of file
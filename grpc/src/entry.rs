@@ -2,20 +2,42 @@
 use crate::{
     enums::data_value::DataRequests,
     errors::BecoError,
+    organisation::user_organisation::UserOrganisation,
     proto::beco::{AddAccountRequest, AddUserRequest, GetUserResponse},
-    user::{public_user::PublicUser, user::User},
+    user::{approval::ApproveChange, public_user::PublicUser, user::User},
 };
 #[cfg(not(feature = "sst"))]
 use crate::{
-    enums::data_value::{DataRequestType, DataRequests, ProcessRequest},
+    audit::access_record::{AccessRecord, ListAccessLog},
+    enums::data_value::{DataRequestType, DataRequests, ProcessRequest, Rejection},
     errors::BecoError,
+    group::group::Group,
+    organisation::{organisation::Organisation, user_organisation::UserOrganisation},
     proto::beco::{
-        AddAccountRequest, AddUserRequest, GetUserResponse, ListUserRequest, ListUserResponse,
+        AddAccountRequest, AddUserRequest, AttestFieldRequest, CredentialStatusListRequest,
+        CredentialStatusListResponse, GetUserResponse, IssueCredentialRequest,
+        IssueCredentialResponse, ListMembershipsRequest,
+        ListPendingApprovalsRequest, ListPendingRequestsRequest, ListUserRequest, ListUserResponse,
+        RequestAccessRequest, SignWithAccountRequest, SignWithAccountResponse, UserDiffRequest,
+        UserDiffResponse, VerifyCredentialRequest,
+    },
+    requests::user_request::UserRequests,
+    user::{
+        approval::{ApproveChange, PendingApproval, RequestApproval},
+        attestation::Attestation,
+        credential::{status_list_id, IssueCredential, SignCredential, VerifiedCredential},
+        national_identifier,
+        public_user::PublicUser,
+        user::User,
     },
-    user::{public_user::PublicUser, user::User},
     utils::{calculate_hash, ProposeEvent},
 };
 #[cfg(not(feature = "sst"))]
+use crate::config::Config;
+use chrono::{DateTime, Utc};
+#[cfg(not(feature = "sst"))]
+use envconfig::Envconfig;
+#[cfg(not(feature = "sst"))]
 use event_listener::Event;
 #[cfg(not(feature = "sst"))]
 use serde_json::Value;
@@ -38,20 +60,34 @@ use tokio::sync::{
 use tonic::Code;
 #[cfg(feature = "sst")]
 use tonic::Code;
+#[cfg(not(feature = "sst"))]
+use uuid::Uuid;
 
 const BAD_BLOCKCHAIN: &str = "Invalid Blockchain value provided";
 const BAD_ACCOUNT: &str = "No matching account found";
 const NOT_AUTH: &str = "Not authorised to perform this action";
 
+fn approval_required() -> BecoError {
+    BecoError {
+        message: "Changes to this field need approval".into(),
+        status: Code::FailedPrecondition,
+    }
+}
+
 #[cfg(not(feature = "sst"))]
 #[derive(Debug)]
 pub struct Entry {
     users: Arc<RwLock<HashMap<String, RwLock<User>>>>,
+    // group ids of calling users, refreshed from the sst before their grants are checked
+    groups: Arc<RwLock<HashMap<String, Vec<String>>>>,
+    // organisation memberships of calling users, refreshed like groups for policies
+    memberships: Arc<RwLock<HashMap<String, Vec<UserOrganisation>>>>,
     tx_p2p: Sender<Value>,
     tx_grpc: Sender<Value>,
     pub rx_grpc: Receiver<Value>,
     pub completion_loops: RwLock<HashMap<u64, ProposeEvent>>,
     pub events: RwLock<HashMap<u64, Event>>,
+    sst_identity_public_key: Option<String>,
     // add a timeout queue that the p2p side can check - just an Instant::now() thing rather than a future so it can be checked multiple times
 }
 
@@ -59,6 +95,10 @@ pub struct Entry {
 #[derive(Debug)]
 pub struct Entry {
     users: Arc<RwLock<HashMap<String, RwLock<User>>>>,
+    // group ids of calling users, set by the sst before their grants are checked
+    groups: Arc<RwLock<HashMap<String, Vec<String>>>>,
+    // organisation memberships of calling users, set by the sst for policies
+    memberships: Arc<RwLock<HashMap<String, Vec<UserOrganisation>>>>,
 }
 
 impl Entry {
@@ -66,17 +106,22 @@ impl Entry {
     pub fn new(tx_p2p: Sender<Value>, tx_grpc: Sender<Value>, rx_grpc: Receiver<Value>) -> Self {
         Self {
             users: Arc::new(RwLock::new(HashMap::new())),
+            groups: Arc::new(RwLock::new(HashMap::new())),
+            memberships: Arc::new(RwLock::new(HashMap::new())),
             tx_p2p,
             tx_grpc,
             rx_grpc,
             completion_loops: RwLock::new(HashMap::new()),
             events: RwLock::new(HashMap::new()),
+            sst_identity_public_key: Config::init_from_env().unwrap().sst_identity_public_key,
         }
     }
     #[cfg(feature = "sst")]
     pub fn new() -> Self {
         Self {
             users: Arc::new(RwLock::new(HashMap::new())),
+            groups: Arc::new(RwLock::new(HashMap::new())),
+            memberships: Arc::new(RwLock::new(HashMap::new())),
         }
     }
     async fn get_public_user<'a>(
@@ -85,17 +130,74 @@ impl Entry {
         user_id: String,
         calling_user_id: String,
     ) -> PublicUser {
-        let user_basic = PublicUser::new(user_id, None, None, None, vec![]);
-        let calling_user_basic = PublicUser::new(calling_user_id, None, None, None, vec![]);
+        let mut user_basic = PublicUser::new(user_id, None, None, None, vec![]);
+        let calling_user_basic = self
+            .with_caller(PublicUser::new(calling_user_id, None, None, None, vec![]))
+            .await;
         if let Some(user_lock) = user_option {
             let user = user_lock.read().await;
-            let user_public = user.as_public_user(&calling_user_basic);
-            return user_public;
+            user_basic = user.as_public_user(&calling_user_basic);
+        }
+        // the calling user keeps its groups and memberships so its grants can be checked
+        if user_basic.id == calling_user_basic.id {
+            user_basic.groups = calling_user_basic.groups;
+            user_basic.memberships = calling_user_basic.memberships;
         }
         user_basic
     }
+
+    pub async fn with_caller(&self, user: PublicUser) -> PublicUser {
+        let mut user = user;
+        user.groups = self
+            .groups
+            .read()
+            .await
+            .get(&user.id)
+            .cloned()
+            .unwrap_or_default();
+        user.memberships = self
+            .memberships
+            .read()
+            .await
+            .get(&user.id)
+            .cloned()
+            .unwrap_or_default();
+        user
+    }
+
+    pub async fn set_groups(&self, user_id: &str, group_ids: Vec<String>) {
+        self.groups
+            .write()
+            .await
+            .insert(user_id.to_string(), group_ids);
+    }
+
+    pub async fn set_memberships(&self, user_id: &str, memberships: Vec<UserOrganisation>) {
+        self.memberships
+            .write()
+            .await
+            .insert(user_id.to_string(), memberships);
+    }
     #[cfg(not(feature = "sst"))]
     pub async fn add_user(&self, request: AddUserRequest) -> Result<GetUserResponse, BecoError> {
+        let mut request = request;
+        // only the sst can read the national identifier, it is never gossiped in the clear
+        if let Some(national_identifier) = request.national_identifier.take() {
+            if self.sst_identity_public_key.is_none() {
+                return Err(BecoError {
+                    message: "National identifiers are not supported by this node".into(),
+                    status: Code::FailedPrecondition,
+                });
+            }
+            let sealed_result = national_identifier::seal(
+                &national_identifier,
+                self.sst_identity_public_key.as_ref().unwrap(),
+            );
+            if let Err(err) = sealed_result {
+                return Err(err);
+            }
+            request.national_identifier = Some(sealed_result.unwrap());
+        }
         let calling_user = {
             let users = self.users.read().await;
             self.get_public_user(
@@ -106,11 +208,7 @@ impl Entry {
             .await
         };
         let data_request = DataRequests::AddUser(request.clone());
-        let hash = calculate_hash(&data_request);
-        {
-            self.create_event(hash.clone(), None).await;
-        }
-        let process_request = ProcessRequest {
+        let mut process_request = ProcessRequest {
             validated_signatures: HashSet::new(),
             failed_signatures: HashSet::new(),
             ignore_signatures: HashSet::new(),
@@ -118,12 +216,19 @@ impl Entry {
             request: data_request,
             calling_user: request.calling_user.clone(),
             user_id: "".to_string(),
-            hash: hash,
-            datetime: None,
+            hash: 0,
+            datetime: Some(Utc::now()),
             connected_peers: 0,
-            originator_hash: Some(hash),
+            originator_hash: None,
             originator_peer_id: None,
         };
+        // new users skip the validator, so stamp the request here to give it a unique id
+        let hash = calculate_hash(&process_request);
+        process_request.hash = hash;
+        process_request.originator_hash = Some(hash);
+        {
+            self.create_event(hash, None).await;
+        }
         self.send_message_return_public(&process_request, hash, &calling_user)
             .await
     }
@@ -135,6 +240,7 @@ impl Entry {
         hash: u64,
     ) -> Result<String, BecoError> {
         let mut user_id: Option<String> = None;
+        let mut rejection: Option<Rejection> = None;
         let _ = self
             .tx_p2p
             .send(serde_json::to_value(&process_request).unwrap())
@@ -151,6 +257,9 @@ impl Entry {
                 if let Some(user) = user_id_option.clone() {
                     user_id = Some(user);
                 }
+                if let Some(DataRequests::Rejected(response)) = completion.response() {
+                    rejection = Some(response);
+                }
                 request_type != DataRequestType::FAILED
             } else {
                 false
@@ -160,6 +269,9 @@ impl Entry {
         {
             self.remove_event(&hash).await;
         }
+        if let Some(rejection) = rejection {
+            return Err(rejection.into());
+        }
         if !success {
             return Err(BecoError {
                 message: "Failed to validate the request within 5 seconds".into(),
@@ -175,6 +287,50 @@ impl Entry {
         Ok(user_id.unwrap())
     }
 
+    #[cfg(not(feature = "sst"))]
+    async fn send_message_wait_response(
+        &self,
+        process_request: &ProcessRequest,
+        hash: u64,
+    ) -> Result<DataRequests, BecoError> {
+        let _ = self
+            .tx_p2p
+            .send(serde_json::to_value(process_request).unwrap())
+            .await;
+        let response = {
+            if let Some(event) = self.events.read().await.get(&hash) {
+                event.listen().await;
+            }
+
+            let completion_loops = self.completion_loops.read().await;
+            let completion_option = completion_loops.get(&hash);
+            if let Some(completion) = completion_option {
+                let (request_type, _) = completion.await;
+                if request_type == DataRequestType::RESPONSE {
+                    completion.response()
+                } else {
+                    None
+                }
+            } else {
+                None
+            }
+        };
+
+        {
+            self.remove_event(&hash).await;
+        }
+        if response.is_none() {
+            return Err(BecoError {
+                message: "Failed to receive a response within 5 seconds".into(),
+                status: Code::DeadlineExceeded,
+            });
+        }
+        if let Some(DataRequests::Rejected(rejection)) = response {
+            return Err(rejection.into());
+        }
+        Ok(response.unwrap())
+    }
+
     #[cfg(not(feature = "sst"))]
     async fn send_message_return_public(
         &self,
@@ -187,20 +343,17 @@ impl Entry {
             return Err(user_id_result.unwrap_err());
         }
         let user_id = user_id_result.unwrap();
+        // the commit may have dropped a diverged user from the cache, fetch it again if so
+        self.does_user_exist(user_id.clone(), calling_user.id.clone())
+            .await?;
         let users = self.users.read().await;
         let user_option = users.get(&user_id);
-        let public_user = user_option
-            .unwrap()
-            .read()
-            .await
-            .as_public_user(calling_user);
-        Ok(public_user.into())
+        let user = user_option.unwrap().read().await;
+        Ok(self.response_for(&user, calling_user))
     }
 
     #[cfg(feature = "sst")]
     pub async fn add_user(&self, request: AddUserRequest) -> Result<(User, PublicUser), BecoError> {
-        // need to implement a check to see if they exist first
-        // need something like the national insurance number for this
         let user = User::new(Some(request.name));
         let mut users = self.users.write().await;
         users.insert(user.id.to_string(), RwLock::new(user.clone()));
@@ -328,19 +481,658 @@ impl Entry {
         if does_user_exist.is_err() {
             return Err(does_user_exist.unwrap_err());
         }
+        self.refresh_caller(&request.calling_user).await;
         let users = &mut self.users.read().await;
-        let public_user = self
-            .get_public_user(
-                &users.get(&request.user_id),
-                request.user_id.clone(),
+        let calling_user = self
+            .with_caller(PublicUser::new(request.calling_user.clone(), None, None, None, vec![]))
+            .await;
+        let response: GetUserResponse = if let Some(user) = users.get(&request.user_id) {
+            self.response_for(&*user.read().await, &calling_user)
+        } else {
+            PublicUser::new(request.user_id.clone(), None, None, None, vec![]).into()
+        };
+        Ok(ListUserResponse {
+            users: vec![response],
+        })
+    }
+
+    // every response that hands a user's fields to a caller goes through here, so a read by
+    // anyone but the owner is recorded
+    #[cfg(not(feature = "sst"))]
+    fn response_for(&self, user: &User, calling_user: &PublicUser) -> GetUserResponse {
+        let read_at = Utc::now();
+        let fields = user.fields_read_by(calling_user, &read_at);
+        if !fields.is_empty() {
+            self.record_access(AccessRecord::new(
+                user.id.clone(),
+                calling_user.id.clone(),
+                fields,
+                read_at,
+            ));
+        }
+        user.as_response(calling_user)
+    }
+
+    // the read is answered without waiting for the sst to store the record
+    #[cfg(not(feature = "sst"))]
+    fn record_access(&self, record: AccessRecord) {
+        let data_request = DataRequests::RecordAccess(record.clone());
+        let hash = calculate_hash(&data_request);
+        let process_request = ProcessRequest {
+            validated_signatures: HashSet::new(),
+            failed_signatures: HashSet::new(),
+            ignore_signatures: HashSet::new(),
+            status: DataRequestType::NEW,
+            request: data_request,
+            calling_user: record.viewer_id,
+            user_id: record.user_id,
+            hash,
+            datetime: Some(record.read_at),
+            connected_peers: 0,
+            originator_hash: None,
+            originator_peer_id: None,
+        };
+        let tx_p2p = self.tx_p2p.clone();
+        tokio::spawn(async move {
+            let send_result = tx_p2p
+                .send(serde_json::to_value(&process_request).unwrap())
+                .await;
+            if let Err(err) = send_result {
+                println!("Failed to ship access record: {err:?}");
+            }
+        });
+    }
+
+    #[cfg(not(feature = "sst"))]
+    pub async fn list_access_log(
+        &self,
+        request: ListAccessLog,
+    ) -> Result<Vec<AccessRecord>, BecoError> {
+        let data_request = DataRequests::ListAccessLog(request.clone());
+        let hash = calculate_hash(&data_request);
+        {
+            self.create_event(hash, None).await;
+        }
+
+        let process_request = ProcessRequest {
+            validated_signatures: HashSet::new(),
+            failed_signatures: HashSet::new(),
+            ignore_signatures: HashSet::new(),
+            status: DataRequestType::FETCH,
+            request: data_request,
+            calling_user: request.calling_user.clone(),
+            user_id: request.user_id.clone(),
+            hash,
+            datetime: None,
+            connected_peers: 0,
+            originator_hash: Some(hash),
+            originator_peer_id: None,
+        };
+        let response_result = self.send_message_wait_response(&process_request, hash).await;
+        if let Err(err) = response_result {
+            return Err(err);
+        }
+        match response_result.unwrap() {
+            DataRequests::AccessLogResult(records) => Ok(records),
+            _ => Err(BecoError {
+                message: "Unexpected response for the access log request".into(),
+                status: Code::Internal,
+            }),
+        }
+    }
+
+    // the private key never leaves this node, only the signature is returned
+    #[cfg(not(feature = "sst"))]
+    pub async fn sign_with_account(
+        &self,
+        request: SignWithAccountRequest,
+    ) -> Result<SignWithAccountResponse, BecoError> {
+        self.does_user_exist(request.user_id.clone(), request.calling_user.clone())
+            .await?;
+        self.refresh_caller(&request.calling_user).await;
+        let users = &self.users.read().await;
+        let user_option = users.get(&request.user_id);
+        if user_option.is_none() {
+            return Err(BecoError {
+                message: BAD_ACCOUNT.to_string(),
+                status: Code::NotFound,
+            });
+        }
+        let calling_user = self
+            .with_caller(PublicUser::new(request.calling_user.clone(), None, None, None, vec![]))
+            .await;
+        let user = user_option.unwrap().read().await;
+        user.sign_with_account(request, &calling_user, &Utc::now())
+    }
+
+    #[cfg(not(feature = "sst"))]
+    pub async fn user_diff(&self, request: UserDiffRequest) -> Result<UserDiffResponse, BecoError> {
+        if request.from_sequence >= request.to_sequence {
+            return Err(BecoError {
+                message: "from_sequence must be lower than to_sequence".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        let data_request = DataRequests::UserDiff(request.clone());
+        let hash = calculate_hash(&data_request);
+        {
+            self.create_event(hash, Some(request.user_id.clone())).await;
+        }
+
+        let process_request = ProcessRequest {
+            validated_signatures: HashSet::new(),
+            failed_signatures: HashSet::new(),
+            ignore_signatures: HashSet::new(),
+            status: DataRequestType::FETCH,
+            request: data_request,
+            calling_user: request.calling_user.clone(),
+            user_id: request.user_id.clone(),
+            hash,
+            datetime: None,
+            connected_peers: 0,
+            originator_hash: Some(hash),
+            originator_peer_id: None,
+        };
+        let response_result = self.send_message_wait_response(&process_request, hash).await;
+        if let Err(err) = response_result {
+            return Err(err);
+        }
+        match response_result.unwrap() {
+            DataRequests::UserDiffResult(Some(changes)) => Ok(UserDiffResponse {
+                user_id: request.user_id,
+                from_sequence: request.from_sequence,
+                to_sequence: request.to_sequence,
+                changes: changes.into_iter().map(|change| change.into()).collect(),
+            }),
+            _ => Err(BecoError {
+                message: "No transactions found for the requested sequences".into(),
+                status: Code::NotFound,
+            }),
+        }
+    }
+
+    // organisations are not gossiped for corroboration, the sst checks and stores them directly
+    #[cfg(not(feature = "sst"))]
+    pub async fn organisation_request(
+        &self,
+        data_request: DataRequests,
+        calling_user: String,
+    ) -> Result<Organisation, BecoError> {
+        let mut process_request = ProcessRequest {
+            validated_signatures: HashSet::new(),
+            failed_signatures: HashSet::new(),
+            ignore_signatures: HashSet::new(),
+            status: DataRequestType::NEW,
+            request: data_request,
+            calling_user: calling_user.clone(),
+            user_id: calling_user,
+            hash: 0,
+            datetime: Some(Utc::now()),
+            connected_peers: 0,
+            originator_hash: None,
+            originator_peer_id: None,
+        };
+        let hash = calculate_hash(&process_request);
+        process_request.hash = hash;
+        process_request.originator_hash = Some(hash);
+        {
+            self.create_event(hash, None).await;
+        }
+        let response_result = self.send_message_wait_response(&process_request, hash).await;
+        if let Err(err) = response_result {
+            return Err(err);
+        }
+        match response_result.unwrap() {
+            DataRequests::OrganisationResult(organisation) => Ok(organisation),
+            _ => Err(BecoError {
+                message: "Unexpected response for the organisation request".into(),
+                status: Code::Internal,
+            }),
+        }
+    }
+
+    #[cfg(not(feature = "sst"))]
+    async fn fetch_organisation(
+        &self,
+        organisation_id: String,
+        calling_user: String,
+    ) -> Result<Organisation, BecoError> {
+        let data_request = DataRequests::FetchOrganisation(organisation_id);
+        let hash = calculate_hash(&data_request);
+        {
+            self.create_event(hash, None).await;
+        }
+
+        let process_request = ProcessRequest {
+            validated_signatures: HashSet::new(),
+            failed_signatures: HashSet::new(),
+            ignore_signatures: HashSet::new(),
+            status: DataRequestType::FETCH,
+            request: data_request,
+            calling_user: calling_user.clone(),
+            user_id: calling_user,
+            hash,
+            datetime: None,
+            connected_peers: 0,
+            originator_hash: Some(hash),
+            originator_peer_id: None,
+        };
+        let response_result = self.send_message_wait_response(&process_request, hash).await;
+        if let Err(err) = response_result {
+            return Err(err);
+        }
+        match response_result.unwrap() {
+            DataRequests::OrganisationResult(organisation) => Ok(organisation),
+            _ => Err(BecoError {
+                message: "Unexpected response for the organisation request".into(),
+                status: Code::Internal,
+            }),
+        }
+    }
+
+    // the organisation details are attached here so every node stores the same request
+    #[cfg(not(feature = "sst"))]
+    pub async fn request_access(
+        &self,
+        request: RequestAccessRequest,
+    ) -> Result<GetUserResponse, BecoError> {
+        let organisation_result = self
+            .fetch_organisation(request.organisation_id.clone(), request.calling_user.clone())
+            .await;
+        if let Err(err) = organisation_result {
+            return Err(err);
+        }
+        let organisation = organisation_result.unwrap();
+        if !organisation.is_admin(&request.calling_user) {
+            return Err(BecoError {
+                message: "User does not have permission to request access for this organisation"
+                    .into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        let access_request_result = UserRequests::new(request.clone(), &organisation);
+        if let Err(err) = access_request_result {
+            return Err(err);
+        }
+        self.propose(
+            DataRequests::RequestAccess(access_request_result.unwrap()),
+            request.calling_user,
+            request.user_id,
+        )
+        .await
+    }
+
+    // the organisation and the admin's auth key are attached here so every node stores the same
+    // attestation
+    #[cfg(not(feature = "sst"))]
+    pub async fn attest_field(
+        &self,
+        request: AttestFieldRequest,
+    ) -> Result<GetUserResponse, BecoError> {
+        let organisation_result = self
+            .fetch_organisation(request.organisation_id.clone(), request.calling_user.clone())
+            .await;
+        if let Err(err) = organisation_result {
+            return Err(err);
+        }
+        let organisation = organisation_result.unwrap();
+        if !organisation.is_admin(&request.calling_user) {
+            return Err(BecoError {
+                message: "User does not have permission to attest for this organisation".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        self.does_user_exist(request.calling_user.clone(), request.calling_user.clone())
+            .await?;
+        let public_key_option = {
+            let users = self.users.read().await;
+            let user_option = users.get(&request.calling_user);
+            if user_option.is_none() {
+                return Err(BecoError {
+                    message: BAD_ACCOUNT.to_string(),
+                    status: Code::NotFound,
+                });
+            }
+            let public_key_option = user_option.unwrap().read().await.auth_key().cloned();
+            public_key_option
+        };
+        if public_key_option.is_none() {
+            return Err(BecoError {
+                message: "An auth key is needed to attest".into(),
+                status: Code::FailedPrecondition,
+            });
+        }
+        let attestation_result =
+            Attestation::new(request.clone(), &organisation, public_key_option.unwrap());
+        if let Err(err) = attestation_result {
+            return Err(err);
+        }
+        self.propose(
+            DataRequests::AttestField(attestation_result.unwrap()),
+            request.calling_user,
+            request.user_id,
+        )
+        .await
+    }
+
+    // the issuance goes through consensus like any change, then the sst signs what was committed
+    #[cfg(not(feature = "sst"))]
+    pub async fn issue_credential(
+        &self,
+        request: IssueCredentialRequest,
+    ) -> Result<IssueCredentialResponse, BecoError> {
+        self.does_user_exist(request.user_id.clone(), request.calling_user.clone())
+            .await?;
+        let issue_result = {
+            let users = self.users.read().await;
+            let user_option = users.get(&request.user_id);
+            if user_option.is_none() {
+                return Err(BecoError {
+                    message: BAD_ACCOUNT.to_string(),
+                    status: Code::NotFound,
+                });
+            }
+            let calling_user =
+                PublicUser::new(request.calling_user.clone(), None, None, None, vec![]);
+            let user = user_option.unwrap().read().await;
+            IssueCredential::new(request.clone(), &user, &calling_user)
+        };
+        if let Err(err) = issue_result {
+            return Err(err);
+        }
+        let issue = issue_result.unwrap();
+        let credential_id = issue.id.clone();
+        self.propose(
+            DataRequests::IssueCredential(issue),
+            request.calling_user.clone(),
+            request.user_id.clone(),
+        )
+        .await?;
+        let sign_request = DataRequests::SignCredential(SignCredential {
+            user_id: request.user_id.clone(),
+            credential_id: credential_id.clone(),
+        });
+        let response_result = self
+            .credential_request(sign_request, request.calling_user, request.user_id)
+            .await;
+        if let Err(err) = response_result {
+            return Err(err);
+        }
+        match response_result.unwrap() {
+            DataRequests::CredentialResult(jwt) => Ok(IssueCredentialResponse {
+                credential_id,
+                jwt,
+            }),
+            _ => Err(BecoError {
+                message: "Unexpected response for the credential request".into(),
+                status: Code::Internal,
+            }),
+        }
+    }
+
+    // only the sst holds the issuer key and the authoritative revocation status
+    #[cfg(not(feature = "sst"))]
+    pub async fn verify_credential(
+        &self,
+        request: VerifyCredentialRequest,
+    ) -> Result<VerifiedCredential, BecoError> {
+        let response_result = self
+            .credential_request(
+                DataRequests::VerifyCredential(request.jwt),
                 request.calling_user.clone(),
+                request.calling_user,
             )
             .await;
-        Ok(ListUserResponse {
-            users: vec![public_user.into()],
+        if let Err(err) = response_result {
+            return Err(err);
+        }
+        match response_result.unwrap() {
+            DataRequests::VerifiedCredentialResult(verified) => Ok(verified),
+            _ => Err(BecoError {
+                message: "Unexpected response for the credential request".into(),
+                status: Code::Internal,
+            }),
+        }
+    }
+
+    #[cfg(not(feature = "sst"))]
+    async fn credential_request(
+        &self,
+        data_request: DataRequests,
+        calling_user: String,
+        user_id: String,
+    ) -> Result<DataRequests, BecoError> {
+        let hash = calculate_hash(&data_request);
+        {
+            self.create_event(hash, None).await;
+        }
+
+        let process_request = ProcessRequest {
+            validated_signatures: HashSet::new(),
+            failed_signatures: HashSet::new(),
+            ignore_signatures: HashSet::new(),
+            status: DataRequestType::FETCH,
+            request: data_request,
+            calling_user,
+            user_id,
+            hash,
+            datetime: None,
+            connected_peers: 0,
+            originator_hash: Some(hash),
+            originator_peer_id: None,
+        };
+        self.send_message_wait_response(&process_request, hash).await
+    }
+
+    // status lists are public so anyone holding a credential can check it
+    #[cfg(not(feature = "sst"))]
+    pub async fn credential_status_list(
+        &self,
+        request: CredentialStatusListRequest,
+    ) -> Result<CredentialStatusListResponse, BecoError> {
+        self.does_user_exist(request.user_id.clone(), request.calling_user.clone())
+            .await?;
+        let users = self.users.read().await;
+        let user_option = users.get(&request.user_id);
+        if user_option.is_none() {
+            return Err(BecoError {
+                message: BAD_ACCOUNT.to_string(),
+                status: Code::NotFound,
+            });
+        }
+        let encoded_list = user_option.unwrap().read().await.credential_status_list();
+        Ok(CredentialStatusListResponse {
+            id: status_list_id(&request.user_id),
+            encoded_list,
         })
     }
 
+    #[cfg(not(feature = "sst"))]
+    pub async fn list_pending_requests(
+        &self,
+        request: ListPendingRequestsRequest,
+    ) -> Result<Vec<UserRequests>, BecoError> {
+        self.does_user_exist(request.user_id.clone(), request.calling_user.clone())
+            .await?;
+        let users = self.users.read().await;
+        let user_option = users.get(&request.user_id);
+        if user_option.is_none() {
+            return Err(BecoError {
+                message: BAD_ACCOUNT.to_string(),
+                status: Code::NotFound,
+            });
+        }
+        let calling_user = PublicUser::new(request.calling_user.clone(), None, None, None, vec![]);
+        let read_user = user_option.unwrap().read().await;
+        read_user.pending_requests(&calling_user)
+    }
+
+    // organisation admins only see the approvals their organisation is named on
+    #[cfg(not(feature = "sst"))]
+    pub async fn list_pending_approvals(
+        &self,
+        request: ListPendingApprovalsRequest,
+    ) -> Result<Vec<PendingApproval>, BecoError> {
+        self.does_user_exist(request.user_id.clone(), request.calling_user.clone())
+            .await?;
+        let organisation_ids = {
+            let users = self.users.read().await;
+            let user_option = users.get(&request.user_id);
+            if user_option.is_none() {
+                return Err(BecoError {
+                    message: BAD_ACCOUNT.to_string(),
+                    status: Code::NotFound,
+                });
+            }
+            let organisation_ids = user_option
+                .unwrap()
+                .read()
+                .await
+                .pending_approval_organisations();
+            organisation_ids
+        };
+        let mut admin_of = vec![];
+        for organisation_id in organisation_ids {
+            let organisation_result = self
+                .fetch_organisation(organisation_id.clone(), request.calling_user.clone())
+                .await;
+            if organisation_result
+                .is_ok_and(|organisation| organisation.is_admin(&request.calling_user))
+            {
+                admin_of.push(organisation_id);
+            }
+        }
+        let users = self.users.read().await;
+        let user_option = users.get(&request.user_id);
+        if user_option.is_none() {
+            return Err(BecoError {
+                message: BAD_ACCOUNT.to_string(),
+                status: Code::NotFound,
+            });
+        }
+        let calling_user = PublicUser::new(request.calling_user.clone(), None, None, None, vec![]);
+        let read_user = user_option.unwrap().read().await;
+        Ok(read_user.pending_approvals(&calling_user, &admin_of, &Utc::now()))
+    }
+
+    #[cfg(not(feature = "sst"))]
+    pub async fn list_memberships(
+        &self,
+        request: ListMembershipsRequest,
+    ) -> Result<Vec<UserOrganisation>, BecoError> {
+        let data_request = DataRequests::ListMemberships(request.clone());
+        let hash = calculate_hash(&data_request);
+        {
+            self.create_event(hash, None).await;
+        }
+
+        let process_request = ProcessRequest {
+            validated_signatures: HashSet::new(),
+            failed_signatures: HashSet::new(),
+            ignore_signatures: HashSet::new(),
+            status: DataRequestType::FETCH,
+            request: data_request,
+            calling_user: request.calling_user.clone(),
+            user_id: request.calling_user.clone(),
+            hash,
+            datetime: None,
+            connected_peers: 0,
+            originator_hash: Some(hash),
+            originator_peer_id: None,
+        };
+        let response_result = self.send_message_wait_response(&process_request, hash).await;
+        if let Err(err) = response_result {
+            return Err(err);
+        }
+        match response_result.unwrap() {
+            DataRequests::MembershipsResult(memberships) => Ok(memberships),
+            _ => Err(BecoError {
+                message: "Unexpected response for the memberships request".into(),
+                status: Code::Internal,
+            }),
+        }
+    }
+
+    // groups are not gossiped for corroboration, the sst checks and stores them directly
+    #[cfg(not(feature = "sst"))]
+    pub async fn group_request(
+        &self,
+        data_request: DataRequests,
+        calling_user: String,
+    ) -> Result<Group, BecoError> {
+        let mut process_request = ProcessRequest {
+            validated_signatures: HashSet::new(),
+            failed_signatures: HashSet::new(),
+            ignore_signatures: HashSet::new(),
+            status: DataRequestType::NEW,
+            request: data_request,
+            calling_user: calling_user.clone(),
+            user_id: calling_user,
+            hash: 0,
+            datetime: Some(Utc::now()),
+            connected_peers: 0,
+            originator_hash: None,
+            originator_peer_id: None,
+        };
+        let hash = calculate_hash(&process_request);
+        process_request.hash = hash;
+        process_request.originator_hash = Some(hash);
+        {
+            self.create_event(hash, None).await;
+        }
+        let response_result = self.send_message_wait_response(&process_request, hash).await;
+        if let Err(err) = response_result {
+            return Err(err);
+        }
+        match response_result.unwrap() {
+            DataRequests::GroupResult(group) => Ok(group),
+            _ => Err(BecoError {
+                message: "Unexpected response for the group request".into(),
+                status: Code::Internal,
+            }),
+        }
+    }
+
+    // groups and memberships change on the sst without touching the user, so they are fetched
+    // before grants are checked. A failed fetch leaves the calling user without them
+    #[cfg(not(feature = "sst"))]
+    async fn refresh_caller(&self, calling_user_id: &str) {
+        let data_request = DataRequests::FetchGroups(calling_user_id.to_string());
+        let hash = calculate_hash(&data_request);
+        {
+            self.create_event(hash, None).await;
+        }
+
+        let process_request = ProcessRequest {
+            validated_signatures: HashSet::new(),
+            failed_signatures: HashSet::new(),
+            ignore_signatures: HashSet::new(),
+            status: DataRequestType::FETCH,
+            request: data_request,
+            calling_user: calling_user_id.to_string(),
+            user_id: calling_user_id.to_string(),
+            hash,
+            datetime: None,
+            connected_peers: 0,
+            originator_hash: Some(hash),
+            originator_peer_id: None,
+        };
+        let group_ids = match self.send_message_wait_response(&process_request, hash).await {
+            Ok(DataRequests::GroupsResult(group_ids)) => group_ids,
+            _ => vec![],
+        };
+        self.set_groups(calling_user_id, group_ids).await;
+        let memberships = self
+            .list_memberships(ListMembershipsRequest {
+                calling_user: calling_user_id.to_string(),
+                organisation_id: None,
+                user_id: Some(calling_user_id.to_string()),
+            })
+            .await
+            .unwrap_or_default();
+        self.set_memberships(calling_user_id, memberships).await;
+    }
+
     // should be a proposal - pass in as param
     pub async fn add_account(
         &self,
@@ -375,7 +1167,8 @@ impl Entry {
             });
         }
         let user = &mut user_option.unwrap().write().await;
-        let wallet_response_result = user.add_account(request, &calling_user).clone();
+        user.check_sequence(request.expected_sequence)?;
+        let wallet_response_result = user.add_account(request, &calling_user, &Utc::now()).clone();
         if let Err(err) = wallet_response_result {
             let message = err.message.clone();
             return Err(BecoError {
@@ -383,64 +1176,8 @@ impl Entry {
                 status: Code::InvalidArgument,
             });
         }
-        Ok(user.as_public_user(&calling_user).into())
-    }
-
-    // pub async fn add_linked_user(
-    //     &self,
-    //     request: ModifyLinkedUserRequest,
-    // ) -> Result<GetUserResponse, BecoError> {
-    //     let users = &mut self.users.read().await;
-    //     let user_option = users.get(&request.calling_user);
-    //     let new_user = PublicUser::new(request.user_id, None, None, None, vec![]);
-    //     if let Some(user) = user_option {
-    //         user.write().await.add_linked_user(&new_user);
-    //         let calling_user = self
-    //             .get_public_user(
-    //                 &users.get(&request.calling_user),
-    //                 request.calling_user.clone(),
-    //                 request.calling_user.clone(),
-    //             )
-    //             .await;
-    //         return Ok(calling_user.into());
-    //     }
-    //     Err(BecoError {
-    //         message: BAD_ACCOUNT.to_string(),
-    //         status: Code::NotFound,
-    //     })
-    // }
-
-    // pub async fn remove_linked_user(
-    //     &self,
-    //     request: ModifyLinkedUserRequest,
-    // ) -> Result<GetUserResponse, BecoError> {
-    //     let users = &mut self.users.read().await;
-    //     let calling_user = self
-    //         .get_public_user(
-    //             &users.get(&request.calling_user),
-    //             request.calling_user.clone(),
-    //             request.calling_user.clone(),
-    //         )
-    //         .await;
-    //     let remove_user = PublicUser::new(request.user_id.clone(), None, None, None, vec![]);
-    //     let user_option = users.get(&request.calling_user);
-    //     if let Some(user_lock) = user_option {
-    //         let user = &mut user_lock.write().await;
-    //         let result = user.remove_linked_user(&remove_user, &calling_user);
-    //         return if result.is_ok() {
-    //             Ok(user.as_public_user(&calling_user).into())
-    //         } else {
-    //             Err(BecoError {
-    //                 message: result.err().unwrap().message.to_string(),
-    //                 status: Code::PermissionDenied,
-    //             })
-    //         };
-    //     }
-    //     Err(BecoError {
-    //         message: BAD_ACCOUNT.to_string(),
-    //         status: Code::NotFound,
-    //     })
-    // }
+        Ok(user.as_response(&calling_user))
+    }
 
     #[cfg(not(feature = "sst"))]
     pub async fn ping_event(&self, hash: &u64) {
@@ -472,6 +1209,12 @@ impl Entry {
         }
     }
     #[cfg(not(feature = "sst"))]
+    pub async fn response_event(&self, hash: u64, response: DataRequests) {
+        if let Some(event) = self.completion_loops.write().await.get_mut(&hash) {
+            event.respond(response);
+        }
+    }
+    #[cfg(not(feature = "sst"))]
     pub async fn does_event_exist(&self, hash: u64) -> bool {
         let completion_loops = self.completion_loops.read().await;
         completion_loops.get(&hash).is_some()
@@ -482,8 +1225,32 @@ impl Entry {
         user_option: &Option<&RwLock<User>>,
         request: DataRequests,
         calling_user: &PublicUser,
+        at: &DateTime<Utc>,
     ) -> Result<(), BecoError> {
         let read_user = user_option.unwrap().read().await;
+        read_user.check_sequence(request.expected_sequence())?;
+        if read_user.needs_approval(&request) {
+            return Err(approval_required());
+        }
+        match request {
+            DataRequests::RequestApproval(request) => {
+                Self::propose_change(&read_user, *request.change.clone(), calling_user, at).await?;
+                read_user.propose_request_approval(&request, calling_user, at)
+            }
+            DataRequests::ApproveChange(request) => {
+                Self::approve_change(&mut read_user.clone(), request, calling_user, at).await
+            }
+            request => Self::propose_change(&read_user, request, calling_user, at).await,
+        }
+    }
+
+    // checks a change against the user without applying it
+    async fn propose_change(
+        read_user: &User,
+        request: DataRequests,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
         match request {
             DataRequests::FirstName(request) => {
                 read_user
@@ -507,9 +1274,119 @@ impl Entry {
                     .await
             }
             DataRequests::AddCryptoAccount(request) => {
-                read_user.propose_account(request, &calling_user)
+                read_user.propose_account(request, calling_user, at)
+            }
+            DataRequests::SetAttribute(request) => {
+                read_user
+                    .user_details
+                    .propose_attribute(&request.key, &request.value, calling_user, at)
+            }
+            DataRequests::ClearAttribute(request) => {
+                read_user
+                    .user_details
+                    .propose_clear_attribute(&request.key, calling_user, at)
+            }
+            DataRequests::RequestAccess(request) => {
+                read_user.propose_access_request(&request, calling_user)
+            }
+            DataRequests::RespondAccessRequest(request) => {
+                read_user.propose_respond_access_request(&request, calling_user, at)
+            }
+            DataRequests::RevokeExpiredGrants(request) => {
+                read_user.propose_revoke_expired_grants(&request, at)
+            }
+            DataRequests::GrantGroupAccess(request) => {
+                read_user.propose_group_access(&request, true, calling_user, at)
+            }
+            DataRequests::RevokeGroupAccess(request) => {
+                read_user.propose_group_access(&request, false, calling_user, at)
+            }
+            DataRequests::RevokeGrant(request) => {
+                read_user.propose_revoke_grant(&request, calling_user, at)
+            }
+            DataRequests::SetFieldPolicy(request) => {
+                read_user.propose_field_policy(&request, calling_user)
+            }
+            DataRequests::SetFieldVisibility(request) => {
+                read_user.propose_field_visibility(&request, calling_user)
+            }
+            DataRequests::RemoveAccount(request) => {
+                read_user.propose_remove_account(&request, calling_user, at)
+            }
+            DataRequests::AddLinkedUser(request) => {
+                read_user.propose_add_linked_user(&request, calling_user)
+            }
+            DataRequests::RemoveLinkedUser(request) => {
+                read_user.propose_remove_linked_user(&request, calling_user)
+            }
+            DataRequests::ConfirmLinkedUser(request) => {
+                read_user.propose_confirm_linked_user(&request)
             }
-            DataRequests::AddUser(_) | DataRequests::LoadUser(_) | DataRequests::FetchUser(_) => {
+            DataRequests::SetGuardian(request) => {
+                read_user.propose_set_guardian(&request, calling_user, at)
+            }
+            DataRequests::EndGuardianship(request) => {
+                read_user.propose_end_guardianship(&request, at)
+            }
+            DataRequests::SetAuthKey(request) => {
+                read_user.propose_set_auth_key(&request, calling_user)
+            }
+            DataRequests::SetRecoveryGuardians(request) => {
+                read_user.propose_set_recovery_guardians(&request, calling_user)
+            }
+            DataRequests::ApproveRecovery(request) => {
+                read_user.propose_approve_recovery(&request, calling_user, at)
+            }
+            DataRequests::CancelRecovery(request) => {
+                read_user.propose_cancel_recovery(&request, calling_user)
+            }
+            DataRequests::CompleteRecovery(request) => {
+                read_user.propose_complete_recovery(&request, at)
+            }
+            DataRequests::SetApprovalPolicy(request) => {
+                read_user.propose_approval_policy(&request, calling_user)
+            }
+            DataRequests::ExpireApprovals(request) => {
+                read_user.propose_expire_approvals(&request, at)
+            }
+            DataRequests::AttestField(request) => {
+                read_user.propose_attest_field(&request, calling_user, at)
+            }
+            DataRequests::IssueCredential(request) => {
+                read_user.propose_issue_credential(&request, calling_user, at)
+            }
+            DataRequests::RevokeCredential(request) => {
+                read_user.propose_revoke_credential(&request, calling_user, at)
+            }
+            DataRequests::AddUser(_)
+            | DataRequests::LoadUser(_)
+            | DataRequests::FetchUser(_)
+            | DataRequests::UserDiff(_)
+            | DataRequests::UserDiffResult(_)
+            | DataRequests::Committed(_)
+            | DataRequests::Rejected(_)
+            | DataRequests::CreateOrganisation(_)
+            | DataRequests::AddMember(_)
+            | DataRequests::EndMembership(_)
+            | DataRequests::OrganisationResult(_)
+            | DataRequests::ListMemberships(_)
+            | DataRequests::MembershipsResult(_)
+            | DataRequests::FetchOrganisation(_)
+            | DataRequests::CreateGroup(_)
+            | DataRequests::AddGroupMember(_)
+            | DataRequests::RemoveGroupMember(_)
+            | DataRequests::GroupResult(_)
+            | DataRequests::FetchGroups(_)
+            | DataRequests::GroupsResult(_)
+            | DataRequests::RecordAccess(_)
+            | DataRequests::ListAccessLog(_)
+            | DataRequests::AccessLogResult(_)
+            | DataRequests::SignCredential(_)
+            | DataRequests::CredentialResult(_)
+            | DataRequests::VerifyCredential(_)
+            | DataRequests::VerifiedCredentialResult(_)
+            | DataRequests::RequestApproval(_)
+            | DataRequests::ApproveChange(_) => {
                 Err(BecoError {
                     message: "Invalid path to perform action".to_string(),
                     status: Code::Internal,
@@ -520,6 +1397,7 @@ impl Entry {
 
     #[cfg(not(feature = "sst"))]
     pub async fn corroborate(&self, request: &mut ProcessRequest) {
+        self.refresh_caller(&request.calling_user).await;
         let users = &self.users.read().await;
         let calling_user = self
             .get_public_user(
@@ -545,7 +1423,12 @@ impl Entry {
             return;
         }
         let result = self
-            .propose_value(&user_option, request.request.clone(), &calling_user)
+            .propose_value(
+                &user_option,
+                request.request.clone(),
+                &calling_user,
+                &request.datetime.unwrap_or(Utc::now()),
+            )
             .await;
 
         if result.is_err() {
@@ -581,7 +1464,8 @@ impl Entry {
         if does_calling_user_exist.is_err() {
             return Err(does_calling_user_exist.unwrap_err());
         }
-        let (calling_user, result) = {
+        self.refresh_caller(&calling_user_id).await;
+        let (calling_user, data_request, result) = {
             let users = &self.users.read().await;
             let calling_user = self
                 .get_public_user(
@@ -599,17 +1483,31 @@ impl Entry {
                 .await;
             let user_option = users.get(&user_id);
 
-            let result: Result<(), BecoError> = if user_option.is_none() {
+            if user_option.is_none() {
                 return Err(BecoError {
                     message: BAD_ACCOUNT.to_string(),
                     status: Code::NotFound,
                 });
+            }
+            // a change to a field with an approval policy waits for its approvers instead
+            let needs_approval = user_option
+                .unwrap()
+                .read()
+                .await
+                .needs_approval(&data_request);
+            let data_request = if needs_approval {
+                DataRequests::RequestApproval(RequestApproval {
+                    id: Uuid::new_v4().to_string(),
+                    change: Box::new(data_request),
+                })
             } else {
-                self.propose_value(&user_option, data_request.clone(), &calling_user)
-                    .await
+                data_request
             };
+            let result = self
+                .propose_value(&user_option, data_request.clone(), &calling_user, &Utc::now())
+                .await;
 
-            (calling_user, result)
+            (calling_user, data_request, result)
         };
 
         if result.is_err() {
@@ -639,31 +1537,79 @@ impl Entry {
         }
     }
 
+    // applies a change the sst has committed to the cached user, if this node holds it
+    #[cfg(not(feature = "sst"))]
+    pub async fn apply_commit(
+        &self,
+        data_request: DataRequests,
+        calling_user_id: String,
+        user_id: String,
+        sequence: u64,
+        datetime: Option<DateTime<Utc>>,
+    ) -> Result<(), BecoError> {
+        let loaded_sequence = {
+            let users = self.users.read().await;
+            if let Some(user) = users.get(&user_id) {
+                Some(user.read().await.sequence())
+            } else {
+                None
+            }
+        };
+        if loaded_sequence.is_none() {
+            return Ok(());
+        }
+        let loaded_sequence = loaded_sequence.unwrap();
+        // already applied, e.g. the commit was delivered twice
+        if loaded_sequence >= sequence {
+            return Ok(());
+        }
+        // a commit was missed, drop the user so it is fetched from the sst when next needed
+        if loaded_sequence + 1 != sequence {
+            self.unload_user(&user_id).await;
+            return Ok(());
+        }
+        let result = self
+            .update_value(data_request, calling_user_id, user_id.clone(), datetime)
+            .await;
+        if result.is_err() || result.as_ref().unwrap().0.sequence() != sequence {
+            self.unload_user(&user_id).await;
+        }
+        Ok(())
+    }
+
+    pub async fn unload_user(&self, user_id: &String) {
+        self.users.write().await.remove(user_id);
+    }
+
     #[cfg(not(feature = "sst"))]
     pub async fn update(
         &self,
         data_request: DataRequests,
         calling_user_id: String,
         user_id: String,
+        datetime: Option<DateTime<Utc>>,
     ) -> Result<GetUserResponse, BecoError> {
         let result = self
-            .update_value(data_request, calling_user_id, user_id)
+            .update_value(data_request, calling_user_id, user_id, datetime)
             .await;
         if result.is_err() {
             let error = result.unwrap_err();
             Err(error)
         } else {
             let (user, calling_user) = result.unwrap();
-            Ok(user.as_public_user(&calling_user).into())
+            Ok(self.response_for(&user, &calling_user))
         }
     }
 
+    // datetime is the time the validator assigned to the request, grants are checked against it
     pub async fn update_value(
         &self,
         data_request: DataRequests,
         calling_user_id: String,
         user_id: String,
+        datetime: Option<DateTime<Utc>>,
     ) -> Result<(User, PublicUser), BecoError> {
+        let at = datetime.unwrap_or(Utc::now());
         let does_user_exist = self
             .does_user_exist(user_id.clone(), calling_user_id.clone())
             .await;
@@ -699,39 +1645,166 @@ impl Entry {
             });
         }
         let write_user = &mut user_option.unwrap().write().await;
+        if write_user.needs_approval(&data_request) {
+            return Err(approval_required());
+        }
         let result = match data_request {
+            DataRequests::RequestApproval(request) => {
+                let change_result =
+                    Self::propose_change(write_user, *request.change.clone(), &calling_user, &at)
+                        .await;
+                if change_result.is_err() {
+                    change_result
+                } else {
+                    write_user.request_approval(request, &calling_user, &at)
+                }
+            }
+            // approved on a copy so a change that no longer applies leaves the approval waiting
+            DataRequests::ApproveChange(request) => {
+                let mut user = write_user.clone();
+                let approve_result =
+                    Self::approve_change(&mut user, request, &calling_user, &at).await;
+                if approve_result.is_ok() {
+                    **write_user = user;
+                }
+                approve_result
+            }
+            request => Self::apply_change(write_user, request, &calling_user, &at).await,
+        };
+        if let Err(error) = result {
+            Err(error)
+        } else {
+            write_user.invalidate_attestations(&at);
+            write_user.increase_sequence();
+            Ok((write_user.clone(), calling_user))
+        }
+    }
+
+    // records the approval and applies the change once it has enough of them
+    async fn approve_change(
+        user: &mut User,
+        request: ApproveChange,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        let approve_result = user.approve_change(request, calling_user, at);
+        if let Err(err) = approve_result {
+            return Err(err);
+        }
+        match approve_result.unwrap() {
+            Some(approval) => {
+                Self::apply_change(user, *approval.change, &approval.requested_by, at).await
+            }
+            None => Ok(()),
+        }
+    }
+
+    async fn apply_change(
+        write_user: &mut User,
+        data_request: DataRequests,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        match data_request {
             DataRequests::FirstName(request) => {
                 write_user
                     .user_details
                     .first_name
-                    .update(Some(request.name), &calling_user)
+                    .update(Some(request.name), calling_user, at)
                     .await
             }
             DataRequests::OtherNames(request) => {
                 write_user
                     .user_details
                     .other_names
-                    .update(Some(request.other_names), &calling_user)
+                    .update(Some(request.other_names), calling_user, at)
                     .await
             }
             DataRequests::LastName(request) => {
                 write_user
                     .user_details
                     .last_name
-                    .update(Some(request.name), &calling_user)
+                    .update(Some(request.name), calling_user, at)
                     .await
             }
             DataRequests::AddCryptoAccount(request) => {
-                write_user.add_account(request, &calling_user)
+                write_user.add_account(request, calling_user, at)
+            }
+            DataRequests::SetAttribute(request) => {
+                write_user
+                    .user_details
+                    .set_attribute(request.key, request.value, calling_user, at)
+                    .await
+            }
+            DataRequests::ClearAttribute(request) => {
+                write_user
+                    .user_details
+                    .clear_attribute(&request.key, calling_user, at)
+                    .await
+            }
+            DataRequests::RequestAccess(request) => {
+                write_user.add_access_request(request, calling_user)
+            }
+            DataRequests::RespondAccessRequest(request) => {
+                write_user.respond_access_request(request, calling_user, at)
+            }
+            DataRequests::RevokeExpiredGrants(request) => {
+                write_user.revoke_expired_grants(request, at)
+            }
+            DataRequests::GrantGroupAccess(request) => {
+                write_user.set_group_access(request, true, calling_user, at)
+            }
+            DataRequests::RevokeGroupAccess(request) => {
+                write_user.set_group_access(request, false, calling_user, at)
+            }
+            DataRequests::RevokeGrant(request) => {
+                write_user.revoke_grant(request, calling_user, at)
+            }
+            DataRequests::SetFieldPolicy(request) => {
+                write_user.set_field_policy(request, calling_user)
+            }
+            DataRequests::SetFieldVisibility(request) => {
+                write_user.set_field_visibility(request, calling_user)
+            }
+            DataRequests::RemoveAccount(request) => {
+                write_user.remove_account(request, calling_user, at)
+            }
+            DataRequests::AddLinkedUser(request) => {
+                write_user.add_linked_user(request, calling_user)
+            }
+            DataRequests::RemoveLinkedUser(request) => {
+                write_user.remove_linked_user(request, calling_user)
+            }
+            DataRequests::ConfirmLinkedUser(request) => write_user.confirm_linked_user(request),
+            DataRequests::SetGuardian(request) => {
+                write_user.set_guardian(request, calling_user, at)
+            }
+            DataRequests::EndGuardianship(request) => write_user.end_guardianship(request, at),
+            DataRequests::SetAuthKey(request) => write_user.set_auth_key(request, calling_user),
+            DataRequests::SetRecoveryGuardians(request) => {
+                write_user.set_recovery_guardians(request, calling_user)
+            }
+            DataRequests::ApproveRecovery(request) => {
+                write_user.approve_recovery(request, calling_user, at)
+            }
+            DataRequests::CancelRecovery(request) => {
+                write_user.cancel_recovery(request, calling_user)
+            }
+            DataRequests::CompleteRecovery(request) => write_user.complete_recovery(request, at),
+            DataRequests::SetApprovalPolicy(request) => {
+                write_user.set_approval_policy(request, calling_user)
+            }
+            DataRequests::ExpireApprovals(request) => write_user.expire_approvals(request, at),
+            DataRequests::AttestField(request) => {
+                write_user.attest_field(request, calling_user, at)
+            }
+            DataRequests::IssueCredential(request) => {
+                write_user.issue_credential(request, calling_user, at)
+            }
+            DataRequests::RevokeCredential(request) => {
+                write_user.revoke_credential(request, calling_user, at)
             }
             _ => Ok(()),
-        };
-        if result.is_err() {
-            let error = result.unwrap_err();
-            Err(error)
-        } else {
-            write_user.increase_sequence();
-            Ok((write_user.clone(), calling_user))
         }
     }
 }
@@ -62,4 +62,22 @@ impl BlockchainCustody {
             BlockchainCustody::EVM(account) => account.clone().as_public(calling_user),
         }
     }
+
+    pub fn can_view(&self, calling_user: &PublicUser) -> bool {
+        match self {
+            BlockchainCustody::XRPL(account) => account.keys.value(calling_user).is_ok(),
+            BlockchainCustody::EVM(account) => account.keys.value(calling_user).is_ok(),
+        }
+    }
+
+    // a field without an alias refers to every key, which each chain holds
+    pub fn has_key(&self, alias: &Option<String>) -> bool {
+        if alias.is_none() {
+            return true;
+        }
+        match self {
+            BlockchainCustody::XRPL(account) => account.has_key(alias.as_ref().unwrap()),
+            BlockchainCustody::EVM(account) => account.has_key(alias.as_ref().unwrap()),
+        }
+    }
 }
@@ -5,8 +5,31 @@ use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString};
 
 use crate::{
-    proto::beco::{AddAccountRequest, AddUserRequest, ModifyNameRequest, ModifyOtherNamesRequest, ListUserRequest},
-    user::user::User,
+    audit::access_record::{AccessRecord, ListAccessLog},
+    chain::chain_custody::RemoveAccount,
+    errors::BecoError,
+    organisation::{organisation::Organisation, user_organisation::UserOrganisation},
+    group::group::Group,
+    permissions::{
+        grant::{GroupAccess, RevokeExpiredGrants, RevokeGrant},
+        policy::SetFieldPolicy,
+        visibility::SetFieldVisibility,
+    },
+    proto::beco::{AddAccountRequest, AddMemberRequest, AddUserRequest, ClearAttributeRequest, CreateGroupRequest, CreateOrganisationRequest, EndMembershipRequest, GroupMemberRequest, ListMembershipsRequest, ModifyNameRequest, ModifyOtherNamesRequest, ListUserRequest, RespondAccessRequest, UserDiffRequest},
+    requests::user_request::UserRequests,
+    user::{
+        approval::{ApproveChange, ExpireApprovals, RequestApproval, SetApprovalPolicy},
+        attestation::Attestation,
+        credential::{IssueCredential, RevokeCredential, SignCredential, VerifiedCredential},
+        attribute::SetAttribute,
+        guardian::{EndGuardianship, SetGuardian},
+        linked_user::{ConfirmLinkedUser, ModifyLinkedUser},
+        recovery::{
+            ApproveRecovery, CancelRecovery, CompleteRecovery, SetAuthKey, SetRecoveryGuardians,
+        },
+        user::User,
+        user_diff::FieldDiff,
+    },
 };
 
 #[derive(Debug, Clone, Display, EnumString, Eq, PartialEq)]
@@ -21,6 +44,8 @@ pub enum DataValue {
     LinkedUsers,
     #[strum(serialize = "CHAIN_ACCOUNTS")]
     ChainAccounts,
+    #[strum(serialize = "ATTRIBUTE")]
+    Attribute,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -46,6 +71,86 @@ impl Hash for ProcessRequest {
     }
 }
 
+impl DataRequests {
+    pub fn expected_sequence(&self) -> Option<u64> {
+        match self {
+            DataRequests::FirstName(request) | DataRequests::LastName(request) => {
+                request.expected_sequence
+            }
+            DataRequests::OtherNames(request) => request.expected_sequence,
+            DataRequests::AddCryptoAccount(request) => request.expected_sequence,
+            DataRequests::SetAttribute(request) => request.expected_sequence,
+            DataRequests::ClearAttribute(request) => request.expected_sequence,
+            DataRequests::RespondAccessRequest(request) => request.expected_sequence,
+            DataRequests::GrantGroupAccess(request) | DataRequests::RevokeGroupAccess(request) => {
+                request.expected_sequence
+            }
+            DataRequests::RevokeGrant(request) => request.expected_sequence,
+            DataRequests::SetFieldPolicy(request) => request.expected_sequence,
+            DataRequests::SetFieldVisibility(request) => request.expected_sequence,
+            DataRequests::RemoveAccount(request) => request.expected_sequence,
+            DataRequests::AddLinkedUser(request) | DataRequests::RemoveLinkedUser(request) => {
+                request.expected_sequence
+            }
+            DataRequests::SetGuardian(request) => request.expected_sequence,
+            DataRequests::SetAuthKey(request) => request.expected_sequence,
+            DataRequests::SetRecoveryGuardians(request) => request.expected_sequence,
+            DataRequests::CancelRecovery(request) => request.expected_sequence,
+            DataRequests::SetApprovalPolicy(request) => request.expected_sequence,
+            DataRequests::RequestApproval(request) => request.change.expected_sequence(),
+            DataRequests::IssueCredential(request) => request.expected_sequence,
+            DataRequests::RevokeCredential(request) => request.expected_sequence,
+            _ => None,
+        }
+    }
+}
+
+// published by the sst once a validated request is durably stored
+#[derive(Debug, Serialize, Deserialize, Clone, Hash)]
+pub struct Commit {
+    pub request: Box<DataRequests>,
+    pub sequence: u64,
+    pub merkle_root: String,
+    // the groups the sst checked the calling user's grants against, applied the same way here
+    #[serde(default)]
+    pub calling_user_groups: Vec<String>,
+    #[serde(default)]
+    pub calling_user_memberships: Vec<UserOrganisation>,
+}
+
+// returned by the sst when it refuses a request, so the grpc node can surface the reason
+#[derive(Debug, Serialize, Deserialize, Clone, Hash)]
+pub struct Rejection {
+    pub message: String,
+    pub code: i32,
+}
+
+impl From<BecoError> for Rejection {
+    fn from(value: BecoError) -> Self {
+        Self {
+            message: value.message,
+            code: value.status as i32,
+        }
+    }
+}
+
+impl From<Rejection> for BecoError {
+    fn from(value: Rejection) -> Self {
+        Self {
+            message: value.message,
+            status: value.code.into(),
+        }
+    }
+}
+
+impl ProcessRequest {
+    // the hash covers the request and the time it was stamped, so redeliveries share the same id
+    pub fn request_id(&self) -> String {
+        format!("{:016x}", self.hash)
+    }
+}
+
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Clone, Display, EnumString, Eq, PartialEq, Serialize, Deserialize)]
 pub enum DataRequestType {
     #[strum(serialize = "PROPOSE")]
@@ -72,6 +177,8 @@ pub enum DataRequestType {
     NEW,
     #[strum(serialize = "RESPONSE")]
     RESPONSE,
+    #[strum(serialize = "COMMITTED")]
+    COMMITTED,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Hash)]
@@ -82,9 +189,61 @@ pub enum DataRequests {
     AddUser(AddUserRequest),
     LoadUser(Option<User>),
     AddCryptoAccount(AddAccountRequest),
+    SetAttribute(SetAttribute),
+    ClearAttribute(ClearAttributeRequest),
     FetchUser(ListUserRequest),
-    // RemoveLinkedUserRequest(ModifyLinkedUserRequest),
-    // AddLinkedUserRequest(ModifyLinkedUserRequest),
-    // AddLinkedUser(ModifyLinkedUserRequest),
-    // RemoveLinkedUser(ModifyLinkedUserRequest),
+    UserDiff(UserDiffRequest),
+    UserDiffResult(Option<Vec<FieldDiff>>),
+    Committed(Commit),
+    Rejected(Rejection),
+    CreateOrganisation(CreateOrganisationRequest),
+    AddMember(AddMemberRequest),
+    EndMembership(EndMembershipRequest),
+    OrganisationResult(Organisation),
+    ListMemberships(ListMembershipsRequest),
+    MembershipsResult(Vec<UserOrganisation>),
+    FetchOrganisation(String),
+    RequestAccess(UserRequests),
+    RespondAccessRequest(RespondAccessRequest),
+    RevokeExpiredGrants(RevokeExpiredGrants),
+    CreateGroup(CreateGroupRequest),
+    AddGroupMember(GroupMemberRequest),
+    RemoveGroupMember(GroupMemberRequest),
+    GroupResult(Group),
+    // the ids of the groups a user belongs to
+    FetchGroups(String),
+    GroupsResult(Vec<String>),
+    GrantGroupAccess(GroupAccess),
+    RevokeGroupAccess(GroupAccess),
+    RevokeGrant(RevokeGrant),
+    SetFieldPolicy(SetFieldPolicy),
+    SetFieldVisibility(SetFieldVisibility),
+    RemoveAccount(RemoveAccount),
+    // shipped to the sst without waiting for a response
+    RecordAccess(AccessRecord),
+    ListAccessLog(ListAccessLog),
+    AccessLogResult(Vec<AccessRecord>),
+    AddLinkedUser(ModifyLinkedUser),
+    RemoveLinkedUser(ModifyLinkedUser),
+    ConfirmLinkedUser(ConfirmLinkedUser),
+    SetGuardian(SetGuardian),
+    EndGuardianship(EndGuardianship),
+    SetAuthKey(SetAuthKey),
+    SetRecoveryGuardians(SetRecoveryGuardians),
+    ApproveRecovery(ApproveRecovery),
+    CancelRecovery(CancelRecovery),
+    CompleteRecovery(CompleteRecovery),
+    SetApprovalPolicy(SetApprovalPolicy),
+    RequestApproval(RequestApproval),
+    ApproveChange(ApproveChange),
+    ExpireApprovals(ExpireApprovals),
+    AttestField(Attestation),
+    IssueCredential(IssueCredential),
+    RevokeCredential(RevokeCredential),
+    SignCredential(SignCredential),
+    // the signed JWT
+    CredentialResult(String),
+    // a JWT to check
+    VerifyCredential(String),
+    VerifiedCredentialResult(VerifiedCredential),
 }
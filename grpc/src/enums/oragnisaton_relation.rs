@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString};
+use tonic::Code;
+
+use crate::{errors::BecoError, proto::beco::OrganisationRelation as ProtoOrganisationRelation};
 
 
 #[derive(Debug, Clone, Display, EnumString, Eq, PartialEq, Hash, Serialize, Deserialize, Copy)]
@@ -8,4 +11,28 @@ pub enum OrganisationRelation {
     EMPLOYER,
     #[strum(serialize = "GOVERNMENT")]
     GOVERNMENT,
+}
+
+impl TryFrom<i32> for OrganisationRelation {
+    type Error = BecoError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match ProtoOrganisationRelation::try_from(value) {
+            Ok(ProtoOrganisationRelation::Employer) => Ok(OrganisationRelation::EMPLOYER),
+            Ok(ProtoOrganisationRelation::Government) => Ok(OrganisationRelation::GOVERNMENT),
+            _ => Err(BecoError {
+                message: "Invalid organisation relation".into(),
+                status: Code::InvalidArgument,
+            }),
+        }
+    }
+}
+
+impl From<OrganisationRelation> for i32 {
+    fn from(val: OrganisationRelation) -> Self {
+        match val {
+            OrganisationRelation::EMPLOYER => ProtoOrganisationRelation::Employer as i32,
+            OrganisationRelation::GOVERNMENT => ProtoOrganisationRelation::Government as i32,
+        }
+    }
 }
\ No newline at end of file
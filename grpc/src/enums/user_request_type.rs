@@ -1,9 +1,31 @@
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString};
 
+use crate::enums::oragnisaton_relation::OrganisationRelation;
 
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Clone, Display, EnumString, Eq, PartialEq, Hash, Serialize, Deserialize, Copy)]
 pub enum UserRequestType {
     #[strum(serialize = "EMPLOYER")]
     EMPLOYER,
-}
\ No newline at end of file
+    #[strum(serialize = "GOVERNMENT")]
+    GOVERNMENT,
+}
+
+impl From<OrganisationRelation> for UserRequestType {
+    fn from(value: OrganisationRelation) -> Self {
+        match value {
+            OrganisationRelation::EMPLOYER => UserRequestType::EMPLOYER,
+            OrganisationRelation::GOVERNMENT => UserRequestType::GOVERNMENT,
+        }
+    }
+}
+
+impl From<UserRequestType> for OrganisationRelation {
+    fn from(val: UserRequestType) -> Self {
+        match val {
+            UserRequestType::EMPLOYER => OrganisationRelation::EMPLOYER,
+            UserRequestType::GOVERNMENT => OrganisationRelation::GOVERNMENT,
+        }
+    }
+}
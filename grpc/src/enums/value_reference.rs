@@ -1,10 +1,44 @@
 use serde::{Deserialize, Serialize};
+use tonic::Code;
+
+use crate::{errors::BecoError, proto::beco::ValueReference as ProtoValueReference};
 
 #[allow(non_camel_case_types)]
-#[derive(Debug, Clone, Serialize, Deserialize, Hash)]
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
 pub enum ValueReference {
     DETAIL_FIRST_NAME = 1,
     DETAIL_OTHER_NAMES = 2,
     DETAIL_LAST_NAME = 3,
     CHAIN_HEYS = 4,
-}
\ No newline at end of file
+    DETAIL_ATTRIBUTE = 5,
+}
+
+impl TryFrom<i32> for ValueReference {
+    type Error = BecoError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match ProtoValueReference::try_from(value) {
+            Ok(ProtoValueReference::DetailFirstName) => Ok(ValueReference::DETAIL_FIRST_NAME),
+            Ok(ProtoValueReference::DetailOtherNames) => Ok(ValueReference::DETAIL_OTHER_NAMES),
+            Ok(ProtoValueReference::DetailLastName) => Ok(ValueReference::DETAIL_LAST_NAME),
+            Ok(ProtoValueReference::ChainKeys) => Ok(ValueReference::CHAIN_HEYS),
+            Ok(ProtoValueReference::DetailAttribute) => Ok(ValueReference::DETAIL_ATTRIBUTE),
+            _ => Err(BecoError {
+                message: "Invalid value reference".into(),
+                status: Code::InvalidArgument,
+            }),
+        }
+    }
+}
+
+impl From<ValueReference> for i32 {
+    fn from(val: ValueReference) -> Self {
+        match val {
+            ValueReference::DETAIL_FIRST_NAME => ProtoValueReference::DetailFirstName as i32,
+            ValueReference::DETAIL_OTHER_NAMES => ProtoValueReference::DetailOtherNames as i32,
+            ValueReference::DETAIL_LAST_NAME => ProtoValueReference::DetailLastName as i32,
+            ValueReference::CHAIN_HEYS => ProtoValueReference::ChainKeys as i32,
+            ValueReference::DETAIL_ATTRIBUTE => ProtoValueReference::DetailAttribute as i32,
+        }
+    }
+}
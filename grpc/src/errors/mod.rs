@@ -1,5 +1,6 @@
 use std::fmt;
 
+use tokio_postgres::error::SqlState;
 use tonic::Code;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -14,8 +15,15 @@ impl fmt::Display for BecoError {
     }
 }
 
+impl std::error::Error for BecoError {}
+
 impl From<tokio_postgres::Error> for BecoError {
     fn from(value: tokio_postgres::Error) -> Self {
-        Self { message: value.to_string(), status: Code::Internal }
+        let status = if value.code() == Some(&SqlState::UNIQUE_VIOLATION) {
+            Code::AlreadyExists
+        } else {
+            Code::Internal
+        };
+        Self { message: value.to_string(), status }
     }
 }
\ No newline at end of file
@@ -1,4 +1,6 @@
+use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
+use tonic::Code;
 
 use crate::{
     chain::chain_custody::{ChainCustody, PublicKey},
@@ -57,9 +59,24 @@ impl Key<EVMAlgortithm> for ChainCustody<EVMKey, EVMKeyValues> {
         algorithm: Option<EVMAlgortithm>,
         request: AddAccountRequest,
         public_user: &PublicUser,
+        _at: &DateTime<Utc>,
     ) -> Result<(), BecoError> {
         unimplemented!()
     }
+
+    // no EVM key can be created yet, so there is nothing to sign with
+    fn sign(
+        &self,
+        _alias: &str,
+        _message: &[u8],
+        _calling_user: &PublicUser,
+        _at: &DateTime<Utc>,
+    ) -> Result<(String, String), BecoError> {
+        Err(BecoError {
+            message: "Signing is not supported for EVM keys".into(),
+            status: Code::Unimplemented,
+        })
+    }
 }
 
 fn generate_key() {}
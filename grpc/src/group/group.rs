@@ -0,0 +1,126 @@
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+use tonic::Code;
+
+#[cfg(feature = "sst")]
+use uuid::Uuid;
+
+use crate::{errors::BecoError, proto::beco::GroupResponse};
+
+const MAX_NAME_LENGTH: usize = 128;
+
+// A named set of users that can be granted a role on fields. Permission models only hold the
+// group's id, membership is looked up when access is checked.
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq)]
+pub struct Group {
+    pub id: String,
+    pub name: String,
+    // a user id or an organisation id
+    pub owner_id: String,
+    members: BTreeSet<String>,
+    sequence: u64,
+}
+
+impl Group {
+    #[cfg(feature = "sst")]
+    pub fn new(name: String, owner_id: String) -> Result<Self, BecoError> {
+        let name = name.trim().to_string();
+        if name.is_empty() || name.len() > MAX_NAME_LENGTH {
+            return Err(BecoError {
+                message: "Invalid group name".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        Ok(Self {
+            id: Uuid::new_v4().to_string(),
+            name,
+            owner_id,
+            members: BTreeSet::new(),
+            sequence: 1,
+        })
+    }
+
+    pub fn increase_sequence(&mut self) -> u64 {
+        self.sequence += 1;
+        self.sequence
+    }
+
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    pub fn check_sequence(&self, expected_sequence: Option<u64>) -> Result<(), BecoError> {
+        if let Some(expected_sequence) = expected_sequence {
+            if expected_sequence != self.sequence {
+                return Err(BecoError {
+                    message: format!(
+                        "Expected sequence {expected_sequence} but the current sequence is {}",
+                        self.sequence
+                    ),
+                    status: Code::Aborted,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn is_member(&self, user_id: &String) -> bool {
+        self.members.contains(user_id)
+    }
+
+    pub fn members(&self) -> &BTreeSet<String> {
+        &self.members
+    }
+
+    // can_manage is decided by the sst, it may need the owning organisation's admins
+    pub fn add_member(&mut self, user_id: String, can_manage: bool) -> Result<(), BecoError> {
+        if !can_manage {
+            return Err(BecoError {
+                message: "User does not have permission to add group members".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        if !self.members.insert(user_id) {
+            return Err(BecoError {
+                message: "User is already a member of this group".into(),
+                status: Code::AlreadyExists,
+            });
+        }
+        Ok(())
+    }
+
+    // members can leave a group they were added to
+    pub fn remove_member(
+        &mut self,
+        user_id: &String,
+        can_manage: bool,
+        calling_user_id: &String,
+    ) -> Result<(), BecoError> {
+        if !can_manage && user_id != calling_user_id {
+            return Err(BecoError {
+                message: "User does not have permission to remove group members".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        if !self.members.remove(user_id) {
+            return Err(BecoError {
+                message: format!("User is not a member of this group: {user_id}"),
+                status: Code::NotFound,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl From<Group> for GroupResponse {
+    fn from(val: Group) -> Self {
+        GroupResponse {
+            id: val.id,
+            name: val.name,
+            owner_id: val.owner_id,
+            members: val.members.into_iter().collect(),
+            sequence: val.sequence,
+        }
+    }
+}
@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+pub mod group;
+
+mod tests;
\ No newline at end of file
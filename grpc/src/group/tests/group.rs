@@ -0,0 +1,57 @@
+#![cfg(all(test, feature = "sst"))]
+
+use tonic::Code;
+
+use crate::group::group::Group;
+
+fn group() -> Group {
+    Group::new("Finance".into(), "owner".into()).unwrap()
+}
+
+#[test]
+fn new_rejects_blank_name() {
+    let result = Group::new(" ".into(), "owner".into());
+    assert_eq!(result.unwrap_err().status, Code::InvalidArgument);
+}
+
+#[test]
+fn manager_can_add_and_remove_members() {
+    let mut group = group();
+
+    group.add_member("member".into(), true).unwrap();
+    assert!(group.is_member(&"member".into()));
+    assert_eq!(
+        group.add_member("member".into(), true).unwrap_err().status,
+        Code::AlreadyExists
+    );
+
+    group
+        .remove_member(&"member".into(), true, &"owner".into())
+        .unwrap();
+    assert!(!group.is_member(&"member".into()));
+}
+
+#[test]
+fn only_a_manager_can_add_members() {
+    let mut group = group();
+
+    let result = group.add_member("member".into(), false);
+
+    assert_eq!(result.unwrap_err().status, Code::PermissionDenied);
+    assert!(group.members().is_empty());
+}
+
+#[test]
+fn member_can_leave_but_not_remove_others() {
+    let mut group = group();
+    group.add_member("member".into(), true).unwrap();
+    group.add_member("other".into(), true).unwrap();
+
+    let result = group.remove_member(&"other".into(), false, &"member".into());
+    assert_eq!(result.unwrap_err().status, Code::PermissionDenied);
+
+    group
+        .remove_member(&"member".into(), false, &"member".into())
+        .unwrap();
+    assert_eq!(group.members().len(), 1);
+}
@@ -0,0 +1 @@
+mod group;
\ No newline at end of file
@@ -13,6 +13,7 @@ impl Hash for AddAccountRequest {
         self.alias.hash(state);
         self.user_id.hash(state);
         self.calling_user.hash(state);
+        self.expected_sequence.hash(state);
     }
 }
 
@@ -21,11 +22,12 @@ impl Serialize for AddAccountRequest {
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_struct("AddAccountRequest", 4)?;
+        let mut state = serializer.serialize_struct("AddAccountRequest", 5)?;
         state.serialize_field("blockchain", &self.blockchain)?;
         state.serialize_field("alias", &self.alias)?;
         state.serialize_field("user_id", &self.user_id)?;
         state.serialize_field("calling_user", &self.calling_user)?;
+        state.serialize_field("expected_sequence", &self.expected_sequence)?;
         state.end()
     }
 }
@@ -37,6 +39,7 @@ enum AddAccountRequestFields {
     Alias,
     UserId,
     CallingUser,
+    ExpectedSequence,
 }
 
 impl<'de> Deserialize<'de> for AddAccountRequest {
@@ -44,7 +47,13 @@ impl<'de> Deserialize<'de> for AddAccountRequest {
     where
         D: serde::Deserializer<'de>,
     {
-        const FIELDS: &'static [&'static str] = &["blockchain", "alias", "user_id", "calling_user"];
+        const FIELDS: &[&str] = &[
+            "blockchain",
+            "alias",
+            "user_id",
+            "calling_user",
+            "expected_sequence",
+        ];
 
         struct RequestVisitor;
 
@@ -71,11 +80,13 @@ impl<'de> Deserialize<'de> for AddAccountRequest {
                 let calling_user = seq
                     .next_element()?
                     .ok_or_else(|| de::Error::invalid_length(3, &self))?;
+                let expected_sequence = seq.next_element()?.unwrap_or(None);
                 Ok(AddAccountRequest {
                     blockchain,
                     alias,
                     user_id,
                     calling_user,
+                    expected_sequence,
                 })
             }
 
@@ -87,6 +98,7 @@ impl<'de> Deserialize<'de> for AddAccountRequest {
                 let mut alias = None;
                 let mut user_id = None;
                 let mut calling_user = None;
+                let mut expected_sequence = None;
                 while let Some(key) = map.next_key()? {
                     match key {
                         AddAccountRequestFields::Blockchain => {
@@ -113,6 +125,12 @@ impl<'de> Deserialize<'de> for AddAccountRequest {
                             }
                             calling_user = Some(map.next_value()?);
                         }
+                        AddAccountRequestFields::ExpectedSequence => {
+                            if expected_sequence.is_some() {
+                                return Err(de::Error::duplicate_field("expected_sequence"));
+                            }
+                            expected_sequence = Some(map.next_value()?);
+                        }
                     }
                 }
                 let blockchain =
@@ -121,14 +139,16 @@ impl<'de> Deserialize<'de> for AddAccountRequest {
                 let user_id = user_id.ok_or_else(|| de::Error::missing_field("user_id"))?;
                 let calling_user =
                     calling_user.ok_or_else(|| de::Error::missing_field("calling_user"))?;
+                let expected_sequence = expected_sequence.unwrap_or(None);
                 Ok(AddAccountRequest {
                     blockchain,
                     alias,
                     user_id,
                     calling_user,
+                    expected_sequence,
                 })
             }
         }
         deserializer.deserialize_struct("AddAccountRequest", FIELDS, RequestVisitor)
     }
-}
\ No newline at end of file
+}
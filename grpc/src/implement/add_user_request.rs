@@ -11,6 +11,7 @@ impl Hash for AddUserRequest {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.name.hash(state);
         self.calling_user.hash(state);
+        self.national_identifier.hash(state);
     }
 }
 
@@ -19,9 +20,10 @@ impl Serialize for AddUserRequest {
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_struct("AddUserRequest", 2)?;
+        let mut state = serializer.serialize_struct("AddUserRequest", 3)?;
         state.serialize_field("name", &self.name)?;
         state.serialize_field("calling_user", &self.calling_user)?;
+        state.serialize_field("national_identifier", &self.national_identifier)?;
         state.end()
     }
 }
@@ -31,6 +33,7 @@ impl Serialize for AddUserRequest {
 enum AddUserRequestFields {
     Name,
     CallingUser,
+    NationalIdentifier,
 }
 
 impl<'de> Deserialize<'de> for AddUserRequest {
@@ -38,7 +41,7 @@ impl<'de> Deserialize<'de> for AddUserRequest {
     where
         D: serde::Deserializer<'de>,
     {
-        const FIELDS: &'static [&'static str] = &["name", "calling_user"];
+        const FIELDS: &[&str] = &["name", "calling_user", "national_identifier"];
 
         struct RequestVisitor;
 
@@ -59,9 +62,11 @@ impl<'de> Deserialize<'de> for AddUserRequest {
                 let calling_user = seq
                     .next_element()?
                     .ok_or_else(|| de::Error::invalid_length(3, &self))?;
+                let national_identifier = seq.next_element()?.unwrap_or(None);
                 Ok(AddUserRequest {
                     name,
                     calling_user,
+                    national_identifier,
                 })
             }
 
@@ -71,6 +76,7 @@ impl<'de> Deserialize<'de> for AddUserRequest {
             {
                 let mut name = None;
                 let mut calling_user = None;
+                let mut national_identifier = None;
                 while let Some(key) = map.next_key()? {
                     match key {
                         AddUserRequestFields::Name => {
@@ -85,13 +91,21 @@ impl<'de> Deserialize<'de> for AddUserRequest {
                             }
                             calling_user = Some(map.next_value()?);
                         }
+                        AddUserRequestFields::NationalIdentifier => {
+                            if national_identifier.is_some() {
+                                return Err(de::Error::duplicate_field("national_identifier"));
+                            }
+                            national_identifier = Some(map.next_value()?);
+                        }
                     }
                 }
                 let name = name.ok_or_else(|| de::Error::missing_field("name"))?;
                 let calling_user = calling_user.ok_or_else(|| de::Error::missing_field("calling_user"))?;
+                let national_identifier = national_identifier.unwrap_or(None);
                 Ok(AddUserRequest {
                     name,
                     calling_user,
+                    national_identifier,
                 })
             }
         }
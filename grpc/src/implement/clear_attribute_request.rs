@@ -0,0 +1,138 @@
+use serde::{
+    de::{self, Deserialize, MapAccess, SeqAccess, Visitor},
+    ser::{Serialize, SerializeStruct},
+    Deserialize as DeserializeDerive,
+};
+use std::hash::Hash;
+
+use crate::proto::beco::ClearAttributeRequest;
+
+impl Hash for ClearAttributeRequest {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.user_id.hash(state);
+        self.calling_user.hash(state);
+        self.key.hash(state);
+        self.expected_sequence.hash(state);
+    }
+}
+
+impl Serialize for ClearAttributeRequest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("ClearAttributeRequest", 4)?;
+        state.serialize_field("user_id", &self.user_id)?;
+        state.serialize_field("calling_user", &self.calling_user)?;
+        state.serialize_field("key", &self.key)?;
+        state.serialize_field("expected_sequence", &self.expected_sequence)?;
+        state.end()
+    }
+}
+
+#[derive(DeserializeDerive)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum ClearAttributeRequestFields {
+    UserId,
+    CallingUser,
+    Key,
+    ExpectedSequence,
+}
+
+impl<'de> Deserialize<'de> for ClearAttributeRequest {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "calling_user",
+            "key",
+            "expected_sequence",
+        ];
+
+        struct RequestVisitor;
+
+        impl<'de> Visitor<'de> for RequestVisitor {
+            type Value = ClearAttributeRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("struct ClearAttributeRequest")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let user_id = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let calling_user = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let key = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                let expected_sequence = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(3, &self))?;
+                Ok(ClearAttributeRequest {
+                    user_id,
+                    calling_user,
+                    key,
+                    expected_sequence,
+                })
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut user_id = None;
+                let mut calling_user = None;
+                let mut key = None;
+                let mut expected_sequence = None;
+                while let Some(field) = map.next_key()? {
+                    match field {
+                        ClearAttributeRequestFields::UserId => {
+                            if user_id.is_some() {
+                                return Err(de::Error::duplicate_field("user_id"));
+                            }
+                            user_id = Some(map.next_value()?);
+                        }
+                        ClearAttributeRequestFields::CallingUser => {
+                            if calling_user.is_some() {
+                                return Err(de::Error::duplicate_field("calling_user"));
+                            }
+                            calling_user = Some(map.next_value()?);
+                        }
+                        ClearAttributeRequestFields::Key => {
+                            if key.is_some() {
+                                return Err(de::Error::duplicate_field("key"));
+                            }
+                            key = Some(map.next_value()?);
+                        }
+                        ClearAttributeRequestFields::ExpectedSequence => {
+                            if expected_sequence.is_some() {
+                                return Err(de::Error::duplicate_field("expected_sequence"));
+                            }
+                            expected_sequence = Some(map.next_value()?);
+                        }
+                    }
+                }
+                let user_id = user_id.ok_or_else(|| de::Error::missing_field("user_id"))?;
+                let calling_user =
+                    calling_user.ok_or_else(|| de::Error::missing_field("calling_user"))?;
+                let key = key.ok_or_else(|| de::Error::missing_field("key"))?;
+                let expected_sequence = expected_sequence.unwrap_or(None);
+                Ok(ClearAttributeRequest {
+                    user_id,
+                    calling_user,
+                    key,
+                    expected_sequence,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ClearAttributeRequest", FIELDS, RequestVisitor)
+    }
+}
@@ -0,0 +1,117 @@
+use serde::{
+    de::{self, Deserialize, MapAccess, SeqAccess, Visitor},
+    ser::{Serialize, SerializeStruct},
+    Deserialize as DeserializeDerive,
+};
+use std::hash::Hash;
+
+use crate::proto::beco::CreateOrganisationRequest;
+
+impl Hash for CreateOrganisationRequest {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.calling_user.hash(state);
+        self.name.hash(state);
+        self.relation.hash(state);
+    }
+}
+
+impl Serialize for CreateOrganisationRequest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("CreateOrganisationRequest", 3)?;
+        state.serialize_field("calling_user", &self.calling_user)?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("relation", &self.relation)?;
+        state.end()
+    }
+}
+
+#[derive(DeserializeDerive)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum CreateOrganisationRequestFields {
+    CallingUser,
+    Name,
+    Relation,
+}
+
+impl<'de> Deserialize<'de> for CreateOrganisationRequest {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["calling_user", "name", "relation"];
+
+        struct RequestVisitor;
+
+        impl<'de> Visitor<'de> for RequestVisitor {
+            type Value = CreateOrganisationRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("struct CreateOrganisationRequest")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let calling_user = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let name = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let relation = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                Ok(CreateOrganisationRequest {
+                    calling_user,
+                    name,
+                    relation,
+                })
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut calling_user = None;
+                let mut name = None;
+                let mut relation = None;
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        CreateOrganisationRequestFields::CallingUser => {
+                            if calling_user.is_some() {
+                                return Err(de::Error::duplicate_field("calling_user"));
+                            }
+                            calling_user = Some(map.next_value()?);
+                        }
+                        CreateOrganisationRequestFields::Name => {
+                            if name.is_some() {
+                                return Err(de::Error::duplicate_field("name"));
+                            }
+                            name = Some(map.next_value()?);
+                        }
+                        CreateOrganisationRequestFields::Relation => {
+                            if relation.is_some() {
+                                return Err(de::Error::duplicate_field("relation"));
+                            }
+                            relation = Some(map.next_value()?);
+                        }
+                    }
+                }
+                let calling_user =
+                    calling_user.ok_or_else(|| de::Error::missing_field("calling_user"))?;
+                let name = name.ok_or_else(|| de::Error::missing_field("name"))?;
+                let relation = relation.ok_or_else(|| de::Error::missing_field("relation"))?;
+                Ok(CreateOrganisationRequest {
+                    calling_user,
+                    name,
+                    relation,
+                })
+            }
+        }
+        deserializer.deserialize_struct("CreateOrganisationRequest", FIELDS, RequestVisitor)
+    }
+}
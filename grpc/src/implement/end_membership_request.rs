@@ -0,0 +1,156 @@
+use serde::{
+    de::{self, Deserialize, MapAccess, SeqAccess, Visitor},
+    ser::{Serialize, SerializeStruct},
+    Deserialize as DeserializeDerive,
+};
+use std::hash::Hash;
+
+use crate::proto::beco::EndMembershipRequest;
+
+impl Hash for EndMembershipRequest {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.organisation_id.hash(state);
+        self.calling_user.hash(state);
+        self.user_id.hash(state);
+        self.end_date.hash(state);
+        self.expected_sequence.hash(state);
+    }
+}
+
+impl Serialize for EndMembershipRequest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("EndMembershipRequest", 5)?;
+        state.serialize_field("organisation_id", &self.organisation_id)?;
+        state.serialize_field("calling_user", &self.calling_user)?;
+        state.serialize_field("user_id", &self.user_id)?;
+        state.serialize_field("end_date", &self.end_date)?;
+        state.serialize_field("expected_sequence", &self.expected_sequence)?;
+        state.end()
+    }
+}
+
+#[derive(DeserializeDerive)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum EndMembershipRequestFields {
+    OrganisationId,
+    CallingUser,
+    UserId,
+    EndDate,
+    ExpectedSequence,
+}
+
+impl<'de> Deserialize<'de> for EndMembershipRequest {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "organisation_id",
+            "calling_user",
+            "user_id",
+            "end_date",
+            "expected_sequence",
+        ];
+
+        struct RequestVisitor;
+
+        impl<'de> Visitor<'de> for RequestVisitor {
+            type Value = EndMembershipRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("struct EndMembershipRequest")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let organisation_id = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let calling_user = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let user_id = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                let end_date = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(3, &self))?;
+                let expected_sequence = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(4, &self))?;
+                Ok(EndMembershipRequest {
+                    organisation_id,
+                    calling_user,
+                    user_id,
+                    end_date,
+                    expected_sequence,
+                })
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut organisation_id = None;
+                let mut calling_user = None;
+                let mut user_id = None;
+                let mut end_date = None;
+                let mut expected_sequence = None;
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        EndMembershipRequestFields::OrganisationId => {
+                            if organisation_id.is_some() {
+                                return Err(de::Error::duplicate_field("organisation_id"));
+                            }
+                            organisation_id = Some(map.next_value()?);
+                        }
+                        EndMembershipRequestFields::CallingUser => {
+                            if calling_user.is_some() {
+                                return Err(de::Error::duplicate_field("calling_user"));
+                            }
+                            calling_user = Some(map.next_value()?);
+                        }
+                        EndMembershipRequestFields::UserId => {
+                            if user_id.is_some() {
+                                return Err(de::Error::duplicate_field("user_id"));
+                            }
+                            user_id = Some(map.next_value()?);
+                        }
+                        EndMembershipRequestFields::EndDate => {
+                            if end_date.is_some() {
+                                return Err(de::Error::duplicate_field("end_date"));
+                            }
+                            end_date = Some(map.next_value()?);
+                        }
+                        EndMembershipRequestFields::ExpectedSequence => {
+                            if expected_sequence.is_some() {
+                                return Err(de::Error::duplicate_field("expected_sequence"));
+                            }
+                            expected_sequence = Some(map.next_value()?);
+                        }
+                    }
+                }
+                let organisation_id =
+                    organisation_id.ok_or_else(|| de::Error::missing_field("organisation_id"))?;
+                let calling_user =
+                    calling_user.ok_or_else(|| de::Error::missing_field("calling_user"))?;
+                let user_id = user_id.ok_or_else(|| de::Error::missing_field("user_id"))?;
+                let end_date = end_date.unwrap_or(None);
+                let expected_sequence = expected_sequence.unwrap_or(None);
+                Ok(EndMembershipRequest {
+                    organisation_id,
+                    calling_user,
+                    user_id,
+                    end_date,
+                    expected_sequence,
+                })
+            }
+        }
+        deserializer.deserialize_struct("EndMembershipRequest", FIELDS, RequestVisitor)
+    }
+}
@@ -0,0 +1,138 @@
+use serde::{
+    de::{self, Deserialize, MapAccess, SeqAccess, Visitor},
+    ser::{Serialize, SerializeStruct},
+    Deserialize as DeserializeDerive,
+};
+use std::hash::Hash;
+
+use crate::proto::beco::GroupMemberRequest;
+
+impl Hash for GroupMemberRequest {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.group_id.hash(state);
+        self.calling_user.hash(state);
+        self.user_id.hash(state);
+        self.expected_sequence.hash(state);
+    }
+}
+
+impl Serialize for GroupMemberRequest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("GroupMemberRequest", 4)?;
+        state.serialize_field("group_id", &self.group_id)?;
+        state.serialize_field("calling_user", &self.calling_user)?;
+        state.serialize_field("user_id", &self.user_id)?;
+        state.serialize_field("expected_sequence", &self.expected_sequence)?;
+        state.end()
+    }
+}
+
+#[derive(DeserializeDerive)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum GroupMemberRequestFields {
+    GroupId,
+    CallingUser,
+    UserId,
+    ExpectedSequence,
+}
+
+impl<'de> Deserialize<'de> for GroupMemberRequest {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "group_id",
+            "calling_user",
+            "user_id",
+            "expected_sequence",
+        ];
+
+        struct RequestVisitor;
+
+        impl<'de> Visitor<'de> for RequestVisitor {
+            type Value = GroupMemberRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("struct GroupMemberRequest")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let group_id = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let calling_user = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let user_id = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                let expected_sequence = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(3, &self))?;
+                Ok(GroupMemberRequest {
+                    group_id,
+                    calling_user,
+                    user_id,
+                    expected_sequence,
+                })
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut group_id = None;
+                let mut calling_user = None;
+                let mut user_id = None;
+                let mut expected_sequence = None;
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        GroupMemberRequestFields::GroupId => {
+                            if group_id.is_some() {
+                                return Err(de::Error::duplicate_field("group_id"));
+                            }
+                            group_id = Some(map.next_value()?);
+                        }
+                        GroupMemberRequestFields::CallingUser => {
+                            if calling_user.is_some() {
+                                return Err(de::Error::duplicate_field("calling_user"));
+                            }
+                            calling_user = Some(map.next_value()?);
+                        }
+                        GroupMemberRequestFields::UserId => {
+                            if user_id.is_some() {
+                                return Err(de::Error::duplicate_field("user_id"));
+                            }
+                            user_id = Some(map.next_value()?);
+                        }
+                        GroupMemberRequestFields::ExpectedSequence => {
+                            if expected_sequence.is_some() {
+                                return Err(de::Error::duplicate_field("expected_sequence"));
+                            }
+                            expected_sequence = Some(map.next_value()?);
+                        }
+                    }
+                }
+                let group_id = group_id.ok_or_else(|| de::Error::missing_field("group_id"))?;
+                let calling_user =
+                    calling_user.ok_or_else(|| de::Error::missing_field("calling_user"))?;
+                let user_id = user_id.ok_or_else(|| de::Error::missing_field("user_id"))?;
+                let expected_sequence = expected_sequence.unwrap_or(None);
+                Ok(GroupMemberRequest {
+                    group_id,
+                    calling_user,
+                    user_id,
+                    expected_sequence,
+                })
+            }
+        }
+        deserializer.deserialize_struct("GroupMemberRequest", FIELDS, RequestVisitor)
+    }
+}
@@ -0,0 +1,117 @@
+use serde::{
+    de::{self, Deserialize, MapAccess, SeqAccess, Visitor},
+    ser::{Serialize, SerializeStruct},
+    Deserialize as DeserializeDerive,
+};
+use std::hash::Hash;
+
+use crate::proto::beco::ListMembershipsRequest;
+
+impl Hash for ListMembershipsRequest {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.calling_user.hash(state);
+        self.organisation_id.hash(state);
+        self.user_id.hash(state);
+    }
+}
+
+impl Serialize for ListMembershipsRequest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("ListMembershipsRequest", 3)?;
+        state.serialize_field("calling_user", &self.calling_user)?;
+        state.serialize_field("organisation_id", &self.organisation_id)?;
+        state.serialize_field("user_id", &self.user_id)?;
+        state.end()
+    }
+}
+
+#[derive(DeserializeDerive)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum ListMembershipsRequestFields {
+    CallingUser,
+    OrganisationId,
+    UserId,
+}
+
+impl<'de> Deserialize<'de> for ListMembershipsRequest {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["calling_user", "organisation_id", "user_id"];
+
+        struct RequestVisitor;
+
+        impl<'de> Visitor<'de> for RequestVisitor {
+            type Value = ListMembershipsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("struct ListMembershipsRequest")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let calling_user = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let organisation_id = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let user_id = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                Ok(ListMembershipsRequest {
+                    calling_user,
+                    organisation_id,
+                    user_id,
+                })
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut calling_user = None;
+                let mut organisation_id = None;
+                let mut user_id = None;
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        ListMembershipsRequestFields::CallingUser => {
+                            if calling_user.is_some() {
+                                return Err(de::Error::duplicate_field("calling_user"));
+                            }
+                            calling_user = Some(map.next_value()?);
+                        }
+                        ListMembershipsRequestFields::OrganisationId => {
+                            if organisation_id.is_some() {
+                                return Err(de::Error::duplicate_field("organisation_id"));
+                            }
+                            organisation_id = Some(map.next_value()?);
+                        }
+                        ListMembershipsRequestFields::UserId => {
+                            if user_id.is_some() {
+                                return Err(de::Error::duplicate_field("user_id"));
+                            }
+                            user_id = Some(map.next_value()?);
+                        }
+                    }
+                }
+                let calling_user =
+                    calling_user.ok_or_else(|| de::Error::missing_field("calling_user"))?;
+                let organisation_id = organisation_id.unwrap_or(None);
+                let user_id = user_id.unwrap_or(None);
+                Ok(ListMembershipsRequest {
+                    calling_user,
+                    organisation_id,
+                    user_id,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ListMembershipsRequest", FIELDS, RequestVisitor)
+    }
+}
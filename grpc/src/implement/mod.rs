@@ -3,4 +3,13 @@ mod modify_other_names_request;
 mod add_account_request;
 mod add_user_request;
 mod modify_linked_user_request;
-mod list_user_request;
\ No newline at end of file
+mod list_user_request;
+mod user_diff_request;
+mod clear_attribute_request;
+mod create_organisation_request;
+mod add_member_request;
+mod end_membership_request;
+mod list_memberships_request;
+mod respond_access_request;
+mod create_group_request;
+mod group_member_request;
@@ -11,6 +11,7 @@ impl Hash for ModifyLinkedUserRequest {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.user_id.hash(state);
         self.calling_user.hash(state);
+        self.expected_sequence.hash(state);
     }
 }
 
@@ -19,9 +20,10 @@ impl Serialize for ModifyLinkedUserRequest {
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_struct("ModifyLinkedUserRequest", 2)?;
+        let mut state = serializer.serialize_struct("ModifyLinkedUserRequest", 3)?;
         state.serialize_field("user_id", &self.user_id)?;
         state.serialize_field("calling_user", &self.calling_user)?;
+        state.serialize_field("expected_sequence", &self.expected_sequence)?;
         state.end()
     }
 }
@@ -31,6 +33,7 @@ impl Serialize for ModifyLinkedUserRequest {
 enum ModifyLinkedUserRequestFields {
     UserId,
     CallingUser,
+    ExpectedSequence,
 }
 
 impl<'de> Deserialize<'de> for ModifyLinkedUserRequest {
@@ -38,7 +41,7 @@ impl<'de> Deserialize<'de> for ModifyLinkedUserRequest {
     where
         D: serde::Deserializer<'de>,
     {
-        const FIELDS: &'static [&'static str] = &["user_id", "calling_user"];
+        const FIELDS: &[&str] = &["user_id", "calling_user", "expected_sequence"];
 
         struct RequestVisitor;
 
@@ -59,9 +62,11 @@ impl<'de> Deserialize<'de> for ModifyLinkedUserRequest {
                 let calling_user = seq
                     .next_element()?
                     .ok_or_else(|| de::Error::invalid_length(3, &self))?;
+                let expected_sequence = seq.next_element()?.unwrap_or(None);
                 Ok(ModifyLinkedUserRequest {
                     user_id,
                     calling_user,
+                    expected_sequence,
                 })
             }
 
@@ -71,6 +76,7 @@ impl<'de> Deserialize<'de> for ModifyLinkedUserRequest {
             {
                 let mut user_id = None;
                 let mut calling_user = None;
+                let mut expected_sequence = None;
                 while let Some(key) = map.next_key()? {
                     match key {
                         ModifyLinkedUserRequestFields::UserId => {
@@ -85,6 +91,12 @@ impl<'de> Deserialize<'de> for ModifyLinkedUserRequest {
                             }
                             calling_user = Some(map.next_value()?);
                         }
+                        ModifyLinkedUserRequestFields::ExpectedSequence => {
+                            if expected_sequence.is_some() {
+                                return Err(de::Error::duplicate_field("expected_sequence"));
+                            }
+                            expected_sequence = Some(map.next_value()?);
+                        }
                     }
                 }
                 let user_id = user_id.ok_or_else(|| de::Error::missing_field("user_id"))?;
@@ -92,6 +104,7 @@ impl<'de> Deserialize<'de> for ModifyLinkedUserRequest {
                 Ok(ModifyLinkedUserRequest {
                     user_id,
                     calling_user,
+                    expected_sequence: expected_sequence.unwrap_or(None),
                 })
             }
         }
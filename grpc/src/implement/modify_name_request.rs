@@ -11,6 +11,7 @@ impl Hash for ModifyNameRequest {
         self.calling_user.hash(state);
         self.user_id.hash(state);
         self.name.hash(state);
+        self.expected_sequence.hash(state);
     }
 }
 
@@ -19,10 +20,11 @@ impl Serialize for ModifyNameRequest {
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_struct("ModifyNameRequest", 3)?;
+        let mut state = serializer.serialize_struct("ModifyNameRequest", 4)?;
         state.serialize_field("user_id", &self.user_id)?;
         state.serialize_field("calling_user", &self.calling_user)?;
         state.serialize_field("name", &self.name)?;
+        state.serialize_field("expected_sequence", &self.expected_sequence)?;
         state.end()
     }
 }
@@ -33,6 +35,7 @@ enum ModifyNameRequestFields {
     UserId,
     CallingUser,
     Name,
+    ExpectedSequence,
 }
 
 impl<'de> Deserialize<'de> for ModifyNameRequest {
@@ -40,7 +43,7 @@ impl<'de> Deserialize<'de> for ModifyNameRequest {
     where
         D: serde::Deserializer<'de>,
     {
-        const FIELDS: &'static [&'static str] = &["user_id", "calling_user", "name"];
+        const FIELDS: &[&str] = &["user_id", "calling_user", "name", "expected_sequence"];
 
         struct RequestVisitor;
 
@@ -64,10 +67,12 @@ impl<'de> Deserialize<'de> for ModifyNameRequest {
                 let name = seq
                     .next_element()?
                     .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                let expected_sequence = seq.next_element()?.unwrap_or(None);
                 Ok(ModifyNameRequest {
                     user_id,
                     calling_user,
                     name,
+                    expected_sequence,
                 })
             }
 
@@ -78,6 +83,7 @@ impl<'de> Deserialize<'de> for ModifyNameRequest {
                 let mut user_id = None;
                 let mut calling_user = None;
                 let mut name = None;
+                let mut expected_sequence = None;
                 while let Some(key) = map.next_key()? {
                     match key {
                         ModifyNameRequestFields::UserId => {
@@ -98,16 +104,24 @@ impl<'de> Deserialize<'de> for ModifyNameRequest {
                             }
                             name = Some(map.next_value()?);
                         }
+                        ModifyNameRequestFields::ExpectedSequence => {
+                            if expected_sequence.is_some() {
+                                return Err(de::Error::duplicate_field("expected_sequence"));
+                            }
+                            expected_sequence = Some(map.next_value()?);
+                        }
                     }
                 }
                 let user_id = user_id.ok_or_else(|| de::Error::missing_field("user_id"))?;
                 let calling_user =
                     calling_user.ok_or_else(|| de::Error::missing_field("calling_user"))?;
                 let name = name.ok_or_else(|| de::Error::missing_field("name"))?;
+                let expected_sequence = expected_sequence.unwrap_or(None);
                 Ok(ModifyNameRequest {
                     user_id,
                     calling_user,
                     name,
+                    expected_sequence,
                 })
             }
         }
@@ -11,6 +11,7 @@ impl Hash for ModifyOtherNamesRequest {
         self.calling_user.hash(state);
         self.user_id.hash(state);
         self.other_names.hash(state);
+        self.expected_sequence.hash(state);
     }
 }
 
@@ -19,10 +20,11 @@ impl Serialize for ModifyOtherNamesRequest {
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_struct("ModifyOtherNamesRequest", 3)?;
+        let mut state = serializer.serialize_struct("ModifyOtherNamesRequest", 4)?;
         state.serialize_field("user_id", &self.user_id)?;
         state.serialize_field("calling_user", &self.calling_user)?;
         state.serialize_field("other_names", &self.other_names)?;
+        state.serialize_field("expected_sequence", &self.expected_sequence)?;
         state.end()
     }
 }
@@ -33,6 +35,7 @@ enum ModifyOtherNamesRequestFields {
     UserId,
     CallingUser,
     OtherNames,
+    ExpectedSequence,
 }
 
 impl<'de> Deserialize<'de> for ModifyOtherNamesRequest {
@@ -40,7 +43,12 @@ impl<'de> Deserialize<'de> for ModifyOtherNamesRequest {
     where
         D: serde::Deserializer<'de>,
     {
-        const FIELDS: &'static [&'static str] = &["user_id", "calling_user", "other_names"];
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "calling_user",
+            "other_names",
+            "expected_sequence",
+        ];
 
         struct RequestVisitor;
 
@@ -64,10 +72,12 @@ impl<'de> Deserialize<'de> for ModifyOtherNamesRequest {
                 let other_names = seq
                     .next_element()?
                     .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                let expected_sequence = seq.next_element()?.unwrap_or(None);
                 Ok(ModifyOtherNamesRequest {
                     user_id,
                     calling_user,
                     other_names,
+                    expected_sequence,
                 })
             }
 
@@ -78,6 +88,7 @@ impl<'de> Deserialize<'de> for ModifyOtherNamesRequest {
                 let mut user_id = None;
                 let mut calling_user = None;
                 let mut other_names = None;
+                let mut expected_sequence = None;
                 while let Some(key) = map.next_key()? {
                     match key {
                         ModifyOtherNamesRequestFields::UserId => {
@@ -98,6 +109,12 @@ impl<'de> Deserialize<'de> for ModifyOtherNamesRequest {
                             }
                             other_names = Some(map.next_value()?);
                         }
+                        ModifyOtherNamesRequestFields::ExpectedSequence => {
+                            if expected_sequence.is_some() {
+                                return Err(de::Error::duplicate_field("expected_sequence"));
+                            }
+                            expected_sequence = Some(map.next_value()?);
+                        }
                     }
                 }
                 let user_id = user_id.ok_or_else(|| de::Error::missing_field("user_id"))?;
@@ -105,13 +122,15 @@ impl<'de> Deserialize<'de> for ModifyOtherNamesRequest {
                     calling_user.ok_or_else(|| de::Error::missing_field("calling_user"))?;
                 let other_names =
                     other_names.ok_or_else(|| de::Error::missing_field("other_names"))?;
+                let expected_sequence = expected_sequence.unwrap_or(None);
                 Ok(ModifyOtherNamesRequest {
                     user_id,
                     calling_user,
                     other_names,
+                    expected_sequence,
                 })
             }
         }
         deserializer.deserialize_struct("ModifyOtherNamesRequest", FIELDS, RequestVisitor)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,140 @@
+use serde::{
+    de::{self, Deserialize, MapAccess, SeqAccess, Visitor},
+    ser::{Serialize, SerializeStruct},
+    Deserialize as DeserializeDerive,
+};
+use std::hash::Hash;
+
+use crate::proto::beco::UserDiffRequest;
+
+impl Hash for UserDiffRequest {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.user_id.hash(state);
+        self.calling_user.hash(state);
+        self.from_sequence.hash(state);
+        self.to_sequence.hash(state);
+    }
+}
+
+impl Serialize for UserDiffRequest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("UserDiffRequest", 4)?;
+        state.serialize_field("user_id", &self.user_id)?;
+        state.serialize_field("calling_user", &self.calling_user)?;
+        state.serialize_field("from_sequence", &self.from_sequence)?;
+        state.serialize_field("to_sequence", &self.to_sequence)?;
+        state.end()
+    }
+}
+
+#[derive(DeserializeDerive)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum UserDiffRequestFields {
+    UserId,
+    CallingUser,
+    FromSequence,
+    ToSequence,
+}
+
+impl<'de> Deserialize<'de> for UserDiffRequest {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user_id",
+            "calling_user",
+            "from_sequence",
+            "to_sequence",
+        ];
+
+        struct RequestVisitor;
+
+        impl<'de> Visitor<'de> for RequestVisitor {
+            type Value = UserDiffRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("struct UserDiffRequest")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let user_id = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let calling_user = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let from_sequence = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                let to_sequence = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(3, &self))?;
+                Ok(UserDiffRequest {
+                    user_id,
+                    calling_user,
+                    from_sequence,
+                    to_sequence,
+                })
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut user_id = None;
+                let mut calling_user = None;
+                let mut from_sequence = None;
+                let mut to_sequence = None;
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        UserDiffRequestFields::UserId => {
+                            if user_id.is_some() {
+                                return Err(de::Error::duplicate_field("user_id"));
+                            }
+                            user_id = Some(map.next_value()?);
+                        }
+                        UserDiffRequestFields::CallingUser => {
+                            if calling_user.is_some() {
+                                return Err(de::Error::duplicate_field("calling_user"));
+                            }
+                            calling_user = Some(map.next_value()?);
+                        }
+                        UserDiffRequestFields::FromSequence => {
+                            if from_sequence.is_some() {
+                                return Err(de::Error::duplicate_field("from_sequence"));
+                            }
+                            from_sequence = Some(map.next_value()?);
+                        }
+                        UserDiffRequestFields::ToSequence => {
+                            if to_sequence.is_some() {
+                                return Err(de::Error::duplicate_field("to_sequence"));
+                            }
+                            to_sequence = Some(map.next_value()?);
+                        }
+                    }
+                }
+                let user_id = user_id.ok_or_else(|| de::Error::missing_field("user_id"))?;
+                let calling_user =
+                    calling_user.ok_or_else(|| de::Error::missing_field("calling_user"))?;
+                let from_sequence =
+                    from_sequence.ok_or_else(|| de::Error::missing_field("from_sequence"))?;
+                let to_sequence =
+                    to_sequence.ok_or_else(|| de::Error::missing_field("to_sequence"))?;
+                Ok(UserDiffRequest {
+                    user_id,
+                    calling_user,
+                    from_sequence,
+                    to_sequence,
+                })
+            }
+        }
+        deserializer.deserialize_struct("UserDiffRequest", FIELDS, RequestVisitor)
+    }
+}
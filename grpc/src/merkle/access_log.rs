@@ -0,0 +1,50 @@
+use tonic::Code;
+
+use crate::{
+    audit::access_record::{AccessRecord, ListAccessLog},
+    enums::data_value::{DataRequests, ProcessRequest},
+    errors::BecoError,
+};
+
+use super::SST;
+
+// Reads are recorded by the node that served them and are not validated, so the log sits next to
+// the user's transactions rather than in their merkle tree.
+#[cfg(feature = "sst")]
+impl SST {
+    pub async fn record_access(&self, process_request: ProcessRequest) -> Result<(), BecoError> {
+        let DataRequests::RecordAccess(mut record) = process_request.request else {
+            return Err(BecoError {
+                message: "Not an access record".into(),
+                status: Code::InvalidArgument,
+            });
+        };
+        if record.viewer_id == record.user_id {
+            return Err(BecoError {
+                message: "Reads by the owner are not recorded".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        if self.fetch_user(&record.user_id).await.is_none() {
+            return Err(BecoError {
+                message: format!("User not found: {}", record.user_id),
+                status: Code::NotFound,
+            });
+        }
+        // the serving node sets its own peer id on everything it publishes
+        record.node = process_request.originator_peer_id.unwrap_or_default();
+        self.storage.save_access_record(&record).await
+    }
+
+    pub async fn access_log(&self, request: ListAccessLog) -> Result<Vec<AccessRecord>, BecoError> {
+        if request.calling_user != request.user_id {
+            return Err(BecoError {
+                message: "User does not have permission to view this access log".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        self.storage
+            .load_access_records(&request.user_id, request.since)
+            .await
+    }
+}
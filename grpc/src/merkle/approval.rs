@@ -0,0 +1,76 @@
+use chrono::{DateTime, Utc};
+use tonic::Code;
+
+use crate::{
+    errors::BecoError,
+    user::{
+        approval::{ApproveChange, Approver, SetApprovalPolicy},
+        public_user::PublicUser,
+    },
+};
+
+use super::SST;
+
+#[cfg(feature = "sst")]
+impl SST {
+    // the users and organisations named on a policy must exist
+    pub async fn verify_approval_policy(
+        &self,
+        request: &SetApprovalPolicy,
+    ) -> Result<(), BecoError> {
+        for approver in request.approvers.iter() {
+            match approver {
+                Approver::User(user_id) => {
+                    if self.fetch_user(user_id).await.is_none() {
+                        return Err(BecoError {
+                            message: format!("User not found: {user_id}"),
+                            status: Code::NotFound,
+                        });
+                    }
+                }
+                Approver::OrganisationAdmin(organisation_id) => {
+                    self.fetch_organisation(organisation_id).await?;
+                }
+                Approver::Guardian => {}
+            }
+        }
+        Ok(())
+    }
+
+    // validators cannot tell who administers an organisation, so approvals by admins are
+    // checked here
+    pub async fn verify_change_approver(
+        &self,
+        request: &ApproveChange,
+        calling_user_id: &String,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        let user_option = self.fetch_user(&request.user_id).await;
+        if user_option.is_none() {
+            return Err(BecoError {
+                message: format!("User not found: {}", request.user_id),
+                status: Code::NotFound,
+            });
+        }
+        let calling_user = PublicUser::new(calling_user_id.clone(), None, None, None, vec![]);
+        let organisation_ids =
+            user_option
+                .unwrap()
+                .approval_organisations(&request.approval_id, &calling_user, at);
+        if organisation_ids.is_empty() {
+            return Ok(());
+        }
+        for organisation_id in organisation_ids.iter() {
+            let organisation_result = self.fetch_organisation(organisation_id).await;
+            if organisation_result.is_ok_and(|organisation| organisation.is_admin(calling_user_id))
+            {
+                return Ok(());
+            }
+        }
+        Err(BecoError {
+            message: "User is not an approver for this change".into(),
+            status: Code::PermissionDenied,
+        })
+    }
+
+}
@@ -0,0 +1,46 @@
+use tonic::Code;
+
+use crate::{errors::BecoError, user::attestation::Attestation};
+
+use super::SST;
+
+#[cfg(feature = "sst")]
+impl SST {
+    // validators cannot tell who administers an organisation or whether the key is still the
+    // admin's, so both are checked here
+    pub async fn verify_attestation(&self, attestation: &Attestation) -> Result<(), BecoError> {
+        let organisation_result = self.fetch_organisation(&attestation.organisation_id).await;
+        if let Err(err) = organisation_result {
+            return Err(err);
+        }
+        let organisation = organisation_result.unwrap();
+        if !organisation.is_admin(&attestation.attested_by) {
+            return Err(BecoError {
+                message: "User does not have permission to attest for this organisation".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        if organisation.name != attestation.organisation_name
+            || organisation.relation != attestation.relation
+        {
+            return Err(BecoError {
+                message: "The attestation does not match the organisation".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        let attester_option = self.fetch_user(&attestation.attested_by).await;
+        if attester_option.is_none() {
+            return Err(BecoError {
+                message: format!("User not found: {}", attestation.attested_by),
+                status: Code::NotFound,
+            });
+        }
+        if attester_option.unwrap().auth_key() != Some(&attestation.public_key) {
+            return Err(BecoError {
+                message: "The attestation was not signed with the admin's auth key".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+use envconfig::Envconfig;
+
+#[derive(Envconfig, Clone)]
+pub struct Config {
+    // hex encoded secp256k1 key, grpc nodes seal national identifiers to its public key
+    #[envconfig(from = "IDENTITY_SECRET_KEY")]
+    pub identity_secret_key: Option<String>,
+
+    // hex encoded key for the national identifier blind index, at least 32 bytes
+    #[envconfig(from = "BLIND_INDEX_KEY")]
+    pub blind_index_key: Option<String>,
+
+    #[envconfig(from = "BLIND_INDEX_SALT", default = "beco")]
+    pub blind_index_salt: String,
+
+    // hex encoded secp256k1 key that signs verifiable credentials
+    #[envconfig(from = "ISSUER_SECRET_KEY")]
+    pub issuer_secret_key: Option<String>,
+}
\ No newline at end of file
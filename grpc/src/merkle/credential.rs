@@ -0,0 +1,128 @@
+use chrono::{DateTime, Utc};
+use tonic::Code;
+
+use crate::{
+    errors::BecoError,
+    user::{
+        attestation::value_hash,
+        credential::{
+            credential_subject, CredentialClaims, Issuer, SignCredential, VerifiedCredential,
+        },
+        public_user::PublicUser,
+    },
+};
+
+use super::SST;
+
+#[cfg(feature = "sst")]
+impl SST {
+    fn issuer(&self) -> Result<&Issuer, BecoError> {
+        if self.issuer.is_none() {
+            return Err(BecoError {
+                message: "No issuer key is configured".into(),
+                status: Code::FailedPrecondition,
+            });
+        }
+        Ok(self.issuer.as_ref().unwrap())
+    }
+
+    // only signs a committed credential for its own user, and only while its fields still hash to
+    // what was issued
+    pub async fn sign_credential(
+        &self,
+        request: SignCredential,
+        calling_user: &String,
+    ) -> Result<String, BecoError> {
+        let issuer_result = self.issuer();
+        if let Err(err) = issuer_result {
+            return Err(err);
+        }
+        if calling_user != &request.user_id {
+            return Err(BecoError {
+                message: "Only the user can have their credential signed".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        let user_option = self.fetch_user(&request.user_id).await;
+        if user_option.is_none() {
+            return Err(BecoError {
+                message: format!("User not found: {}", request.user_id),
+                status: Code::NotFound,
+            });
+        }
+        let user = user_option.unwrap();
+        let credential_option = user.credential(&request.credential_id);
+        if credential_option.is_none() {
+            return Err(BecoError {
+                message: format!("Credential not found: {}", request.credential_id),
+                status: Code::NotFound,
+            });
+        }
+        let credential = credential_option.unwrap();
+        if credential.revoked_at.is_some() {
+            return Err(BecoError {
+                message: format!("Credential revoked: {}", request.credential_id),
+                status: Code::FailedPrecondition,
+            });
+        }
+        let owner = PublicUser::new(user.id.clone(), None, None, None, vec![]);
+        let subject_result = credential_subject(&user, &owner, &credential.fields);
+        if let Err(err) = subject_result {
+            return Err(err);
+        }
+        let subject = subject_result.unwrap();
+        if value_hash(&subject) != credential.claims_hash {
+            return Err(BecoError {
+                message: "The user's fields changed after the credential was issued".into(),
+                status: Code::FailedPrecondition,
+            });
+        }
+        Ok(issuer_result
+            .unwrap()
+            .sign(&credential.claims(&user.id, subject)))
+    }
+
+    // the signature is checked against the issuer key and the status against the user's record
+    pub async fn verify_credential(
+        &self,
+        jwt: &str,
+        at: &DateTime<Utc>,
+    ) -> Result<VerifiedCredential, BecoError> {
+        let issuer_result = self.issuer();
+        if let Err(err) = issuer_result {
+            return Err(err);
+        }
+        let claims_result = issuer_result.unwrap().verify(jwt);
+        if let Err(err) = claims_result {
+            return Err(err);
+        }
+        let credential_claims_result = CredentialClaims::try_from(claims_result.unwrap());
+        if let Err(err) = credential_claims_result {
+            return Err(err);
+        }
+        let credential_claims = credential_claims_result.unwrap();
+        let user_option = self.fetch_user(&credential_claims.user_id).await;
+        if user_option.is_none() {
+            return Err(BecoError {
+                message: format!("User not found: {}", credential_claims.user_id),
+                status: Code::NotFound,
+            });
+        }
+        let user = user_option.unwrap();
+        let credential_option = user.credential(&credential_claims.credential_id);
+        if credential_option.is_none() {
+            return Err(BecoError {
+                message: format!("Credential not found: {}", credential_claims.credential_id),
+                status: Code::NotFound,
+            });
+        }
+        Ok(VerifiedCredential {
+            credential_id: credential_claims.credential_id,
+            user_id: credential_claims.user_id,
+            status: credential_option.unwrap().status(at),
+            credential_subject: credential_claims.credential_subject.to_string(),
+            issued_at: credential_claims.issued_at,
+            expires_at: credential_claims.expires_at,
+        })
+    }
+}
@@ -0,0 +1,54 @@
+use rs_merkle::{algorithms::Sha256, Hasher};
+use serde::{Deserialize, Serialize};
+
+// The right edge of an append-only merkle tree: one perfect subtree root (peak) per set bit of
+// the leaf count, largest first. Appending a leaf and computing the root are both O(log n) and
+// the root matches `rs_merkle::MerkleTree::from_leaves` over the same leaves.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct MerkleFrontier {
+    leaf_count: u64,
+    peaks: Vec<[u8; 32]>,
+}
+
+impl MerkleFrontier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_leaves(leaves: &[[u8; 32]]) -> Self {
+        let mut frontier = MerkleFrontier::new();
+        leaves.iter().for_each(|leaf| frontier.append(*leaf));
+        frontier
+    }
+
+    pub fn leaf_count(&self) -> u64 {
+        self.leaf_count
+    }
+
+    pub fn append(&mut self, leaf: [u8; 32]) {
+        // merge equally sized subtrees like carrying bits in a binary counter
+        let mut node = leaf;
+        let mut height = 0;
+        while self.leaf_count >> height & 1 == 1 {
+            let left = self.peaks.pop().unwrap();
+            node = Sha256::concat_and_hash(&left, Some(&node));
+            height += 1;
+        }
+        self.peaks.push(node);
+        self.leaf_count += 1;
+    }
+
+    pub fn root(&self) -> Option<[u8; 32]> {
+        let mut peaks = self.peaks.iter().rev();
+        let mut root = *peaks.next()?;
+        for peak in peaks {
+            root = Sha256::concat_and_hash(peak, Some(&root));
+        }
+        Some(root)
+    }
+
+    pub fn root_hex(&self) -> Option<String> {
+        self.root()
+            .map(|root| root.iter().map(|byte| format!("{byte:02x}")).collect())
+    }
+}
@@ -0,0 +1,221 @@
+use std::collections::HashMap;
+
+use tonic::Code;
+
+use crate::{
+    enums::data_value::{DataRequests, ProcessRequest},
+    errors::BecoError,
+    group::group::Group,
+};
+
+use super::{transaction::GroupTransaction, SST};
+
+// Groups are only changed by the sst. They have a transaction log but no merkle tree, the grants
+// that reference them are part of the user's tree.
+#[cfg(feature = "sst")]
+impl SST {
+    pub async fn update_group(&self, process_request: ProcessRequest) -> Result<Group, BecoError> {
+        // gossipsub can deliver a request more than once, acknowledge it without reapplying
+        let committed_result = self
+            .storage
+            .load_group_transaction_for_request(&process_request.request_id())
+            .await;
+        if let Err(err) = committed_result {
+            return Err(err);
+        }
+        if let Some(transaction) = committed_result.unwrap() {
+            return Ok(transaction.group);
+        }
+
+        let calling_user = process_request.calling_user.clone();
+        if self.fetch_user(&calling_user).await.is_none() {
+            return Err(BecoError {
+                message: format!("User not found: {calling_user}"),
+                status: Code::NotFound,
+            });
+        }
+
+        let mut groups = self.groups.write().await;
+        let updated_result = match process_request.request.clone() {
+            DataRequests::CreateGroup(request) => {
+                let owner_id = match request.organisation_id {
+                    Some(organisation_id) => {
+                        let organisation_result = self.fetch_organisation(&organisation_id).await;
+                        if let Err(err) = organisation_result {
+                            return Err(err);
+                        }
+                        if !organisation_result.unwrap().is_admin(&calling_user) {
+                            return Err(BecoError {
+                                message: "User does not have permission to create groups for this organisation".into(),
+                                status: Code::PermissionDenied,
+                            });
+                        }
+                        organisation_id
+                    }
+                    None => calling_user,
+                };
+                Group::new(request.name, owner_id)
+            }
+            DataRequests::AddGroupMember(request) => {
+                let loaded_result = self
+                    .group_for_update(&mut groups, &request.group_id, request.expected_sequence)
+                    .await;
+                if let Err(err) = loaded_result {
+                    return Err(err);
+                }
+                let mut group = loaded_result.unwrap();
+                if self.fetch_user(&request.user_id).await.is_none() {
+                    return Err(BecoError {
+                        message: format!("User not found: {}", request.user_id),
+                        status: Code::NotFound,
+                    });
+                }
+                let can_manage = self.can_manage_group(&group, &calling_user).await;
+                group.add_member(request.user_id, can_manage).map(|_| {
+                    group.increase_sequence();
+                    group
+                })
+            }
+            DataRequests::RemoveGroupMember(request) => {
+                let loaded_result = self
+                    .group_for_update(&mut groups, &request.group_id, request.expected_sequence)
+                    .await;
+                if let Err(err) = loaded_result {
+                    return Err(err);
+                }
+                let mut group = loaded_result.unwrap();
+                let can_manage = self.can_manage_group(&group, &calling_user).await;
+                group
+                    .remove_member(&request.user_id, can_manage, &calling_user)
+                    .map(|_| {
+                        group.increase_sequence();
+                        group
+                    })
+            }
+            _ => Err(BecoError {
+                message: "Not a group request".to_string(),
+                status: Code::InvalidArgument,
+            }),
+        };
+        if let Err(err) = updated_result {
+            return Err(err);
+        }
+        let group = updated_result.unwrap();
+
+        let save_result = self.save_group(&group, process_request.clone()).await;
+        if let Err(err) = save_result {
+            // lost the race against a redelivery of the same request
+            if err.status == Code::AlreadyExists {
+                if let Ok(Some(transaction)) = self
+                    .storage
+                    .load_group_transaction_for_request(&process_request.request_id())
+                    .await
+                {
+                    return Ok(transaction.group);
+                }
+            }
+            return Err(err);
+        }
+        groups.insert(group.id.clone(), group.clone());
+        Ok(group)
+    }
+
+    // a copy to change, the cache is only updated once the change is persisted
+    async fn group_for_update(
+        &self,
+        groups: &mut HashMap<String, Group>,
+        group_id: &String,
+        expected_sequence: Option<u64>,
+    ) -> Result<Group, BecoError> {
+        if !groups.contains_key(group_id) {
+            let loaded_result = self.storage.load_group(group_id).await;
+            if let Err(err) = loaded_result {
+                return Err(err);
+            }
+            let loaded_option = loaded_result.unwrap();
+            if loaded_option.is_none() {
+                return Err(BecoError {
+                    message: format!("Group not found: {group_id}"),
+                    status: Code::NotFound,
+                });
+            }
+            groups.insert(group_id.clone(), loaded_option.unwrap());
+        }
+        let group = groups.get(group_id).unwrap().clone();
+        group.check_sequence(expected_sequence)?;
+        Ok(group)
+    }
+
+    // the owner manages a user's group, the admins manage an organisation's group
+    async fn can_manage_group(&self, group: &Group, calling_user: &String) -> bool {
+        if &group.owner_id == calling_user {
+            return true;
+        }
+        match self.fetch_organisation(&group.owner_id).await {
+            Ok(organisation) => organisation.is_admin(calling_user),
+            Err(_) => false,
+        }
+    }
+
+    async fn save_group(
+        &self,
+        group: &Group,
+        process_request: ProcessRequest,
+    ) -> Result<(), BecoError> {
+        let request_id = process_request.request_id();
+        let transaction = GroupTransaction {
+            group: group.clone(),
+            sequence: group.sequence(),
+            process_request,
+        };
+        let serialised_group_result = serde_json::to_value(group);
+        let serialised_transaction_result = serde_json::to_value(&transaction);
+        if serialised_group_result.is_err() || serialised_transaction_result.is_err() {
+            return Err(BecoError {
+                message: "Failed to serialize the group".to_string(),
+                status: Code::Internal,
+            });
+        }
+        let sequence: i64 = group.sequence().try_into().unwrap();
+        self.storage
+            .save_group_request(
+                &group.id,
+                &serialised_group_result.unwrap(),
+                &serialised_transaction_result.unwrap(),
+                &request_id,
+                sequence,
+            )
+            .await
+    }
+
+    pub async fn fetch_group(&self, group_id: &String) -> Result<Group, BecoError> {
+        if let Some(group) = self.groups.read().await.get(group_id) {
+            return Ok(group.clone());
+        }
+        let loaded_result = self.storage.load_group(group_id).await;
+        if let Err(err) = loaded_result {
+            return Err(err);
+        }
+        let loaded_option = loaded_result.unwrap();
+        if loaded_option.is_none() {
+            return Err(BecoError {
+                message: format!("Group not found: {group_id}"),
+                status: Code::NotFound,
+            });
+        }
+        Ok(loaded_option.unwrap())
+    }
+
+    // read from storage so a membership change made by another request is always seen
+    pub async fn group_ids_for_member(&self, user_id: &str) -> Result<Vec<String>, BecoError> {
+        let groups_result = self.storage.load_groups_for_member(user_id).await;
+        if let Err(err) = groups_result {
+            return Err(err);
+        }
+        Ok(groups_result
+            .unwrap()
+            .into_iter()
+            .map(|group| group.id)
+            .collect())
+    }
+}
@@ -0,0 +1,157 @@
+use chrono::Utc;
+use tonic::Code;
+
+use crate::{
+    enums::data_value::{DataRequests, ProcessRequest},
+    errors::BecoError,
+    user::{
+        linked_user::{ConfirmLinkedUser, ModifyLinkedUser},
+        user::User,
+    },
+};
+
+use super::SST;
+
+// A transaction only changes one user, so each side of a link holds its own consent and the sst,
+// which sees both users, proposes the change the other side needs.
+#[cfg(feature = "sst")]
+impl SST {
+    pub async fn verify_linked_user(&self, linked_user_id: &String) -> Result<(), BecoError> {
+        if self.fetch_user(linked_user_id).await.is_none() {
+            return Err(BecoError {
+                message: format!("User not found: {linked_user_id}"),
+                status: Code::NotFound,
+            });
+        }
+        Ok(())
+    }
+
+    // a link is only confirmed while the other user still consents to it
+    pub async fn verify_link_confirmation(
+        &self,
+        request: &ConfirmLinkedUser,
+    ) -> Result<(), BecoError> {
+        let linked_user_option = self.fetch_user(&request.linked_user_id).await;
+        if linked_user_option.is_none()
+            || !linked_user_option.unwrap().consents_to_link(&request.user_id)
+        {
+            return Err(BecoError {
+                message: format!("{} has not consented to this link", request.linked_user_id),
+                status: Code::FailedPrecondition,
+            });
+        }
+        Ok(())
+    }
+
+    // called once the request is committed, each result is proposed as
+    // (request, calling user, user id). The sweep proposes them again if they never commit
+    pub async fn linked_user_follow_ups(
+        &self,
+        process_request: &ProcessRequest,
+    ) -> Vec<(DataRequests, String, String)> {
+        let follow_ups = self.committed_link_follow_ups(process_request).await;
+        let sweep_result = self.sweep_later(&follow_ups, &Utc::now()).await;
+        if let Err(err) = sweep_result {
+            println!("{err:?}");
+        }
+        follow_ups
+    }
+
+    async fn committed_link_follow_ups(
+        &self,
+        process_request: &ProcessRequest,
+    ) -> Vec<(DataRequests, String, String)> {
+        let user_id = process_request.user_id.clone();
+        match &process_request.request {
+            // both users consented, confirm the link on each side that is still waiting
+            DataRequests::AddLinkedUser(request) => {
+                let linked_user_option = self.fetch_user(&request.user_id).await;
+                if linked_user_option.is_none() {
+                    return vec![];
+                }
+                let linked_user = linked_user_option.unwrap();
+                if !linked_user.consents_to_link(&user_id) {
+                    return vec![];
+                }
+                let mut follow_ups = vec![(
+                    DataRequests::ConfirmLinkedUser(ConfirmLinkedUser {
+                        user_id: user_id.clone(),
+                        linked_user_id: linked_user.id.clone(),
+                    }),
+                    user_id.clone(),
+                    user_id.clone(),
+                )];
+                if !linked_user.is_linked_to(&user_id) {
+                    follow_ups.push((
+                        DataRequests::ConfirmLinkedUser(ConfirmLinkedUser {
+                            user_id: linked_user.id.clone(),
+                            linked_user_id: user_id,
+                        }),
+                        linked_user.id.clone(),
+                        linked_user.id,
+                    ));
+                }
+                follow_ups
+            }
+            // the other user drops their side too, proposed as this user leaving the link
+            DataRequests::RemoveLinkedUser(request) => {
+                let removed_user_id = request.user_id.clone();
+                let removed_user_option = self.fetch_user(&removed_user_id).await;
+                if removed_user_option.is_none()
+                    || !removed_user_option.unwrap().consents_to_link(&user_id)
+                {
+                    return vec![];
+                }
+                vec![(
+                    DataRequests::RemoveLinkedUser(ModifyLinkedUser {
+                        user_id: user_id.clone(),
+                        calling_user: user_id.clone(),
+                        expected_sequence: None,
+                    }),
+                    user_id,
+                    removed_user_id,
+                )]
+            }
+            _ => vec![],
+        }
+    }
+
+    // the follow-ups still missing on the user's side of its links, from the current state of
+    // both users, so a lost proposal is made again. A link the other user never consented to
+    // is left waiting
+    pub async fn outstanding_link_changes(
+        &self,
+        user: &User,
+    ) -> Vec<(DataRequests, String, String)> {
+        let mut changes = vec![];
+        for linked_user_id in user.linked_user_ids() {
+            let linked_user_option = self.fetch_user(&linked_user_id).await;
+            if linked_user_option.is_none() {
+                continue;
+            }
+            let linked_user = linked_user_option.unwrap();
+            let is_linked = user.is_linked_to(&linked_user_id);
+            if linked_user.consents_to_link(&user.id) && !is_linked {
+                changes.push((
+                    DataRequests::ConfirmLinkedUser(ConfirmLinkedUser {
+                        user_id: user.id.clone(),
+                        linked_user_id,
+                    }),
+                    user.id.clone(),
+                    user.id.clone(),
+                ));
+            } else if !linked_user.consents_to_link(&user.id) && is_linked {
+                changes.push((
+                    DataRequests::RemoveLinkedUser(ModifyLinkedUser {
+                        user_id: linked_user_id.clone(),
+                        calling_user: linked_user_id.clone(),
+                        expected_sequence: None,
+                    }),
+                    linked_user_id,
+                    user.id.clone(),
+                ));
+            }
+        }
+        changes
+    }
+}
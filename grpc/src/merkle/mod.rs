@@ -10,51 +10,135 @@
 
 //
 
-mod transaction;
+pub mod access_log;
+pub mod approval;
+pub mod attestation;
+pub mod credential;
+mod config;
+pub mod frontier;
+pub mod group;
+pub mod linked_user;
+pub mod organisation;
+pub mod rebuild;
+pub mod recovery;
+pub mod sweep;
+pub mod transaction;
+
+mod tests;
 
 use std::collections::HashMap;
 
-use rs_merkle::{algorithms::Sha256, Hasher, MerkleTree};
+use chrono::{DateTime, Utc};
+use envconfig::Envconfig;
+use rs_merkle::{algorithms::Sha256, Hasher};
 use serde_json::Value;
 use tokio::sync::RwLock;
 use tonic::Code;
 
 use crate::{
-    db::DB,
+    db::{
+        storage::{CommittedRequest, Storage},
+        DB,
+    },
     entry::Entry,
     enums::data_value::{DataRequests, ProcessRequest},
     errors::BecoError,
-    user::{public_user::PublicUser, user::User},
+    group::group::Group,
+    organisation::organisation::Organisation,
+    proto::beco::UserDiffRequest,
+    user::{
+        credential::Issuer, national_identifier::BlindIndex,
+        public_user::PublicUser, user::User, user_diff::FieldDiff,
+    },
 };
 
-use self::transaction::Transaction;
+use self::{config::Config, frontier::MerkleFrontier, transaction::Transaction};
 
 #[cfg(feature = "sst")]
 pub struct SST {
-    // hashmap of user id and merkle tree
-    trees: RwLock<HashMap<String, RwLock<MerkleTree<Sha256>>>>,
+    // hashmap of user id and the right edge of their merkle tree
+    trees: RwLock<HashMap<String, RwLock<MerkleFrontier>>>,
     // entry to perform update and export current state
     entry: Entry,
-    db: DB,
+    storage: Box<dyn Storage>,
+    // turns sealed national identifiers into blind indexes, None when not configured
+    blind_index: Option<BlindIndex>,
+    // organisations and their merkle frontier, the write lock serialises organisation updates
+    organisations: RwLock<HashMap<String, (Organisation, MerkleFrontier)>>,
+    // the write lock serialises group updates
+    groups: RwLock<HashMap<String, Group>>,
+    // signs verifiable credentials, None when not configured
+    issuer: Option<Issuer>,
+    // hashes of the changes the sweeper proposed and when, dropped once they can be retried
+    swept: RwLock<HashMap<u64, DateTime<Utc>>>,
 }
 
 #[cfg(feature = "sst")]
 impl SST {
-    pub fn new() -> Self {
-        let db = DB::new();
+    pub fn new() -> Result<Self, BecoError> {
+        let config_result = Config::init_from_env();
+        if let Err(err) = config_result {
+            return Err(BecoError {
+                message: format!("Invalid sst config: {err}"),
+                status: Code::InvalidArgument,
+            });
+        }
+        let config = config_result.unwrap();
+        let mut sst = SST::with_storage(Box::new(DB::new()));
+        if let Some(issuer_secret_key) = config.issuer_secret_key {
+            let issuer_result = Issuer::new(&issuer_secret_key);
+            if let Err(err) = issuer_result {
+                return Err(err);
+            }
+            let issuer = issuer_result.unwrap();
+            println!("Issuer public key: {}", issuer.public_key());
+            sst = sst.with_issuer(issuer);
+        }
+        if config.identity_secret_key.is_none() || config.blind_index_key.is_none() {
+            return Ok(sst);
+        }
+        let blind_index_result = BlindIndex::new(
+            &config.identity_secret_key.unwrap(),
+            &config.blind_index_key.unwrap(),
+            &config.blind_index_salt,
+        );
+        if let Err(err) = blind_index_result {
+            return Err(err);
+        }
+        let blind_index = blind_index_result.unwrap();
+        println!("Identity public key: {}", blind_index.public_key());
+        Ok(sst.with_blind_index(blind_index))
+    }
+
+    pub fn with_storage(storage: Box<dyn Storage>) -> Self {
         Self {
             trees: RwLock::new(HashMap::new()),
             entry: Entry::new(),
-            db,
+            storage,
+            blind_index: None,
+            organisations: RwLock::new(HashMap::new()),
+            groups: RwLock::new(HashMap::new()),
+            issuer: None,
+            swept: RwLock::new(HashMap::new()),
         }
     }
 
+    pub fn with_blind_index(mut self, blind_index: BlindIndex) -> Self {
+        self.blind_index = Some(blind_index);
+        self
+    }
+
+    pub fn with_issuer(mut self, issuer: Issuer) -> Self {
+        self.issuer = Some(issuer);
+        self
+    }
+
     pub async fn fetch_user(&self, user_id: &String) -> Option<User> {
         let user_option = self.entry.fetch_user(user_id).await;
         if user_option.is_some() {
             return user_option;
         }
-        let user_result = self.db.load_user(user_id).await;
+        let user_result = self.storage.load_user(user_id).await;
         if let Ok(user) = user_result {
             self.entry.load_user(user.clone()).await;
             return Some(user);
@@ -62,49 +146,112 @@ impl SST {
         None
     }
 
+    pub async fn user_diff(&self, request: UserDiffRequest) -> Result<Vec<FieldDiff>, BecoError> {
+        let from_sequence: i64 = request.from_sequence.try_into().unwrap_or(i64::MAX);
+        let to_sequence: i64 = request.to_sequence.try_into().unwrap_or(i64::MAX);
+        let transactions_result = self
+            .storage
+            .load_transactions(&request.user_id, from_sequence, to_sequence)
+            .await;
+        if let Err(err) = transactions_result {
+            return Err(err);
+        }
+        let transactions = transactions_result.unwrap();
+        let starts_at_from = transactions
+            .first()
+            .is_some_and(|transaction| transaction.sequence == request.from_sequence);
+        if !starts_at_from {
+            return Err(BecoError {
+                message: format!(
+                    "No transaction found for sequence {}",
+                    request.from_sequence
+                ),
+                status: Code::NotFound,
+            });
+        }
+        // redaction is based on the permissions held at the time of each change
+        let viewer = PublicUser::new(request.calling_user, None, None, None, vec![]);
+        let changes = transactions
+            .windows(2)
+            .flat_map(|pair| {
+                FieldDiff::between(
+                    &pair[0].user,
+                    &pair[1].user,
+                    &pair[1].process_request.calling_user,
+                    pair[1].process_request.datetime,
+                    &viewer,
+                )
+            })
+            .collect();
+        Ok(changes)
+    }
+
     pub async fn update(
         &self,
         process_request: ProcessRequest,
-    ) -> Result<(User, PublicUser), crate::errors::BecoError> {
-        let cloned_process_request = process_request.clone();
-        let updated_user_result = match cloned_process_request.request.clone() {
-            DataRequests::AddCryptoAccount(data_request) => {
-                self.entry
-                    .update_value(
-                        cloned_process_request.request,
-                        cloned_process_request.calling_user,
-                        cloned_process_request.user_id,
-                    )
-                    .await
-            }
-            DataRequests::FirstName(data_request) => {
-                self.entry
-                    .update_value(
-                        cloned_process_request.request,
-                        cloned_process_request.calling_user,
-                        cloned_process_request.user_id,
-                    )
-                    .await
-            }
-            DataRequests::OtherNames(data_request) => {
-                self.entry
-                    .update_value(
-                        cloned_process_request.request,
-                        cloned_process_request.calling_user,
-                        cloned_process_request.user_id,
-                    )
-                    .await
+    ) -> Result<(User, PublicUser, String), crate::errors::BecoError> {
+        // gossipsub can deliver a request more than once, acknowledge it without reapplying
+        let committed_result = self.committed_transaction(&process_request).await;
+        if let Err(err) = committed_result {
+            return Err(err);
+        }
+        if let Some(committed) = committed_result.unwrap() {
+            println!(
+                "Request {} has already been committed",
+                process_request.request_id()
+            );
+            return Ok(committed);
+        }
+        let expected_sequence = process_request.request.expected_sequence();
+        if expected_sequence.is_some() {
+            let user_option = self.fetch_user(&process_request.user_id).await;
+            if let Some(user) = user_option {
+                user.check_sequence(expected_sequence)?;
             }
-            DataRequests::LastName(data_request) => {
+        }
+        // the sealed national identifier is swapped for its blind index and never stored
+        let mut process_request = process_request;
+        let blind_index_result = self.national_identifier_index(&mut process_request).await;
+        if let Err(err) = blind_index_result {
+            return Err(err);
+        }
+        let blind_index = blind_index_result.unwrap();
+        // grants to groups and policies are checked against the calling user's current memberships
+        let calling_user_groups_result = self
+            .group_ids_for_member(&process_request.calling_user)
+            .await;
+        if let Err(err) = calling_user_groups_result {
+            return Err(err);
+        }
+        self.entry
+            .set_groups(&process_request.calling_user, calling_user_groups_result.unwrap())
+            .await;
+        let calling_user_memberships_result = self
+            .memberships_for_user(&process_request.calling_user)
+            .await;
+        if let Err(err) = calling_user_memberships_result {
+            return Err(err);
+        }
+        self.entry
+            .set_memberships(
+                &process_request.calling_user,
+                calling_user_memberships_result.unwrap(),
+            )
+            .await;
+        self.verify_request(&process_request).await?;
+        let cloned_process_request = process_request.clone();
+        let updated_user_result = match cloned_process_request.request {
+            DataRequests::AddUser(data_request) => self.entry.add_user(data_request).await,
+            request if SST::is_mutation(&request) => {
                 self.entry
                     .update_value(
-                        cloned_process_request.request,
+                        request,
                         cloned_process_request.calling_user,
                         cloned_process_request.user_id,
+                        cloned_process_request.datetime,
                     )
                     .await
             }
-            DataRequests::AddUser(data_request) => self.entry.add_user(data_request).await,
             _ => Err(BecoError {
                 message: "Not iomplemented".to_string(),
                 status: Code::Unimplemented,
@@ -114,27 +261,194 @@ impl SST {
             return Err(updated_user_result.unwrap_err());
         }
         // update and save merkle tree
-        let (user, public_user) = updated_user_result.unwrap();
-        let merkle_update_result = self.update_merkle_tree(user.clone(), process_request).await;
-        if merkle_update_result.is_err() {
-            let err = merkle_update_result.clone().unwrap_err();
+        let (user, calling_user) = updated_user_result.unwrap();
+        let merkle_update_result = self
+            .update_merkle_tree(
+                user.clone(),
+                process_request.clone(),
+                &calling_user,
+                blind_index.as_ref(),
+            )
+            .await;
+        if let Err(err) = merkle_update_result {
             println!("{err:?}");
-            return Err(merkle_update_result.unwrap_err());
+            // the change was applied in memory but not persisted, go back to the stored user
+            match self.storage.load_user(&user.id).await {
+                Ok(stored_user) => self.entry.load_user(stored_user).await,
+                Err(_) => self.entry.unload_user(&user.id).await,
+            }
+            // lost the race against a redelivery of the same request
+            if err.status == Code::AlreadyExists {
+                if let Ok(Some(committed)) = self.committed_transaction(&process_request).await {
+                    return Ok(committed);
+                }
+            }
+            return Err(err);
         }
-        Ok((user, public_user))
+        Ok((user, calling_user, merkle_update_result.unwrap()))
+    }
+
+    // the checks only the sst can make, such as that other users, groups and organisations exist,
+    // everything else is checked by the user's own rules in update_value
+    async fn verify_request(&self, process_request: &ProcessRequest) -> Result<(), BecoError> {
+        match &process_request.request {
+            DataRequests::RequestAccess(request) => self.verify_access_request(request).await,
+            DataRequests::GrantGroupAccess(request) => {
+                self.fetch_group(&request.group_id).await.map(|_| ())
+            }
+            DataRequests::AddLinkedUser(request) => self.verify_linked_user(&request.user_id).await,
+            DataRequests::ConfirmLinkedUser(request) => {
+                self.verify_link_confirmation(request).await
+            }
+            DataRequests::SetGuardian(request) => {
+                let guardian_id = &request.guardianship.guardian_id;
+                if self.fetch_user(guardian_id).await.is_none() {
+                    return Err(BecoError {
+                        message: format!("User not found: {guardian_id}"),
+                        status: Code::NotFound,
+                    });
+                }
+                Ok(())
+            }
+            DataRequests::SetRecoveryGuardians(request) => {
+                self.verify_recovery_guardians(request).await
+            }
+            DataRequests::SetApprovalPolicy(request) => self.verify_approval_policy(request).await,
+            DataRequests::RequestApproval(request) => match request.change.as_ref() {
+                DataRequests::SetApprovalPolicy(policy) => {
+                    self.verify_approval_policy(policy).await
+                }
+                _ => Ok(()),
+            },
+            DataRequests::ApproveChange(request) => {
+                self.verify_change_approver(
+                    request,
+                    &process_request.calling_user,
+                    &process_request.datetime.unwrap_or(Utc::now()),
+                )
+                .await
+            }
+            DataRequests::AttestField(request) => self.verify_attestation(request).await,
+            _ => Ok(()),
+        }
+    }
+
+    // every request that changes a user, these are committed as a transaction on the user
+    fn is_mutation(request: &DataRequests) -> bool {
+        !matches!(
+            request,
+            DataRequests::LoadUser(_)
+                | DataRequests::FetchUser(_)
+                | DataRequests::UserDiff(_)
+                | DataRequests::UserDiffResult(_)
+                | DataRequests::Committed(_)
+                | DataRequests::Rejected(_)
+                | DataRequests::CreateOrganisation(_)
+                | DataRequests::AddMember(_)
+                | DataRequests::EndMembership(_)
+                | DataRequests::OrganisationResult(_)
+                | DataRequests::ListMemberships(_)
+                | DataRequests::MembershipsResult(_)
+                | DataRequests::FetchOrganisation(_)
+                | DataRequests::CreateGroup(_)
+                | DataRequests::AddGroupMember(_)
+                | DataRequests::RemoveGroupMember(_)
+                | DataRequests::GroupResult(_)
+                | DataRequests::FetchGroups(_)
+                | DataRequests::GroupsResult(_)
+                | DataRequests::RecordAccess(_)
+                | DataRequests::ListAccessLog(_)
+                | DataRequests::AccessLogResult(_)
+                | DataRequests::SignCredential(_)
+                | DataRequests::CredentialResult(_)
+                | DataRequests::VerifyCredential(_)
+                | DataRequests::VerifiedCredentialResult(_)
+        )
+    }
+
+    async fn national_identifier_index(
+        &self,
+        process_request: &mut ProcessRequest,
+    ) -> Result<Option<String>, BecoError> {
+        let sealed = match &mut process_request.request {
+            DataRequests::AddUser(request) => request.national_identifier.take(),
+            _ => None,
+        };
+        if sealed.is_none() {
+            return Ok(None);
+        }
+        if self.blind_index.is_none() {
+            return Err(BecoError {
+                message: "National identifiers are not configured on this sst".into(),
+                status: Code::FailedPrecondition,
+            });
+        }
+        let index_result = self
+            .blind_index
+            .as_ref()
+            .unwrap()
+            .index_sealed(&sealed.unwrap());
+        if let Err(err) = index_result {
+            return Err(err);
+        }
+        let index = index_result.unwrap();
+        let existing_result = self.storage.user_for_blind_index(&index).await;
+        if let Err(err) = existing_result {
+            return Err(err);
+        }
+        if existing_result.unwrap().is_some() {
+            return Err(BecoError {
+                message: "A user with this national identifier already exists".into(),
+                status: Code::AlreadyExists,
+            });
+        }
+        Ok(Some(index))
+    }
+
+    async fn committed_transaction(
+        &self,
+        process_request: &ProcessRequest,
+    ) -> Result<Option<(User, PublicUser, String)>, BecoError> {
+        if !SST::is_mutation(&process_request.request) {
+            return Ok(None);
+        }
+        let transaction_result = self
+            .storage
+            .load_transaction_for_request(&process_request.request_id())
+            .await;
+        if let Err(err) = transaction_result {
+            return Err(err);
+        }
+        Ok(transaction_result.unwrap().map(|(transaction, merkle_root)| {
+            let mut calling_user = PublicUser::new(
+                transaction.process_request.calling_user.clone(),
+                None,
+                None,
+                None,
+                vec![],
+            );
+            calling_user.groups = transaction.calling_user_groups;
+            calling_user.memberships = transaction.calling_user_memberships;
+            (transaction.user, calling_user, merkle_root)
+        }))
     }
 
     async fn update_merkle_tree(
         &self,
         user: User,
         process_request: ProcessRequest,
-    ) -> Result<(), BecoError> {
+        calling_user: &PublicUser,
+        blind_index: Option<&String>,
+    ) -> Result<String, BecoError> {
+        let request_id = process_request.request_id();
         let sequence = user.sequence();
         let serialised_user_result = serde_json::to_value(&user);
         let transaction = Transaction {
             user: user.clone(),
             sequence,
             process_request,
+            calling_user_groups: calling_user.groups.clone(),
+            calling_user_memberships: calling_user.memberships.clone(),
         };
         let serialised_transaction_result = serde_json::to_value(&transaction);
         if serialised_user_result.is_err() {
@@ -157,70 +471,70 @@ impl SST {
             trees.contains_key(&user.id)
         };
         if !tree_loaded {
-            self.load_merkle_tree_for_user(user.id.clone()).await;
+            self.load_merkle_tree_for_user(user.id.clone()).await?;
         }
-        // now get and update merkle tree
 
-        {
-            let mut trees = self.trees.write().await;
-            let tree = trees.get_mut(&user.id).unwrap();
-            let mut writable_tree = tree.write().await;
-            writable_tree.insert(Sha256::hash(serialised_transaction.to_string().as_bytes()));
-            writable_tree.commit();
+        // extend a copy so the cached frontier only moves once the transaction is persisted
+        let leaf = Sha256::hash(serialised_transaction.to_string().as_bytes());
+        let mut frontier = {
+            let trees = self.trees.read().await;
+            let tree = trees.get(&user.id).unwrap();
+            let read_tree = tree.read().await;
+            read_tree.clone()
+        };
+        frontier.append(leaf);
+        let root_option = frontier.root_hex();
+        if root_option.is_none() {
+            return Err(BecoError {
+                message: "Merkle tree does not exist".to_string(),
+                status: Code::NotFound,
+            });
         }
-        self.save_user_request_and_merkle(user, serialised_transaction, serialised_user)
-            .await
-    }
+        let root = root_option.unwrap();
 
-    async fn load_merkle_tree_for_user(&self, user_id: String) {
-        // should load from DB first but will implement later on
-        let tree_result = self.db.load_merkle(&user_id).await;
-        let tree = if tree_result.is_err() {
-            MerkleTree::new()
-        } else {
-            let leaves = tree_result.unwrap();
-            MerkleTree::from_leaves(&leaves)
+        let request = CommittedRequest {
+            request_id: &request_id,
+            sequence: sequence.try_into().unwrap(),
+            serialised_transaction: &serialised_transaction,
+            leaf,
+            root: &root,
         };
+        self.save_user_request_and_merkle(&user, &serialised_user, request, &frontier, blind_index)
+            .await?;
+
+        let trees = self.trees.read().await;
+        let tree = trees.get(&user.id).unwrap();
+        *tree.write().await = frontier;
+        Ok(root)
+    }
+
+    async fn load_merkle_tree_for_user(&self, user_id: String) -> Result<(), BecoError> {
+        let tree_result = self.storage.load_merkle(&user_id).await;
+        if let Err(err) = tree_result {
+            return Err(err);
+        }
+        let tree = tree_result.unwrap().unwrap_or_default();
         let mut trees = self.trees.write().await;
         trees.insert(user_id, RwLock::new(tree));
+        Ok(())
     }
 
     async fn save_user_request_and_merkle(
         &self,
-        user: User,
-        serialised_transaction: Value,
-        serialised_user: Value,
+        user: &User,
+        serialised_user: &Value,
+        request: CommittedRequest<'_>,
+        frontier: &MerkleFrontier,
+        blind_index: Option<&String>,
     ) -> Result<(), BecoError> {
-        let sequence: i64 = user.sequence().try_into().unwrap();
-        let (root_option, leaves_option) = {
-            let trees = self.trees.read().await;
-            let tree_option = trees.get(&user.id);
-            if let Some(tree) = tree_option {
-                let read_tree = tree.write().await;
-                (read_tree.root_hex(), read_tree.leaves())
-            } else {
-                (None, None)
-            }
-        };
-
-        if leaves_option.is_none() || root_option.is_none() {
-            return Err(BecoError {
-                message: "Merkle tree does not exist".to_string(),
-                status: Code::NotFound,
-            });
-        }
-
-        let leaves = leaves_option.unwrap();
-        let root = root_option.unwrap();
-
-        self.db
+        self.storage
             .save_user_request_and_merkle(
                 &user.id,
-                &serialised_user,
-                &serialised_transaction,
-                sequence,
-                &root,
-                leaves,
+                serialised_user,
+                request,
+                self.next_sweep(user, &Utc::now()).await,
+                frontier,
+                blind_index,
             )
             .await
     }
@@ -0,0 +1,312 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
+use rs_merkle::{algorithms::Sha256, Hasher};
+use tonic::Code;
+
+use crate::{
+    db::storage::CommittedRequest,
+    enums::{
+        data_value::{DataRequests, ProcessRequest},
+        oragnisaton_relation::OrganisationRelation,
+        user_request_type::UserRequestType,
+    },
+    errors::BecoError,
+    organisation::{
+        organisation::{parse_date, Organisation},
+        user_organisation::UserOrganisation,
+    },
+    proto::beco::ListMembershipsRequest,
+    requests::user_request::UserRequests,
+};
+
+use super::{frontier::MerkleFrontier, transaction::OrganisationTransaction, SST};
+
+// Organisations are only changed by the sst. Requests arrive like new users, are checked against
+// the stored organisation and appended to its own transaction log and merkle tree.
+#[cfg(feature = "sst")]
+impl SST {
+    pub async fn update_organisation(
+        &self,
+        process_request: ProcessRequest,
+    ) -> Result<Organisation, BecoError> {
+        // gossipsub can deliver a request more than once, acknowledge it without reapplying
+        let committed_result = self
+            .storage
+            .load_organisation_transaction_for_request(&process_request.request_id())
+            .await;
+        if let Err(err) = committed_result {
+            return Err(err);
+        }
+        if let Some(transaction) = committed_result.unwrap() {
+            return Ok(transaction.organisation);
+        }
+
+        let datetime = process_request.datetime.unwrap_or(Utc::now());
+        let calling_user = process_request.calling_user.clone();
+        if self.fetch_user(&calling_user).await.is_none() {
+            return Err(BecoError {
+                message: format!("User not found: {calling_user}"),
+                status: Code::NotFound,
+            });
+        }
+
+        let mut organisations = self.organisations.write().await;
+        let updated_result = match process_request.request.clone() {
+            DataRequests::CreateOrganisation(request) => {
+                let relation_result = OrganisationRelation::try_from(request.relation);
+                if let Err(err) = relation_result {
+                    return Err(err);
+                }
+                Organisation::new(request.name, relation_result.unwrap(), calling_user)
+                    .map(|organisation| (organisation, MerkleFrontier::new()))
+            }
+            DataRequests::AddMember(request) => {
+                let loaded_result = self
+                    .organisation_for_update(&mut organisations, &request.organisation_id, request.expected_sequence)
+                    .await;
+                if let Err(err) = loaded_result {
+                    return Err(err);
+                }
+                let (mut organisation, frontier) = loaded_result.unwrap();
+                let start_date_result = parse_date(&request.start_date, Some(datetime));
+                if let Err(err) = start_date_result {
+                    return Err(err);
+                }
+                let end_date_result = parse_date(&request.end_date, None);
+                if let Err(err) = end_date_result {
+                    return Err(err);
+                }
+                if self.fetch_user(&request.user_id).await.is_none() {
+                    return Err(BecoError {
+                        message: format!("User not found: {}", request.user_id),
+                        status: Code::NotFound,
+                    });
+                }
+                organisation
+                    .add_member(
+                        request.user_id,
+                        start_date_result.unwrap().unwrap(),
+                        end_date_result.unwrap(),
+                        &calling_user,
+                    )
+                    .map(|_| {
+                        organisation.increase_sequence();
+                        (organisation, frontier)
+                    })
+            }
+            DataRequests::EndMembership(request) => {
+                let loaded_result = self
+                    .organisation_for_update(&mut organisations, &request.organisation_id, request.expected_sequence)
+                    .await;
+                if let Err(err) = loaded_result {
+                    return Err(err);
+                }
+                let (mut organisation, frontier) = loaded_result.unwrap();
+                let end_date_result = parse_date(&request.end_date, Some(datetime));
+                if let Err(err) = end_date_result {
+                    return Err(err);
+                }
+                organisation
+                    .end_membership(
+                        &request.user_id,
+                        end_date_result.unwrap().unwrap(),
+                        &calling_user,
+                    )
+                    .map(|_| {
+                        organisation.increase_sequence();
+                        (organisation, frontier)
+                    })
+            }
+            _ => Err(BecoError {
+                message: "Not an organisation request".to_string(),
+                status: Code::InvalidArgument,
+            }),
+        };
+        if let Err(err) = updated_result {
+            return Err(err);
+        }
+        let (organisation, mut frontier) = updated_result.unwrap();
+
+        let save_result = self
+            .save_organisation(&organisation, process_request.clone(), &mut frontier)
+            .await;
+        if let Err(err) = save_result {
+            // lost the race against a redelivery of the same request
+            if err.status == Code::AlreadyExists {
+                if let Ok(Some(transaction)) = self
+                    .storage
+                    .load_organisation_transaction_for_request(&process_request.request_id())
+                    .await
+                {
+                    return Ok(transaction.organisation);
+                }
+            }
+            return Err(err);
+        }
+        organisations.insert(organisation.id.clone(), (organisation.clone(), frontier));
+        Ok(organisation)
+    }
+
+    // a copy to change, the cache is only updated once the change is persisted
+    async fn organisation_for_update(
+        &self,
+        organisations: &mut HashMap<String, (Organisation, MerkleFrontier)>,
+        organisation_id: &String,
+        expected_sequence: Option<u64>,
+    ) -> Result<(Organisation, MerkleFrontier), BecoError> {
+        if !organisations.contains_key(organisation_id) {
+            let loaded_result = self.storage.load_organisation(organisation_id).await;
+            if let Err(err) = loaded_result {
+                return Err(err);
+            }
+            let loaded_option = loaded_result.unwrap();
+            if loaded_option.is_none() {
+                return Err(BecoError {
+                    message: format!("Organisation not found: {organisation_id}"),
+                    status: Code::NotFound,
+                });
+            }
+            organisations.insert(organisation_id.clone(), loaded_option.unwrap());
+        }
+        let (organisation, frontier) = organisations.get(organisation_id).unwrap().clone();
+        organisation.check_sequence(expected_sequence)?;
+        Ok((organisation, frontier))
+    }
+
+    async fn save_organisation(
+        &self,
+        organisation: &Organisation,
+        process_request: ProcessRequest,
+        frontier: &mut MerkleFrontier,
+    ) -> Result<(), BecoError> {
+        let request_id = process_request.request_id();
+        let transaction = OrganisationTransaction {
+            organisation: organisation.clone(),
+            sequence: organisation.sequence(),
+            process_request,
+        };
+        let serialised_organisation_result = serde_json::to_value(organisation);
+        let serialised_transaction_result = serde_json::to_value(&transaction);
+        if serialised_organisation_result.is_err() || serialised_transaction_result.is_err() {
+            return Err(BecoError {
+                message: "Failed to serialize the organisation".to_string(),
+                status: Code::Internal,
+            });
+        }
+        let serialised_transaction = serialised_transaction_result.unwrap();
+        let leaf = Sha256::hash(serialised_transaction.to_string().as_bytes());
+        frontier.append(leaf);
+        let request = CommittedRequest {
+            request_id: &request_id,
+            sequence: organisation.sequence().try_into().unwrap(),
+            serialised_transaction: &serialised_transaction,
+            leaf,
+            root: &frontier.root_hex().unwrap(),
+        };
+        self.storage
+            .save_organisation_request_and_merkle(
+                &organisation.id,
+                &serialised_organisation_result.unwrap(),
+                request,
+                frontier,
+            )
+            .await
+    }
+
+    pub async fn list_memberships(
+        &self,
+        request: ListMembershipsRequest,
+    ) -> Result<Vec<UserOrganisation>, BecoError> {
+        match (&request.organisation_id, &request.user_id) {
+            (Some(organisation_id), None) => {
+                let organisation_result = self.fetch_organisation(organisation_id).await;
+                if let Err(err) = organisation_result {
+                    return Err(err);
+                }
+                let organisation = organisation_result.unwrap();
+                if !organisation.is_admin(&request.calling_user) {
+                    return Err(BecoError {
+                        message: "User does not have permission to list these memberships".into(),
+                        status: Code::PermissionDenied,
+                    });
+                }
+                Ok(organisation.user_organisations(None))
+            }
+            (None, Some(user_id)) => {
+                if user_id != &request.calling_user {
+                    return Err(BecoError {
+                        message: "User does not have permission to list these memberships".into(),
+                        status: Code::PermissionDenied,
+                    });
+                }
+                self.memberships_for_user(user_id).await
+            }
+            _ => Err(BecoError {
+                message: "Set exactly one of organisation_id or user_id".into(),
+                status: Code::InvalidArgument,
+            }),
+        }
+    }
+
+    // every membership of the user, active or not, policies check them against the request time
+    pub async fn memberships_for_user(
+        &self,
+        user_id: &String,
+    ) -> Result<Vec<UserOrganisation>, BecoError> {
+        let organisations_result = self.storage.load_organisations_for_member(user_id).await;
+        if let Err(err) = organisations_result {
+            return Err(err);
+        }
+        Ok(organisations_result
+            .unwrap()
+            .iter()
+            .flat_map(|organisation| organisation.user_organisations(Some(user_id)))
+            .collect())
+    }
+
+    // the user nodes take the organisation details from the proposer, this is where they are checked
+    pub async fn verify_access_request(&self, request: &UserRequests) -> Result<(), BecoError> {
+        let organisation_result = self.fetch_organisation(&request.organisation_id).await;
+        if let Err(err) = organisation_result {
+            return Err(err);
+        }
+        let organisation = organisation_result.unwrap();
+        if !organisation.is_admin(&request.requested_by) {
+            return Err(BecoError {
+                message: "User does not have permission to request access for this organisation"
+                    .into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        let request_type: UserRequestType = organisation.relation.into();
+        if organisation.name != request.organisation_name || request_type != request.request_type {
+            return Err(BecoError {
+                message: "The access request does not match the organisation".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        Ok(())
+    }
+
+    pub async fn fetch_organisation(
+        &self,
+        organisation_id: &String,
+    ) -> Result<Organisation, BecoError> {
+        if let Some((organisation, _)) = self.organisations.read().await.get(organisation_id) {
+            return Ok(organisation.clone());
+        }
+        let loaded_result = self.storage.load_organisation(organisation_id).await;
+        if let Err(err) = loaded_result {
+            return Err(err);
+        }
+        let loaded_option = loaded_result.unwrap();
+        if loaded_option.is_none() {
+            return Err(BecoError {
+                message: format!("Organisation not found: {organisation_id}"),
+                status: Code::NotFound,
+            });
+        }
+        Ok(loaded_option.unwrap().0)
+    }
+}
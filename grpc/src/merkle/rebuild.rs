@@ -0,0 +1,209 @@
+use chrono::Utc;
+use rs_merkle::{algorithms::Sha256, Hasher};
+use serde_json::Value;
+use tonic::Code;
+
+use crate::{
+    entry::Entry,
+    enums::data_value::DataRequests,
+    errors::BecoError,
+    user::user::User,
+};
+
+use super::{frontier::MerkleFrontier, transaction::Transaction, SST};
+
+#[derive(Debug, Clone)]
+pub struct RebuildReport {
+    pub user_id: String,
+    pub transactions: usize,
+    pub replayed_sequence: u64,
+    pub stored_sequence: Option<u64>,
+    // first sequence where the replayed user no longer matches the user recorded in the transaction
+    pub first_divergent_sequence: Option<u64>,
+    pub snapshot_diverged: bool,
+    pub applied: bool,
+}
+
+impl RebuildReport {
+    pub fn diverged(&self) -> bool {
+        self.first_divergent_sequence.is_some() || self.snapshot_diverged
+    }
+}
+
+#[cfg(feature = "sst")]
+impl SST {
+    // replays the transaction log through a fresh entry and compares the outcome with the stored
+    // snapshot. When apply is set, the snapshot, merkle leaves and frontiers are rewritten from the replay
+    pub async fn rebuild(
+        &self,
+        user_id: Option<String>,
+        apply: bool,
+    ) -> Result<Vec<RebuildReport>, BecoError> {
+        let user_ids = if let Some(user_id) = user_id {
+            vec![user_id]
+        } else {
+            let user_ids_result = self.storage.load_user_ids().await;
+            if let Err(err) = user_ids_result {
+                return Err(err);
+            }
+            user_ids_result.unwrap()
+        };
+        let mut reports: Vec<RebuildReport> = vec![];
+        for user_id in user_ids {
+            let report_result = self.rebuild_user(user_id, apply).await;
+            if let Err(err) = report_result {
+                return Err(err);
+            }
+            reports.push(report_result.unwrap());
+        }
+        Ok(reports)
+    }
+
+    async fn rebuild_user(&self, user_id: String, apply: bool) -> Result<RebuildReport, BecoError> {
+        let rows_result = self.storage.load_transaction_rows(&user_id).await;
+        if let Err(err) = rows_result {
+            return Err(err);
+        }
+        let rows = rows_result.unwrap();
+        if rows.is_empty() {
+            return Err(BecoError {
+                message: format!("No transactions found for user {user_id}"),
+                status: Code::NotFound,
+            });
+        }
+
+        let mut frontier = MerkleFrontier::new();
+        let mut leaves: Vec<(i64, [u8; 32], MerkleFrontier)> = vec![];
+        let mut transactions: Vec<Transaction> = vec![];
+        for (transaction_id, serialised_transaction) in rows {
+            let leaf = Sha256::hash(serialised_transaction.to_string().as_bytes());
+            frontier.append(leaf);
+            leaves.push((transaction_id, leaf, frontier.clone()));
+            let transaction_result = serde_json::from_value(serialised_transaction);
+            if transaction_result.is_err() {
+                return Err(BecoError {
+                    message: format!(
+                        "Failed to deserialize transaction {transaction_id} for user {user_id}"
+                    ),
+                    status: Code::Internal,
+                });
+            }
+            transactions.push(transaction_result.unwrap());
+        }
+
+        let replay_result = SST::replay(&transactions).await;
+        if let Err(err) = replay_result {
+            return Err(err);
+        }
+        let (user, first_divergent_sequence) = replay_result.unwrap();
+
+        let stored_user = self.storage.load_user(&user_id).await.ok();
+        let stored_sequence = stored_user.as_ref().map(|stored| stored.sequence());
+        let snapshot_diverged = stored_user.is_none() || !SST::same_user(&user, &stored_user.unwrap());
+
+        if apply {
+            let serialised_user_result = serde_json::to_value(&user);
+            if serialised_user_result.is_err() {
+                return Err(BecoError {
+                    message: "Failed to serialize the user".to_string(),
+                    status: Code::Internal,
+                });
+            }
+            let sequence: i64 = user.sequence().try_into().unwrap();
+            self.storage
+                .rebuild_user(
+                    &user_id,
+                    &serialised_user_result.unwrap(),
+                    sequence,
+                    self.next_sweep(&user, &Utc::now()).await,
+                    leaves,
+                )
+                .await?;
+            // force the cached frontier to be reloaded from the rebuilt transactions
+            self.trees.write().await.remove(&user_id);
+        }
+
+        Ok(RebuildReport {
+            user_id,
+            transactions: transactions.len(),
+            replayed_sequence: user.sequence(),
+            stored_sequence,
+            first_divergent_sequence,
+            snapshot_diverged,
+            applied: apply,
+        })
+    }
+
+    // the first transaction holds the user as created, every later one is replayed on top of it
+    pub async fn replay(transactions: &[Transaction]) -> Result<(User, Option<u64>), BecoError> {
+        let first_transaction_option = transactions.first();
+        if first_transaction_option.is_none() {
+            return Err(BecoError {
+                message: "No transactions to replay".to_string(),
+                status: Code::NotFound,
+            });
+        }
+        let first_transaction = first_transaction_option.unwrap();
+        let entry = Entry::new();
+        let mut user = first_transaction.user.clone();
+        entry.load_user(user.clone()).await;
+        let mut first_divergent_sequence: Option<u64> = None;
+
+        for transaction in transactions.iter().skip(1) {
+            let process_request = transaction.process_request.clone();
+            let is_crypto_account = matches!(
+                process_request.request,
+                DataRequests::AddCryptoAccount(_)
+            );
+            entry
+                .set_groups(
+                    &process_request.calling_user,
+                    transaction.calling_user_groups.clone(),
+                )
+                .await;
+            entry
+                .set_memberships(
+                    &process_request.calling_user,
+                    transaction.calling_user_memberships.clone(),
+                )
+                .await;
+            let update_result = entry
+                .update_value(
+                    process_request.request,
+                    process_request.calling_user,
+                    user.id.clone(),
+                    process_request.datetime,
+                )
+                .await;
+            if let Err(err) = update_result {
+                return Err(BecoError {
+                    message: format!(
+                        "Failed to replay sequence {}: {}",
+                        transaction.sequence, err.message
+                    ),
+                    status: Code::Aborted,
+                });
+            }
+            let (updated_user, _) = update_result.unwrap();
+            user = updated_user;
+            if is_crypto_account {
+                // key generation is not deterministic so adopt the keys that were recorded
+                user.replace_chain_accounts(&transaction.user);
+                entry.load_user(user.clone()).await;
+            }
+            if first_divergent_sequence.is_none()
+                && (user.sequence() != transaction.sequence
+                    || !SST::same_user(&user, &transaction.user))
+            {
+                first_divergent_sequence = Some(transaction.sequence);
+            }
+        }
+        Ok((user, first_divergent_sequence))
+    }
+
+    fn same_user(user: &User, other: &User) -> bool {
+        let serialised_user: Option<Value> = serde_json::to_value(user).ok();
+        let serialised_other: Option<Value> = serde_json::to_value(other).ok();
+        serialised_user.is_some() && serialised_user == serialised_other
+    }
+}
@@ -0,0 +1,35 @@
+use tonic::Code;
+
+use crate::{
+    errors::BecoError,
+    user::recovery::SetRecoveryGuardians,
+};
+
+use super::SST;
+
+#[cfg(feature = "sst")]
+impl SST {
+    // validators only see the user being changed, so the guardians' keys are checked here
+    pub async fn verify_recovery_guardians(
+        &self,
+        request: &SetRecoveryGuardians,
+    ) -> Result<(), BecoError> {
+        for guardian in request.guardians.iter() {
+            let guardian_user_option = self.fetch_user(&guardian.user_id).await;
+            if guardian_user_option.is_none() {
+                return Err(BecoError {
+                    message: format!("User not found: {}", guardian.user_id),
+                    status: Code::NotFound,
+                });
+            }
+            if guardian_user_option.unwrap().auth_key() != Some(&guardian.public_key) {
+                return Err(BecoError {
+                    message: format!("Public key is not the auth key of {}", guardian.user_id),
+                    status: Code::FailedPrecondition,
+                });
+            }
+        }
+        Ok(())
+    }
+
+}
@@ -0,0 +1,107 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::{
+    enums::data_value::DataRequests, errors::BecoError, permissions::grant::RevokeExpiredGrants,
+    user::user::User, utils::calculate_hash,
+};
+
+use super::SST;
+
+// a swept change that never committed, e.g. one the validators rejected, is proposed again after
+// this long
+const SWEEP_RETRY_MINUTES: i64 = 5;
+
+fn sweep_retry() -> Duration {
+    Duration::minutes(SWEEP_RETRY_MINUTES)
+}
+
+#[cfg(feature = "sst")]
+impl SST {
+    // swept on an interval, only users whose next expiry has passed are loaded. Each result is
+    // proposed as (request, calling user, user id) so it goes through consensus, and is not
+    // proposed again while it is in flight
+    pub async fn due_changes(
+        &self,
+        at: &DateTime<Utc>,
+    ) -> Result<Vec<(DataRequests, String, String)>, BecoError> {
+        let user_ids_result = self.storage.load_due_user_ids(at).await;
+        if let Err(err) = user_ids_result {
+            return Err(err);
+        }
+        let mut changes = vec![];
+        for user_id in user_ids_result.unwrap() {
+            let user_option = self.fetch_user(&user_id).await;
+            if user_option.is_none() {
+                continue;
+            }
+            let user = user_option.unwrap();
+            let mut user_changes = SST::user_due_changes(&user, at);
+            user_changes.extend(self.outstanding_link_changes(&user).await);
+            // nothing is due after all, e.g. a user stored before next expiry was kept
+            if user_changes.is_empty() {
+                self.storage
+                    .save_next_expiry(&user_id, self.next_sweep(&user, at).await)
+                    .await?;
+                continue;
+            }
+            changes.extend(user_changes);
+        }
+        let mut swept = self.swept.write().await;
+        swept.retain(|_, proposed_at| *proposed_at + sweep_retry() > *at);
+        changes.retain(|change| {
+            let hash = calculate_hash(change);
+            if swept.contains_key(&hash) {
+                return false;
+            }
+            swept.insert(hash, *at);
+            true
+        });
+        Ok(changes)
+    }
+
+    // when the sweep should next load the user, a link still missing a side is checked again once
+    // a proposal for it could be retried
+    pub async fn next_sweep(&self, user: &User, at: &DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let has_outstanding_links = !self.outstanding_link_changes(user).await.is_empty();
+        let link_retry = has_outstanding_links.then(|| *at + sweep_retry());
+        [user.next_expiry(), link_retry].into_iter().flatten().min()
+    }
+
+    // changes proposed outside the sweep are held back like swept ones, and their users are made
+    // due so the sweep proposes them again if they never commit
+    pub async fn sweep_later(
+        &self,
+        changes: &[(DataRequests, String, String)],
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        let mut swept = self.swept.write().await;
+        for change in changes.iter() {
+            swept.insert(calculate_hash(change), *at);
+            self.storage
+                .save_next_expiry(&change.2, Some(*at + sweep_retry()))
+                .await?;
+        }
+        Ok(())
+    }
+
+    // the guardian's grants lapse with the guardianship, so both are swept together
+    fn user_due_changes(user: &User, at: &DateTime<Utc>) -> Vec<(DataRequests, String, String)> {
+        let grants = user.expired_grants(at);
+        let revocation = (!grants.is_empty()).then(|| {
+            DataRequests::RevokeExpiredGrants(RevokeExpiredGrants {
+                user_id: user.id.clone(),
+                grants,
+            })
+        });
+        [
+            revocation,
+            user.ended_guardianship(at).map(DataRequests::EndGuardianship),
+            user.completed_recovery(at).map(DataRequests::CompleteRecovery),
+            user.expired_approvals(at).map(DataRequests::ExpireApprovals),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|change| (change, user.id.clone(), user.id.clone()))
+        .collect()
+    }
+}
@@ -0,0 +1,95 @@
+#![cfg(all(test, feature = "sst"))]
+
+use chrono::{Duration, Utc};
+use tonic::Code;
+
+use crate::{
+    audit::access_record::{AccessRecord, ListAccessLog},
+    enums::{
+        data_value::{DataRequestType, DataRequests},
+        value_reference::ValueReference,
+    },
+    requests::user_request::FieldReference,
+    user::user::User,
+};
+
+use super::sst::{add_user, new_sst, process_request};
+
+fn access_record(user: &User, viewer_id: &str) -> AccessRecord {
+    AccessRecord::new(
+        user.id.clone(),
+        viewer_id.into(),
+        vec![FieldReference {
+            reference: ValueReference::DETAIL_FIRST_NAME,
+            key: None,
+        }],
+        Utc::now(),
+    )
+}
+
+fn list_access_log(user: &User, calling_user: &str) -> ListAccessLog {
+    ListAccessLog {
+        user_id: user.id.clone(),
+        calling_user: calling_user.to_string(),
+        since: None,
+    }
+}
+
+#[tokio::test]
+async fn record_access_keeps_serving_node_once() {
+    let (sst, _) = new_sst();
+    let user = add_user(&sst).await;
+    let mut request = process_request(
+        DataRequests::RecordAccess(access_record(&user, "viewer")),
+        "viewer".into(),
+        DataRequestType::NEW,
+    );
+    request.originator_peer_id = Some("node-a".into());
+
+    sst.record_access(request.clone()).await.unwrap();
+    sst.record_access(request).await.unwrap();
+
+    let records = sst.access_log(list_access_log(&user, &user.id)).await.unwrap();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].viewer_id, "viewer");
+    assert_eq!(records[0].node, "node-a");
+}
+
+#[tokio::test]
+async fn access_log_is_only_listed_for_the_owner() {
+    let (sst, _) = new_sst();
+    let user = add_user(&sst).await;
+    let request = process_request(
+        DataRequests::RecordAccess(access_record(&user, "viewer")),
+        "viewer".into(),
+        DataRequestType::NEW,
+    );
+    sst.record_access(request).await.unwrap();
+
+    let result = sst.access_log(list_access_log(&user, "viewer")).await;
+
+    assert_eq!(result.unwrap_err().status, Code::PermissionDenied);
+}
+
+#[tokio::test]
+async fn access_log_since_skips_older_reads() {
+    let (sst, _) = new_sst();
+    let user = add_user(&sst).await;
+    let mut older = access_record(&user, "viewer");
+    older.read_at = Utc::now() - Duration::days(2);
+    for record in [older, access_record(&user, "other")] {
+        let request = process_request(
+            DataRequests::RecordAccess(record),
+            "viewer".into(),
+            DataRequestType::NEW,
+        );
+        sst.record_access(request).await.unwrap();
+    }
+    let mut request = list_access_log(&user, &user.id);
+    request.since = Some(Utc::now() - Duration::days(1));
+
+    let records = sst.access_log(request).await.unwrap();
+
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].viewer_id, "other");
+}
@@ -0,0 +1,341 @@
+#![cfg(all(test, feature = "sst"))]
+
+use chrono::{DateTime, Duration, Utc};
+use tonic::Code;
+
+use crate::{
+    db::storage::Storage,
+    enums::data_value::{DataRequestType, DataRequests},
+    merkle::SST,
+    organisation::organisation::Organisation,
+    permissions::{
+        grant::{RevokeExpiredGrants, RevokeGrant},
+        model::GrantRole,
+    },
+    proto::beco::{
+        AddMemberRequest, FieldReference, RequestAccessRequest, RespondAccessRequest,
+        ValueReference,
+    },
+    requests::user_request::UserRequests,
+    user::{public_user::PublicUser, user::User},
+};
+
+use super::sst::{add_user, create_organisation, new_sst, process_request, update_as};
+
+fn access_request(organisation: &Organisation, calling_user: &str, user: &User) -> UserRequests {
+    UserRequests::new(
+        RequestAccessRequest {
+            organisation_id: organisation.id.clone(),
+            calling_user: calling_user.to_string(),
+            user_id: user.id.clone(),
+            fields: vec![FieldReference {
+                reference: ValueReference::DetailFirstName.into(),
+                key: None,
+            }],
+            expires_at: None,
+        },
+        organisation,
+    )
+    .unwrap()
+}
+
+async fn request_access(
+    sst: &SST,
+    request: UserRequests,
+) -> Result<User, crate::errors::BecoError> {
+    let mut validated_request = process_request(
+        DataRequests::RequestAccess(request.clone()),
+        request.user_id.clone(),
+        DataRequestType::VALIDATED,
+    );
+    validated_request.calling_user = request.requested_by.clone();
+    sst.update(validated_request).await.map(|(user, _, _)| user)
+}
+
+async fn respond(sst: &SST, user: &User, request_id: &str, approve: bool) -> User {
+    let request = DataRequests::RespondAccessRequest(RespondAccessRequest {
+        user_id: user.id.clone(),
+        calling_user: user.id.clone(),
+        request_id: request_id.to_string(),
+        approve,
+        expected_sequence: None,
+    });
+    sst.update(process_request(
+        request,
+        user.id.clone(),
+        DataRequestType::VALIDATED,
+    ))
+    .await
+    .unwrap()
+    .0
+}
+
+// the grant is to the organisation, a user reads through their membership of it
+async fn member(sst: &SST, organisation: &Organisation, admin_id: &str) -> PublicUser {
+    let user = add_user(sst).await;
+    let request = DataRequests::AddMember(AddMemberRequest {
+        organisation_id: organisation.id.clone(),
+        calling_user: admin_id.to_string(),
+        user_id: user.id.clone(),
+        start_date: None,
+        end_date: None,
+        expected_sequence: None,
+    });
+    sst.update_organisation(process_request(
+        request,
+        admin_id.to_string(),
+        DataRequestType::NEW,
+    ))
+    .await
+    .unwrap();
+    let mut member = PublicUser::new(user.id.clone(), None, None, None, vec![]);
+    member.memberships = sst.memberships_for_user(&user.id).await.unwrap();
+    member
+}
+
+async fn expired_grants(sst: &SST, at: &DateTime<Utc>) -> Vec<RevokeExpiredGrants> {
+    sst.due_changes(at)
+        .await
+        .unwrap()
+        .into_iter()
+        .filter_map(|(change, _, _)| match change {
+            DataRequests::RevokeExpiredGrants(revocation) => Some(revocation),
+            _ => None,
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn access_request_is_stored_until_approved() {
+    let (sst, storage) = new_sst();
+    let admin = add_user(&sst).await;
+    let user = add_user(&sst).await;
+    let owner = PublicUser::new(user.id.clone(), None, None, None, vec![]);
+    let organisation = create_organisation(&sst, &admin.id).await;
+    let request = access_request(&organisation, &admin.id, &user);
+
+    let pending_user = request_access(&sst, request.clone()).await.unwrap();
+    assert_eq!(pending_user.pending_requests(&owner).unwrap(), vec![request.clone()]);
+    let viewer = member(&sst, &organisation, &admin.id).await;
+    assert!(pending_user.user_details.first_name.value(&viewer).is_err());
+
+    respond(&sst, &user, &request.id, true).await;
+
+    let stored_user = storage.load_user(&user.id).await.unwrap();
+    assert!(stored_user.pending_requests(&owner).unwrap().is_empty());
+    assert_eq!(
+        stored_user.user_details.first_name.value(&viewer).unwrap(),
+        Some("boop".into())
+    );
+    assert!(stored_user.user_details.last_name.value(&viewer).is_err());
+    let other_organisation = create_organisation(&sst, &admin.id).await;
+    let stranger = member(&sst, &other_organisation, &admin.id).await;
+    assert!(stored_user.user_details.first_name.value(&stranger).is_err());
+}
+
+#[tokio::test]
+async fn denied_access_request_grants_nothing() {
+    let (sst, _) = new_sst();
+    let admin = add_user(&sst).await;
+    let user = add_user(&sst).await;
+    let owner = PublicUser::new(user.id.clone(), None, None, None, vec![]);
+    let organisation = create_organisation(&sst, &admin.id).await;
+    let request = access_request(&organisation, &admin.id, &user);
+    request_access(&sst, request.clone()).await.unwrap();
+
+    let denied_user = respond(&sst, &user, &request.id, false).await;
+
+    let viewer = member(&sst, &organisation, &admin.id).await;
+    assert!(denied_user.pending_requests(&owner).unwrap().is_empty());
+    assert!(denied_user.user_details.first_name.value(&viewer).is_err());
+}
+
+#[tokio::test]
+async fn access_request_from_non_admin_is_rejected() {
+    let (sst, storage) = new_sst();
+    let admin = add_user(&sst).await;
+    let stranger = add_user(&sst).await;
+    let user = add_user(&sst).await;
+    let organisation = create_organisation(&sst, &admin.id).await;
+
+    let result = request_access(&sst, access_request(&organisation, &stranger.id, &user)).await;
+
+    assert_eq!(result.unwrap_err().status, Code::PermissionDenied);
+    assert_eq!(storage.transaction_count(&user.id).await, 1);
+}
+
+#[tokio::test]
+async fn only_the_user_can_respond_or_list_requests() {
+    let (sst, _) = new_sst();
+    let admin = add_user(&sst).await;
+    let user = add_user(&sst).await;
+    let organisation = create_organisation(&sst, &admin.id).await;
+    let request = access_request(&organisation, &admin.id, &user);
+    let pending_user = request_access(&sst, request.clone()).await.unwrap();
+    let admin_user = PublicUser::new(admin.id.clone(), None, None, None, vec![]);
+
+    let result = pending_user.propose_respond_access_request(
+        &RespondAccessRequest {
+            user_id: user.id.clone(),
+            calling_user: admin.id.clone(),
+            request_id: request.id,
+            approve: true,
+            expected_sequence: None,
+        },
+        &admin_user,
+        &Utc::now(),
+    );
+    assert_eq!(result.unwrap_err().status, Code::PermissionDenied);
+    assert_eq!(
+        pending_user.pending_requests(&admin_user).unwrap_err().status,
+        Code::PermissionDenied
+    );
+}
+
+#[tokio::test]
+async fn lapsed_grant_is_swept_and_revoked() {
+    let (sst, storage) = new_sst();
+    let admin = add_user(&sst).await;
+    let user = add_user(&sst).await;
+    let organisation = create_organisation(&sst, &admin.id).await;
+    let mut request = access_request(&organisation, &admin.id, &user);
+    let expires = Utc::now() + Duration::minutes(1);
+    request.expires = Some(expires);
+    request_access(&sst, request.clone()).await.unwrap();
+    let approved_user = respond(&sst, &user, &request.id, true).await;
+    let viewer = member(&sst, &organisation, &admin.id).await;
+    assert_eq!(
+        approved_user.user_details.first_name.value(&viewer).unwrap(),
+        Some("boop".into())
+    );
+    assert!(sst.due_changes(&Utc::now()).await.unwrap().is_empty());
+
+    let after_expiry = expires + Duration::seconds(1);
+    assert!(approved_user
+        .user_details
+        .first_name
+        .value_at(&viewer, &after_expiry)
+        .is_err());
+    let revocations = expired_grants(&sst, &after_expiry).await;
+    assert_eq!(revocations.len(), 1);
+    assert_eq!(revocations[0].user_id, user.id);
+    assert_eq!(revocations[0].grants[0].grantee_id, organisation.id);
+
+    let early_request = process_request(
+        DataRequests::RevokeExpiredGrants(revocations[0].clone()),
+        user.id.clone(),
+        DataRequestType::VALIDATED,
+    );
+    assert_eq!(
+        sst.update(early_request).await.unwrap_err().status,
+        Code::FailedPrecondition
+    );
+
+    let mut revoke_request = process_request(
+        DataRequests::RevokeExpiredGrants(revocations[0].clone()),
+        user.id.clone(),
+        DataRequestType::VALIDATED,
+    );
+    revoke_request.datetime = Some(after_expiry);
+    sst.update(revoke_request).await.unwrap();
+
+    let stored_user = storage.load_user(&user.id).await.unwrap();
+    assert!(stored_user
+        .user_details
+        .first_name
+        .expires(&organisation.id, GrantRole::VIEWER)
+        .is_none());
+    assert!(stored_user
+        .user_details
+        .first_name
+        .value_at(&viewer, &Utc::now())
+        .is_err());
+    assert!(sst.due_changes(&after_expiry).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn swept_change_is_not_proposed_again_while_in_flight() {
+    let (sst, storage) = new_sst();
+    let admin = add_user(&sst).await;
+    let user = add_user(&sst).await;
+    let organisation = create_organisation(&sst, &admin.id).await;
+    let mut request = access_request(&organisation, &admin.id, &user);
+    let expires = Utc::now() + Duration::minutes(1);
+    request.expires = Some(expires);
+    request_access(&sst, request.clone()).await.unwrap();
+    respond(&sst, &user, &request.id, true).await;
+
+    let after_expiry = expires + Duration::seconds(1);
+    assert!(storage.load_due_user_ids(&Utc::now()).await.unwrap().is_empty());
+    assert_eq!(storage.load_due_user_ids(&after_expiry).await.unwrap(), vec![user.id]);
+    assert_eq!(expired_grants(&sst, &after_expiry).await.len(), 1);
+    assert!(expired_grants(&sst, &after_expiry).await.is_empty());
+    let after_retry = after_expiry + Duration::minutes(6);
+    assert_eq!(expired_grants(&sst, &after_retry).await.len(), 1);
+}
+
+#[tokio::test]
+async fn approved_grant_is_revoked_before_it_lapses() {
+    let (sst, storage) = new_sst();
+    let admin = add_user(&sst).await;
+    let user = add_user(&sst).await;
+    let organisation = create_organisation(&sst, &admin.id).await;
+    let mut request = access_request(&organisation, &admin.id, &user);
+    request.expires = Some(Utc::now() + Duration::days(1));
+    request_access(&sst, request.clone()).await.unwrap();
+    respond(&sst, &user, &request.id, true).await;
+    let viewer = member(&sst, &organisation, &admin.id).await;
+    let revoke = DataRequests::RevokeGrant(RevokeGrant {
+        user_id: user.id.clone(),
+        calling_user: user.id.clone(),
+        grantee_id: organisation.id.clone(),
+        fields: request.fields.clone(),
+        role: GrantRole::VIEWER,
+        expected_sequence: None,
+    });
+
+    assert_eq!(
+        update_as(&sst, revoke.clone(), &admin.id, &user.id)
+            .await
+            .unwrap_err(),
+        Code::PermissionDenied
+    );
+    update_as(&sst, revoke.clone(), &user.id, &user.id)
+        .await
+        .unwrap();
+
+    let stored_user = storage.load_user(&user.id).await.unwrap();
+    assert!(stored_user.user_details.first_name.value(&viewer).is_err());
+    assert!(stored_user
+        .user_details
+        .first_name
+        .expires(&organisation.id, GrantRole::VIEWER)
+        .is_none());
+    assert_eq!(stored_user.next_expiry(), None);
+    assert_eq!(
+        update_as(&sst, revoke, &user.id, &user.id)
+            .await
+            .unwrap_err(),
+        Code::NotFound
+    );
+}
+
+#[tokio::test]
+async fn empty_revocation_is_rejected() {
+    let (sst, _) = new_sst();
+    let user = add_user(&sst).await;
+    let revocation = RevokeExpiredGrants {
+        user_id: user.id.clone(),
+        grants: vec![],
+    };
+
+    let result = sst
+        .update(process_request(
+            DataRequests::RevokeExpiredGrants(revocation),
+            user.id.clone(),
+            DataRequestType::VALIDATED,
+        ))
+        .await;
+
+    assert_eq!(result.unwrap_err().status, Code::InvalidArgument);
+}
@@ -0,0 +1,259 @@
+#![cfg(all(test, feature = "sst"))]
+
+use chrono::{Duration, Utc};
+use tonic::Code;
+
+use crate::{
+    enums::data_value::DataRequests,
+    user::{
+        approval::{ApproveChange, Approver, ExpireApprovals, RequestApproval, SetApprovalPolicy},
+        public_user::PublicUser,
+        user::User,
+    },
+};
+
+use super::sst::{
+    add_user, create_organisation, last_name, last_name_field, new_sst, update_as, update_at,
+};
+
+fn set_policy(user: &User, approvers: Vec<Approver>, required: u32) -> DataRequests {
+    DataRequests::SetApprovalPolicy(SetApprovalPolicy {
+        user_id: user.id.clone(),
+        calling_user: user.id.clone(),
+        field: last_name_field(),
+        approvers,
+        required,
+        expected_sequence: None,
+    })
+}
+
+fn request_approval(id: &str, change: DataRequests) -> DataRequests {
+    DataRequests::RequestApproval(RequestApproval {
+        id: id.into(),
+        change: Box::new(change),
+    })
+}
+
+fn approve(user: &User, calling_user: &str, approval_id: &str) -> DataRequests {
+    DataRequests::ApproveChange(ApproveChange {
+        user_id: user.id.clone(),
+        calling_user: calling_user.to_string(),
+        approval_id: approval_id.into(),
+    })
+}
+
+fn stored_last_name(user: &User) -> Option<String> {
+    let owner = PublicUser::new(user.id.clone(), None, None, None, vec![]);
+    user.user_details.last_name.value(&owner).unwrap()
+}
+
+#[tokio::test]
+async fn guarded_change_waits_for_its_approver() {
+    let (sst, _) = new_sst();
+    let user = add_user(&sst).await;
+    let approver = add_user(&sst).await;
+    let stranger = add_user(&sst).await;
+    update_as(
+        &sst,
+        set_policy(&user, vec![Approver::User(approver.id.clone())], 1),
+        &user.id,
+        &user.id,
+    )
+    .await
+    .unwrap();
+
+    let direct_result = update_as(&sst, last_name(&user, "beep"), &user.id, &user.id).await;
+    assert_eq!(direct_result.unwrap_err(), Code::FailedPrecondition);
+    let requested = update_as(
+        &sst,
+        request_approval("a", last_name(&user, "beep")),
+        &user.id,
+        &user.id,
+    )
+    .await
+    .unwrap();
+    assert_eq!(stored_last_name(&requested), None);
+    let owner = PublicUser::new(user.id.clone(), None, None, None, vec![]);
+    assert_eq!(
+        requested.as_response(&owner).pending_approvals,
+        vec!["a".to_string()]
+    );
+
+    let own_result = update_as(&sst, approve(&user, &user.id, "a"), &user.id, &user.id).await;
+    assert_eq!(own_result.unwrap_err(), Code::PermissionDenied);
+    let stranger_result = update_as(
+        &sst,
+        approve(&user, &stranger.id, "a"),
+        &stranger.id,
+        &user.id,
+    )
+    .await;
+    assert_eq!(stranger_result.unwrap_err(), Code::PermissionDenied);
+    let approved = update_as(
+        &sst,
+        approve(&user, &approver.id, "a"),
+        &approver.id,
+        &user.id,
+    )
+    .await
+    .unwrap();
+    assert_eq!(stored_last_name(&approved), Some("beep".into()));
+    assert!(approved
+        .pending_approvals(&owner, &[], &Utc::now())
+        .is_empty());
+}
+
+#[tokio::test]
+async fn organisation_admin_approvals_are_checked_by_the_sst() {
+    let (sst, _) = new_sst();
+    let user = add_user(&sst).await;
+    let admin = add_user(&sst).await;
+    let stranger = add_user(&sst).await;
+    let organisation = create_organisation(&sst, &admin.id).await;
+    let approvers = vec![Approver::OrganisationAdmin(organisation.id.clone())];
+    update_as(&sst, set_policy(&user, approvers, 1), &user.id, &user.id)
+        .await
+        .unwrap();
+    let requested = update_as(
+        &sst,
+        request_approval("a", last_name(&user, "beep")),
+        &user.id,
+        &user.id,
+    )
+    .await
+    .unwrap();
+
+    let admin_user = PublicUser::new(admin.id.clone(), None, None, None, vec![]);
+    let stranger_user = PublicUser::new(stranger.id.clone(), None, None, None, vec![]);
+    assert_eq!(
+        requested
+            .pending_approvals(&admin_user, std::slice::from_ref(&organisation.id), &Utc::now())
+            .len(),
+        1
+    );
+    assert!(requested
+        .pending_approvals(&stranger_user, &[], &Utc::now())
+        .is_empty());
+    let stranger_result = update_as(
+        &sst,
+        approve(&user, &stranger.id, "a"),
+        &stranger.id,
+        &user.id,
+    )
+    .await;
+    assert_eq!(stranger_result.unwrap_err(), Code::PermissionDenied);
+    let approved = update_as(&sst, approve(&user, &admin.id, "a"), &admin.id, &user.id)
+        .await
+        .unwrap();
+    assert_eq!(stored_last_name(&approved), Some("beep".into()));
+}
+
+#[tokio::test]
+async fn loosening_a_policy_needs_its_approvals() {
+    let (sst, _) = new_sst();
+    let user = add_user(&sst).await;
+    let first = add_user(&sst).await;
+    let second = add_user(&sst).await;
+    let approvers = vec![
+        Approver::User(first.id.clone()),
+        Approver::User(second.id.clone()),
+    ];
+    update_as(&sst, set_policy(&user, approvers, 2), &user.id, &user.id)
+        .await
+        .unwrap();
+
+    let direct_result = update_as(&sst, set_policy(&user, vec![], 0), &user.id, &user.id).await;
+    assert_eq!(direct_result.unwrap_err(), Code::FailedPrecondition);
+    update_as(
+        &sst,
+        request_approval("a", set_policy(&user, vec![], 0)),
+        &user.id,
+        &user.id,
+    )
+    .await
+    .unwrap();
+    let half_approved = update_as(&sst, approve(&user, &first.id, "a"), &first.id, &user.id)
+        .await
+        .unwrap();
+    assert!(half_approved.needs_approval(&last_name(&user, "beep")));
+    let repeat_result = update_as(&sst, approve(&user, &first.id, "a"), &first.id, &user.id).await;
+    assert_eq!(repeat_result.unwrap_err(), Code::AlreadyExists);
+    update_as(&sst, approve(&user, &second.id, "a"), &second.id, &user.id)
+        .await
+        .unwrap();
+
+    let renamed = update_as(&sst, last_name(&user, "beep"), &user.id, &user.id)
+        .await
+        .unwrap();
+    assert_eq!(stored_last_name(&renamed), Some("beep".into()));
+}
+
+#[tokio::test]
+async fn unapproved_changes_expire() {
+    let (sst, _) = new_sst();
+    let user = add_user(&sst).await;
+    let approver = add_user(&sst).await;
+    update_as(
+        &sst,
+        set_policy(&user, vec![Approver::User(approver.id.clone())], 1),
+        &user.id,
+        &user.id,
+    )
+    .await
+    .unwrap();
+    update_as(
+        &sst,
+        request_approval("a", last_name(&user, "beep")),
+        &user.id,
+        &user.id,
+    )
+    .await
+    .unwrap();
+
+    assert!(sst.due_changes(&Utc::now()).await.unwrap().is_empty());
+    let later = Utc::now() + Duration::days(8);
+    let late_result = update_at(
+        &sst,
+        approve(&user, &approver.id, "a"),
+        &approver.id,
+        &user.id,
+        later,
+    )
+    .await;
+    assert_eq!(late_result.unwrap_err(), Code::FailedPrecondition);
+    let due = sst.due_changes(&later).await.unwrap();
+    let expired: Vec<ExpireApprovals> = due
+        .into_iter()
+        .filter_map(|(change, _, _)| match change {
+            DataRequests::ExpireApprovals(expire_approvals) => Some(expire_approvals),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        expired,
+        vec![ExpireApprovals {
+            user_id: user.id.clone(),
+            approval_ids: vec!["a".into()],
+        }]
+    );
+
+    let early_result = update_as(
+        &sst,
+        DataRequests::ExpireApprovals(expired[0].clone()),
+        &user.id,
+        &user.id,
+    )
+    .await;
+    assert_eq!(early_result.unwrap_err(), Code::FailedPrecondition);
+    let swept = update_at(
+        &sst,
+        DataRequests::ExpireApprovals(expired[0].clone()),
+        &user.id,
+        &user.id,
+        later,
+    )
+    .await
+    .unwrap();
+    assert!(swept.expired_approvals(&later).is_none());
+    assert_eq!(stored_last_name(&swept), None);
+}
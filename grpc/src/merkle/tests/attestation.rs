@@ -0,0 +1,146 @@
+#![cfg(all(test, feature = "sst"))]
+
+use k256::ecdsa::{signature::Signer, Signature, SigningKey};
+use tonic::Code;
+
+use crate::{
+    enums::data_value::DataRequests,
+    organisation::organisation::Organisation,
+    proto::beco::{AttestFieldRequest, AttestationStatus},
+    user::{
+        attestation::{attestation_message, Attestation},
+        public_user::PublicUser,
+        user::User,
+    },
+};
+
+use super::sst::{
+    add_user, add_user_with_key, create_organisation, last_name, last_name_field, new_sst,
+    update_as,
+};
+
+// an attestation of the user's last name, signed by the admin over the given hash
+fn attest(
+    user: &User,
+    organisation: &Organisation,
+    admin: &(User, SigningKey),
+    value_hash: String,
+) -> DataRequests {
+    let field = last_name_field();
+    let claim: String = "matches passport".into();
+    let message = attestation_message(&user.id, &organisation.id, &field, &value_hash, &claim);
+    let signature: Signature = admin.1.sign(message.as_bytes());
+    let request = AttestFieldRequest {
+        organisation_id: organisation.id.clone(),
+        calling_user: admin.0.id.clone(),
+        user_id: user.id.clone(),
+        field: Some(field.into()),
+        value_hash,
+        claim,
+        signature: hex::encode(signature.to_bytes()),
+    };
+    let public_key = admin.0.auth_key().unwrap().clone();
+    DataRequests::AttestField(Attestation::new(request, organisation, public_key).unwrap())
+}
+
+fn current_hash(user: &User) -> String {
+    user.user_details.value_hash(&last_name_field()).unwrap()
+}
+
+#[tokio::test]
+async fn attestation_is_bound_to_the_value() {
+    let (sst, _) = new_sst();
+    let user = add_user(&sst).await;
+    let admin = add_user_with_key(&sst).await;
+    let organisation = create_organisation(&sst, &admin.0.id).await;
+    let user = update_as(&sst, last_name(&user, "beep"), &user.id, &user.id)
+        .await
+        .unwrap();
+
+    let wrong_hash_result = update_as(
+        &sst,
+        attest(&user, &organisation, &admin, "00".into()),
+        &admin.0.id,
+        &user.id,
+    )
+    .await;
+    assert_eq!(wrong_hash_result.unwrap_err(), Code::FailedPrecondition);
+    let attested = update_as(
+        &sst,
+        attest(&user, &organisation, &admin, current_hash(&user)),
+        &admin.0.id,
+        &user.id,
+    )
+    .await
+    .unwrap();
+    let owner = PublicUser::new(user.id.clone(), None, None, None, vec![]);
+    let attestations = attested.as_response(&owner).attestations;
+    assert_eq!(attestations.len(), 1);
+    assert_eq!(attestations[0].status, AttestationStatus::Valid as i32);
+    assert_eq!(attestations[0].organisation_name, "Beco");
+
+    let renamed = update_as(&sst, last_name(&user, "boop"), &user.id, &user.id)
+        .await
+        .unwrap();
+    let attestations = renamed.as_response(&owner).attestations;
+    assert_eq!(attestations[0].status, AttestationStatus::Invalidated as i32);
+    // changing the value back does not make the attestation valid again
+    let restored = update_as(&sst, last_name(&user, "beep"), &user.id, &user.id)
+        .await
+        .unwrap();
+    let attestations = restored.as_response(&owner).attestations;
+    assert_eq!(attestations[0].status, AttestationStatus::Invalidated as i32);
+}
+
+#[tokio::test]
+async fn attestation_needs_the_admins_signature() {
+    let (sst, _) = new_sst();
+    let user = add_user(&sst).await;
+    let admin = add_user_with_key(&sst).await;
+    let stranger = add_user_with_key(&sst).await;
+    let organisation = create_organisation(&sst, &admin.0.id).await;
+    let user = update_as(&sst, last_name(&user, "beep"), &user.id, &user.id)
+        .await
+        .unwrap();
+
+    let forged = (admin.0.clone(), stranger.1.clone());
+    let forged_result = update_as(
+        &sst,
+        attest(&user, &organisation, &forged, current_hash(&user)),
+        &admin.0.id,
+        &user.id,
+    )
+    .await;
+    assert_eq!(forged_result.unwrap_err(), Code::PermissionDenied);
+    let stranger_result = update_as(
+        &sst,
+        attest(&user, &organisation, &stranger, current_hash(&user)),
+        &stranger.0.id,
+        &user.id,
+    )
+    .await;
+    assert_eq!(stranger_result.unwrap_err(), Code::PermissionDenied);
+}
+
+#[tokio::test]
+async fn attestations_follow_field_visibility() {
+    let (sst, _) = new_sst();
+    let user = add_user(&sst).await;
+    let admin = add_user_with_key(&sst).await;
+    let stranger = add_user(&sst).await;
+    let organisation = create_organisation(&sst, &admin.0.id).await;
+    let user = update_as(&sst, last_name(&user, "beep"), &user.id, &user.id)
+        .await
+        .unwrap();
+    let attested = update_as(
+        &sst,
+        attest(&user, &organisation, &admin, current_hash(&user)),
+        &admin.0.id,
+        &user.id,
+    )
+    .await
+    .unwrap();
+
+    let stranger_user = PublicUser::new(stranger.id.clone(), None, None, None, vec![]);
+    assert!(attested.as_response(&stranger_user).attestations.is_empty());
+}
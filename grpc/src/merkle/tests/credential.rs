@@ -0,0 +1,225 @@
+#![cfg(all(test, feature = "sst"))]
+
+use std::sync::Arc;
+
+use chrono::{Duration, NaiveDate, Utc};
+use k256::{ecdsa::SigningKey, elliptic_curve::rand_core::OsRng};
+use tonic::Code;
+
+use crate::{
+    db::{memory::MemoryStorage, storage::Storage},
+    enums::{data_value::DataRequests, value_reference::ValueReference},
+    merkle::SST,
+    proto::beco::IssueCredentialRequest,
+    requests::user_request::FieldReference,
+    user::{
+        attribute::{AttributeValue, SetAttribute},
+        credential::{CredentialStatus, IssueCredential, Issuer, RevokeCredential, SignCredential},
+        public_user::PublicUser,
+        user::User,
+    },
+};
+
+use super::sst::{add_user, last_name, new_sst, update_as};
+
+fn new_issuer() -> Issuer {
+    let secret_key = hex::encode(SigningKey::random(&mut OsRng).to_bytes());
+    Issuer::new(&secret_key).unwrap()
+}
+
+fn sst_with_issuer() -> (SST, Arc<MemoryStorage>) {
+    let (sst, storage) = new_sst();
+    (sst.with_issuer(new_issuer()), storage)
+}
+
+// a credential over the user's names and birth date as they see themselves
+fn issue(user: &User) -> IssueCredential {
+    let fields = vec![
+        FieldReference {
+            reference: ValueReference::DETAIL_FIRST_NAME,
+            key: None,
+        },
+        FieldReference {
+            reference: ValueReference::DETAIL_LAST_NAME,
+            key: None,
+        },
+        FieldReference {
+            reference: ValueReference::DETAIL_ATTRIBUTE,
+            key: Some("birth_date".into()),
+        },
+    ];
+    let request = IssueCredentialRequest {
+        user_id: user.id.clone(),
+        calling_user: user.id.clone(),
+        fields: fields.into_iter().map(|field| field.into()).collect(),
+        expected_sequence: None,
+    };
+    let owner = PublicUser::new(user.id.clone(), None, None, None, vec![]);
+    IssueCredential::new(request, user, &owner).unwrap()
+}
+
+async fn named_user(sst: &SST) -> User {
+    let user = add_user(sst).await;
+    let birth_date = DataRequests::SetAttribute(SetAttribute {
+        user_id: user.id.clone(),
+        calling_user: user.id.clone(),
+        key: "birth_date".into(),
+        value: AttributeValue::Date(NaiveDate::from_ymd_opt(2001, 2, 3).unwrap()),
+        expected_sequence: None,
+    });
+    update_as(sst, birth_date, &user.id, &user.id).await.unwrap();
+    update_as(sst, last_name(&user, "beep"), &user.id, &user.id)
+        .await
+        .unwrap()
+}
+
+fn sign(user: &User, credential_id: &str) -> SignCredential {
+    SignCredential {
+        user_id: user.id.clone(),
+        credential_id: credential_id.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn issued_credential_is_recorded_signed_and_verified() {
+    let (sst, storage) = sst_with_issuer();
+    let user = named_user(&sst).await;
+    let issue = issue(&user);
+
+    let issued = update_as(
+        &sst,
+        DataRequests::IssueCredential(issue.clone()),
+        &user.id,
+        &user.id,
+    )
+    .await
+    .unwrap();
+    let transactions = storage
+        .load_transactions(&user.id, issued.sequence() as i64, issued.sequence() as i64)
+        .await
+        .unwrap();
+    assert!(matches!(
+        transactions[0].process_request.request,
+        DataRequests::IssueCredential(_)
+    ));
+
+    let jwt = sst.sign_credential(sign(&user, &issue.id), &user.id).await.unwrap();
+    let verified = sst.verify_credential(&jwt, &Utc::now()).await.unwrap();
+    assert_eq!(verified.credential_id, issue.id);
+    assert_eq!(verified.user_id, user.id);
+    assert_eq!(verified.status, CredentialStatus::Active);
+    let subject: serde_json::Value = serde_json::from_str(&verified.credential_subject).unwrap();
+    assert_eq!(subject["firstName"], "boop");
+    assert_eq!(subject["lastName"], "beep");
+    assert_eq!(subject["attributes"]["birth_date"], "2001-02-03");
+
+    let later = Utc::now() + Duration::days(366);
+    let expired = sst.verify_credential(&jwt, &later).await.unwrap();
+    assert_eq!(expired.status, CredentialStatus::Expired);
+}
+
+#[tokio::test]
+async fn revoked_credential_is_set_in_the_status_list() {
+    let (sst, _) = sst_with_issuer();
+    let user = named_user(&sst).await;
+    let first = issue(&user);
+    let second = issue(&user);
+    for issue in [&first, &second] {
+        update_as(
+            &sst,
+            DataRequests::IssueCredential(issue.clone()),
+            &user.id,
+            &user.id,
+        )
+        .await
+        .unwrap();
+    }
+    let jwt = sst.sign_credential(sign(&user, &second.id), &user.id).await.unwrap();
+
+    let revoke = DataRequests::RevokeCredential(RevokeCredential {
+        user_id: user.id.clone(),
+        calling_user: user.id.clone(),
+        credential_id: second.id.clone(),
+        expected_sequence: None,
+    });
+    let revoked = update_as(&sst, revoke.clone(), &user.id, &user.id)
+        .await
+        .unwrap();
+    // the second credential has status index 1
+    assert_eq!(revoked.credential_status_list(), "QA");
+    let verified = sst.verify_credential(&jwt, &Utc::now()).await.unwrap();
+    assert_eq!(verified.status, CredentialStatus::Revoked);
+    let sign_result = sst.sign_credential(sign(&user, &second.id), &user.id).await;
+    assert_eq!(sign_result.unwrap_err().status, Code::FailedPrecondition);
+    let repeat_result = update_as(&sst, revoke, &user.id, &user.id).await;
+    assert_eq!(repeat_result.unwrap_err(), Code::FailedPrecondition);
+}
+
+#[tokio::test]
+async fn credential_must_match_the_users_fields() {
+    let (sst, _) = sst_with_issuer();
+    let user = named_user(&sst).await;
+    let stranger = add_user(&sst).await;
+
+    let mut tampered = issue(&user);
+    tampered.claims_hash = "00".into();
+    let tampered_result = update_as(
+        &sst,
+        DataRequests::IssueCredential(tampered),
+        &user.id,
+        &user.id,
+    )
+    .await;
+    assert_eq!(tampered_result.unwrap_err(), Code::FailedPrecondition);
+    let stranger_result = update_as(
+        &sst,
+        DataRequests::IssueCredential(issue(&user)),
+        &stranger.id,
+        &user.id,
+    )
+    .await;
+    assert_eq!(stranger_result.unwrap_err(), Code::PermissionDenied);
+
+    let issue = issue(&user);
+    update_as(
+        &sst,
+        DataRequests::IssueCredential(issue.clone()),
+        &user.id,
+        &user.id,
+    )
+    .await
+    .unwrap();
+    update_as(&sst, last_name(&user, "boop"), &user.id, &user.id)
+        .await
+        .unwrap();
+    let sign_result = sst.sign_credential(sign(&user, &issue.id), &user.id).await;
+    assert_eq!(sign_result.unwrap_err().status, Code::FailedPrecondition);
+    let stranger_sign_result = sst.sign_credential(sign(&user, &issue.id), &stranger.id).await;
+    assert_eq!(stranger_sign_result.unwrap_err().status, Code::PermissionDenied);
+}
+
+#[tokio::test]
+async fn credential_from_another_issuer_is_rejected() {
+    let (sst, _) = sst_with_issuer();
+    let (other_sst, _) = new_sst();
+    let other_sst = other_sst.with_issuer(new_issuer());
+    let user = named_user(&other_sst).await;
+    let issue = issue(&user);
+    update_as(
+        &other_sst,
+        DataRequests::IssueCredential(issue.clone()),
+        &user.id,
+        &user.id,
+    )
+    .await
+    .unwrap();
+    let jwt = other_sst
+        .sign_credential(sign(&user, &issue.id), &user.id)
+        .await
+        .unwrap();
+
+    let verify_result = sst.verify_credential(&jwt, &Utc::now()).await;
+    assert_eq!(verify_result.unwrap_err().status, Code::PermissionDenied);
+    let malformed_result = sst.verify_credential("a.b", &Utc::now()).await;
+    assert_eq!(malformed_result.unwrap_err().status, Code::InvalidArgument);
+}
@@ -0,0 +1,54 @@
+#![cfg(all(test, feature = "sst"))]
+
+use rs_merkle::{algorithms::Sha256, Hasher, MerkleTree};
+
+use crate::merkle::frontier::MerkleFrontier;
+
+fn leaves(count: usize) -> Vec<[u8; 32]> {
+    (0..count)
+        .map(|index| Sha256::hash(format!("transaction {index}").as_bytes()))
+        .collect()
+}
+
+#[test]
+fn empty_frontier_has_no_root() {
+    let frontier = MerkleFrontier::new();
+    assert_eq!(frontier.leaf_count(), 0);
+    assert_eq!(frontier.root(), None);
+    assert_eq!(frontier.root_hex(), None);
+}
+
+#[test]
+fn root_matches_full_tree() {
+    for count in 1..=33 {
+        let leaves = leaves(count);
+        let tree = MerkleTree::<Sha256>::from_leaves(&leaves);
+        let frontier = MerkleFrontier::from_leaves(&leaves);
+        assert_eq!(frontier.leaf_count(), count as u64);
+        assert_eq!(frontier.root(), tree.root(), "{count} leaves");
+        assert_eq!(frontier.root_hex(), tree.root_hex(), "{count} leaves");
+    }
+}
+
+#[test]
+fn frontier_stays_logarithmic() {
+    let frontier = MerkleFrontier::from_leaves(&leaves(1000));
+    let serialised = serde_json::to_value(&frontier).unwrap();
+    // 1000 = 0b1111101000, one peak per set bit
+    assert_eq!(serialised["peaks"].as_array().unwrap().len(), 6);
+}
+
+#[test]
+fn append_after_roundtrip() {
+    let leaves = leaves(12);
+    let frontier = MerkleFrontier::from_leaves(&leaves[..7]);
+    let serialised = serde_json::to_value(&frontier).unwrap();
+    let mut restored: MerkleFrontier = serde_json::from_value(serialised).unwrap();
+    assert_eq!(restored, frontier);
+
+    leaves[7..].iter().for_each(|leaf| restored.append(*leaf));
+    assert_eq!(
+        restored.root(),
+        MerkleTree::<Sha256>::from_leaves(&leaves).root()
+    );
+}
@@ -0,0 +1,144 @@
+#![cfg(all(test, feature = "sst"))]
+
+use tonic::Code;
+
+use crate::{
+    db::storage::Storage,
+    enums::data_value::{DataRequestType, DataRequests},
+    group::group::Group,
+    merkle::SST,
+    permissions::{grant::GroupAccess, model::GrantRole},
+    proto::beco::{CreateGroupRequest, GroupMemberRequest},
+    user::user::User,
+};
+
+use super::sst::{add_user, last_name, last_name_field, new_sst, process_request, update_as};
+
+async fn create_group(sst: &SST, owner_id: &str) -> Group {
+    let request = DataRequests::CreateGroup(CreateGroupRequest {
+        calling_user: owner_id.to_string(),
+        name: "Finance".into(),
+        organisation_id: None,
+    });
+    sst.update_group(process_request(request, owner_id.to_string(), DataRequestType::NEW))
+        .await
+        .unwrap()
+}
+
+fn group_member(group_id: &str, calling_user: &str, user_id: &str) -> GroupMemberRequest {
+    GroupMemberRequest {
+        group_id: group_id.to_string(),
+        calling_user: calling_user.to_string(),
+        user_id: user_id.to_string(),
+        expected_sequence: None,
+    }
+}
+
+fn group_access(user: &User, group_id: &str, role: GrantRole) -> GroupAccess {
+    GroupAccess {
+        user_id: user.id.clone(),
+        calling_user: user.id.clone(),
+        group_id: group_id.to_string(),
+        fields: vec![last_name_field()],
+        role,
+        expected_sequence: None,
+    }
+}
+
+#[tokio::test]
+async fn owner_manages_members() {
+    let (sst, storage) = new_sst();
+    let owner = add_user(&sst).await;
+    let member = add_user(&sst).await;
+    let group = create_group(&sst, &owner.id).await;
+
+    let request = DataRequests::AddGroupMember(group_member(&group.id, &owner.id, &member.id));
+    let updated_group = sst
+        .update_group(process_request(request, owner.id.clone(), DataRequestType::NEW))
+        .await
+        .unwrap();
+
+    assert!(updated_group.is_member(&member.id));
+    assert_eq!(updated_group.sequence(), 2);
+    let stored_group = storage.load_group(&group.id).await.unwrap().unwrap();
+    assert_eq!(stored_group, updated_group);
+    assert_eq!(
+        sst.group_ids_for_member(&member.id).await.unwrap(),
+        vec![group.id]
+    );
+}
+
+#[tokio::test]
+async fn stranger_cannot_add_members() {
+    let (sst, _) = new_sst();
+    let owner = add_user(&sst).await;
+    let stranger = add_user(&sst).await;
+    let group = create_group(&sst, &owner.id).await;
+
+    let request = DataRequests::AddGroupMember(group_member(&group.id, &stranger.id, &stranger.id));
+    let result = sst
+        .update_group(process_request(request, stranger.id.clone(), DataRequestType::NEW))
+        .await;
+
+    assert_eq!(result.unwrap_err().status, Code::PermissionDenied);
+    assert!(sst.group_ids_for_member(&stranger.id).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn group_grant_follows_membership() {
+    let (sst, storage) = new_sst();
+    let owner = add_user(&sst).await;
+    let member = add_user(&sst).await;
+    let group = create_group(&sst, &owner.id).await;
+
+    let request = DataRequests::GrantGroupAccess(group_access(&owner, &group.id, GrantRole::EDITOR));
+    sst.update(process_request(request, owner.id.clone(), DataRequestType::VALIDATED))
+        .await
+        .unwrap();
+
+    // granted to the group, but not yet a member
+    let result = update_as(&sst, last_name(&owner, "beep"), &member.id, &owner.id).await;
+    assert_eq!(result.unwrap_err(), Code::PermissionDenied);
+
+    let request = DataRequests::AddGroupMember(group_member(&group.id, &owner.id, &member.id));
+    sst.update_group(process_request(request, owner.id.clone(), DataRequestType::NEW))
+        .await
+        .unwrap();
+    let updated_user = update_as(&sst, last_name(&owner, "beep"), &member.id, &owner.id)
+        .await
+        .unwrap();
+    assert_eq!(updated_user.sequence(), 3);
+    let transaction = storage
+        .load_transactions(&owner.id, 3, 3)
+        .await
+        .unwrap()
+        .remove(0);
+    assert_eq!(transaction.calling_user_groups, vec![group.id.clone()]);
+
+    // leaving the group takes the access away without touching the user
+    let request = DataRequests::RemoveGroupMember(group_member(&group.id, &member.id, &member.id));
+    sst.update_group(process_request(request, member.id.clone(), DataRequestType::NEW))
+        .await
+        .unwrap();
+    let result = update_as(&sst, last_name(&owner, "beep"), &member.id, &owner.id).await;
+    assert_eq!(result.unwrap_err(), Code::PermissionDenied);
+    assert_eq!(sst.fetch_user(&owner.id).await.unwrap().sequence(), 3);
+}
+
+#[tokio::test]
+async fn grant_to_unknown_group_is_rejected() {
+    let (sst, storage) = new_sst();
+    let owner = add_user(&sst).await;
+
+    let request = DataRequests::GrantGroupAccess(group_access(
+        &owner,
+        "00000000-0000-0000-0000-000000000000",
+        GrantRole::VIEWER,
+    ));
+    let result = sst
+        .update(process_request(request, owner.id.clone(), DataRequestType::VALIDATED))
+        .await;
+
+    assert_eq!(result.unwrap_err().status, Code::NotFound);
+    assert_eq!(storage.transaction_count(&owner.id).await, 1);
+}
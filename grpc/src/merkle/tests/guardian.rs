@@ -0,0 +1,147 @@
+#![cfg(all(test, feature = "sst"))]
+
+use chrono::{DateTime, Duration, Utc};
+use tonic::Code;
+
+use crate::{
+    db::storage::Storage,
+    enums::data_value::{DataRequestType, DataRequests},
+    permissions::model::GrantRole,
+    proto::beco::ModifyNameRequest,
+    user::{
+        attribute::{AttributeValue, SetAttribute},
+        guardian::{EndGuardianship, Guardianship, SetGuardian},
+        public_user::PublicUser,
+        user::User,
+    },
+};
+
+use super::sst::{add_user, new_sst, process_request, update_as};
+
+fn set_guardian(user: &User, guardian: &User, until: DateTime<Utc>) -> DataRequests {
+    DataRequests::SetGuardian(SetGuardian {
+        user_id: user.id.clone(),
+        calling_user: user.id.clone(),
+        guardianship: Guardianship {
+            guardian_id: guardian.id.clone(),
+            until,
+        },
+        expected_sequence: None,
+    })
+}
+
+#[tokio::test]
+async fn guardian_edits_until_rights_return_to_the_dependant() {
+    let (sst, storage) = new_sst();
+    let dependant = add_user(&sst).await;
+    let guardian = add_user(&sst).await;
+    let until = Utc::now() + Duration::minutes(1);
+    update_as(&sst, set_guardian(&dependant, &guardian, until), &dependant.id, &dependant.id)
+        .await
+        .unwrap();
+
+    let last_name = DataRequests::LastName(ModifyNameRequest {
+        user_id: dependant.id.clone(),
+        calling_user: guardian.id.clone(),
+        name: "boop".into(),
+        expected_sequence: None,
+    });
+    update_as(&sst, last_name, &guardian.id, &dependant.id).await.unwrap();
+    let attribute = DataRequests::SetAttribute(SetAttribute {
+        user_id: dependant.id.clone(),
+        calling_user: guardian.id.clone(),
+        key: "school".into(),
+        value: AttributeValue::String("boop primary".into()),
+        expected_sequence: None,
+    });
+    let updated = update_as(&sst, attribute, &guardian.id, &dependant.id).await.unwrap();
+    let owner = PublicUser::new(dependant.id.clone(), None, None, None, vec![]);
+    let guardian_user = PublicUser::new(guardian.id.clone(), None, None, None, vec![]);
+    assert_eq!(updated.user_details.last_name.value(&owner).unwrap(), Some("boop".into()));
+    assert_eq!(
+        updated.as_response(&guardian_user).guardian.unwrap().guardian_id,
+        guardian.id
+    );
+    assert!(sst.due_changes(&Utc::now()).await.unwrap().is_empty());
+
+    let after_end = until + Duration::seconds(1);
+    let due: Vec<DataRequests> = sst
+        .due_changes(&after_end)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|(change, _, _)| change)
+        .collect();
+    assert_eq!(due.len(), 2);
+    // first, other and last name, the attribute and the keys, one grant for every chain
+    assert!(matches!(
+        &due[0],
+        DataRequests::RevokeExpiredGrants(revocation) if revocation.grants.len() == 5
+    ));
+    assert!(matches!(&due[1], DataRequests::EndGuardianship(_)));
+    for request in due {
+        let mut process_request =
+            process_request(request, dependant.id.clone(), DataRequestType::VALIDATED);
+        process_request.datetime = Some(after_end);
+        sst.update(process_request).await.unwrap();
+    }
+
+    let stored_user = storage.load_user(&dependant.id).await.unwrap();
+    assert!(stored_user.guardian(&owner).is_none());
+    assert!(stored_user
+        .user_details
+        .last_name
+        .expires(&guardian.id, GrantRole::EDITOR)
+        .is_none());
+    let late_edit = DataRequests::FirstName(ModifyNameRequest {
+        user_id: dependant.id.clone(),
+        calling_user: guardian.id.clone(),
+        name: "beep".into(),
+        expected_sequence: None,
+    });
+    assert_eq!(
+        update_as(&sst, late_edit, &guardian.id, &dependant.id).await.unwrap_err(),
+        Code::PermissionDenied
+    );
+}
+
+#[tokio::test]
+async fn only_the_dependant_names_a_guardian() {
+    let (sst, _) = new_sst();
+    let dependant = add_user(&sst).await;
+    let guardian = add_user(&sst).await;
+    let until = Utc::now() + Duration::days(1);
+
+    let result = update_as(
+        &sst,
+        set_guardian(&dependant, &guardian, until),
+        &guardian.id,
+        &dependant.id,
+    )
+    .await;
+
+    assert_eq!(result.unwrap_err(), Code::PermissionDenied);
+}
+
+#[tokio::test]
+async fn guardianship_cannot_end_early() {
+    let (sst, _) = new_sst();
+    let dependant = add_user(&sst).await;
+    let guardian = add_user(&sst).await;
+    let until = Utc::now() + Duration::days(1);
+    update_as(&sst, set_guardian(&dependant, &guardian, until), &dependant.id, &dependant.id)
+        .await
+        .unwrap();
+
+    let result = update_as(
+        &sst,
+        DataRequests::EndGuardianship(EndGuardianship {
+            user_id: dependant.id.clone(),
+        }),
+        &dependant.id,
+        &dependant.id,
+    )
+    .await;
+
+    assert_eq!(result.unwrap_err(), Code::FailedPrecondition);
+}
@@ -0,0 +1,159 @@
+#![cfg(all(test, feature = "sst"))]
+
+use chrono::{Duration, Utc};
+use tonic::Code;
+
+use crate::{
+    db::storage::Storage,
+    enums::data_value::{DataRequestType, DataRequests},
+    merkle::SST,
+    user::{
+        linked_user::{ConfirmLinkedUser, ModifyLinkedUser},
+        public_user::PublicUser,
+        user::User,
+    },
+};
+
+use super::sst::{add_user, new_sst, process_request};
+
+fn add_linked_user(user_id: &str, calling_user: &str) -> DataRequests {
+    DataRequests::AddLinkedUser(ModifyLinkedUser {
+        user_id: user_id.to_string(),
+        calling_user: calling_user.to_string(),
+        expected_sequence: None,
+    })
+}
+
+// commits the request and then every change the sst proposes after it, as validators would
+async fn commit(sst: &SST, request: DataRequests, calling_user: &str, user_id: &str) {
+    let mut pending = vec![(request, calling_user.to_string(), user_id.to_string())];
+    while let Some((request, calling_user, user_id)) = pending.pop() {
+        let mut process_request = process_request(request, user_id, DataRequestType::VALIDATED);
+        process_request.calling_user = calling_user;
+        sst.update(process_request.clone()).await.unwrap();
+        pending.extend(sst.linked_user_follow_ups(&process_request).await);
+    }
+}
+
+// commits the request but loses every change the sst proposes after it
+async fn commit_losing_follow_ups(
+    sst: &SST,
+    request: DataRequests,
+    calling_user: &str,
+    user_id: &str,
+) {
+    let mut process_request =
+        process_request(request, user_id.to_string(), DataRequestType::VALIDATED);
+    process_request.calling_user = calling_user.to_string();
+    sst.update(process_request.clone()).await.unwrap();
+    sst.linked_user_follow_ups(&process_request).await;
+}
+
+// commits what the sweep proposes once a lost follow-up can be retried
+async fn sweep(sst: &SST) -> usize {
+    let after_retry = Utc::now() + Duration::minutes(6);
+    let changes = sst.due_changes(&after_retry).await.unwrap();
+    for (request, calling_user, user_id) in changes.iter() {
+        commit(sst, request.clone(), calling_user, user_id).await;
+    }
+    changes.len()
+}
+
+async fn link(sst: &SST, user: &User, other: &User) {
+    commit(sst, add_linked_user(&other.id, &user.id), &user.id, &user.id).await;
+    commit(sst, add_linked_user(&user.id, &other.id), &other.id, &other.id).await;
+}
+
+#[tokio::test]
+async fn link_is_confirmed_once_both_users_consent() {
+    let (sst, storage) = new_sst();
+    let user = add_user(&sst).await;
+    let other = add_user(&sst).await;
+    let owner = PublicUser::new(user.id.clone(), None, None, None, vec![]);
+
+    commit(&sst, add_linked_user(&other.id, &user.id), &user.id, &user.id).await;
+
+    let stored_user = storage.load_user(&user.id).await.unwrap();
+    assert!(stored_user.consents_to_link(&other.id));
+    assert_eq!(stored_user.as_response(&owner).linked_users, Vec::<String>::new());
+
+    commit(&sst, add_linked_user(&user.id, &other.id), &other.id, &other.id).await;
+
+    let stored_user = storage.load_user(&user.id).await.unwrap();
+    let stored_other = storage.load_user(&other.id).await.unwrap();
+    assert_eq!(stored_user.as_response(&owner).linked_users, vec![other.id.clone()]);
+    assert!(stored_other.is_linked_to(&user.id));
+}
+
+#[tokio::test]
+async fn confirm_linked_user_needs_the_other_consent() {
+    let (sst, _) = new_sst();
+    let user = add_user(&sst).await;
+    let other = add_user(&sst).await;
+    commit(&sst, add_linked_user(&other.id, &user.id), &user.id, &user.id).await;
+
+    let result = sst
+        .update(process_request(
+            DataRequests::ConfirmLinkedUser(ConfirmLinkedUser {
+                user_id: user.id.clone(),
+                linked_user_id: other.id.clone(),
+            }),
+            user.id.clone(),
+            DataRequestType::VALIDATED,
+        ))
+        .await;
+
+    assert_eq!(result.err().unwrap().status, Code::FailedPrecondition);
+}
+
+#[tokio::test]
+async fn removing_a_link_removes_both_sides() {
+    let (sst, storage) = new_sst();
+    let user = add_user(&sst).await;
+    let other = add_user(&sst).await;
+    link(&sst, &user, &other).await;
+
+    commit(
+        &sst,
+        DataRequests::RemoveLinkedUser(ModifyLinkedUser {
+            user_id: other.id.clone(),
+            calling_user: user.id.clone(),
+            expected_sequence: None,
+        }),
+        &user.id,
+        &user.id,
+    )
+    .await;
+
+    let stored_user = storage.load_user(&user.id).await.unwrap();
+    let stored_other = storage.load_user(&other.id).await.unwrap();
+    assert!(!stored_user.consents_to_link(&other.id));
+    assert!(!stored_other.consents_to_link(&user.id));
+}
+
+#[tokio::test]
+async fn lost_follow_ups_are_swept() {
+    let (sst, storage) = new_sst();
+    let user = add_user(&sst).await;
+    let other = add_user(&sst).await;
+    commit(&sst, add_linked_user(&other.id, &user.id), &user.id, &user.id).await;
+    commit_losing_follow_ups(&sst, add_linked_user(&user.id, &other.id), &other.id, &other.id)
+        .await;
+    assert!(!storage.load_user(&user.id).await.unwrap().is_linked_to(&other.id));
+
+    assert_eq!(sweep(&sst).await, 2);
+    assert!(storage.load_user(&user.id).await.unwrap().is_linked_to(&other.id));
+    assert!(storage.load_user(&other.id).await.unwrap().is_linked_to(&user.id));
+
+    let remove = DataRequests::RemoveLinkedUser(ModifyLinkedUser {
+        user_id: other.id.clone(),
+        calling_user: user.id.clone(),
+        expected_sequence: None,
+    });
+    commit_losing_follow_ups(&sst, remove, &user.id, &user.id).await;
+    assert!(storage.load_user(&other.id).await.unwrap().consents_to_link(&user.id));
+
+    assert_eq!(sweep(&sst).await, 1);
+    assert!(!storage.load_user(&other.id).await.unwrap().consents_to_link(&user.id));
+    assert_eq!(sweep(&sst).await, 0);
+}
@@ -0,0 +1,13 @@
+mod access_log;
+mod access_request;
+mod approval;
+mod attestation;
+mod credential;
+mod frontier;
+mod group;
+mod guardian;
+mod linked_user;
+mod organisation;
+mod rebuild;
+mod recovery;
+mod sst;
\ No newline at end of file
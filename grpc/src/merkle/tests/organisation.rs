@@ -0,0 +1,245 @@
+#![cfg(all(test, feature = "sst"))]
+
+use tonic::Code;
+
+use crate::{
+    db::storage::Storage,
+    enums::{
+        data_value::{DataRequestType, DataRequests},
+        value_reference::ValueReference,
+    },
+    permissions::{
+        model::GrantRole,
+        policy::{AccessPolicy, PolicyCondition, SetFieldPolicy},
+    },
+    proto::beco::{
+        AddMemberRequest, CreateOrganisationRequest, EndMembershipRequest, ListMembershipsRequest,
+        ModifyNameRequest, OrganisationRelation,
+    },
+    requests::user_request::FieldReference,
+};
+
+use super::sst::{add_user, create_organisation, new_sst, process_request};
+
+fn add_member(organisation_id: &str, admin_id: &str, user_id: &str) -> DataRequests {
+    DataRequests::AddMember(AddMemberRequest {
+        organisation_id: organisation_id.to_string(),
+        calling_user: admin_id.to_string(),
+        user_id: user_id.to_string(),
+        start_date: None,
+        end_date: None,
+        expected_sequence: None,
+    })
+}
+
+#[tokio::test]
+async fn create_organisation_persists_with_its_own_merkle() {
+    let (sst, storage) = new_sst();
+    let admin = add_user(&sst).await;
+
+    let organisation = create_organisation(&sst, &admin.id).await;
+
+    let (stored, frontier) = storage
+        .load_organisation(&organisation.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.sequence(), 1);
+    assert!(stored.is_admin(&admin.id));
+    assert_eq!(frontier.leaf_count(), 1);
+    assert_eq!(storage.organisation_transaction_count(&organisation.id).await, 1);
+}
+
+#[tokio::test]
+async fn create_organisation_requires_known_user() {
+    let (sst, _) = new_sst();
+
+    let request = DataRequests::CreateOrganisation(CreateOrganisationRequest {
+        calling_user: "nobody".into(),
+        name: "Beco".into(),
+        relation: OrganisationRelation::Employer.into(),
+    });
+    let result = sst
+        .update_organisation(process_request(request, "nobody".into(), DataRequestType::NEW))
+        .await;
+
+    assert_eq!(result.unwrap_err().status, Code::NotFound);
+}
+
+#[tokio::test]
+async fn add_and_end_membership_extend_merkle() {
+    let (sst, storage) = new_sst();
+    let admin = add_user(&sst).await;
+    let member = add_user(&sst).await;
+    let organisation = create_organisation(&sst, &admin.id).await;
+
+    let updated = sst
+        .update_organisation(process_request(
+            add_member(&organisation.id, &admin.id, &member.id),
+            admin.id.clone(),
+            DataRequestType::NEW,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(updated.sequence(), 2);
+
+    let request = DataRequests::EndMembership(EndMembershipRequest {
+        organisation_id: organisation.id.clone(),
+        calling_user: member.id.clone(),
+        user_id: member.id.clone(),
+        end_date: None,
+        expected_sequence: Some(2),
+    });
+    let ended = sst
+        .update_organisation(process_request(
+            request,
+            member.id.clone(),
+            DataRequestType::NEW,
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(ended.sequence(), 3);
+    assert!(ended.memberships()[0].end_date.is_some());
+    let (_, frontier) = storage
+        .load_organisation(&organisation.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(frontier.leaf_count(), 3);
+}
+
+#[tokio::test]
+async fn non_admin_cannot_add_member() {
+    let (sst, storage) = new_sst();
+    let admin = add_user(&sst).await;
+    let member = add_user(&sst).await;
+    let organisation = create_organisation(&sst, &admin.id).await;
+
+    let result = sst
+        .update_organisation(process_request(
+            add_member(&organisation.id, &member.id, &member.id),
+            member.id.clone(),
+            DataRequestType::NEW,
+        ))
+        .await;
+
+    assert_eq!(result.unwrap_err().status, Code::PermissionDenied);
+    assert_eq!(storage.organisation_transaction_count(&organisation.id).await, 1);
+}
+
+#[tokio::test]
+async fn redelivered_organisation_request_is_not_reapplied() {
+    let (sst, storage) = new_sst();
+    let admin = add_user(&sst).await;
+    let member = add_user(&sst).await;
+    let organisation = create_organisation(&sst, &admin.id).await;
+
+    let request = process_request(
+        add_member(&organisation.id, &admin.id, &member.id),
+        admin.id.clone(),
+        DataRequestType::NEW,
+    );
+    sst.update_organisation(request.clone()).await.unwrap();
+    let redelivered = sst.update_organisation(request).await.unwrap();
+
+    assert_eq!(redelivered.sequence(), 2);
+    assert_eq!(redelivered.memberships().len(), 1);
+    assert_eq!(storage.organisation_transaction_count(&organisation.id).await, 2);
+}
+
+#[tokio::test]
+async fn list_memberships_by_organisation_and_user() {
+    let (sst, _) = new_sst();
+    let admin = add_user(&sst).await;
+    let member = add_user(&sst).await;
+    let organisation = create_organisation(&sst, &admin.id).await;
+    sst.update_organisation(process_request(
+        add_member(&organisation.id, &admin.id, &member.id),
+        admin.id.clone(),
+        DataRequestType::NEW,
+    ))
+    .await
+    .unwrap();
+
+    let by_organisation = sst
+        .list_memberships(ListMembershipsRequest {
+            calling_user: admin.id.clone(),
+            organisation_id: Some(organisation.id.clone()),
+            user_id: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(by_organisation.len(), 1);
+
+    let by_user = sst
+        .list_memberships(ListMembershipsRequest {
+            calling_user: member.id.clone(),
+            organisation_id: None,
+            user_id: Some(member.id.clone()),
+        })
+        .await
+        .unwrap();
+    assert_eq!(by_user[0].id, organisation.id);
+
+    let result = sst
+        .list_memberships(ListMembershipsRequest {
+            calling_user: member.id.clone(),
+            organisation_id: Some(organisation.id.clone()),
+            user_id: None,
+        })
+        .await;
+    assert_eq!(result.unwrap_err().status, Code::PermissionDenied);
+}
+
+#[tokio::test]
+async fn policy_follows_active_membership() {
+    let (sst, _) = new_sst();
+    let owner = add_user(&sst).await;
+    let admin = add_user(&sst).await;
+    let member = add_user(&sst).await;
+    let organisation = create_organisation(&sst, &admin.id).await;
+
+    let request = DataRequests::SetFieldPolicy(SetFieldPolicy {
+        user_id: owner.id.clone(),
+        calling_user: owner.id.clone(),
+        field: FieldReference {
+            reference: ValueReference::DETAIL_LAST_NAME,
+            key: None,
+        },
+        policies: vec![AccessPolicy {
+            role: GrantRole::EDITOR,
+            condition: PolicyCondition::MemberOf(organisation.id.clone()),
+        }],
+        expected_sequence: None,
+    });
+    sst.update(process_request(request, owner.id.clone(), DataRequestType::VALIDATED))
+        .await
+        .unwrap();
+    let modify_last_name = || {
+        let request = DataRequests::LastName(ModifyNameRequest {
+            user_id: owner.id.clone(),
+            calling_user: member.id.clone(),
+            name: "beep".into(),
+            expected_sequence: None,
+        });
+        let mut request = process_request(request, owner.id.clone(), DataRequestType::VALIDATED);
+        request.calling_user = member.id.clone();
+        request
+    };
+
+    let result = sst.update(modify_last_name()).await;
+    assert_eq!(result.unwrap_err().status, Code::PermissionDenied);
+
+    sst.update_organisation(process_request(
+        add_member(&organisation.id, &admin.id, &member.id),
+        admin.id.clone(),
+        DataRequestType::NEW,
+    ))
+    .await
+    .unwrap();
+    let (updated_user, calling_user, _) = sst.update(modify_last_name()).await.unwrap();
+
+    assert_eq!(updated_user.sequence(), 3);
+    assert_eq!(calling_user.memberships.len(), 1);
+}
@@ -0,0 +1,109 @@
+#![cfg(all(test, feature = "sst"))]
+
+use std::collections::HashSet;
+
+use chrono::Utc;
+
+use crate::{
+    enums::data_value::{DataRequestType, DataRequests, ProcessRequest},
+    merkle::{transaction::Transaction, SST},
+    proto::beco::AddUserRequest,
+    user::{public_user::PublicUser, user::User},
+};
+
+use super::sst::last_name;
+
+fn transaction(user: &User, request: DataRequests) -> Transaction {
+    Transaction {
+        user: user.clone(),
+        sequence: user.sequence(),
+        process_request: ProcessRequest {
+            validated_signatures: HashSet::new(),
+            failed_signatures: HashSet::new(),
+            ignore_signatures: HashSet::new(),
+            status: DataRequestType::VALIDATED,
+            request,
+            calling_user: user.id.clone(),
+            user_id: user.id.clone(),
+            hash: 0,
+            datetime: None,
+            connected_peers: 0,
+            originator_hash: None,
+            originator_peer_id: None,
+        },
+        calling_user_groups: vec![],
+        calling_user_memberships: vec![],
+    }
+}
+
+#[tokio::test]
+async fn replay_matches_log() {
+    let created = User::new(Some("boop".into()));
+    let owner = PublicUser::new(created.id.clone(), None, None, None, vec![]);
+    let mut updated = created.clone();
+    let _ = updated
+        .user_details
+        .last_name
+        .update(Some("beep".into()), &owner, &Utc::now())
+        .await;
+    updated.increase_sequence();
+    let transactions = vec![
+        transaction(
+            &created,
+            DataRequests::AddUser(AddUserRequest {
+                calling_user: "".into(),
+                name: "boop".into(),
+                national_identifier: None,
+            }),
+        ),
+        transaction(&updated, last_name(&updated, "beep")),
+    ];
+
+    let (user, first_divergent_sequence) = SST::replay(&transactions).await.unwrap();
+
+    assert_eq!(user.sequence(), 2);
+    assert_eq!(first_divergent_sequence, None);
+    assert_eq!(
+        user.user_details.last_name.value(&owner).unwrap(),
+        Some("beep".into())
+    );
+}
+
+#[tokio::test]
+async fn replay_reports_divergence() {
+    let created = User::new(Some("boop".into()));
+    let owner = PublicUser::new(created.id.clone(), None, None, None, vec![]);
+    let mut updated = created.clone();
+    let _ = updated
+        .user_details
+        .last_name
+        .update(Some("drifted".into()), &owner, &Utc::now())
+        .await;
+    updated.increase_sequence();
+    let transactions = vec![
+        transaction(
+            &created,
+            DataRequests::AddUser(AddUserRequest {
+                calling_user: "".into(),
+                name: "boop".into(),
+                national_identifier: None,
+            }),
+        ),
+        transaction(&updated, last_name(&updated, "beep")),
+    ];
+
+    let (user, first_divergent_sequence) = SST::replay(&transactions).await.unwrap();
+
+    assert_eq!(first_divergent_sequence, Some(2));
+    assert_eq!(
+        user.user_details.last_name.value(&owner).unwrap(),
+        Some("beep".into())
+    );
+}
+
+#[tokio::test]
+async fn replay_without_transactions() {
+    let result = SST::replay(&[]).await;
+
+    assert_eq!(result.unwrap_err().message, "No transactions to replay");
+}
@@ -0,0 +1,244 @@
+#![cfg(all(test, feature = "sst"))]
+
+use chrono::{Duration, Utc};
+use k256::{
+    ecdsa::{signature::Signer, Signature, SigningKey},
+    elliptic_curve::rand_core::OsRng,
+};
+use tonic::Code;
+
+use crate::{
+    db::storage::Storage,
+    enums::data_value::{DataRequestType, DataRequests},
+    merkle::SST,
+    user::{
+        recovery::{
+            recovery_message, ApproveRecovery, CancelRecovery, RecoveryGuardian, SetAuthKey,
+            SetRecoveryGuardians,
+        },
+        public_user::PublicUser,
+        user::User,
+    },
+};
+
+use super::sst::{add_user_with_key, new_sst, process_request, public_key, update_as};
+
+fn sign(signing_key: &SigningKey, message: String) -> String {
+    let signature: Signature = signing_key.sign(message.as_bytes());
+    hex::encode(signature.to_bytes())
+}
+
+// owner signatures are over the user's current sequence
+async fn sequence(sst: &SST, user: &User) -> u64 {
+    sst.fetch_user(&user.id).await.unwrap().sequence()
+}
+
+async fn nominate(
+    sst: &SST,
+    user: &User,
+    user_key: &SigningKey,
+    guardians: &[(User, SigningKey)],
+    threshold: u32,
+) {
+    let mut request = SetRecoveryGuardians {
+        user_id: user.id.clone(),
+        calling_user: user.id.clone(),
+        guardians: guardians
+            .iter()
+            .map(|(guardian, signing_key)| RecoveryGuardian {
+                user_id: guardian.id.clone(),
+                public_key: public_key(signing_key),
+            })
+            .collect(),
+        threshold,
+        expected_sequence: None,
+        signature: "".into(),
+    };
+    request.signature = sign(user_key, request.message(sequence(sst, user).await));
+    let request = DataRequests::SetRecoveryGuardians(request);
+    update_as(sst, request, &user.id, &user.id).await.unwrap();
+}
+
+// signed over the user's current recovery epoch
+async fn approve(
+    sst: &SST,
+    user: &User,
+    guardian: &(User, SigningKey),
+    new_key: &String,
+) -> DataRequests {
+    let epoch = sst.fetch_user(&user.id).await.unwrap().recovery_epoch();
+    DataRequests::ApproveRecovery(ApproveRecovery {
+        user_id: user.id.clone(),
+        calling_user: guardian.0.id.clone(),
+        public_key: new_key.clone(),
+        signature: sign(&guardian.1, recovery_message(&user.id, epoch, new_key)),
+    })
+}
+
+async fn guardians(sst: &SST) -> Vec<(User, SigningKey)> {
+    vec![
+        add_user_with_key(sst).await,
+        add_user_with_key(sst).await,
+        add_user_with_key(sst).await,
+    ]
+}
+
+fn chain_keys(user: &User) -> serde_json::Value {
+    let owner = PublicUser::new(user.id.clone(), None, None, None, vec![]);
+    serde_json::to_value(user.sorted_public_chain_accounts(&owner)).unwrap()
+}
+
+#[tokio::test]
+async fn recovery_rotates_the_auth_key_after_the_delay() {
+    let (sst, storage) = new_sst();
+    let (user, user_key) = add_user_with_key(&sst).await;
+    let guardians = guardians(&sst).await;
+    nominate(&sst, &user, &user_key, &guardians, 2).await;
+    let new_key = public_key(&SigningKey::random(&mut OsRng));
+    let guardian = PublicUser::new(guardians[0].0.id.clone(), None, None, None, vec![]);
+
+    let approval = approve(&sst, &user, &guardians[0], &new_key).await;
+    let approved = update_as(&sst, approval, &guardian.id, &user.id)
+        .await
+        .unwrap();
+    assert!(approved.recovery(&guardian).unwrap().ready_at.is_none());
+    let approval = approve(&sst, &user, &guardians[1], &new_key).await;
+    update_as(&sst, approval, &guardians[1].0.id, &user.id)
+        .await
+        .unwrap();
+    let after_delay = Utc::now() + Duration::hours(73);
+    assert!(sst.due_changes(&Utc::now()).await.unwrap().is_empty());
+    let due = sst.due_changes(&after_delay).await.unwrap();
+    assert_eq!(due.len(), 1);
+    assert!(matches!(&due[0].0, DataRequests::CompleteRecovery(_)));
+
+    let mut request = process_request(
+        due[0].0.clone(),
+        user.id.clone(),
+        DataRequestType::VALIDATED,
+    );
+    request.datetime = Some(after_delay);
+    sst.update(request).await.unwrap();
+
+    let stored_user = storage.load_user(&user.id).await.unwrap();
+    assert_eq!(stored_user.auth_key(), Some(&new_key));
+    assert_eq!(chain_keys(&stored_user), chain_keys(&user));
+    assert!(sst.due_changes(&after_delay).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn user_cancels_recovery_during_the_delay() {
+    let (sst, _) = new_sst();
+    let (user, user_key) = add_user_with_key(&sst).await;
+    let guardians = guardians(&sst).await;
+    nominate(&sst, &user, &user_key, &guardians, 1).await;
+    let new_key = public_key(&SigningKey::random(&mut OsRng));
+    let approval = approve(&sst, &user, &guardians[0], &new_key).await;
+    update_as(&sst, approval.clone(), &guardians[0].0.id, &user.id)
+        .await
+        .unwrap();
+
+    let mut cancel = CancelRecovery {
+        user_id: user.id.clone(),
+        calling_user: user.id.clone(),
+        expected_sequence: None,
+        signature: "".into(),
+    };
+    let unsigned_result =
+        update_as(&sst, DataRequests::CancelRecovery(cancel.clone()), &user.id, &user.id).await;
+    assert_eq!(unsigned_result.unwrap_err(), Code::PermissionDenied);
+    cancel.signature = sign(&user_key, cancel.message(sequence(&sst, &user).await));
+    let cancelled = update_as(&sst, DataRequests::CancelRecovery(cancel), &user.id, &user.id)
+        .await
+        .unwrap();
+
+    let after_delay = Utc::now() + Duration::hours(73);
+    assert!(sst.due_changes(&after_delay).await.unwrap().is_empty());
+    assert_eq!(cancelled.auth_key(), user.auth_key());
+    // the cancelled approval was signed over the previous epoch
+    let replay_result = update_as(&sst, approval, &guardians[0].0.id, &user.id).await;
+    assert_eq!(replay_result.unwrap_err(), Code::PermissionDenied);
+}
+
+#[tokio::test]
+async fn approval_must_be_signed_by_the_guardian() {
+    let (sst, _) = new_sst();
+    let (user, user_key) = add_user_with_key(&sst).await;
+    let guardians = guardians(&sst).await;
+    nominate(&sst, &user, &user_key, &guardians[..2], 1).await;
+    let new_key = public_key(&SigningKey::random(&mut OsRng));
+    let forged = (guardians[0].0.clone(), guardians[2].1.clone());
+
+    let forged_approval = approve(&sst, &user, &forged, &new_key).await;
+    let forged_result = update_as(&sst, forged_approval, &forged.0.id, &user.id).await;
+    let outsider_approval = approve(&sst, &user, &guardians[2], &new_key).await;
+    let outsider_result =
+        update_as(&sst, outsider_approval, &guardians[2].0.id, &user.id).await;
+
+    assert_eq!(forged_result.unwrap_err(), Code::PermissionDenied);
+    assert_eq!(outsider_result.unwrap_err(), Code::PermissionDenied);
+}
+
+#[tokio::test]
+async fn guardian_key_must_be_their_auth_key() {
+    let (sst, _) = new_sst();
+    let (user, user_key) = add_user_with_key(&sst).await;
+    let (guardian, _) = add_user_with_key(&sst).await;
+
+    let mut request = SetRecoveryGuardians {
+        user_id: user.id.clone(),
+        calling_user: user.id.clone(),
+        guardians: vec![RecoveryGuardian {
+            user_id: guardian.id.clone(),
+            public_key: public_key(&SigningKey::random(&mut OsRng)),
+        }],
+        threshold: 1,
+        expected_sequence: None,
+        signature: "".into(),
+    };
+    request.signature = sign(&user_key, request.message(sequence(&sst, &user).await));
+    let request = DataRequests::SetRecoveryGuardians(request);
+
+    assert_eq!(
+        update_as(&sst, request, &user.id, &user.id).await.unwrap_err(),
+        Code::FailedPrecondition
+    );
+}
+
+#[tokio::test]
+async fn new_auth_key_needs_the_current_one_and_drops_a_recovery() {
+    let (sst, _) = new_sst();
+    let (user, user_key) = add_user_with_key(&sst).await;
+    let guardians = guardians(&sst).await;
+    nominate(&sst, &user, &user_key, &guardians, 1).await;
+    let new_key = public_key(&SigningKey::random(&mut OsRng));
+    let approval = approve(&sst, &user, &guardians[0], &new_key).await;
+    update_as(&sst, approval, &guardians[0].0.id, &user.id)
+        .await
+        .unwrap();
+
+    let rotated_key = SigningKey::random(&mut OsRng);
+    let mut request = SetAuthKey {
+        user_id: user.id.clone(),
+        calling_user: user.id.clone(),
+        public_key: public_key(&rotated_key),
+        expected_sequence: None,
+        signature: "".into(),
+    };
+    let unsigned_result =
+        update_as(&sst, DataRequests::SetAuthKey(request.clone()), &user.id, &user.id).await;
+    assert_eq!(unsigned_result.unwrap_err(), Code::PermissionDenied);
+    request.signature = sign(&rotated_key, request.message(sequence(&sst, &user).await));
+    let forged_result =
+        update_as(&sst, DataRequests::SetAuthKey(request.clone()), &user.id, &user.id).await;
+    assert_eq!(forged_result.unwrap_err(), Code::PermissionDenied);
+    request.signature = sign(&user_key, request.message(sequence(&sst, &user).await));
+    let rotated = update_as(&sst, DataRequests::SetAuthKey(request), &user.id, &user.id)
+        .await
+        .unwrap();
+
+    let owner = PublicUser::new(user.id.clone(), None, None, None, vec![]);
+    assert!(rotated.recovery(&owner).unwrap().pending_public_key.is_none());
+    let after_delay = Utc::now() + Duration::hours(73);
+    assert!(sst.due_changes(&after_delay).await.unwrap().is_empty());
+}
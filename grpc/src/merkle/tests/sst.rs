@@ -0,0 +1,458 @@
+#![cfg(all(test, feature = "sst"))]
+
+use std::{collections::HashSet, sync::Arc};
+
+use chrono::{DateTime, Utc};
+use k256::{ecdsa::SigningKey, elliptic_curve::rand_core::OsRng};
+use tonic::Code;
+
+use crate::{
+    db::{
+        memory::MemoryStorage,
+        storage::{CommittedRequest, Storage},
+    },
+    enums::{
+        data_value::{DataRequestType, DataRequests, ProcessRequest},
+        value_reference::ValueReference,
+    },
+    merkle::SST,
+    organisation::organisation::Organisation,
+    proto::beco::{
+        AddUserRequest, ClearAttributeRequest, CreateOrganisationRequest, ModifyNameRequest,
+        OrganisationRelation,
+    },
+    requests::user_request::FieldReference,
+    user::{
+        attribute::{AttributeValue, SetAttribute},
+        national_identifier::{seal, BlindIndex},
+        public_user::PublicUser,
+        recovery::SetAuthKey,
+        user::User,
+    },
+    utils::calculate_hash,
+};
+
+pub(super) fn process_request(
+    request: DataRequests,
+    user_id: String,
+    status: DataRequestType,
+) -> ProcessRequest {
+    let mut process_request = ProcessRequest {
+        validated_signatures: HashSet::new(),
+        failed_signatures: HashSet::new(),
+        ignore_signatures: HashSet::new(),
+        status,
+        request,
+        calling_user: user_id.clone(),
+        user_id,
+        hash: 0,
+        datetime: Some(Utc::now()),
+        connected_peers: 0,
+        originator_hash: None,
+        originator_peer_id: None,
+    };
+    process_request.hash = calculate_hash(&process_request);
+    process_request
+}
+
+pub(super) fn new_sst() -> (SST, Arc<MemoryStorage>) {
+    let storage = Arc::new(MemoryStorage::new());
+    (SST::with_storage(Box::new(storage.clone())), storage)
+}
+
+pub(super) async fn add_user(sst: &SST) -> User {
+    let request = DataRequests::AddUser(AddUserRequest {
+        calling_user: "".into(),
+        name: "boop".into(),
+        national_identifier: None,
+    });
+    let (user, _, _) = sst
+        .update(process_request(request, "".into(), DataRequestType::NEW))
+        .await
+        .unwrap();
+    user
+}
+
+pub(super) async fn update_at(
+    sst: &SST,
+    request: DataRequests,
+    calling_user: &str,
+    user_id: &str,
+    at: DateTime<Utc>,
+) -> Result<User, Code> {
+    let mut process_request =
+        process_request(request, user_id.to_string(), DataRequestType::VALIDATED);
+    process_request.calling_user = calling_user.to_string();
+    process_request.datetime = Some(at);
+    sst.update(process_request)
+        .await
+        .map(|(user, _, _)| user)
+        .map_err(|err| err.status)
+}
+
+pub(super) async fn update_as(
+    sst: &SST,
+    request: DataRequests,
+    calling_user: &str,
+    user_id: &str,
+) -> Result<User, Code> {
+    update_at(sst, request, calling_user, user_id, Utc::now()).await
+}
+
+pub(super) fn public_key(signing_key: &SigningKey) -> String {
+    hex::encode(signing_key.verifying_key().to_sec1_bytes())
+}
+
+// a user with an auth key, returned with the key they sign with
+pub(super) async fn add_user_with_key(sst: &SST) -> (User, SigningKey) {
+    let user = add_user(sst).await;
+    let signing_key = SigningKey::random(&mut OsRng);
+    let request = DataRequests::SetAuthKey(SetAuthKey {
+        user_id: user.id.clone(),
+        calling_user: user.id.clone(),
+        public_key: public_key(&signing_key),
+        expected_sequence: None,
+        signature: "".into(),
+    });
+    let user = update_as(sst, request, &user.id, &user.id).await.unwrap();
+    (user, signing_key)
+}
+
+pub(super) async fn create_organisation(sst: &SST, admin_id: &str) -> Organisation {
+    let request = DataRequests::CreateOrganisation(CreateOrganisationRequest {
+        calling_user: admin_id.to_string(),
+        name: "Beco".into(),
+        relation: OrganisationRelation::Employer.into(),
+    });
+    sst.update_organisation(process_request(
+        request,
+        admin_id.to_string(),
+        DataRequestType::NEW,
+    ))
+    .await
+    .unwrap()
+}
+
+pub(super) fn last_name_field() -> FieldReference {
+    FieldReference {
+        reference: ValueReference::DETAIL_LAST_NAME,
+        key: None,
+    }
+}
+
+pub(super) fn last_name(user: &User, name: &str) -> DataRequests {
+    DataRequests::LastName(ModifyNameRequest {
+        user_id: user.id.clone(),
+        calling_user: user.id.clone(),
+        name: name.into(),
+        expected_sequence: None,
+    })
+}
+
+#[tokio::test]
+async fn update_add_user_persists() {
+    let (sst, storage) = new_sst();
+
+    let user = add_user(&sst).await;
+
+    let stored_user = storage.load_user(&user.id).await.unwrap();
+    assert_eq!(stored_user.sequence(), 1);
+    assert_eq!(storage.transaction_count(&user.id).await, 1);
+    let frontier = storage.load_merkle(&user.id).await.unwrap().unwrap();
+    assert_eq!(frontier.leaf_count(), 1);
+    assert_eq!(frontier.root_hex(), storage.merkle_root_hex(&user.id).await);
+}
+
+#[tokio::test]
+async fn update_name_persists_and_extends_merkle() {
+    let (sst, storage) = new_sst();
+    let user = add_user(&sst).await;
+    let owner = PublicUser::new(user.id.clone(), None, None, None, vec![]);
+    let first_root = storage.merkle_root_hex(&user.id).await.unwrap();
+
+    let request = DataRequests::LastName(ModifyNameRequest {
+        user_id: user.id.clone(),
+        calling_user: user.id.clone(),
+        name: "beep".into(),
+        expected_sequence: None,
+    });
+    let (updated_user, _, _) = sst
+        .update(process_request(
+            request,
+            user.id.clone(),
+            DataRequestType::VALIDATED,
+        ))
+        .await
+        .unwrap();
+
+    let stored_user = storage.load_user(&user.id).await.unwrap();
+    assert_eq!(updated_user.sequence(), 2);
+    assert_eq!(stored_user.sequence(), 2);
+    assert_eq!(
+        stored_user.user_details.last_name.value(&owner).unwrap(),
+        Some("beep".into())
+    );
+    assert_eq!(storage.transaction_count(&user.id).await, 2);
+    let frontier = storage.load_merkle(&user.id).await.unwrap().unwrap();
+    assert_eq!(frontier.leaf_count(), 2);
+    assert_eq!(frontier.root_hex(), storage.merkle_root_hex(&user.id).await);
+    assert_ne!(storage.merkle_root_hex(&user.id).await.unwrap(), first_root);
+}
+
+#[tokio::test]
+async fn update_without_permission_fails() {
+    let (sst, storage) = new_sst();
+    let user = add_user(&sst).await;
+
+    let request = DataRequests::LastName(ModifyNameRequest {
+        user_id: user.id.clone(),
+        calling_user: "someone else".into(),
+        name: "beep".into(),
+        expected_sequence: None,
+    });
+    let mut stranger_request =
+        process_request(request, user.id.clone(), DataRequestType::VALIDATED);
+    stranger_request.calling_user = "someone else".into();
+    let result = sst.update(stranger_request).await;
+
+    assert!(result.is_err());
+    assert_eq!(storage.transaction_count(&user.id).await, 1);
+}
+
+#[tokio::test]
+async fn redelivered_request_is_not_reapplied() {
+    let (sst, storage) = new_sst();
+    let user = add_user(&sst).await;
+
+    let request = DataRequests::LastName(ModifyNameRequest {
+        user_id: user.id.clone(),
+        calling_user: user.id.clone(),
+        name: "beep".into(),
+        expected_sequence: None,
+    });
+    let validated_request = process_request(request, user.id.clone(), DataRequestType::VALIDATED);
+    let (first_user, _, first_root) = sst.update(validated_request.clone()).await.unwrap();
+    let (redelivered_user, _, redelivered_root) = sst.update(validated_request).await.unwrap();
+
+    assert_eq!(first_user.sequence(), 2);
+    assert_eq!(redelivered_user.sequence(), 2);
+    assert_eq!(redelivered_root, first_root);
+    assert_eq!(storage.merkle_root_hex(&user.id).await, Some(first_root));
+    assert_eq!(sst.fetch_user(&user.id).await.unwrap().sequence(), 2);
+    assert_eq!(storage.load_user(&user.id).await.unwrap().sequence(), 2);
+    assert_eq!(storage.transaction_count(&user.id).await, 2);
+}
+
+#[tokio::test]
+async fn redelivered_add_user_returns_same_user() {
+    let (sst, storage) = new_sst();
+    let request = DataRequests::AddUser(AddUserRequest {
+        calling_user: "".into(),
+        name: "boop".into(),
+        national_identifier: None,
+    });
+    let new_request = process_request(request, "".into(), DataRequestType::NEW);
+
+    let (user, _, _) = sst.update(new_request.clone()).await.unwrap();
+    let (redelivered_user, _, _) = sst.update(new_request).await.unwrap();
+
+    assert_eq!(redelivered_user.id, user.id);
+    assert_eq!(storage.load_user_ids().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn storage_rejects_duplicate_request_id() {
+    let (sst, storage) = new_sst();
+    let user = add_user(&sst).await;
+    let transaction = storage
+        .load_transactions(&user.id, 1, 1)
+        .await
+        .unwrap()
+        .remove(0);
+    let request_id = transaction.process_request.request_id();
+    let frontier = storage.load_merkle(&user.id).await.unwrap().unwrap();
+
+    let request = CommittedRequest {
+        request_id: &request_id,
+        sequence: 2,
+        serialised_transaction: &serde_json::to_value(&transaction).unwrap(),
+        leaf: [0; 32],
+        root: &frontier.root_hex().unwrap(),
+    };
+    let result = storage
+        .save_user_request_and_merkle(
+            &user.id,
+            &serde_json::to_value(&user).unwrap(),
+            request,
+            None,
+            &frontier,
+            None,
+        )
+        .await;
+
+    assert_eq!(result.unwrap_err().status, tonic::Code::AlreadyExists);
+    assert_eq!(storage.transaction_count(&user.id).await, 1);
+}
+
+#[tokio::test]
+async fn update_with_stale_expected_sequence_is_aborted() {
+    let (sst, storage) = new_sst();
+    let user = add_user(&sst).await;
+
+    let request = DataRequests::LastName(ModifyNameRequest {
+        user_id: user.id.clone(),
+        calling_user: user.id.clone(),
+        name: "beep".into(),
+        expected_sequence: Some(0),
+    });
+    let result = sst
+        .update(process_request(
+            request,
+            user.id.clone(),
+            DataRequestType::VALIDATED,
+        ))
+        .await;
+
+    let err = result.unwrap_err();
+    assert_eq!(err.status, tonic::Code::Aborted);
+    assert!(err.message.contains("current sequence is 1"));
+    assert_eq!(storage.transaction_count(&user.id).await, 1);
+}
+
+#[tokio::test]
+async fn update_with_current_expected_sequence_applies() {
+    let (sst, storage) = new_sst();
+    let user = add_user(&sst).await;
+
+    let request = DataRequests::LastName(ModifyNameRequest {
+        user_id: user.id.clone(),
+        calling_user: user.id.clone(),
+        name: "beep".into(),
+        expected_sequence: Some(user.sequence()),
+    });
+    let (updated_user, _, _) = sst
+        .update(process_request(
+            request,
+            user.id.clone(),
+            DataRequestType::VALIDATED,
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(updated_user.sequence(), 2);
+    assert_eq!(storage.transaction_count(&user.id).await, 2);
+}
+
+fn sst_with_blind_index() -> (SST, Arc<MemoryStorage>, String) {
+    let storage = Arc::new(MemoryStorage::new());
+    let blind_index = BlindIndex::new(
+        "0101010101010101010101010101010101010101010101010101010101010101",
+        "0303030303030303030303030303030303030303030303030303030303030303",
+        "salt",
+    )
+    .unwrap();
+    let public_key = blind_index.public_key();
+    let sst = SST::with_storage(Box::new(storage.clone())).with_blind_index(blind_index);
+    (sst, storage, public_key)
+}
+
+fn add_user_with_identifier(national_identifier: &str, public_key: &str) -> ProcessRequest {
+    let request = DataRequests::AddUser(AddUserRequest {
+        calling_user: "".into(),
+        name: "boop".into(),
+        national_identifier: Some(seal(national_identifier, public_key).unwrap()),
+    });
+    process_request(request, "".into(), DataRequestType::NEW)
+}
+
+#[tokio::test]
+async fn update_add_user_stores_only_the_blind_index() {
+    let (sst, storage, public_key) = sst_with_blind_index();
+    let request = add_user_with_identifier("AB123456C", &public_key);
+    let sealed = match &request.request {
+        DataRequests::AddUser(request) => request.national_identifier.clone().unwrap(),
+        _ => unreachable!(),
+    };
+
+    let (user, _, _) = sst.update(request).await.unwrap();
+
+    let rows = storage.load_transaction_rows(&user.id).await.unwrap();
+    let stored_transaction = rows[0].1.to_string();
+    assert!(!stored_transaction.contains("AB123456C"));
+    assert!(!stored_transaction.contains(&sealed));
+    assert!(stored_transaction.contains("\"national_identifier\":null"));
+}
+
+#[tokio::test]
+async fn update_add_user_rejects_duplicate_national_identifier() {
+    let (sst, storage, public_key) = sst_with_blind_index();
+    let user = sst
+        .update(add_user_with_identifier("AB123456C", &public_key))
+        .await
+        .unwrap()
+        .0;
+
+    let result = sst
+        .update(add_user_with_identifier("ab 12 34 56 c", &public_key))
+        .await;
+
+    let err = result.unwrap_err();
+    assert_eq!(err.status, tonic::Code::AlreadyExists);
+    assert_eq!(storage.load_user_ids().await.unwrap(), vec![user.id]);
+}
+
+#[tokio::test]
+async fn update_add_user_with_identifier_requires_blind_index() {
+    let (sst, storage) = new_sst();
+    let (_, _, public_key) = sst_with_blind_index();
+
+    let result = sst
+        .update(add_user_with_identifier("AB123456C", &public_key))
+        .await;
+
+    assert_eq!(result.unwrap_err().status, tonic::Code::FailedPrecondition);
+    assert!(storage.load_user_ids().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn update_attribute_persists_and_clears() {
+    let (sst, storage) = new_sst();
+    let user = add_user(&sst).await;
+    let owner = PublicUser::new(user.id.clone(), None, None, None, vec![]);
+
+    let request = DataRequests::SetAttribute(SetAttribute {
+        user_id: user.id.clone(),
+        calling_user: user.id.clone(),
+        key: "email".into(),
+        value: AttributeValue::String("boop@example.com".into()),
+        expected_sequence: Some(user.sequence()),
+    });
+    sst.update(process_request(
+        request,
+        user.id.clone(),
+        DataRequestType::VALIDATED,
+    ))
+    .await
+    .unwrap();
+
+    let stored_user = storage.load_user(&user.id).await.unwrap();
+    assert_eq!(stored_user.user_details.public_attributes(&owner).len(), 1);
+
+    let request = DataRequests::ClearAttribute(ClearAttributeRequest {
+        user_id: user.id.clone(),
+        calling_user: user.id.clone(),
+        key: "email".into(),
+        expected_sequence: None,
+    });
+    sst.update(process_request(
+        request,
+        user.id.clone(),
+        DataRequestType::VALIDATED,
+    ))
+    .await
+    .unwrap();
+
+    let stored_user = storage.load_user(&user.id).await.unwrap();
+    assert_eq!(stored_user.sequence(), 3);
+    assert!(stored_user.user_details.public_attributes(&owner).is_empty());
+}
@@ -1,10 +1,35 @@
 use serde::{Serialize, Deserialize};
 
-use crate::{enums::data_value::ProcessRequest, user::user::User};
+use crate::{
+    enums::data_value::ProcessRequest,
+    group::group::Group,
+    organisation::{organisation::Organisation, user_organisation::UserOrganisation},
+    user::user::User,
+};
 
 #[derive(Debug, Hash, Clone, Serialize, Deserialize)]
 pub struct Transaction {
     pub user: User,
     pub sequence: u64,
     pub process_request: ProcessRequest,
+    // groups and memberships are not part of the user, so a replay needs what the change was
+    // checked with
+    #[serde(default)]
+    pub calling_user_groups: Vec<String>,
+    #[serde(default)]
+    pub calling_user_memberships: Vec<UserOrganisation>,
+}
+
+#[derive(Debug, Hash, Clone, Serialize, Deserialize)]
+pub struct OrganisationTransaction {
+    pub organisation: Organisation,
+    pub sequence: u64,
+    pub process_request: ProcessRequest,
+}
+
+#[derive(Debug, Hash, Clone, Serialize, Deserialize)]
+pub struct GroupTransaction {
+    pub group: Group,
+    pub sequence: u64,
+    pub process_request: ProcessRequest,
 }
@@ -1 +1,5 @@
-pub mod user_organisation;
\ No newline at end of file
+#[allow(clippy::module_inception)]
+pub mod organisation;
+pub mod user_organisation;
+
+mod tests;
\ No newline at end of file
@@ -0,0 +1,213 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tonic::Code;
+
+#[cfg(feature = "sst")]
+use uuid::Uuid;
+
+use crate::{
+    enums::oragnisaton_relation::OrganisationRelation,
+    errors::BecoError,
+    organisation::user_organisation::UserOrganisation,
+    proto::beco::{MembershipResponse, OrganisationResponse},
+};
+
+const MAX_NAME_LENGTH: usize = 128;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq)]
+pub struct Membership {
+    pub user_id: String,
+    pub start_date: DateTime<Utc>,
+    pub end_date: Option<DateTime<Utc>>,
+}
+
+impl Membership {
+    fn overlaps(&self, start_date: &DateTime<Utc>, end_date: &Option<DateTime<Utc>>) -> bool {
+        self.end_date.is_none_or(|end| start_date < &end)
+            && end_date.is_none_or(|end| self.start_date < end)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Hash)]
+pub struct Organisation {
+    pub id: String,
+    pub name: String,
+    pub relation: OrganisationRelation,
+    admins: Vec<String>,
+    // the full history, ended memberships are kept with their end date
+    memberships: Vec<Membership>,
+    sequence: u64,
+}
+
+impl Organisation {
+    #[cfg(feature = "sst")]
+    pub fn new(
+        name: String,
+        relation: OrganisationRelation,
+        admin_id: String,
+    ) -> Result<Self, BecoError> {
+        let name = name.trim().to_string();
+        if name.is_empty() || name.len() > MAX_NAME_LENGTH {
+            return Err(BecoError {
+                message: "Invalid organisation name".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        Ok(Self {
+            id: Uuid::new_v4().to_string(),
+            name,
+            relation,
+            admins: vec![admin_id],
+            memberships: vec![],
+            sequence: 1,
+        })
+    }
+
+    pub fn increase_sequence(&mut self) -> u64 {
+        self.sequence += 1;
+        self.sequence
+    }
+
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    pub fn check_sequence(&self, expected_sequence: Option<u64>) -> Result<(), BecoError> {
+        if let Some(expected_sequence) = expected_sequence {
+            if expected_sequence != self.sequence {
+                return Err(BecoError {
+                    message: format!(
+                        "Expected sequence {expected_sequence} but the current sequence is {}",
+                        self.sequence
+                    ),
+                    status: Code::Aborted,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn is_admin(&self, user_id: &String) -> bool {
+        self.admins.contains(user_id)
+    }
+
+    pub fn memberships(&self) -> &Vec<Membership> {
+        &self.memberships
+    }
+
+    pub fn add_member(
+        &mut self,
+        user_id: String,
+        start_date: DateTime<Utc>,
+        end_date: Option<DateTime<Utc>>,
+        calling_user_id: &String,
+    ) -> Result<(), BecoError> {
+        if !self.is_admin(calling_user_id) {
+            return Err(BecoError {
+                message: "User does not have permission to add members".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        if end_date.is_some_and(|end_date| end_date <= start_date) {
+            return Err(BecoError {
+                message: "end_date must be after start_date".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        let overlaps = self.memberships.iter().any(|membership| {
+            membership.user_id == user_id && membership.overlaps(&start_date, &end_date)
+        });
+        if overlaps {
+            return Err(BecoError {
+                message: "User is already a member for this period".into(),
+                status: Code::AlreadyExists,
+            });
+        }
+        self.memberships.push(Membership {
+            user_id,
+            start_date,
+            end_date,
+        });
+        Ok(())
+    }
+
+    // admins can end anyone's membership, members can leave
+    pub fn end_membership(
+        &mut self,
+        user_id: &String,
+        end_date: DateTime<Utc>,
+        calling_user_id: &String,
+    ) -> Result<(), BecoError> {
+        if !self.is_admin(calling_user_id) && calling_user_id != user_id {
+            return Err(BecoError {
+                message: "User does not have permission to end this membership".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        let membership_option = self.memberships.iter_mut().find(|membership| {
+            &membership.user_id == user_id
+                && membership.end_date.is_none_or(|current| end_date < current)
+                && membership.start_date < end_date
+        });
+        if membership_option.is_none() {
+            return Err(BecoError {
+                message: "No membership to end at this date".into(),
+                status: Code::NotFound,
+            });
+        }
+        membership_option.unwrap().end_date = Some(end_date);
+        Ok(())
+    }
+
+    pub fn user_organisations(&self, user_id: Option<&String>) -> Vec<UserOrganisation> {
+        self.memberships
+            .iter()
+            .filter(|membership| user_id.is_none_or(|user_id| &membership.user_id == user_id))
+            .map(|membership| UserOrganisation {
+                id: self.id.clone(),
+                name: self.name.clone(),
+                user_id: membership.user_id.clone(),
+                relation: self.relation,
+                start_date: membership.start_date,
+                end_date: membership.end_date,
+            })
+            .collect()
+    }
+
+    pub fn as_response(&self, calling_user_id: &String) -> OrganisationResponse {
+        let visible_user = if self.is_admin(calling_user_id) {
+            None
+        } else {
+            Some(calling_user_id)
+        };
+        OrganisationResponse {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            relation: self.relation.into(),
+            admins: self.admins.clone(),
+            memberships: self
+                .user_organisations(visible_user)
+                .into_iter()
+                .map(|membership| -> MembershipResponse { membership.into() })
+                .collect(),
+            sequence: self.sequence,
+        }
+    }
+}
+
+// dates in requests are RFC 3339, a missing date falls back to when the request was made
+pub fn parse_date(
+    date: &Option<String>,
+    default: Option<DateTime<Utc>>,
+) -> Result<Option<DateTime<Utc>>, BecoError> {
+    if date.is_none() {
+        return Ok(default);
+    }
+    let date = date.as_ref().unwrap();
+    DateTime::parse_from_rfc3339(date)
+        .map(|date| Some(date.with_timezone(&Utc)))
+        .map_err(|_| BecoError {
+            message: format!("Invalid date, expected RFC 3339: {date}"),
+            status: Code::InvalidArgument,
+        })
+}
@@ -0,0 +1 @@
+mod organisation;
\ No newline at end of file
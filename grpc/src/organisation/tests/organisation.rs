@@ -0,0 +1,123 @@
+#![cfg(all(test, feature = "sst"))]
+
+use chrono::{Duration, TimeZone, Utc};
+use tonic::Code;
+
+use crate::{
+    enums::oragnisaton_relation::OrganisationRelation,
+    organisation::organisation::{parse_date, Organisation},
+};
+
+fn organisation() -> Organisation {
+    Organisation::new("Beco".into(), OrganisationRelation::EMPLOYER, "admin".into()).unwrap()
+}
+
+#[test]
+fn new_rejects_blank_name() {
+    let result = Organisation::new("  ".into(), OrganisationRelation::EMPLOYER, "admin".into());
+    assert_eq!(result.unwrap_err().status, Code::InvalidArgument);
+}
+
+#[test]
+fn admin_can_add_and_end_membership() {
+    let mut organisation = organisation();
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    organisation
+        .add_member("member".into(), start, None, &"admin".into())
+        .unwrap();
+    assert!(organisation.user_organisations(None)[0].is_active(&Utc::now()));
+
+    let end = start + Duration::days(30);
+    organisation
+        .end_membership(&"member".into(), end, &"admin".into())
+        .unwrap();
+    let memberships = organisation.user_organisations(Some(&"member".into()));
+    assert_eq!(memberships[0].end_date, Some(end));
+    assert!(!memberships[0].is_active(&Utc::now()));
+}
+
+#[test]
+fn non_admin_cannot_add_member() {
+    let mut organisation = organisation();
+    let result = organisation.add_member("member".into(), Utc::now(), None, &"member".into());
+    assert_eq!(result.unwrap_err().status, Code::PermissionDenied);
+    assert!(organisation.memberships().is_empty());
+}
+
+#[test]
+fn member_can_end_own_membership_but_not_others() {
+    let mut organisation = organisation();
+    let start = Utc::now() - Duration::days(1);
+    organisation
+        .add_member("member".into(), start, None, &"admin".into())
+        .unwrap();
+    organisation
+        .add_member("other".into(), start, None, &"admin".into())
+        .unwrap();
+
+    let result = organisation.end_membership(&"other".into(), Utc::now(), &"member".into());
+    assert_eq!(result.unwrap_err().status, Code::PermissionDenied);
+    organisation
+        .end_membership(&"member".into(), Utc::now(), &"member".into())
+        .unwrap();
+}
+
+#[test]
+fn overlapping_membership_is_rejected() {
+    let mut organisation = organisation();
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let end = start + Duration::days(30);
+    organisation
+        .add_member("member".into(), start, Some(end), &"admin".into())
+        .unwrap();
+
+    let result = organisation.add_member(
+        "member".into(),
+        start + Duration::days(10),
+        None,
+        &"admin".into(),
+    );
+    assert_eq!(result.unwrap_err().status, Code::AlreadyExists);
+    organisation
+        .add_member("member".into(), end, None, &"admin".into())
+        .unwrap();
+    assert_eq!(organisation.memberships().len(), 2);
+}
+
+#[test]
+fn end_date_must_follow_start_date() {
+    let mut organisation = organisation();
+    let start = Utc::now();
+    let result = organisation.add_member("member".into(), start, Some(start), &"admin".into());
+    assert_eq!(result.unwrap_err().status, Code::InvalidArgument);
+}
+
+#[test]
+fn non_admin_response_only_shows_own_membership() {
+    let mut organisation = organisation();
+    organisation
+        .add_member("member".into(), Utc::now(), None, &"admin".into())
+        .unwrap();
+    organisation
+        .add_member("other".into(), Utc::now(), None, &"admin".into())
+        .unwrap();
+
+    assert_eq!(organisation.as_response(&"admin".into()).memberships.len(), 2);
+    let response = organisation.as_response(&"member".into());
+    assert_eq!(response.memberships.len(), 1);
+    assert_eq!(response.memberships[0].user_id, "member");
+}
+
+#[test]
+fn parse_date_falls_back_to_default() {
+    let default = Utc::now();
+    assert_eq!(parse_date(&None, Some(default)).unwrap(), Some(default));
+    assert_eq!(
+        parse_date(&Some("2024-01-01T00:00:00Z".into()), None).unwrap(),
+        Some(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap())
+    );
+    assert_eq!(
+        parse_date(&Some("yesterday".into()), None).unwrap_err().status,
+        Code::InvalidArgument
+    );
+}
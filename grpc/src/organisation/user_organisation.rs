@@ -2,13 +2,36 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 // use uuid::Uuid;
 
-use crate::enums::oragnisaton_relation::OrganisationRelation;
+use crate::{enums::oragnisaton_relation::OrganisationRelation, proto::beco::MembershipResponse};
 
+// a membership as seen from the user's side
 #[cfg(any(feature = "validator", feature = "sst", feature = "user"))]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq)]
 pub struct UserOrganisation {
     pub id: String,
+    pub name: String,
+    pub user_id: String,
     pub relation: OrganisationRelation,
     pub start_date: DateTime<Utc>,
     pub end_date: Option<DateTime<Utc>>,
 }
+
+impl UserOrganisation {
+    pub fn is_active(&self, at: &DateTime<Utc>) -> bool {
+        &self.start_date <= at && self.end_date.is_none_or(|end_date| at < &end_date)
+    }
+}
+
+impl From<UserOrganisation> for MembershipResponse {
+    fn from(val: UserOrganisation) -> Self {
+        MembershipResponse {
+            active: val.is_active(&Utc::now()),
+            organisation_id: val.id,
+            organisation_name: val.name,
+            relation: val.relation.into(),
+            user_id: val.user_id,
+            start_date: val.start_date.to_rfc3339(),
+            end_date: val.end_date.map(|end_date| end_date.to_rfc3339()),
+        }
+    }
+}
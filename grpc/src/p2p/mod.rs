@@ -88,6 +88,7 @@ pub struct P2P {
     load_user_gossip_sub: gossipsub::IdentTopic,
     new_user_gossip_sub: gossipsub::IdentTopic,
     response_gossip_sub: gossipsub::IdentTopic,
+    committed_gossip_sub: gossipsub::IdentTopic,
     config: Config,
     rendezvous_address: Multiaddr,
 }
@@ -111,10 +112,12 @@ pub struct P2P {
     keys: identity::Keypair,
     peer_id: PeerId,
     sst: SST,
+    propose_gossip_sub: gossipsub::IdentTopic,
     validated_gossip_sub: gossipsub::IdentTopic,
     load_gossip_sub: gossipsub::IdentTopic,
     new_user_gossip_sub: gossipsub::IdentTopic,
     response_gossip_sub: gossipsub::IdentTopic,
+    committed_gossip_sub: gossipsub::IdentTopic,
     config: Config,
     rendezvous_address: Multiaddr,
 }
@@ -5,7 +5,7 @@ use std::time::Duration;
 
 use envconfig::Envconfig;
 
-use crate::enums::data_value::{DataRequestType, DataRequests, ProcessRequest};
+use crate::enums::data_value::{Commit, DataRequestType, DataRequests, ProcessRequest};
 
 use crate::p2p::USER_NAMESPACE;
 
@@ -25,16 +25,18 @@ use libp2p::gossipsub::IdentTopic;
 
 impl P2P {
     #[cfg(feature = "sst")]
-    pub fn new() -> Self {
+    pub fn new(sst: SST) -> Self {
         let config = Config::init_from_env().unwrap();
         let keys = identity::Keypair::generate_ed25519();
         let peer_id = PeerId::from(keys.public());
-        let sst = SST::new();
+        let propose_gossip_sub = gossipsub::IdentTopic::new(DataRequestType::PROPOSE.to_string());
         let validated_gossip_sub =
             gossipsub::IdentTopic::new(DataRequestType::VALIDATED.to_string());
         let load_gossip_sub = gossipsub::IdentTopic::new(DataRequestType::LOAD.to_string());
         let new_user_gossip_sub = gossipsub::IdentTopic::new(DataRequestType::NEW.to_string());
         let response_gossip_sub = gossipsub::IdentTopic::new(DataRequestType::RESPONSE.to_string());
+        let committed_gossip_sub =
+            gossipsub::IdentTopic::new(DataRequestType::COMMITTED.to_string());
         let rendezvous_address = config
             .rendezvous_address
             .clone()
@@ -44,10 +46,12 @@ impl P2P {
             keys,
             peer_id,
             sst,
+            propose_gossip_sub,
             validated_gossip_sub,
             load_gossip_sub,
             new_user_gossip_sub,
             response_gossip_sub,
+            committed_gossip_sub,
             config,
             rendezvous_address,
         }
@@ -75,12 +79,18 @@ impl P2P {
             .gossipsub
             .subscribe(&self.response_gossip_sub)
             .unwrap();
+        println!("Subscribing to {:?}", self.committed_gossip_sub);
+        behaviour
+            .gossipsub
+            .subscribe(&self.committed_gossip_sub)
+            .unwrap();
     }
 
     #[cfg(any(feature = "sst"))]
     pub async fn loop_swarm(&mut self) {
         let mut swarm = self.create_swarm().unwrap();
         let mut discover_tick = tokio::time::interval(Duration::from_secs(30));
+        let mut expiry_tick = tokio::time::interval(Duration::from_secs(60));
         let mut cookie = None;
         loop {
             tokio::select! {
@@ -95,6 +105,9 @@ impl P2P {
                         // )
                     }
                 }
+                _ = expiry_tick.tick() => {
+                    self.propose_due_changes(&mut swarm).await;
+                }
                 // need a mechanism to routeinly go around and check the queues process the next one if something went wrong
                 event = swarm.select_next_some() => {
                     self.process_swarm_events(event, &mut swarm, &mut cookie).await;
@@ -111,17 +124,18 @@ impl P2P {
     ) {
         let process_request: ProcessRequest =
             serde_json::from_str(&String::from_utf8_lossy(&message.data)).unwrap();
-        let result = self.sst.update(process_request.clone()).await;
         match process_request.status {
             DataRequestType::NEW => match process_request.request {
-                DataRequests::AddUser(request) => {
-                    let (user_option, user_id) = if result.is_err() {
-                        (None, "".to_string())
+                DataRequests::AddUser(_) => {
+                    let result = self.sst.update(process_request.clone()).await;
+                    // a rejection carries the reason back, e.g. a duplicate national identifier
+                    let (data_request, user_id) = if let Err(err) = result {
+                        println!("{err:?}");
+                        (DataRequests::Rejected(err.into()), "".to_string())
                     } else {
-                        let (user, _) = result.unwrap();
-                        (Some(user.clone()), user.id)
+                        let (user, _, _) = result.unwrap();
+                        (DataRequests::LoadUser(Some(user.clone())), user.id)
                     };
-                    let data_request = DataRequests::LoadUser(user_option.clone());
                     P2P::send_process_request(
                         swarm,
                         self.response_gossip_sub.clone(),
@@ -134,8 +148,104 @@ impl P2P {
                         process_request.originator_peer_id,
                     )
                 }
+                DataRequests::CreateGroup(_)
+                | DataRequests::AddGroupMember(_)
+                | DataRequests::RemoveGroupMember(_) => {
+                    let result = self.sst.update_group(process_request.clone()).await;
+                    let data_request = if let Err(err) = result {
+                        println!("{err:?}");
+                        DataRequests::Rejected(err.into())
+                    } else {
+                        DataRequests::GroupResult(result.unwrap())
+                    };
+                    P2P::send_process_request(
+                        swarm,
+                        self.response_gossip_sub.clone(),
+                        DataRequestType::RESPONSE,
+                        data_request,
+                        process_request.calling_user,
+                        process_request.user_id,
+                        Some(Utc::now()),
+                        process_request.originator_hash,
+                        process_request.originator_peer_id,
+                    )
+                }
+                // nobody waits on the record, so nothing is sent back
+                DataRequests::RecordAccess(_) => {
+                    let result = self.sst.record_access(process_request).await;
+                    if let Err(err) = result {
+                        println!("{err:?}");
+                    }
+                }
+                DataRequests::CreateOrganisation(_)
+                | DataRequests::AddMember(_)
+                | DataRequests::EndMembership(_) => {
+                    let result = self.sst.update_organisation(process_request.clone()).await;
+                    let data_request = if let Err(err) = result {
+                        println!("{err:?}");
+                        DataRequests::Rejected(err.into())
+                    } else {
+                        DataRequests::OrganisationResult(result.unwrap())
+                    };
+                    P2P::send_process_request(
+                        swarm,
+                        self.response_gossip_sub.clone(),
+                        DataRequestType::RESPONSE,
+                        data_request,
+                        process_request.calling_user,
+                        process_request.user_id,
+                        Some(Utc::now()),
+                        process_request.originator_hash,
+                        process_request.originator_peer_id,
+                    )
+                }
                 _ => {}
             },
+            DataRequestType::VALIDATED => {
+                // user nodes only apply the change once it has been stored
+                let result = self.sst.update(process_request.clone()).await;
+                let mut follow_ups = vec![];
+                let (status, data_request) = if let Err(err) = result {
+                    println!("{err:?}");
+                    (DataRequestType::FAILED, process_request.request)
+                } else {
+                    follow_ups = self.sst.linked_user_follow_ups(&process_request).await;
+                    let (user, calling_user, merkle_root) = result.unwrap();
+                    let commit = Commit {
+                        request: Box::new(process_request.request),
+                        sequence: user.sequence(),
+                        merkle_root,
+                        calling_user_groups: calling_user.groups,
+                        calling_user_memberships: calling_user.memberships,
+                    };
+                    (DataRequestType::COMMITTED, DataRequests::Committed(commit))
+                };
+                P2P::send_process_request(
+                    swarm,
+                    self.committed_gossip_sub.clone(),
+                    status,
+                    data_request,
+                    process_request.calling_user,
+                    process_request.user_id,
+                    process_request.datetime,
+                    process_request.originator_hash,
+                    process_request.originator_peer_id,
+                );
+                // proposed like any other change so validators check each side of the link
+                for (data_request, calling_user, user_id) in follow_ups {
+                    P2P::send_process_request(
+                        swarm,
+                        self.propose_gossip_sub.clone(),
+                        DataRequestType::PROPOSE,
+                        data_request,
+                        calling_user,
+                        user_id,
+                        None,
+                        None,
+                        None,
+                    )
+                }
+            }
             DataRequestType::FETCH => match process_request.request {
                 DataRequests::FetchUser(request) => {
                     let user = self.sst.fetch_user(&request.user_id).await;
@@ -158,12 +268,178 @@ impl P2P {
                         process_request.originator_peer_id,
                     )
                 }
+                DataRequests::UserDiff(request) => {
+                    let user_id = request.user_id.clone();
+                    let changes_result = self.sst.user_diff(request).await;
+                    if let Err(err) = &changes_result {
+                        println!("{err:?}");
+                    }
+
+                    let data_request = DataRequests::UserDiffResult(changes_result.ok());
+                    P2P::send_process_request(
+                        swarm,
+                        self.response_gossip_sub.clone(),
+                        DataRequestType::RESPONSE,
+                        data_request,
+                        process_request.calling_user,
+                        user_id,
+                        Some(Utc::now()),
+                        process_request.originator_hash,
+                        process_request.originator_peer_id,
+                    )
+                }
+                DataRequests::FetchOrganisation(organisation_id) => {
+                    let result = self.sst.fetch_organisation(&organisation_id).await;
+                    let data_request = if let Err(err) = result {
+                        println!("{err:?}");
+                        DataRequests::Rejected(err.into())
+                    } else {
+                        DataRequests::OrganisationResult(result.unwrap())
+                    };
+                    P2P::send_process_request(
+                        swarm,
+                        self.response_gossip_sub.clone(),
+                        DataRequestType::RESPONSE,
+                        data_request,
+                        process_request.calling_user,
+                        process_request.user_id,
+                        Some(Utc::now()),
+                        process_request.originator_hash,
+                        process_request.originator_peer_id,
+                    )
+                }
+                DataRequests::FetchGroups(user_id) => {
+                    let result = self.sst.group_ids_for_member(&user_id).await;
+                    let data_request = if let Err(err) = result {
+                        println!("{err:?}");
+                        DataRequests::Rejected(err.into())
+                    } else {
+                        DataRequests::GroupsResult(result.unwrap())
+                    };
+                    P2P::send_process_request(
+                        swarm,
+                        self.response_gossip_sub.clone(),
+                        DataRequestType::RESPONSE,
+                        data_request,
+                        process_request.calling_user,
+                        user_id,
+                        Some(Utc::now()),
+                        process_request.originator_hash,
+                        process_request.originator_peer_id,
+                    )
+                }
+                DataRequests::SignCredential(request) => {
+                    let result = self
+                        .sst
+                        .sign_credential(request, &process_request.calling_user)
+                        .await;
+                    let data_request = if let Err(err) = result {
+                        println!("{err:?}");
+                        DataRequests::Rejected(err.into())
+                    } else {
+                        DataRequests::CredentialResult(result.unwrap())
+                    };
+                    P2P::send_process_request(
+                        swarm,
+                        self.response_gossip_sub.clone(),
+                        DataRequestType::RESPONSE,
+                        data_request,
+                        process_request.calling_user,
+                        process_request.user_id,
+                        Some(Utc::now()),
+                        process_request.originator_hash,
+                        process_request.originator_peer_id,
+                    )
+                }
+                DataRequests::VerifyCredential(jwt) => {
+                    let result = self.sst.verify_credential(&jwt, &Utc::now()).await;
+                    let data_request = if let Err(err) = result {
+                        println!("{err:?}");
+                        DataRequests::Rejected(err.into())
+                    } else {
+                        DataRequests::VerifiedCredentialResult(result.unwrap())
+                    };
+                    P2P::send_process_request(
+                        swarm,
+                        self.response_gossip_sub.clone(),
+                        DataRequestType::RESPONSE,
+                        data_request,
+                        process_request.calling_user,
+                        process_request.user_id,
+                        Some(Utc::now()),
+                        process_request.originator_hash,
+                        process_request.originator_peer_id,
+                    )
+                }
+                DataRequests::ListAccessLog(request) => {
+                    let result = self.sst.access_log(request).await;
+                    let data_request = if let Err(err) = result {
+                        println!("{err:?}");
+                        DataRequests::Rejected(err.into())
+                    } else {
+                        DataRequests::AccessLogResult(result.unwrap())
+                    };
+                    P2P::send_process_request(
+                        swarm,
+                        self.response_gossip_sub.clone(),
+                        DataRequestType::RESPONSE,
+                        data_request,
+                        process_request.calling_user,
+                        process_request.user_id,
+                        Some(Utc::now()),
+                        process_request.originator_hash,
+                        process_request.originator_peer_id,
+                    )
+                }
+                DataRequests::ListMemberships(request) => {
+                    let result = self.sst.list_memberships(request).await;
+                    let data_request = if let Err(err) = result {
+                        println!("{err:?}");
+                        DataRequests::Rejected(err.into())
+                    } else {
+                        DataRequests::MembershipsResult(result.unwrap())
+                    };
+                    P2P::send_process_request(
+                        swarm,
+                        self.response_gossip_sub.clone(),
+                        DataRequestType::RESPONSE,
+                        data_request,
+                        process_request.calling_user,
+                        process_request.user_id,
+                        Some(Utc::now()),
+                        process_request.originator_hash,
+                        process_request.originator_peer_id,
+                    )
+                }
                 _ => {}
             },
             _ => {}
         };
     }
 
+    // the sst only proposes, validators stamp the time each deadline is checked against
+    #[cfg(feature = "sst")]
+    async fn propose_due_changes(&self, swarm: &mut Swarm<BecoBehaviour>) {
+        let changes_result = self.sst.due_changes(&Utc::now()).await;
+        if let Err(err) = changes_result {
+            println!("{err:?}");
+            return;
+        }
+        for (change, calling_user, user_id) in changes_result.unwrap() {
+            P2P::send_process_request(
+                swarm,
+                self.propose_gossip_sub.clone(),
+                DataRequestType::PROPOSE,
+                change,
+                calling_user,
+                user_id,
+                None,
+                None,
+                None,
+            )
+        }
+    }
+
     #[cfg(feature = "sst")]
     fn send_process_request(
         swarm: &mut Swarm<BecoBehaviour>,
@@ -186,7 +462,7 @@ impl P2P {
             calling_user: calling_user,
             user_id,
             hash,
-            datetime: Some(Utc::now()),
+            datetime,
             connected_peers: 0,
             originator_hash: originator_hash,
             originator_peer_id: originator_peer_id,
@@ -33,6 +33,8 @@ impl P2P {
         let load_user_gossip_sub = gossipsub::IdentTopic::new(DataRequestType::LOAD.to_string());
         let new_user_gossip_sub = gossipsub::IdentTopic::new(DataRequestType::NEW.to_string());
         let response_gossip_sub = gossipsub::IdentTopic::new(DataRequestType::RESPONSE.to_string());
+        let committed_gossip_sub =
+            gossipsub::IdentTopic::new(DataRequestType::COMMITTED.to_string());
         let rendezvous_address = config
             .rendezvous_address
             .clone()
@@ -49,6 +51,7 @@ impl P2P {
             load_user_gossip_sub,
             new_user_gossip_sub,
             response_gossip_sub,
+            committed_gossip_sub,
             config,
             rendezvous_address,
         }
@@ -85,6 +88,11 @@ impl P2P {
             .gossipsub
             .subscribe(&self.response_gossip_sub)
             .unwrap();
+        println!("Subscribing to {:?}", self.committed_gossip_sub);
+        behaviour
+            .gossipsub
+            .subscribe(&self.committed_gossip_sub)
+            .unwrap();
     }
 
     pub async fn loop_swarm(&mut self) {
@@ -115,6 +123,7 @@ impl P2P {
                         DataRequestType::NEW => { &self.new_user_gossip_sub},
                         DataRequestType::RESPONSE => { &self.response_gossip_sub},
                         DataRequestType::FETCH => { &self.load_user_gossip_sub},
+                        DataRequestType::COMMITTED => { &self.committed_gossip_sub},
                     };
                     if let Err(e) = swarm
                         .behaviour_mut()
@@ -155,26 +164,37 @@ impl P2P {
             DataRequestType::CORROBORATE => {
                 let response = self.entry.corroborate(&mut process_request).await;
             }
-            DataRequestType::VALIDATED => {
-                let hash = calculate_hash(&process_request.request);
-                let response = self
-                    .entry
-                    .update(
-                        process_request.request,
-                        process_request.calling_user,
-                        process_request.user_id.clone(),
-                    )
-                    .await;
-                if response.is_ok() {
+            DataRequestType::COMMITTED => {
+                // validated requests are only applied once the sst has stored them
+                if let DataRequests::Committed(commit) = process_request.request {
+                    let hash = calculate_hash(&commit.request);
                     self.entry
-                        .success_event(hash, Some(process_request.user_id), process_request.status)
+                        .set_groups(&process_request.calling_user, commit.calling_user_groups)
                         .await;
-                    self.entry.ping_event(&hash).await;
-                } else {
                     self.entry
-                        .fail_event(hash, Some(process_request.user_id))
+                        .set_memberships(&process_request.calling_user, commit.calling_user_memberships)
+                        .await;
+                    let response = self
+                        .entry
+                        .apply_commit(
+                            *commit.request,
+                            process_request.calling_user,
+                            process_request.user_id.clone(),
+                            commit.sequence,
+                            process_request.datetime,
+                        )
                         .await;
-                    self.entry.ping_event(&hash).await;
+                    if response.is_ok() {
+                        self.entry
+                            .success_event(hash, Some(process_request.user_id), process_request.status)
+                            .await;
+                        self.entry.ping_event(&hash).await;
+                    } else {
+                        self.entry
+                            .fail_event(hash, Some(process_request.user_id))
+                            .await;
+                        self.entry.ping_event(&hash).await;
+                    }
                 }
             }
             DataRequestType::FAILED | DataRequestType::NOTFOUND => {
@@ -209,6 +229,29 @@ impl P2P {
                             .await;
                         self.entry.ping_event(&hash).await;
                     }
+                    DataRequests::UserDiffResult(changes_option) => {
+                        if changes_option.is_none() {
+                            self.entry.fail_event(hash, None).await;
+                        } else {
+                            self.entry
+                                .response_event(hash, DataRequests::UserDiffResult(changes_option))
+                                .await;
+                        }
+                        self.entry.ping_event(&hash).await;
+                    }
+                    DataRequests::Rejected(_)
+                    | DataRequests::OrganisationResult(_)
+                    | DataRequests::MembershipsResult(_)
+                    | DataRequests::GroupResult(_)
+                    | DataRequests::GroupsResult(_)
+                    | DataRequests::AccessLogResult(_)
+                    | DataRequests::CredentialResult(_)
+                    | DataRequests::VerifiedCredentialResult(_) => {
+                        self.entry
+                            .response_event(hash, process_request.request)
+                            .await;
+                        self.entry.ping_event(&hash).await;
+                    }
                     _ => {}
                 }
             }
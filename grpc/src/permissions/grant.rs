@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    errors::BecoError,
+    permissions::model::GrantRole,
+    proto::beco::{GroupAccessRequest, RevokeGrantRequest},
+    requests::user_request::{field_references, FieldReference},
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct ExpiredGrant {
+    pub field: FieldReference,
+    pub grantee_id: String,
+    pub role: GrantRole,
+}
+
+// emitted by the sst sweeper and validated like any other change, so a lapsed grant is also
+// removed from the persisted history
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct RevokeExpiredGrants {
+    pub user_id: String,
+    pub grants: Vec<ExpiredGrant>,
+}
+
+// the validated form of GroupAccessRequest, used to grant and to revoke
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct GroupAccess {
+    pub user_id: String,
+    pub calling_user: String,
+    pub group_id: String,
+    pub fields: Vec<FieldReference>,
+    pub role: GrantRole,
+    pub expected_sequence: Option<u64>,
+}
+
+impl TryFrom<GroupAccessRequest> for GroupAccess {
+    type Error = BecoError;
+
+    fn try_from(request: GroupAccessRequest) -> Result<Self, Self::Error> {
+        let role_result = GrantRole::try_from(request.role);
+        if let Err(err) = role_result {
+            return Err(err);
+        }
+        let fields_result = field_references(request.fields);
+        if let Err(err) = fields_result {
+            return Err(err);
+        }
+        Ok(Self {
+            user_id: request.user_id,
+            calling_user: request.calling_user,
+            group_id: request.group_id,
+            fields: fields_result.unwrap(),
+            role: role_result.unwrap(),
+            expected_sequence: request.expected_sequence,
+        })
+    }
+}
+
+// the validated form of RevokeGrantRequest
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct RevokeGrant {
+    pub user_id: String,
+    pub calling_user: String,
+    pub grantee_id: String,
+    pub fields: Vec<FieldReference>,
+    pub role: GrantRole,
+    pub expected_sequence: Option<u64>,
+}
+
+impl TryFrom<RevokeGrantRequest> for RevokeGrant {
+    type Error = BecoError;
+
+    fn try_from(request: RevokeGrantRequest) -> Result<Self, Self::Error> {
+        let role_result = GrantRole::try_from(request.role);
+        if let Err(err) = role_result {
+            return Err(err);
+        }
+        let fields_result = field_references(request.fields);
+        if let Err(err) = fields_result {
+            return Err(err);
+        }
+        Ok(Self {
+            user_id: request.user_id,
+            calling_user: request.calling_user,
+            grantee_id: request.grantee_id,
+            fields: fields_result.unwrap(),
+            role: role_result.unwrap(),
+            expected_sequence: request.expected_sequence,
+        })
+    }
+}
@@ -1,3 +1,6 @@
+pub mod grant;
 pub mod model;
+pub mod policy;
+pub mod visibility;
 
 mod tests;
\ No newline at end of file
@@ -1,11 +1,15 @@
-use std::fmt::Debug;
+use std::{collections::BTreeMap, fmt::Debug};
 
-use serde::{Serialize, Deserialize};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
 use tonic::Code;
 use std::hash::Hash;
 
 use crate::{
-    enums::value_reference::ValueReference, errors::BecoError, user::public_user::PublicUser,
+    enums::value_reference::ValueReference, errors::BecoError,
+    permissions::{policy::AccessPolicy, visibility::Visibility},
+    proto::beco::GrantRole as ProtoGrantRole,
+    user::public_user::PublicUser,
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,11 +18,51 @@ where
     T: Clone + Debug + Hash,
 {
     owner_id: String,
-    editors: Vec<PublicUser>,
-    viewers: Vec<PublicUser>,
+    // grantees are kept by id, a copy of their details would go stale and leak into this record
+    #[serde(deserialize_with = "deserialize_grantees")]
+    editors: Vec<String>,
+    #[serde(deserialize_with = "deserialize_grantees")]
+    viewers: Vec<String>,
+    #[serde(default)]
+    editor_groups: Vec<String>,
+    #[serde(default)]
+    viewer_groups: Vec<String>,
     value: T,
     key: String,
     reference: ValueReference,
+    // grants without an entry never expire, keyed by the grantee's id
+    #[serde(default)]
+    viewer_expiries: BTreeMap<String, DateTime<Utc>>,
+    #[serde(default)]
+    editor_expiries: BTreeMap<String, DateTime<Utc>>,
+    // grant a role to callers matching a rule instead of by id
+    #[serde(default)]
+    policies: Vec<AccessPolicy>,
+    // fields stored before visibility existed are shared
+    #[serde(default)]
+    visibility: Visibility,
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub enum GrantRole {
+    VIEWER,
+    EDITOR,
+}
+
+impl TryFrom<i32> for GrantRole {
+    type Error = BecoError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match ProtoGrantRole::try_from(value) {
+            Ok(ProtoGrantRole::Viewer) => Ok(GrantRole::VIEWER),
+            Ok(ProtoGrantRole::Editor) => Ok(GrantRole::EDITOR),
+            _ => Err(BecoError {
+                message: format!("Invalid grant role: {value}"),
+                status: Code::InvalidArgument,
+            }),
+        }
+    }
 }
 
 impl<T> Hash for PermissionModel<T>
@@ -28,9 +72,15 @@ where
         self.owner_id.hash(state);
         self.editors.hash(state);
         self.viewers.hash(state);
+        self.editor_groups.hash(state);
+        self.viewer_groups.hash(state);
         self.value.hash(state);
         self.key.hash(state);
         self.reference.hash(state);
+        self.viewer_expiries.hash(state);
+        self.editor_expiries.hash(state);
+        self.policies.hash(state);
+        self.visibility.hash(state);
     }
 }
 
@@ -43,15 +93,24 @@ where
             owner_id,
             editors: vec![],
             viewers: vec![],
+            editor_groups: vec![],
+            viewer_groups: vec![],
             value,
             key,
             reference,
+            viewer_expiries: BTreeMap::new(),
+            editor_expiries: BTreeMap::new(),
+            policies: vec![],
+            visibility: Visibility::SHARED,
         }
     }
+    // reads are not validated, so grants are checked against the local time
     pub fn value(&self, user: &PublicUser) -> Result<T, BecoError> {
-        if !PermissionModel::is_owner_or_editor(&self, user)
-            && !PermissionModel::is_owner_or_viewer(&self, user)
-        {
+        self.value_at(user, &Utc::now())
+    }
+
+    pub fn value_at(&self, user: &PublicUser, at: &DateTime<Utc>) -> Result<T, BecoError> {
+        if !self.can_view(user, at) {
             return Err(BecoError {
                 message: format!(
                     "User does not have permission to view this value: {}",
@@ -63,8 +122,23 @@ where
         Ok(self.value.clone())
     }
 
-    pub fn value_mut(&mut self, user: &PublicUser) -> Result<&mut T, BecoError> {
-        if !PermissionModel::is_owner_or_editor(&self, user) {
+    pub fn can_view(&self, user: &PublicUser, at: &DateTime<Utc>) -> bool {
+        match self.visibility {
+            Visibility::PUBLIC => true,
+            Visibility::SHARED => {
+                PermissionModel::is_owner_or_editor(self, user, at)
+                    || PermissionModel::is_owner_or_viewer(self, user, at)
+            }
+            Visibility::PRIVATE => PermissionModel::is_owner_or_editor(self, user, at),
+        }
+    }
+
+    pub fn value_mut(
+        &mut self,
+        user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<&mut T, BecoError> {
+        if !PermissionModel::is_owner_or_editor(self, user, at) {
             return Err(BecoError {
                 message: format!(
                     "User does not have permission to view this mut value: {}",
@@ -84,8 +158,14 @@ where
         Ok(())
     }
 
-    pub async fn update(&mut self, value: T, calling_user: &PublicUser) -> Result<(), BecoError> {
-        if !PermissionModel::is_owner_or_editor(&self, calling_user) {
+    // at is the time the validator assigned to the request
+    pub async fn update(
+        &mut self,
+        value: T,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        if !PermissionModel::is_owner_or_editor(self, calling_user, at) {
             return Err(BecoError {
                 message: format!(
                     "User does not have permission to update this value: {}",
@@ -100,127 +180,398 @@ where
 
     pub fn add_viewer(
         &mut self,
-        user: PublicUser,
+        user_id: String,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        self.add_viewer_until(user_id, calling_user, None, at)
+    }
+
+    pub fn add_viewer_until(
+        &mut self,
+        user_id: String,
         calling_user: &PublicUser,
+        expires: Option<DateTime<Utc>>,
+        at: &DateTime<Utc>,
     ) -> Result<(), BecoError> {
-        if !PermissionModel::is_owner_or_editor(&self, calling_user) {
+        if !PermissionModel::is_owner_or_editor(self, calling_user, at) {
             return Err(BecoError {
                 message: "User does not have permission to add a viewer".into(),
                 status: Code::PermissionDenied,
             });
         }
-        if self.owner_id == user.id {
+        if self.owner_id == user_id {
             return Err(BecoError {
                 message: "User is owner".into(),
                 status: Code::AlreadyExists,
             });
         }
-        if self.viewers.iter().find(|&usr| usr == &user).is_some() {
+        if self.viewers.contains(&user_id) {
             return Err(BecoError {
                 message: "User already has permission to view this value".into(),
                 status: Code::AlreadyExists,
             });
         }
-        self.viewers.push(user);
+        if let Some(expires) = self.capped_expiry(calling_user, expires) {
+            self.viewer_expiries.insert(user_id.clone(), expires);
+        }
+        self.viewers.push(user_id);
         Ok(())
     }
 
     pub fn add_editor(
         &mut self,
-        user: PublicUser,
+        user_id: String,
         calling_user: &PublicUser,
+        at: &DateTime<Utc>,
     ) -> Result<(), BecoError> {
-        if !PermissionModel::is_owner_or_editor(&self, calling_user) {
+        self.add_editor_until(user_id, calling_user, None, at)
+    }
+
+    pub fn add_editor_until(
+        &mut self,
+        user_id: String,
+        calling_user: &PublicUser,
+        expires: Option<DateTime<Utc>>,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        if !PermissionModel::is_owner_or_editor(self, calling_user, at) {
             return Err(BecoError {
                 message: "User does not have permission to add an editor".into(),
                 status: Code::PermissionDenied,
             });
         }
-        if self.owner_id == user.id {
+        if self.owner_id == user_id {
             return Err(BecoError {
                 message: "User is owner".into(),
                 status: Code::AlreadyExists,
             });
         }
-        if self.editors.iter().find(|&usr| usr == &user).is_some() {
+        if self.editors.contains(&user_id) {
             return Err(BecoError {
                 message: "User already has permission to edit this value".into(),
                 status: Code::AlreadyExists,
             });
         }
-        self.editors.push(user);
+        if let Some(expires) = self.capped_expiry(calling_user, expires) {
+            self.editor_expiries.insert(user_id.clone(), expires);
+        }
+        self.editors.push(user_id);
         Ok(())
     }
 
     pub fn remove_viewer(
         &mut self,
-        user: PublicUser,
+        user_id: String,
         calling_user: &PublicUser,
+        at: &DateTime<Utc>,
     ) -> Result<(), BecoError> {
-        if !PermissionModel::is_owner_or_editor(&self, calling_user) {
+        if !PermissionModel::is_owner_or_editor(self, calling_user, at) {
             return Err(BecoError {
                 message: "User does not have permission to remove a viewer".into(),
                 status: Code::PermissionDenied,
             });
         }
-        if self.owner_id == user.id {
+        if self.owner_id == user_id {
             return Err(BecoError {
                 message: "Cannot remove owner".into(),
                 status: Code::PermissionDenied,
             });
         }
-        if !self.viewers.iter().any(|usr| usr == &user) {
+        if !self.viewers.contains(&user_id) {
             return Err(BecoError {
                 message: "User is not a viewer".into(),
                 status: Code::NotFound,
             });
         }
-        self.viewers = self
-            .viewers
-            .iter()
-            .cloned()
-            .filter(|usr| usr != &user)
-            .collect();
+        self.viewers.retain(|id| id != &user_id);
+        self.viewer_expiries.remove(&user_id);
         Ok(())
     }
 
     pub fn remove_editor(
         &mut self,
-        user: PublicUser,
+        user_id: String,
         calling_user: &PublicUser,
+        at: &DateTime<Utc>,
     ) -> Result<(), BecoError> {
-        if !PermissionModel::is_owner_or_editor(&self, calling_user) {
+        if !PermissionModel::is_owner_or_editor(self, calling_user, at) {
             return Err(BecoError {
                 message: "User does not have permission to remove an editor".into(),
                 status: Code::PermissionDenied,
             });
         }
-        if self.owner_id == user.id {
+        if self.owner_id == user_id {
             return Err(BecoError {
                 message: "Cannot remove owner".into(),
                 status: Code::PermissionDenied,
             });
         }
-        if !self.editors.iter().any(|usr| usr == &user) {
+        if !self.editors.contains(&user_id) {
             return Err(BecoError {
                 message: "User is not an editor".into(),
                 status: Code::NotFound,
             });
         }
-        self.editors = self
-            .editors
-            .iter()
-            .cloned()
-            .filter(|usr| usr != &user)
-            .collect();
+        self.editors.retain(|id| id != &user_id);
+        self.editor_expiries.remove(&user_id);
         Ok(())
     }
 
-    pub fn is_owner_or_editor(this: &Self, user: &PublicUser) -> bool {
-        user.id == this.owner_id || this.editors.iter().find(|&usr| usr == user).is_some()
+    // takes a role away before its grant expires
+    pub fn revoke(
+        &mut self,
+        user_id: &str,
+        role: GrantRole,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        match role {
+            GrantRole::VIEWER => self.remove_viewer(user_id.to_string(), calling_user, at),
+            GrantRole::EDITOR => self.remove_editor(user_id.to_string(), calling_user, at),
+        }
+    }
+
+    // an editor whose own grant expires, such as a guardian, cannot hand out rights that outlast it
+    fn capped_expiry(
+        &self,
+        calling_user: &PublicUser,
+        expires: Option<DateTime<Utc>>,
+    ) -> Option<DateTime<Utc>> {
+        if calling_user.id == self.owner_id {
+            return expires;
+        }
+        match self.editor_expiries.get(&calling_user.id) {
+            Some(limit) => Some(expires.map_or(*limit, |expires| expires.min(*limit))),
+            None => expires,
+        }
+    }
+
+    // a grant lapses at its expiry, at is the validator time for anything going through consensus
+    pub fn is_owner_or_editor(this: &Self, user: &PublicUser, at: &DateTime<Utc>) -> bool {
+        user.id == this.owner_id
+            || (this.editors.contains(&user.id)
+                && !has_lapsed(&this.editor_expiries, &user.id, at))
+            || this.editor_groups.iter().any(|group_id| user.groups.contains(group_id))
+            || this.policies.iter().any(|policy| policy.grants(GrantRole::EDITOR, user, at))
     }
 
-    pub fn is_owner_or_viewer(this: &Self, user: &PublicUser) -> bool {
-        user.id == this.owner_id || this.viewers.iter().find(|&usr| usr == user).is_some()
+    pub fn is_owner_or_viewer(this: &Self, user: &PublicUser, at: &DateTime<Utc>) -> bool {
+        user.id == this.owner_id
+            || (this.viewers.contains(&user.id)
+                && !has_lapsed(&this.viewer_expiries, &user.id, at))
+            // an approved access request grants the organisation, which reaches its members
+            || user.memberships.iter().any(|membership| {
+                membership.is_active(at)
+                    && this.viewers.contains(&membership.id)
+                    && !has_lapsed(&this.viewer_expiries, &membership.id, at)
+            })
+            || this.viewer_groups.iter().any(|group_id| user.groups.contains(group_id))
+            || this.policies.iter().any(|policy| policy.grants(GrantRole::VIEWER, user, at))
     }
+
+    pub fn owner_id(&self) -> &String {
+        &self.owner_id
+    }
+
+    pub fn policies(&self) -> &Vec<AccessPolicy> {
+        &self.policies
+    }
+
+    // a policy can reach anyone, so unlike viewers and editors only the owner sets them
+    pub fn set_policies(
+        &mut self,
+        policies: Vec<AccessPolicy>,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        if calling_user.id != self.owner_id {
+            return Err(BecoError {
+                message: "User does not have permission to set policies".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        self.policies = policies;
+        Ok(())
+    }
+
+    pub fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+
+    pub fn set_visibility(
+        &mut self,
+        visibility: Visibility,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        if calling_user.id != self.owner_id {
+            return Err(BecoError {
+                message: "User does not have permission to change the visibility".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        self.visibility = visibility;
+        Ok(())
+    }
+
+    // members are not copied, the calling user carries the groups it belongs to
+    pub fn add_group(
+        &mut self,
+        group_id: String,
+        role: GrantRole,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        if !PermissionModel::is_owner_or_editor(self, calling_user, at) {
+            return Err(BecoError {
+                message: "User does not have permission to add a group".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        // group grants do not expire, so they would outlast the editor's own
+        if calling_user.id != self.owner_id && self.editor_expiries.contains_key(&calling_user.id) {
+            return Err(BecoError {
+                message: "An editor with an expiring grant cannot add a group".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        let groups = match role {
+            GrantRole::VIEWER => &mut self.viewer_groups,
+            GrantRole::EDITOR => &mut self.editor_groups,
+        };
+        if groups.contains(&group_id) {
+            return Err(BecoError {
+                message: "Group already has this permission".into(),
+                status: Code::AlreadyExists,
+            });
+        }
+        groups.push(group_id);
+        Ok(())
+    }
+
+    // grant is false to remove the group's role instead
+    pub fn set_group(
+        &mut self,
+        group_id: &String,
+        role: GrantRole,
+        grant: bool,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        if grant {
+            self.add_group(group_id.clone(), role, calling_user, at)
+        } else {
+            self.remove_group(group_id, role, calling_user, at)
+        }
+    }
+
+    pub fn remove_group(
+        &mut self,
+        group_id: &String,
+        role: GrantRole,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        if !PermissionModel::is_owner_or_editor(self, calling_user, at) {
+            return Err(BecoError {
+                message: "User does not have permission to remove a group".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        let groups = match role {
+            GrantRole::VIEWER => &mut self.viewer_groups,
+            GrantRole::EDITOR => &mut self.editor_groups,
+        };
+        if !groups.contains(group_id) {
+            return Err(BecoError {
+                message: "Group does not have this permission".into(),
+                status: Code::NotFound,
+            });
+        }
+        groups.retain(|id| id != group_id);
+        Ok(())
+    }
+
+    pub fn expires(&self, user_id: &String, role: GrantRole) -> Option<DateTime<Utc>> {
+        match role {
+            GrantRole::VIEWER => self.viewer_expiries.get(user_id).cloned(),
+            GrantRole::EDITOR => self.editor_expiries.get(user_id).cloned(),
+        }
+    }
+
+    // the earliest time a grant lapses, the sst only sweeps users once this has passed
+    pub fn next_expiry(&self) -> Option<DateTime<Utc>> {
+        self.viewer_expiries
+            .values()
+            .chain(self.editor_expiries.values())
+            .min()
+            .cloned()
+    }
+
+    // grants that have lapsed but are still stored, the sst sweeper revokes them
+    pub fn expired_grants(&self, at: &DateTime<Utc>) -> Vec<(String, GrantRole)> {
+        let viewers = self
+            .viewer_expiries
+            .iter()
+            .filter(|(_, expires)| *expires <= at)
+            .map(|(user_id, _)| (user_id.clone(), GrantRole::VIEWER));
+        let editors = self
+            .editor_expiries
+            .iter()
+            .filter(|(_, expires)| *expires <= at)
+            .map(|(user_id, _)| (user_id.clone(), GrantRole::EDITOR));
+        viewers.chain(editors).collect()
+    }
+
+    // no calling user, the lapse itself is the authority, checked against the validator time
+    pub fn revoke_expired(
+        &mut self,
+        user_id: &String,
+        role: GrantRole,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        let (grantees, expiries) = match role {
+            GrantRole::VIEWER => (&mut self.viewers, &mut self.viewer_expiries),
+            GrantRole::EDITOR => (&mut self.editors, &mut self.editor_expiries),
+        };
+        if !has_lapsed(expiries, user_id, at) {
+            return Err(BecoError {
+                message: format!("No expired grant for {user_id} on {}", self.key),
+                status: Code::FailedPrecondition,
+            });
+        }
+        grantees.retain(|id| id != user_id);
+        expiries.remove(user_id);
+        Ok(())
+    }
+}
+
+fn has_lapsed(
+    expiries: &BTreeMap<String, DateTime<Utc>>,
+    user_id: &String,
+    at: &DateTime<Utc>,
+) -> bool {
+    expiries.get(user_id).is_some_and(|expires| expires <= at)
+}
+
+// records written before grantees were stored by id hold a copy of the grantee, only the id is
+// kept. Transactions are never rewritten, so this is still needed once the users are migrated
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredGrantee {
+    Id(String),
+    Snapshot { id: String },
+}
+
+fn deserialize_grantees<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let grantees = Vec::<StoredGrantee>::deserialize(deserializer)?;
+    Ok(grantees
+        .into_iter()
+        .map(|grantee| match grantee {
+            StoredGrantee::Id(id) => id,
+            StoredGrantee::Snapshot { id } => id,
+        })
+        .collect())
 }
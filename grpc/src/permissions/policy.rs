@@ -0,0 +1,200 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tonic::Code;
+
+use crate::{
+    enums::oragnisaton_relation::OrganisationRelation,
+    errors::BecoError,
+    organisation::organisation::parse_date,
+    permissions::model::GrantRole,
+    proto::beco::{
+        policy_condition::Condition, AccessPolicy as AccessPolicyMessage,
+        PolicyCondition as PolicyConditionMessage, SetFieldPolicyRequest,
+    },
+    requests::user_request::FieldReference,
+    user::public_user::PublicUser,
+};
+
+// keeps a stored policy cheap to evaluate on every read
+const MAX_DEPTH: usize = 4;
+const MAX_CONDITIONS: usize = 32;
+const MAX_POLICIES: usize = 16;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq)]
+pub enum PolicyCondition {
+    MemberOf(String),
+    MemberOfRelation(OrganisationRelation),
+    All(Vec<PolicyCondition>),
+    Any(Vec<PolicyCondition>),
+    NotBefore(DateTime<Utc>),
+    NotAfter(DateTime<Utc>),
+}
+
+impl PolicyCondition {
+    // memberships are only set on a calling user, at is the time the request is checked against
+    pub fn matches(&self, user: &PublicUser, at: &DateTime<Utc>) -> bool {
+        match self {
+            PolicyCondition::MemberOf(organisation_id) => user
+                .memberships
+                .iter()
+                .any(|membership| &membership.id == organisation_id && membership.is_active(at)),
+            PolicyCondition::MemberOfRelation(relation) => user
+                .memberships
+                .iter()
+                .any(|membership| &membership.relation == relation && membership.is_active(at)),
+            PolicyCondition::All(conditions) => {
+                conditions.iter().all(|condition| condition.matches(user, at))
+            }
+            PolicyCondition::Any(conditions) => {
+                conditions.iter().any(|condition| condition.matches(user, at))
+            }
+            PolicyCondition::NotBefore(not_before) => at >= not_before,
+            PolicyCondition::NotAfter(not_after) => at < not_after,
+        }
+    }
+
+    fn parse(
+        message: Option<PolicyConditionMessage>,
+        depth: usize,
+        count: &mut usize,
+    ) -> Result<Self, BecoError> {
+        *count += 1;
+        if depth > MAX_DEPTH || *count > MAX_CONDITIONS {
+            return Err(BecoError {
+                message: "Policy condition is too large".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        let condition_option = message.and_then(|message| message.condition);
+        if condition_option.is_none() {
+            return Err(BecoError {
+                message: "No policy condition provided".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        match condition_option.unwrap() {
+            Condition::MemberOf(organisation_id) => {
+                Ok(PolicyCondition::MemberOf(organisation_id))
+            }
+            Condition::MemberOfRelation(relation) => {
+                OrganisationRelation::try_from(relation).map(PolicyCondition::MemberOfRelation)
+            }
+            Condition::All(conditions) => {
+                PolicyCondition::parse_all(conditions.conditions, depth, count)
+                    .map(PolicyCondition::All)
+            }
+            Condition::Any(conditions) => {
+                PolicyCondition::parse_all(conditions.conditions, depth, count)
+                    .map(PolicyCondition::Any)
+            }
+            Condition::NotBefore(date) => {
+                parse_date(&Some(date), None).map(|date| PolicyCondition::NotBefore(date.unwrap()))
+            }
+            Condition::NotAfter(date) => {
+                parse_date(&Some(date), None).map(|date| PolicyCondition::NotAfter(date.unwrap()))
+            }
+        }
+    }
+
+    fn parse_all(
+        messages: Vec<PolicyConditionMessage>,
+        depth: usize,
+        count: &mut usize,
+    ) -> Result<Vec<Self>, BecoError> {
+        if messages.is_empty() {
+            return Err(BecoError {
+                message: "A policy condition group needs at least one condition".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        let mut conditions = vec![];
+        for message in messages {
+            let condition_result = PolicyCondition::parse(Some(message), depth + 1, count);
+            if let Err(err) = condition_result {
+                return Err(err);
+            }
+            conditions.push(condition_result.unwrap());
+        }
+        Ok(conditions)
+    }
+}
+
+// grants the role to any calling user matching the condition
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq)]
+pub struct AccessPolicy {
+    pub role: GrantRole,
+    pub condition: PolicyCondition,
+}
+
+impl AccessPolicy {
+    pub fn grants(&self, role: GrantRole, user: &PublicUser, at: &DateTime<Utc>) -> bool {
+        self.role == role && self.condition.matches(user, at)
+    }
+}
+
+impl TryFrom<AccessPolicyMessage> for AccessPolicy {
+    type Error = BecoError;
+
+    fn try_from(message: AccessPolicyMessage) -> Result<Self, Self::Error> {
+        let role_result = GrantRole::try_from(message.role);
+        if let Err(err) = role_result {
+            return Err(err);
+        }
+        let condition_result = PolicyCondition::parse(message.condition, 0, &mut 0);
+        if let Err(err) = condition_result {
+            return Err(err);
+        }
+        Ok(Self {
+            role: role_result.unwrap(),
+            condition: condition_result.unwrap(),
+        })
+    }
+}
+
+// the validated form of SetFieldPolicyRequest, an empty list removes every policy from the field
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq)]
+pub struct SetFieldPolicy {
+    pub user_id: String,
+    pub calling_user: String,
+    pub field: FieldReference,
+    pub policies: Vec<AccessPolicy>,
+    pub expected_sequence: Option<u64>,
+}
+
+impl TryFrom<SetFieldPolicyRequest> for SetFieldPolicy {
+    type Error = BecoError;
+
+    fn try_from(request: SetFieldPolicyRequest) -> Result<Self, Self::Error> {
+        if request.field.is_none() {
+            return Err(BecoError {
+                message: "No field provided".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        let field_result = FieldReference::try_from(request.field.unwrap());
+        if let Err(err) = field_result {
+            return Err(err);
+        }
+        if request.policies.len() > MAX_POLICIES {
+            return Err(BecoError {
+                message: format!("A field can have at most {MAX_POLICIES} policies"),
+                status: Code::InvalidArgument,
+            });
+        }
+        let mut policies = vec![];
+        for message in request.policies {
+            let policy_result = AccessPolicy::try_from(message);
+            if let Err(err) = policy_result {
+                return Err(err);
+            }
+            policies.push(policy_result.unwrap());
+        }
+        Ok(Self {
+            user_id: request.user_id,
+            calling_user: request.calling_user,
+            field: field_result.unwrap(),
+            policies,
+            expected_sequence: request.expected_sequence,
+        })
+    }
+}
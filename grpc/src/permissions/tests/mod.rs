@@ -1 +1,2 @@
-mod model;
\ No newline at end of file
+mod model;
+mod policy;
\ No newline at end of file
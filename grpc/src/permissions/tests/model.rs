@@ -1,8 +1,10 @@
 #![cfg(test)]
 
+use chrono::{Duration, Utc};
+use tonic::Code;
 use uuid::Uuid;
 
-use crate::{permissions::model::PermissionModel, user::public_user::PublicUser, enums::value_reference::ValueReference};
+use crate::{permissions::model::{GrantRole, PermissionModel}, user::public_user::PublicUser, enums::value_reference::ValueReference};
 
 #[test]
 fn create_new_permission_model() {
@@ -11,7 +13,7 @@ fn create_new_permission_model() {
     let key: String = "blah".into();
     let permission_model = PermissionModel::new(id.to_string(), value.clone(), key.clone(), ValueReference::DETAIL_FIRST_NAME);
     
-    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
     
     assert_eq!(permission_model.value(&calling_user).unwrap(), value);
 }
@@ -23,7 +25,7 @@ fn get_value_throw_err() {
     let key: String = "blah".into();
     let permission_model = PermissionModel::new(id.to_string(), value.clone(), key.clone(), ValueReference::DETAIL_FIRST_NAME);
     
-    let calling_user = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+    let calling_user = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
     
     assert_eq!(permission_model.value(&calling_user).unwrap_err().message, format!("User does not have permission to view this value: {key}"));
 }
@@ -36,10 +38,10 @@ fn add_viewer() {
     let key: String = "blah".into();
     let mut permission_model = PermissionModel::new(id.to_string(), value.clone(), key.clone(), ValueReference::DETAIL_FIRST_NAME);
     
-    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-    let new_viewer = PublicUser { id: viewer_id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let new_viewer = PublicUser { id: viewer_id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
 
-    let result = permission_model.add_viewer(new_viewer.clone(), &calling_user);
+    let result = permission_model.add_viewer(new_viewer.id.clone(), &calling_user, &Utc::now());
     
     assert!(result.is_ok());
     assert_eq!(permission_model.value(&new_viewer).unwrap(), value);
@@ -53,10 +55,10 @@ fn add_viewer_no_permissions() {
     let key: String = "blah".into();
     let mut permission_model = PermissionModel::new(id.to_string(), value.clone(), key.clone(), ValueReference::DETAIL_FIRST_NAME);
     
-    let calling_user = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-    let new_viewer = PublicUser { id: viewer_id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+    let calling_user = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let new_viewer = PublicUser { id: viewer_id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
 
-    let result = permission_model.add_viewer(new_viewer.clone(), &calling_user);
+    let result = permission_model.add_viewer(new_viewer.id.clone(), &calling_user, &Utc::now());
     
     assert_eq!(result.unwrap_err().message, "User does not have permission to add a viewer");
 }
@@ -68,9 +70,9 @@ fn add_viewer_owner() {
     let key: String = "blah".into();
     let mut permission_model = PermissionModel::new(id.to_string(), value.clone(), key.clone(), ValueReference::DETAIL_FIRST_NAME);
     
-    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
 
-    let result = permission_model.add_viewer(calling_user.clone(), &calling_user);
+    let result = permission_model.add_viewer(calling_user.id.clone(), &calling_user, &Utc::now());
     
     assert_eq!(result.unwrap_err().message, "User is owner");
 }
@@ -83,14 +85,14 @@ fn add_viewer_twice_err() {
     let key: String = "blah".into();
     let mut permission_model = PermissionModel::new(id.to_string(), value.clone(), key.clone(), ValueReference::DETAIL_FIRST_NAME);
     
-    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-    let new_viewer = PublicUser { id: viewer_id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let new_viewer = PublicUser { id: viewer_id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
 
-    let result = permission_model.add_viewer(new_viewer.clone(), &calling_user);
+    let result = permission_model.add_viewer(new_viewer.id.clone(), &calling_user, &Utc::now());
     
     assert!(result.is_ok());
 
-    let second_result = permission_model.add_viewer(new_viewer.clone(), &calling_user);
+    let second_result = permission_model.add_viewer(new_viewer.id.clone(), &calling_user, &Utc::now());
     assert_eq!(second_result.unwrap_err().message, "User already has permission to view this value");
     assert_eq!(permission_model.value(&new_viewer).unwrap(), value);
 }
@@ -103,13 +105,13 @@ fn add_viewer_cannot_mut() {
     let key: String = "blah".into();
     let mut permission_model = PermissionModel::new(id.to_string(), value.clone(), key.clone(), ValueReference::DETAIL_FIRST_NAME);
     
-    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-    let new_viewer = PublicUser { id: viewer_id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let new_viewer = PublicUser { id: viewer_id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
 
-    let result = permission_model.add_viewer(new_viewer.clone(), &calling_user);
+    let result = permission_model.add_viewer(new_viewer.id.clone(), &calling_user, &Utc::now());
     
     assert!(result.is_ok());
-    assert_eq!(permission_model.value_mut(&new_viewer).unwrap_err().message, format!("User does not have permission to view this mut value: {key}"));
+    assert_eq!(permission_model.value_mut(&new_viewer, &Utc::now()).unwrap_err().message, format!("User does not have permission to view this mut value: {key}"));
 }
 
 #[test]
@@ -120,10 +122,10 @@ fn add_editor() {
     let key: String = "blah".into();
     let mut permission_model = PermissionModel::new(id.to_string(), value.clone(), key.clone(), ValueReference::DETAIL_FIRST_NAME);
     
-    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-    let new_editor = PublicUser { id: editor_id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let new_editor = PublicUser { id: editor_id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
 
-    let result = permission_model.add_editor(new_editor.clone(), &calling_user);
+    let result = permission_model.add_editor(new_editor.id.clone(), &calling_user, &Utc::now());
     
     assert!(result.is_ok());
     assert_eq!(permission_model.value(&new_editor).unwrap(), value);
@@ -137,10 +139,10 @@ fn add_editor_no_permissions() {
     let key: String = "blah".into();
     let mut permission_model = PermissionModel::new(id.to_string(), value.clone(), key.clone(), ValueReference::DETAIL_FIRST_NAME);
     
-    let calling_user = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-    let new_editor = PublicUser { id: editor_id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+    let calling_user = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let new_editor = PublicUser { id: editor_id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
 
-    let result = permission_model.add_editor(new_editor.clone(), &calling_user);
+    let result = permission_model.add_editor(new_editor.id.clone(), &calling_user, &Utc::now());
     
     assert_eq!(result.unwrap_err().message, "User does not have permission to add an editor");
 }
@@ -154,15 +156,15 @@ fn add_editor_as_editor() {
     let key: String = "blah".into();
     let mut permission_model = PermissionModel::new(id.to_string(), value.clone(), key.clone(), ValueReference::DETAIL_FIRST_NAME);
     
-    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-    let new_editor = PublicUser { id: editor_id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-    let second_editor = PublicUser { id: second_editor_id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let new_editor = PublicUser { id: editor_id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let second_editor = PublicUser { id: second_editor_id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
 
-    let result = permission_model.add_editor(new_editor.clone(), &calling_user);
+    let result = permission_model.add_editor(new_editor.id.clone(), &calling_user, &Utc::now());
 
     assert!(result.is_ok());
 
-    let second_result = permission_model.add_editor(second_editor.clone(), &new_editor);
+    let second_result = permission_model.add_editor(second_editor.id.clone(), &new_editor, &Utc::now());
 
     assert!(second_result.is_ok());
     assert_eq!(permission_model.value(&new_editor).unwrap(), value.clone());
@@ -176,9 +178,9 @@ fn add_editor_owner() {
     let key: String = "blah".into();
     let mut permission_model = PermissionModel::new(id.to_string(), value.clone(), key.clone(), ValueReference::DETAIL_FIRST_NAME);
     
-    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
 
-    let result = permission_model.add_editor(calling_user.clone(), &calling_user);
+    let result = permission_model.add_editor(calling_user.id.clone(), &calling_user, &Utc::now());
     
     assert_eq!(result.unwrap_err().message, "User is owner");
 }
@@ -191,14 +193,14 @@ fn add_editor_twice_err() {
     let key: String = "blah".into();
     let mut permission_model = PermissionModel::new(id.to_string(), value.clone(), key.clone(), ValueReference::DETAIL_FIRST_NAME);
     
-    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-    let new_editor = PublicUser { id: editor_id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let new_editor = PublicUser { id: editor_id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
 
-    let result = permission_model.add_editor(new_editor.clone(), &calling_user);
+    let result = permission_model.add_editor(new_editor.id.clone(), &calling_user, &Utc::now());
     
     assert!(result.is_ok());
 
-    let second_result = permission_model.add_editor(new_editor.clone(), &calling_user);
+    let second_result = permission_model.add_editor(new_editor.id.clone(), &calling_user, &Utc::now());
     assert_eq!(second_result.unwrap_err().message, "User already has permission to edit this value");
     assert_eq!(permission_model.value(&new_editor).unwrap(), value);
 }
@@ -211,13 +213,13 @@ fn add_editor_can_mut() {
     let key: String = "blah".into();
     let mut permission_model = PermissionModel::new(id.to_string(), value.clone(), key.clone(), ValueReference::DETAIL_FIRST_NAME);
     
-    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-    let new_editor = PublicUser { id: editor_id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let new_editor = PublicUser { id: editor_id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
 
-    let result = permission_model.add_editor(new_editor.clone(), &calling_user);
+    let result = permission_model.add_editor(new_editor.id.clone(), &calling_user, &Utc::now());
     
     assert!(result.is_ok());
-    assert_eq!(permission_model.value_mut(&new_editor).unwrap(), &mut value);
+    assert_eq!(permission_model.value_mut(&new_editor, &Utc::now()).unwrap(), &mut value);
 }
 
 #[test]
@@ -227,9 +229,9 @@ fn get_value_mut() {
     let key: String = "blah".into();
     let mut permission_model = PermissionModel::new(id.to_string(), value.clone(), key.clone(), ValueReference::DETAIL_FIRST_NAME);
     
-    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
     
-    assert_eq!(permission_model.value_mut(&calling_user).unwrap(), &mut value);
+    assert_eq!(permission_model.value_mut(&calling_user, &Utc::now()).unwrap(), &mut value);
 }
 
 #[test]
@@ -239,20 +241,20 @@ fn remove_viewer() {
     let key: String = "blah".into();
     let mut permission_model = PermissionModel::new(id.to_string(), value.clone(), key.clone(), ValueReference::DETAIL_FIRST_NAME);
     
-    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-    let new_editor = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-    let new_viewer = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let new_editor = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let new_viewer = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
 
-    let add_viewer_result = permission_model.add_viewer(new_viewer.clone(), &calling_user);
+    let add_viewer_result = permission_model.add_viewer(new_viewer.id.clone(), &calling_user, &Utc::now());
 
     assert!(add_viewer_result.is_ok());
     assert_eq!(permission_model.value(&new_viewer).unwrap(), value);
 
-    let result = permission_model.add_editor(new_editor.clone(), &calling_user);
+    let result = permission_model.add_editor(new_editor.id.clone(), &calling_user, &Utc::now());
     
     assert!(result.is_ok());
 
-    let remove_result = permission_model.remove_viewer(new_viewer.clone(), &new_editor);
+    let remove_result = permission_model.remove_viewer(new_viewer.id.clone(), &new_editor, &Utc::now());
     assert!(remove_result.is_ok());
     assert!(permission_model.value(&new_viewer).is_err());
 }
@@ -264,20 +266,188 @@ fn remove_editor() {
     let key: String = "blah".into();
     let mut permission_model = PermissionModel::new(id.to_string(), value.clone(), key.clone(), ValueReference::DETAIL_FIRST_NAME);
     
-    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-    let new_editor = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-    let new_viewer = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let new_editor = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let new_viewer = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
 
-    let add_viewer_result = permission_model.add_viewer(new_viewer.clone(), &calling_user);
+    let add_viewer_result = permission_model.add_viewer(new_viewer.id.clone(), &calling_user, &Utc::now());
 
     assert!(add_viewer_result.is_ok());
     assert_eq!(permission_model.value(&new_viewer).unwrap(), value);
 
-    let result = permission_model.add_editor(new_editor.clone(), &calling_user);
+    let result = permission_model.add_editor(new_editor.id.clone(), &calling_user, &Utc::now());
     
     assert!(result.is_ok());
 
-    let remove_result = permission_model.remove_viewer(new_viewer.clone(), &new_editor);
+    let remove_result = permission_model.remove_viewer(new_viewer.id.clone(), &new_editor, &Utc::now());
     assert!(remove_result.is_ok());
     assert!(permission_model.value(&new_viewer).is_err());
+}
+
+#[test]
+fn viewer_grant_lapses_at_expiry() {
+    let id = Uuid::new_v4();
+    let value: String = "whoop".into();
+    let key: String = "blah".into();
+    let mut permission_model = PermissionModel::new(id.to_string(), value.clone(), key.clone(), ValueReference::DETAIL_FIRST_NAME);
+
+    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let new_viewer = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let expires = Utc::now() + Duration::hours(1);
+
+    let result = permission_model.add_viewer_until(new_viewer.id.clone(), &calling_user, Some(expires), &Utc::now());
+
+    assert!(result.is_ok());
+    assert_eq!(permission_model.value(&new_viewer).unwrap(), value);
+    assert_eq!(permission_model.value_at(&new_viewer, &expires).unwrap_err().status, Code::PermissionDenied);
+    assert_eq!(permission_model.value_at(&calling_user, &expires).unwrap(), value);
+}
+
+#[test]
+fn editor_grant_lapses_at_expiry() {
+    let id = Uuid::new_v4();
+    let value: String = "whoop".into();
+    let key: String = "blah".into();
+    let mut permission_model = PermissionModel::new(id.to_string(), value.clone(), key.clone(), ValueReference::DETAIL_FIRST_NAME);
+
+    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let new_editor = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let expires = Utc::now() + Duration::hours(1);
+    let _ = permission_model.add_editor_until(new_editor.id.clone(), &calling_user, Some(expires), &Utc::now());
+
+    assert!(PermissionModel::is_owner_or_editor(&permission_model, &new_editor, &Utc::now()));
+    assert!(!PermissionModel::is_owner_or_editor(&permission_model, &new_editor, &expires));
+}
+
+#[test]
+fn expiring_editor_judged_at_request_time() {
+    let id = Uuid::new_v4();
+    let value: String = "whoop".into();
+    let key: String = "blah".into();
+    let mut permission_model = PermissionModel::new(id.to_string(), value.clone(), key.clone(), ValueReference::DETAIL_FIRST_NAME);
+
+    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let new_editor = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let new_viewer = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let expires = Utc::now() + Duration::hours(1);
+    let _ = permission_model.add_editor_until(new_editor.id.clone(), &calling_user, Some(expires), &Utc::now());
+
+    assert_eq!(permission_model.add_viewer(new_viewer.id.clone(), &new_editor, &expires).unwrap_err().status, Code::PermissionDenied);
+    assert!(permission_model.value_mut(&new_editor, &expires).is_err());
+    assert!(permission_model.add_viewer(new_viewer.id.clone(), &new_editor, &Utc::now()).is_ok());
+}
+
+#[test]
+fn expiring_editor_cannot_grant_beyond_their_expiry() {
+    let id = Uuid::new_v4();
+    let value: String = "whoop".into();
+    let key: String = "blah".into();
+    let mut permission_model = PermissionModel::new(id.to_string(), value.clone(), key.clone(), ValueReference::DETAIL_FIRST_NAME);
+
+    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let new_editor = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let second_editor = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let new_viewer = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let expires = Utc::now() + Duration::hours(1);
+    let _ = permission_model.add_editor_until(new_editor.id.clone(), &calling_user, Some(expires), &Utc::now());
+
+    permission_model.add_editor(second_editor.id.clone(), &new_editor, &Utc::now()).unwrap();
+    permission_model.add_viewer_until(new_viewer.id.clone(), &new_editor, Some(expires + Duration::hours(1)), &Utc::now()).unwrap();
+
+    assert_eq!(permission_model.expires(&second_editor.id, GrantRole::EDITOR), Some(expires));
+    assert_eq!(permission_model.expires(&new_viewer.id, GrantRole::VIEWER), Some(expires));
+    assert_eq!(permission_model.add_group("group".into(), GrantRole::EDITOR, &new_editor, &Utc::now()).unwrap_err().status, Code::PermissionDenied);
+}
+
+#[test]
+fn revoke_expired_only_after_lapse() {
+    let id = Uuid::new_v4();
+    let value: String = "whoop".into();
+    let key: String = "blah".into();
+    let mut permission_model = PermissionModel::new(id.to_string(), value.clone(), key.clone(), ValueReference::DETAIL_FIRST_NAME);
+
+    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let new_viewer = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let expires = Utc::now() + Duration::hours(1);
+    let _ = permission_model.add_viewer_until(new_viewer.id.clone(), &calling_user, Some(expires), &Utc::now());
+
+    assert!(permission_model.expired_grants(&Utc::now()).is_empty());
+    assert_eq!(permission_model.revoke_expired(&new_viewer.id, GrantRole::VIEWER, &Utc::now()).unwrap_err().status, Code::FailedPrecondition);
+    assert_eq!(permission_model.expired_grants(&expires), vec![(new_viewer.id.clone(), GrantRole::VIEWER)]);
+
+    let result = permission_model.revoke_expired(&new_viewer.id, GrantRole::VIEWER, &expires);
+
+    assert!(result.is_ok());
+    assert!(permission_model.value_at(&new_viewer, &Utc::now()).is_err());
+    assert!(permission_model.expires(&new_viewer.id, GrantRole::VIEWER).is_none());
+}
+
+#[test]
+fn deserialize_legacy_grantee_snapshots() {
+    let json = r#"{"owner_id":"owner","editors":[{"id":"editor","first_name":"boop","other_names":null,"last_name":null,"chain_accounts":[]}],"viewers":["viewer"],"value":"whoop","key":"blah","reference":"DETAIL_FIRST_NAME"}"#;
+
+    let permission_model: PermissionModel<String> = serde_json::from_str(json).unwrap();
+
+    let editor = PublicUser { id: "editor".into(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let viewer = PublicUser { id: "viewer".into(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    assert!(PermissionModel::is_owner_or_editor(&permission_model, &editor, &Utc::now()));
+    assert_eq!(permission_model.value(&viewer).unwrap(), "whoop");
+    let serialised = serde_json::to_string(&permission_model).unwrap();
+    assert!(serialised.contains(r#""editors":["editor"]"#));
+    assert!(!serialised.contains("boop"));
+}
+
+#[test]
+fn group_viewer_resolved_from_calling_user() {
+    let id = Uuid::new_v4();
+    let group_id = Uuid::new_v4().to_string();
+    let value: String = "whoop".into();
+    let key: String = "blah".into();
+    let mut permission_model = PermissionModel::new(id.to_string(), value.clone(), key.clone(), ValueReference::DETAIL_FIRST_NAME);
+
+    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let member = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![group_id.clone()], memberships: vec![] };
+    let non_member = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+
+    permission_model.add_group(group_id.clone(), GrantRole::VIEWER, &calling_user, &Utc::now()).unwrap();
+
+    assert_eq!(permission_model.value(&member).unwrap(), value);
+    assert!(permission_model.value(&non_member).is_err());
+    assert!(permission_model.value_mut(&member, &Utc::now()).is_err());
+    assert_eq!(permission_model.add_group(group_id, GrantRole::VIEWER, &calling_user, &Utc::now()).unwrap_err().status, Code::AlreadyExists);
+}
+
+#[test]
+fn group_editor_can_mut() {
+    let id = Uuid::new_v4();
+    let group_id = Uuid::new_v4().to_string();
+    let mut value: String = "whoop".into();
+    let key: String = "blah".into();
+    let mut permission_model = PermissionModel::new(id.to_string(), value.clone(), key.clone(), ValueReference::DETAIL_FIRST_NAME);
+
+    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let member = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![group_id.clone()], memberships: vec![] };
+
+    permission_model.add_group(group_id, GrantRole::EDITOR, &calling_user, &Utc::now()).unwrap();
+
+    assert_eq!(permission_model.value_mut(&member, &Utc::now()).unwrap(), &mut value);
+}
+
+#[test]
+fn remove_group() {
+    let id = Uuid::new_v4();
+    let group_id = Uuid::new_v4().to_string();
+    let value: String = "whoop".into();
+    let key: String = "blah".into();
+    let mut permission_model = PermissionModel::new(id.to_string(), value.clone(), key.clone(), ValueReference::DETAIL_FIRST_NAME);
+
+    let calling_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let member = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![group_id.clone()], memberships: vec![] };
+
+    permission_model.add_group(group_id.clone(), GrantRole::VIEWER, &calling_user, &Utc::now()).unwrap();
+    assert_eq!(permission_model.remove_group(&group_id, GrantRole::EDITOR, &calling_user, &Utc::now()).unwrap_err().status, Code::NotFound);
+    assert_eq!(permission_model.remove_group(&group_id, GrantRole::VIEWER, &member, &Utc::now()).unwrap_err().status, Code::PermissionDenied);
+    permission_model.remove_group(&group_id, GrantRole::VIEWER, &calling_user, &Utc::now()).unwrap();
+
+    assert!(permission_model.value(&member).is_err());
 }
\ No newline at end of file
@@ -0,0 +1,101 @@
+#![cfg(test)]
+
+use chrono::{Duration, Utc};
+use tonic::Code;
+use uuid::Uuid;
+
+use crate::{
+    enums::{oragnisaton_relation::OrganisationRelation, value_reference::ValueReference},
+    organisation::user_organisation::UserOrganisation,
+    permissions::{
+        model::{GrantRole, PermissionModel},
+        policy::{AccessPolicy, PolicyCondition},
+    },
+    proto::beco::{
+        policy_condition::Condition, AccessPolicy as AccessPolicyMessage,
+        GrantRole as ProtoGrantRole, PolicyCondition as PolicyConditionMessage, PolicyConditions,
+    },
+    user::public_user::PublicUser,
+};
+
+fn caller(memberships: Vec<UserOrganisation>) -> PublicUser {
+    let mut user = PublicUser::new(Uuid::new_v4().to_string(), None, None, None, vec![]);
+    user.memberships = memberships;
+    user
+}
+
+fn membership(organisation_id: &str, relation: OrganisationRelation, ended: bool) -> UserOrganisation {
+    UserOrganisation {
+        id: organisation_id.into(),
+        name: "Beco".into(),
+        user_id: "member".into(),
+        relation,
+        start_date: Utc::now() - Duration::days(30),
+        end_date: if ended { Some(Utc::now() - Duration::days(1)) } else { None },
+    }
+}
+
+#[test]
+fn member_of_requires_an_active_membership() {
+    let condition = PolicyCondition::MemberOf("org".into());
+
+    assert!(condition.matches(&caller(vec![membership("org", OrganisationRelation::EMPLOYER, false)]), &Utc::now()));
+    assert!(!condition.matches(&caller(vec![membership("org", OrganisationRelation::EMPLOYER, true)]), &Utc::now()));
+    assert!(!condition.matches(&caller(vec![membership("other", OrganisationRelation::EMPLOYER, false)]), &Utc::now()));
+}
+
+#[test]
+fn member_of_relation_matches_any_organisation() {
+    let condition = PolicyCondition::MemberOfRelation(OrganisationRelation::GOVERNMENT);
+
+    assert!(condition.matches(&caller(vec![membership("org", OrganisationRelation::GOVERNMENT, false)]), &Utc::now()));
+    assert!(!condition.matches(&caller(vec![membership("org", OrganisationRelation::EMPLOYER, false)]), &Utc::now()));
+}
+
+#[test]
+fn conditions_are_checked_at_the_request_time() {
+    let cutoff = Utc::now();
+    let condition = PolicyCondition::All(vec![
+        PolicyCondition::MemberOf("org".into()),
+        PolicyCondition::NotAfter(cutoff),
+    ]);
+    let member = caller(vec![membership("org", OrganisationRelation::EMPLOYER, false)]);
+
+    assert!(condition.matches(&member, &(cutoff - Duration::minutes(1))));
+    assert!(!condition.matches(&member, &cutoff));
+    assert!(PolicyCondition::Any(vec![PolicyCondition::NotBefore(cutoff), PolicyCondition::MemberOf("other".into())]).matches(&member, &cutoff));
+}
+
+#[test]
+fn policy_grants_role_on_permission_model() {
+    let owner = PublicUser::new(Uuid::new_v4().to_string(), None, None, None, vec![]);
+    let value: String = "whoop".into();
+    let mut permission_model = PermissionModel::new(owner.id.clone(), value.clone(), "blah".into(), ValueReference::DETAIL_LAST_NAME);
+    let policy = AccessPolicy { role: GrantRole::VIEWER, condition: PolicyCondition::MemberOfRelation(OrganisationRelation::GOVERNMENT) };
+    let official = caller(vec![membership("org", OrganisationRelation::GOVERNMENT, false)]);
+
+    assert!(permission_model.value(&official).is_err());
+    assert_eq!(permission_model.set_policies(vec![policy.clone()], &official).unwrap_err().status, Code::PermissionDenied);
+    permission_model.set_policies(vec![policy], &owner).unwrap();
+
+    assert_eq!(permission_model.value(&official).unwrap(), value);
+    assert!(permission_model.value_mut(&official, &Utc::now()).is_err());
+    assert!(permission_model.value(&caller(vec![])).is_err());
+}
+
+#[test]
+fn parse_rejects_empty_and_deep_conditions() {
+    let member_of = PolicyConditionMessage { condition: Some(Condition::MemberOf("org".into())) };
+    let empty = AccessPolicyMessage { role: ProtoGrantRole::Viewer as i32, condition: Some(PolicyConditionMessage { condition: Some(Condition::All(PolicyConditions { conditions: vec![] })) }) };
+    assert_eq!(AccessPolicy::try_from(empty).unwrap_err().status, Code::InvalidArgument);
+
+    let mut deep = member_of.clone();
+    for _ in 0..8 {
+        deep = PolicyConditionMessage { condition: Some(Condition::Any(PolicyConditions { conditions: vec![deep] })) };
+    }
+    let deep_policy = AccessPolicyMessage { role: ProtoGrantRole::Viewer as i32, condition: Some(deep) };
+    assert_eq!(AccessPolicy::try_from(deep_policy).unwrap_err().message, "Policy condition is too large");
+
+    let policy = AccessPolicy::try_from(AccessPolicyMessage { role: ProtoGrantRole::Editor as i32, condition: Some(member_of) }).unwrap();
+    assert_eq!(policy, AccessPolicy { role: GrantRole::EDITOR, condition: PolicyCondition::MemberOf("org".into()) });
+}
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+use tonic::Code;
+
+use crate::{
+    errors::BecoError,
+    proto::beco::{SetFieldVisibilityRequest, Visibility as ProtoVisibility},
+    requests::user_request::{field_references, FieldReference},
+};
+
+// who can read a field, editing is unaffected
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Hash, PartialEq, Eq, Default)]
+pub enum Visibility {
+    // viewers keep their grants but cannot read until the field is shared again
+    PRIVATE,
+    #[default]
+    SHARED,
+    PUBLIC,
+}
+
+impl TryFrom<i32> for Visibility {
+    type Error = BecoError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match ProtoVisibility::try_from(value) {
+            Ok(ProtoVisibility::Private) => Ok(Visibility::PRIVATE),
+            Ok(ProtoVisibility::Shared) => Ok(Visibility::SHARED),
+            Ok(ProtoVisibility::Public) => Ok(Visibility::PUBLIC),
+            _ => Err(BecoError {
+                message: format!("Invalid visibility: {value}"),
+                status: Code::InvalidArgument,
+            }),
+        }
+    }
+}
+
+// the validated form of SetFieldVisibilityRequest
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct SetFieldVisibility {
+    pub user_id: String,
+    pub calling_user: String,
+    pub fields: Vec<FieldReference>,
+    pub visibility: Visibility,
+    pub expected_sequence: Option<u64>,
+}
+
+impl TryFrom<SetFieldVisibilityRequest> for SetFieldVisibility {
+    type Error = BecoError;
+
+    fn try_from(request: SetFieldVisibilityRequest) -> Result<Self, Self::Error> {
+        let visibility_result = Visibility::try_from(request.visibility);
+        if let Err(err) = visibility_result {
+            return Err(err);
+        }
+        let fields_result = field_references(request.fields);
+        if let Err(err) = fields_result {
+            return Err(err);
+        }
+        Ok(Self {
+            user_id: request.user_id,
+            calling_user: request.calling_user,
+            fields: fields_result.unwrap(),
+            visibility: visibility_result.unwrap(),
+            expected_sequence: request.expected_sequence,
+        })
+    }
+}
@@ -1,3 +1,4 @@
+// This file is @generated by prost-build.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AddAccountRequest {
@@ -9,6 +10,9 @@ pub struct AddAccountRequest {
     pub blockchain: i32,
     #[prost(string, tag = "4")]
     pub alias: ::prost::alloc::string::String,
+    /// rejected with ABORTED if the user has moved on from this sequence
+    #[prost(uint64, optional, tag = "5")]
+    pub expected_sequence: ::core::option::Option<u64>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -41,6 +45,8 @@ pub struct AddUserRequest {
     pub calling_user: ::prost::alloc::string::String,
     #[prost(string, tag = "3")]
     pub name: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "4")]
+    pub national_identifier: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -55,6 +61,30 @@ pub struct GetUserResponse {
     pub last_name: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(message, repeated, tag = "5")]
     pub chain_accounts: ::prost::alloc::vec::Vec<ChainResponse>,
+    /// pass back as expected_sequence to reject the change if the user was modified meanwhile
+    #[prost(uint64, optional, tag = "6")]
+    pub sequence: ::core::option::Option<u64>,
+    /// only the attributes the caller may view
+    #[prost(message, repeated, tag = "7")]
+    pub attributes: ::prost::alloc::vec::Vec<AttributeResponse>,
+    /// users linked with the consent of both sides, shown to the user and the linked users
+    #[prost(string, repeated, tag = "8")]
+    pub linked_users: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// shown to the user and their guardian
+    #[prost(message, optional, tag = "9")]
+    pub guardian: ::core::option::Option<GuardianResponse>,
+    /// hex encoded secp256k1 public key the user authenticates with
+    #[prost(string, optional, tag = "10")]
+    pub auth_key: ::core::option::Option<::prost::alloc::string::String>,
+    /// shown to the user and their recovery guardians
+    #[prost(message, optional, tag = "11")]
+    pub recovery: ::core::option::Option<RecoveryResponse>,
+    /// ids of the changes waiting on approval that the caller requested or may approve
+    #[prost(string, repeated, tag = "12")]
+    pub pending_approvals: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// attestations of the fields the caller may view
+    #[prost(message, repeated, tag = "13")]
+    pub attestations: ::prost::alloc::vec::Vec<AttestationResponse>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -73,10 +103,13 @@ pub struct ListUserResponse {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ModifyLinkedUserRequest {
+    /// the other side of the link
     #[prost(string, tag = "1")]
     pub user_id: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub calling_user: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag = "3")]
+    pub expected_sequence: ::core::option::Option<u64>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -87,6 +120,8 @@ pub struct ModifyNameRequest {
     pub calling_user: ::prost::alloc::string::String,
     #[prost(string, tag = "3")]
     pub name: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag = "4")]
+    pub expected_sequence: ::core::option::Option<u64>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -97,395 +132,3822 @@ pub struct ModifyOtherNamesRequest {
     pub calling_user: ::prost::alloc::string::String,
     #[prost(string, repeated, tag = "3")]
     pub other_names: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(uint64, optional, tag = "4")]
+    pub expected_sequence: ::core::option::Option<u64>,
 }
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
-#[repr(i32)]
-pub enum Blockchain {
-    Unspecified = 0,
-    Xrpl = 1,
-    Evm = 2,
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PostalAddress {
+    #[prost(string, repeated, tag = "1")]
+    pub lines: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag = "2")]
+    pub locality: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "3")]
+    pub region: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag = "4")]
+    pub postal_code: ::prost::alloc::string::String,
+    /// ISO 3166-1 alpha-2
+    #[prost(string, tag = "5")]
+    pub country: ::prost::alloc::string::String,
 }
-impl Blockchain {
-    /// String value of the enum field names used in the ProtoBuf definition.
-    ///
-    /// The values are not transformed in any way and thus are considered stable
-    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
-    pub fn as_str_name(&self) -> &'static str {
-        match self {
-            Blockchain::Unspecified => "UNSPECIFIED",
-            Blockchain::Xrpl => "XRPL",
-            Blockchain::Evm => "EVM",
-        }
-    }
-    /// Creates an enum from field names used in the ProtoBuf definition.
-    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
-        match value {
-            "UNSPECIFIED" => Some(Self::Unspecified),
-            "XRPL" => Some(Self::Xrpl),
-            "EVM" => Some(Self::Evm),
-            _ => None,
-        }
-    }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttributeValue {
+    #[prost(oneof = "attribute_value::Value", tags = "1, 2, 3")]
+    pub value: ::core::option::Option<attribute_value::Value>,
 }
-/// Generated client implementations.
-pub mod beco_client {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
-    use tonic::codegen::http::Uri;
-    #[derive(Debug, Clone)]
-    pub struct BecoClient<T> {
-        inner: tonic::client::Grpc<T>,
-    }
-    impl BecoClient<tonic::transport::Channel> {
-        /// Attempt to create a new client by connecting to a given endpoint.
-        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
-        where
-            D: TryInto<tonic::transport::Endpoint>,
-            D::Error: Into<StdError>,
-        {
-            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
-            Ok(Self::new(conn))
-        }
-    }
-    impl<T> BecoClient<T>
-    where
-        T: tonic::client::GrpcService<tonic::body::BoxBody>,
-        T::Error: Into<StdError>,
-        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
-        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
-    {
-        pub fn new(inner: T) -> Self {
-            let inner = tonic::client::Grpc::new(inner);
-            Self { inner }
-        }
-        pub fn with_origin(inner: T, origin: Uri) -> Self {
-            let inner = tonic::client::Grpc::with_origin(inner, origin);
-            Self { inner }
-        }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> BecoClient<InterceptedService<T, F>>
-        where
-            F: tonic::service::Interceptor,
-            T::ResponseBody: Default,
-            T: tonic::codegen::Service<
-                http::Request<tonic::body::BoxBody>,
-                Response = http::Response<
-                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
-                >,
-            >,
-            <T as tonic::codegen::Service<
-                http::Request<tonic::body::BoxBody>,
-            >>::Error: Into<StdError> + Send + Sync,
-        {
-            BecoClient::new(InterceptedService::new(inner, interceptor))
-        }
-        /// Compress requests with the given encoding.
-        ///
-        /// This requires the server to support it otherwise it might respond with an
-        /// error.
-        #[must_use]
-        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.inner = self.inner.send_compressed(encoding);
-            self
-        }
-        /// Enable decompressing responses.
-        #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.inner = self.inner.accept_compressed(encoding);
-            self
-        }
-        /// Limits the maximum size of a decoded message.
-        ///
-        /// Default: `4MB`
-        #[must_use]
-        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_decoding_message_size(limit);
-            self
-        }
-        /// Limits the maximum size of an encoded message.
-        ///
-        /// Default: `usize::MAX`
-        #[must_use]
-        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_encoding_message_size(limit);
-            self
-        }
-        pub async fn list_user(
-            &mut self,
-            request: impl tonic::IntoRequest<super::ListUserRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::ListUserResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static("/beco.Beco/ListUser");
-            let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "ListUser"));
-            self.inner.unary(req, path, codec).await
-        }
-        pub async fn add_user(
-            &mut self,
-            request: impl tonic::IntoRequest<super::AddUserRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::GetUserResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static("/beco.Beco/AddUser");
-            let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "AddUser"));
-            self.inner.unary(req, path, codec).await
-        }
-        /// rpc AddLinkedUser(ModifyLinkedUserRequest) returns (GetUserResponse);
-        /// rpc RemoveLinkedUser(ModifyLinkedUserRequest) returns (GetUserResponse);
-        pub async fn update_first_name(
-            &mut self,
-            request: impl tonic::IntoRequest<super::ModifyNameRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::GetUserResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/beco.Beco/UpdateFirstName",
-            );
-            let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "UpdateFirstName"));
-            self.inner.unary(req, path, codec).await
-        }
-        pub async fn update_other_names(
-            &mut self,
-            request: impl tonic::IntoRequest<super::ModifyOtherNamesRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::GetUserResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/beco.Beco/UpdateOtherNames",
-            );
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("beco.Beco", "UpdateOtherNames"));
-            self.inner.unary(req, path, codec).await
-        }
-        pub async fn update_last_name(
-            &mut self,
-            request: impl tonic::IntoRequest<super::ModifyNameRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::GetUserResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static("/beco.Beco/UpdateLastName");
-            let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "UpdateLastName"));
-            self.inner.unary(req, path, codec).await
-        }
-        pub async fn add_account(
-            &mut self,
-            request: impl tonic::IntoRequest<super::AddAccountRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::GetUserResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static("/beco.Beco/AddAccount");
-            let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "AddAccount"));
-            self.inner.unary(req, path, codec).await
-        }
+/// Nested message and enum types in `AttributeValue`.
+pub mod attribute_value {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Value {
+        #[prost(string, tag = "1")]
+        Text(::prost::alloc::string::String),
+        /// YYYY-MM-DD
+        #[prost(string, tag = "2")]
+        Date(::prost::alloc::string::String),
+        #[prost(message, tag = "3")]
+        Address(super::PostalAddress),
     }
 }
-/// Generated server implementations.
-pub mod beco_server {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with BecoServer.
-    #[async_trait]
-    pub trait Beco: Send + Sync + 'static {
-        async fn list_user(
-            &self,
-            request: tonic::Request<super::ListUserRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::ListUserResponse>,
-            tonic::Status,
-        >;
-        async fn add_user(
-            &self,
-            request: tonic::Request<super::AddUserRequest>,
-        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
-        /// rpc AddLinkedUser(ModifyLinkedUserRequest) returns (GetUserResponse);
-        /// rpc RemoveLinkedUser(ModifyLinkedUserRequest) returns (GetUserResponse);
-        async fn update_first_name(
-            &self,
-            request: tonic::Request<super::ModifyNameRequest>,
-        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
-        async fn update_other_names(
-            &self,
-            request: tonic::Request<super::ModifyOtherNamesRequest>,
-        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
-        async fn update_last_name(
-            &self,
-            request: tonic::Request<super::ModifyNameRequest>,
-        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
-        async fn add_account(
-            &self,
-            request: tonic::Request<super::AddAccountRequest>,
-        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
-    }
-    #[derive(Debug)]
-    pub struct BecoServer<T: Beco> {
-        inner: _Inner<T>,
-        accept_compression_encodings: EnabledCompressionEncodings,
-        send_compression_encodings: EnabledCompressionEncodings,
-        max_decoding_message_size: Option<usize>,
-        max_encoding_message_size: Option<usize>,
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttributeResponse {
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub value: ::core::option::Option<AttributeValue>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetAttributeRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+    /// lower case letters, digits and underscores, e.g. email or date_of_birth
+    #[prost(string, tag = "3")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "4")]
+    pub value: ::core::option::Option<AttributeValue>,
+    #[prost(uint64, optional, tag = "5")]
+    pub expected_sequence: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClearAttributeRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag = "4")]
+    pub expected_sequence: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UserDiffRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "3")]
+    pub from_sequence: u64,
+    #[prost(uint64, tag = "4")]
+    pub to_sequence: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FieldDiffResponse {
+    #[prost(string, tag = "1")]
+    pub field: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub sequence: u64,
+    #[prost(string, optional, tag = "3")]
+    pub old_value: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "4")]
+    pub new_value: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag = "5")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "6")]
+    pub datetime: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, tag = "7")]
+    pub redacted: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UserDiffResponse {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub from_sequence: u64,
+    #[prost(uint64, tag = "3")]
+    pub to_sequence: u64,
+    #[prost(message, repeated, tag = "4")]
+    pub changes: ::prost::alloc::vec::Vec<FieldDiffResponse>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateOrganisationRequest {
+    /// becomes the first admin
+    #[prost(string, tag = "1")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(enumeration = "OrganisationRelation", tag = "3")]
+    pub relation: i32,
+}
+/// dates are RFC 3339, start_date defaults to the time the request was made
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddMemberRequest {
+    #[prost(string, tag = "1")]
+    pub organisation_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "4")]
+    pub start_date: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "5")]
+    pub end_date: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, optional, tag = "6")]
+    pub expected_sequence: ::core::option::Option<u64>,
+}
+/// end_date defaults to the time the request was made
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EndMembershipRequest {
+    #[prost(string, tag = "1")]
+    pub organisation_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "4")]
+    pub end_date: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, optional, tag = "5")]
+    pub expected_sequence: ::core::option::Option<u64>,
+}
+/// set exactly one of organisation_id (admins only) or user_id (that user only)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListMembershipsRequest {
+    #[prost(string, tag = "1")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "2")]
+    pub organisation_id: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "3")]
+    pub user_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MembershipResponse {
+    #[prost(string, tag = "1")]
+    pub organisation_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub organisation_name: ::prost::alloc::string::String,
+    #[prost(enumeration = "OrganisationRelation", tag = "3")]
+    pub relation: i32,
+    #[prost(string, tag = "4")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub start_date: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "6")]
+    pub end_date: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, tag = "7")]
+    pub active: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrganisationResponse {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(enumeration = "OrganisationRelation", tag = "3")]
+    pub relation: i32,
+    #[prost(string, repeated, tag = "4")]
+    pub admins: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// admins see every membership, members only their own
+    #[prost(message, repeated, tag = "5")]
+    pub memberships: ::prost::alloc::vec::Vec<MembershipResponse>,
+    #[prost(uint64, tag = "6")]
+    pub sequence: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListMembershipsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub memberships: ::prost::alloc::vec::Vec<MembershipResponse>,
+}
+/// key names the attribute for DETAIL_ATTRIBUTE, or a single key alias for CHAIN_KEYS. Left unset
+/// CHAIN_KEYS refers to every key the user holds
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FieldReference {
+    #[prost(enumeration = "ValueReference", tag = "1")]
+    pub reference: i32,
+    #[prost(string, optional, tag = "2")]
+    pub key: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// calling_user must be an admin of the organisation
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestAccessRequest {
+    #[prost(string, tag = "1")]
+    pub organisation_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "4")]
+    pub fields: ::prost::alloc::vec::Vec<FieldReference>,
+    /// RFC 3339, access granted on approval lapses at this time
+    #[prost(string, optional, tag = "5")]
+    pub expires_at: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccessRequestResponse {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub organisation_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub organisation_name: ::prost::alloc::string::String,
+    #[prost(enumeration = "OrganisationRelation", tag = "4")]
+    pub relation: i32,
+    #[prost(string, tag = "5")]
+    pub requested_by: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "6")]
+    pub fields: ::prost::alloc::vec::Vec<FieldReference>,
+    #[prost(string, tag = "7")]
+    pub created: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "8")]
+    pub expires_at: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPendingRequestsRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPendingRequestsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub requests: ::prost::alloc::vec::Vec<AccessRequestResponse>,
+}
+/// approving makes the organisation a viewer of every requested field
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RespondAccessRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub request_id: ::prost::alloc::string::String,
+    #[prost(bool, tag = "4")]
+    pub approve: bool,
+    #[prost(uint64, optional, tag = "5")]
+    pub expected_sequence: ::core::option::Option<u64>,
+}
+/// owned by the calling user, or by organisation_id when the calling user is one of its admins
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateGroupRequest {
+    #[prost(string, tag = "1")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "3")]
+    pub organisation_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// members can remove themselves, anything else needs the group's owner
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GroupMemberRequest {
+    #[prost(string, tag = "1")]
+    pub group_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag = "4")]
+    pub expected_sequence: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GroupResponse {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub owner_id: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag = "4")]
+    pub members: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(uint64, tag = "5")]
+    pub sequence: u64,
+}
+/// every member of the group gets the role on each field, resolved when access is checked
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GroupAccessRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub group_id: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "4")]
+    pub fields: ::prost::alloc::vec::Vec<FieldReference>,
+    #[prost(enumeration = "GrantRole", tag = "5")]
+    pub role: i32,
+    #[prost(uint64, optional, tag = "6")]
+    pub expected_sequence: ::core::option::Option<u64>,
+}
+/// takes the role away from grantee_id on each field before its grant expires
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RevokeGrantRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub grantee_id: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "4")]
+    pub fields: ::prost::alloc::vec::Vec<FieldReference>,
+    #[prost(enumeration = "GrantRole", tag = "5")]
+    pub role: i32,
+    #[prost(uint64, optional, tag = "6")]
+    pub expected_sequence: ::core::option::Option<u64>,
+}
+/// matched against the calling user's memberships and the time of the request
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolicyCondition {
+    #[prost(oneof = "policy_condition::Condition", tags = "1, 2, 3, 4, 5, 6")]
+    pub condition: ::core::option::Option<policy_condition::Condition>,
+}
+/// Nested message and enum types in `PolicyCondition`.
+pub mod policy_condition {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Condition {
+        /// an active membership of the organisation
+        #[prost(string, tag = "1")]
+        MemberOf(::prost::alloc::string::String),
+        /// an active membership of any organisation with this relation
+        #[prost(enumeration = "super::OrganisationRelation", tag = "2")]
+        MemberOfRelation(i32),
+        #[prost(message, tag = "3")]
+        All(super::PolicyConditions),
+        #[prost(message, tag = "4")]
+        Any(super::PolicyConditions),
+        /// RFC 3339
+        #[prost(string, tag = "5")]
+        NotBefore(::prost::alloc::string::String),
+        #[prost(string, tag = "6")]
+        NotAfter(::prost::alloc::string::String),
     }
-    struct _Inner<T>(Arc<T>);
-    impl<T: Beco> BecoServer<T> {
-        pub fn new(inner: T) -> Self {
-            Self::from_arc(Arc::new(inner))
-        }
-        pub fn from_arc(inner: Arc<T>) -> Self {
-            let inner = _Inner(inner);
-            Self {
-                inner,
-                accept_compression_encodings: Default::default(),
-                send_compression_encodings: Default::default(),
-                max_decoding_message_size: None,
-                max_encoding_message_size: None,
-            }
-        }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> InterceptedService<Self, F>
-        where
-            F: tonic::service::Interceptor,
-        {
-            InterceptedService::new(Self::new(inner), interceptor)
-        }
-        /// Enable decompressing requests with the given encoding.
-        #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.accept_compression_encodings.enable(encoding);
-            self
-        }
-        /// Compress responses with the given encoding, if the client supports it.
-        #[must_use]
-        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.send_compression_encodings.enable(encoding);
-            self
-        }
-        /// Limits the maximum size of a decoded message.
-        ///
-        /// Default: `4MB`
-        #[must_use]
-        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.max_decoding_message_size = Some(limit);
-            self
-        }
-        /// Limits the maximum size of an encoded message.
-        ///
-        /// Default: `usize::MAX`
-        #[must_use]
-        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.max_encoding_message_size = Some(limit);
-            self
-        }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolicyConditions {
+    #[prost(message, repeated, tag = "1")]
+    pub conditions: ::prost::alloc::vec::Vec<PolicyCondition>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccessPolicy {
+    #[prost(enumeration = "GrantRole", tag = "1")]
+    pub role: i32,
+    #[prost(message, optional, tag = "2")]
+    pub condition: ::core::option::Option<PolicyCondition>,
+}
+/// replaces every policy on the field, only the owner can set them
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetFieldPolicyRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub field: ::core::option::Option<FieldReference>,
+    #[prost(message, repeated, tag = "4")]
+    pub policies: ::prost::alloc::vec::Vec<AccessPolicy>,
+    #[prost(uint64, optional, tag = "5")]
+    pub expected_sequence: ::core::option::Option<u64>,
+}
+/// only the owner can change the visibility of a field
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetFieldVisibilityRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub fields: ::prost::alloc::vec::Vec<FieldReference>,
+    #[prost(enumeration = "Visibility", tag = "4")]
+    pub visibility: i32,
+    #[prost(uint64, optional, tag = "5")]
+    pub expected_sequence: ::core::option::Option<u64>,
+}
+/// calling_user needs editor rights on the key, or on the chain keys as a whole
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveAccountRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(enumeration = "Blockchain", tag = "3")]
+    pub blockchain: i32,
+    #[prost(string, tag = "4")]
+    pub alias: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag = "5")]
+    pub expected_sequence: ::core::option::Option<u64>,
+}
+/// signs message with the key's private key, which never leaves the node
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SignWithAccountRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(enumeration = "Blockchain", tag = "3")]
+    pub blockchain: i32,
+    #[prost(string, tag = "4")]
+    pub alias: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "5")]
+    pub message: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SignWithAccountResponse {
+    #[prost(string, tag = "1")]
+    pub alias: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub public_key: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub signature: ::prost::alloc::string::String,
+}
+/// only the user can list who read their fields, since is an RFC 3339 date
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAccessLogRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "3")]
+    pub since: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AccessRecordResponse {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub viewer_id: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub fields: ::prost::alloc::vec::Vec<FieldReference>,
+    #[prost(string, tag = "4")]
+    pub read_at: ::prost::alloc::string::String,
+    /// the peer id of the node that served the read
+    #[prost(string, tag = "5")]
+    pub node: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAccessLogResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub records: ::prost::alloc::vec::Vec<AccessRecordResponse>,
+}
+/// set by the dependant, until is the RFC 3339 date the guardian's rights end
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetGuardianRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub guardian_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub until: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag = "5")]
+    pub expected_sequence: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GuardianResponse {
+    #[prost(string, tag = "1")]
+    pub guardian_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub until: ::prost::alloc::string::String,
+}
+/// once the user has an auth key, signature is by that key over
+/// "auth-key:<user_id>:<sequence>:<public_key>" where sequence is the user's current sequence
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetAuthKeyRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+    /// hex encoded SEC1 secp256k1 public key
+    #[prost(string, tag = "3")]
+    pub public_key: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag = "4")]
+    pub expected_sequence: ::core::option::Option<u64>,
+    #[prost(string, tag = "5")]
+    pub signature: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RecoveryGuardian {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    /// must match the guardian's current auth key
+    #[prost(string, tag = "2")]
+    pub public_key: ::prost::alloc::string::String,
+}
+/// no guardians turns recovery off. Once the user has an auth key, signature is by that key over
+/// "recovery-guardians:<user_id>:<sequence>:<threshold>:<user_id>=<public_key>,..." with the
+/// guardians in request order
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetRecoveryGuardiansRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub guardians: ::prost::alloc::vec::Vec<RecoveryGuardian>,
+    #[prost(uint32, tag = "4")]
+    pub threshold: u32,
+    #[prost(uint64, optional, tag = "5")]
+    pub expected_sequence: ::core::option::Option<u64>,
+    #[prost(string, tag = "6")]
+    pub signature: ::prost::alloc::string::String,
+}
+/// signature is the hex encoded 64 byte ECDSA signature by the guardian's auth key over
+/// "recover:<user_id>:<epoch>:<public_key>", epoch is RecoveryResponse.epoch
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ApproveRecoveryRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub public_key: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub signature: ::prost::alloc::string::String,
+}
+/// signature is by the user's auth key over "cancel-recovery:<user_id>:<sequence>"
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelRecoveryRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag = "3")]
+    pub expected_sequence: ::core::option::Option<u64>,
+    #[prost(string, tag = "4")]
+    pub signature: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RecoveryResponse {
+    #[prost(string, repeated, tag = "1")]
+    pub guardian_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(uint32, tag = "2")]
+    pub threshold: u32,
+    #[prost(string, optional, tag = "3")]
+    pub pending_public_key: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag = "4")]
+    pub approvals: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// the recovery completes after this RFC 3339 date unless the user cancels it
+    #[prost(string, optional, tag = "5")]
+    pub ready_at: ::core::option::Option<::prost::alloc::string::String>,
+    /// changes when a recovery is cancelled, completed or its guardians replaced
+    #[prost(uint64, tag = "6")]
+    pub epoch: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Approver {
+    #[prost(oneof = "approver::Kind", tags = "1, 2, 3")]
+    pub kind: ::core::option::Option<approver::Kind>,
+}
+/// Nested message and enum types in `Approver`.
+pub mod approver {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Kind {
+        #[prost(string, tag = "1")]
+        UserId(::prost::alloc::string::String),
+        /// whoever is the user's guardian when the change is approved
+        #[prost(bool, tag = "2")]
+        Guardian(bool),
+        /// any admin of the organisation
+        #[prost(string, tag = "3")]
+        OrganisationAdmin(::prost::alloc::string::String),
     }
-    impl<T, B> tonic::codegen::Service<http::Request<B>> for BecoServer<T>
-    where
-        T: Beco,
-        B: Body + Send + 'static,
-        B::Error: Into<StdError> + Send + 'static,
-    {
-        type Response = http::Response<tonic::body::BoxBody>;
-        type Error = std::convert::Infallible;
-        type Future = BoxFuture<Self::Response, Self::Error>;
-        fn poll_ready(
-            &mut self,
-            _cx: &mut Context<'_>,
-        ) -> Poll<std::result::Result<(), Self::Error>> {
-            Poll::Ready(Ok(()))
+}
+/// replaces the policy on the field, no approvers removes it. While a policy is set, changes to the
+/// field and to its policy wait for required approvals from distinct approvers other than the
+/// caller. A policy on CHAIN_KEYS without a key covers every key
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetApprovalPolicyRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub field: ::core::option::Option<FieldReference>,
+    #[prost(message, repeated, tag = "4")]
+    pub approvers: ::prost::alloc::vec::Vec<Approver>,
+    #[prost(uint32, tag = "5")]
+    pub required: u32,
+    #[prost(uint64, optional, tag = "6")]
+    pub expected_sequence: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPendingApprovalsRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingApprovalResponse {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub field: ::core::option::Option<FieldReference>,
+    #[prost(string, tag = "3")]
+    pub requested_by: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "4")]
+    pub approvers: ::prost::alloc::vec::Vec<Approver>,
+    #[prost(uint32, tag = "5")]
+    pub required: u32,
+    #[prost(string, repeated, tag = "6")]
+    pub approvals: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag = "7")]
+    pub created: ::prost::alloc::string::String,
+    /// RFC 3339, the change is dropped if it is not approved by then
+    #[prost(string, tag = "8")]
+    pub expires_at: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPendingApprovalsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub approvals: ::prost::alloc::vec::Vec<PendingApprovalResponse>,
+}
+/// the change is applied once the last required approval is given
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ApproveChangeRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub approval_id: ::prost::alloc::string::String,
+}
+/// calling_user must be an admin of the organisation. value_hash is the hex encoded sha256 of the
+/// JSON encoding of the field's value, and signature is the hex encoded 64 byte ECDSA signature by
+/// the admin's auth key over "attest:<user_id>:<organisation_id>:<reference>:<key>:<value_hash>:<claim>"
+/// with the reference's name and an empty key for the names
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttestFieldRequest {
+    #[prost(string, tag = "1")]
+    pub organisation_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "4")]
+    pub field: ::core::option::Option<FieldReference>,
+    #[prost(string, tag = "5")]
+    pub value_hash: ::prost::alloc::string::String,
+    /// what the value was checked against, e.g. "passport"
+    #[prost(string, tag = "6")]
+    pub claim: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub signature: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttestationResponse {
+    #[prost(string, tag = "1")]
+    pub organisation_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub organisation_name: ::prost::alloc::string::String,
+    #[prost(enumeration = "OrganisationRelation", tag = "3")]
+    pub relation: i32,
+    #[prost(message, optional, tag = "4")]
+    pub field: ::core::option::Option<FieldReference>,
+    #[prost(string, tag = "5")]
+    pub value_hash: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub claim: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub attested_by: ::prost::alloc::string::String,
+    /// the admin's auth key the signature verifies against
+    #[prost(string, tag = "8")]
+    pub public_key: ::prost::alloc::string::String,
+    #[prost(string, tag = "9")]
+    pub signature: ::prost::alloc::string::String,
+    #[prost(string, tag = "10")]
+    pub attested_at: ::prost::alloc::string::String,
+    #[prost(enumeration = "AttestationStatus", tag = "11")]
+    pub status: i32,
+}
+/// only the user can issue a credential about themselves. fields are the names and chain keys,
+/// each chain key by its alias
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IssueCredentialRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub fields: ::prost::alloc::vec::Vec<FieldReference>,
+    #[prost(uint64, optional, tag = "4")]
+    pub expected_sequence: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IssueCredentialResponse {
+    #[prost(string, tag = "1")]
+    pub credential_id: ::prost::alloc::string::String,
+    /// the verifiable credential as a JWT signed by the sst's issuer key with ES256K
+    #[prost(string, tag = "2")]
+    pub jwt: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VerifyCredentialRequest {
+    #[prost(string, tag = "1")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub jwt: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VerifyCredentialResponse {
+    #[prost(string, tag = "1")]
+    pub credential_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "CredentialStatus", tag = "3")]
+    pub status: i32,
+    /// the JSON encoded credential subject
+    #[prost(string, tag = "4")]
+    pub credential_subject: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub issued_at: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub expires_at: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RevokeCredentialRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub credential_id: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag = "4")]
+    pub expected_sequence: ::core::option::Option<u64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CredentialStatusListRequest {
+    #[prost(string, tag = "1")]
+    pub user_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub calling_user: ::prost::alloc::string::String,
+}
+/// bit n is set when the credential with status index n is revoked, most significant bit first
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CredentialStatusListResponse {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// base64url encoded without padding and not compressed
+    #[prost(string, tag = "2")]
+    pub encoded_list: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Blockchain {
+    Unspecified = 0,
+    Xrpl = 1,
+    Evm = 2,
+}
+impl Blockchain {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Blockchain::Unspecified => "UNSPECIFIED",
+            Blockchain::Xrpl => "XRPL",
+            Blockchain::Evm => "EVM",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "UNSPECIFIED" => Some(Self::Unspecified),
+            "XRPL" => Some(Self::Xrpl),
+            "EVM" => Some(Self::Evm),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum OrganisationRelation {
+    Unspecified = 0,
+    Employer = 1,
+    Government = 2,
+}
+impl OrganisationRelation {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            OrganisationRelation::Unspecified => "ORGANISATION_RELATION_UNSPECIFIED",
+            OrganisationRelation::Employer => "EMPLOYER",
+            OrganisationRelation::Government => "GOVERNMENT",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ORGANISATION_RELATION_UNSPECIFIED" => Some(Self::Unspecified),
+            "EMPLOYER" => Some(Self::Employer),
+            "GOVERNMENT" => Some(Self::Government),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ValueReference {
+    Unspecified = 0,
+    DetailFirstName = 1,
+    DetailOtherNames = 2,
+    DetailLastName = 3,
+    ChainKeys = 4,
+    DetailAttribute = 5,
+}
+impl ValueReference {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ValueReference::Unspecified => "VALUE_REFERENCE_UNSPECIFIED",
+            ValueReference::DetailFirstName => "DETAIL_FIRST_NAME",
+            ValueReference::DetailOtherNames => "DETAIL_OTHER_NAMES",
+            ValueReference::DetailLastName => "DETAIL_LAST_NAME",
+            ValueReference::ChainKeys => "CHAIN_KEYS",
+            ValueReference::DetailAttribute => "DETAIL_ATTRIBUTE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "VALUE_REFERENCE_UNSPECIFIED" => Some(Self::Unspecified),
+            "DETAIL_FIRST_NAME" => Some(Self::DetailFirstName),
+            "DETAIL_OTHER_NAMES" => Some(Self::DetailOtherNames),
+            "DETAIL_LAST_NAME" => Some(Self::DetailLastName),
+            "CHAIN_KEYS" => Some(Self::ChainKeys),
+            "DETAIL_ATTRIBUTE" => Some(Self::DetailAttribute),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum GrantRole {
+    Unspecified = 0,
+    Viewer = 1,
+    Editor = 2,
+}
+impl GrantRole {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            GrantRole::Unspecified => "GRANT_ROLE_UNSPECIFIED",
+            GrantRole::Viewer => "VIEWER",
+            GrantRole::Editor => "EDITOR",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "GRANT_ROLE_UNSPECIFIED" => Some(Self::Unspecified),
+            "VIEWER" => Some(Self::Viewer),
+            "EDITOR" => Some(Self::Editor),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Visibility {
+    Unspecified = 0,
+    /// only the owner and editors
+    Private = 1,
+    /// the owner and anyone granted access, the default
+    Shared = 2,
+    /// anyone
+    Public = 3,
+}
+impl Visibility {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Visibility::Unspecified => "VISIBILITY_UNSPECIFIED",
+            Visibility::Private => "PRIVATE",
+            Visibility::Shared => "SHARED",
+            Visibility::Public => "PUBLIC",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "VISIBILITY_UNSPECIFIED" => Some(Self::Unspecified),
+            "PRIVATE" => Some(Self::Private),
+            "SHARED" => Some(Self::Shared),
+            "PUBLIC" => Some(Self::Public),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AttestationStatus {
+    Unspecified = 0,
+    Valid = 1,
+    /// the field changed after it was attested
+    Invalidated = 2,
+}
+impl AttestationStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            AttestationStatus::Unspecified => "ATTESTATION_STATUS_UNSPECIFIED",
+            AttestationStatus::Valid => "VALID",
+            AttestationStatus::Invalidated => "INVALIDATED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ATTESTATION_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "VALID" => Some(Self::Valid),
+            "INVALIDATED" => Some(Self::Invalidated),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CredentialStatus {
+    Unspecified = 0,
+    Active = 1,
+    Revoked = 2,
+    Expired = 3,
+}
+impl CredentialStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            CredentialStatus::Unspecified => "CREDENTIAL_STATUS_UNSPECIFIED",
+            CredentialStatus::Active => "ACTIVE",
+            CredentialStatus::Revoked => "REVOKED",
+            CredentialStatus::Expired => "EXPIRED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CREDENTIAL_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "ACTIVE" => Some(Self::Active),
+            "REVOKED" => Some(Self::Revoked),
+            "EXPIRED" => Some(Self::Expired),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod beco_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct BecoClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl BecoClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> BecoClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> BecoClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            BecoClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        pub async fn list_user(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListUserRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/beco.Beco/ListUser");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "ListUser"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn add_user(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddUserRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/beco.Beco/AddUser");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "AddUser"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn add_linked_user(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ModifyLinkedUserRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/beco.Beco/AddLinkedUser");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "AddLinkedUser"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn remove_linked_user(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ModifyLinkedUserRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/beco.Beco/RemoveLinkedUser",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("beco.Beco", "RemoveLinkedUser"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn update_first_name(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ModifyNameRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/beco.Beco/UpdateFirstName",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "UpdateFirstName"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn update_other_names(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ModifyOtherNamesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/beco.Beco/UpdateOtherNames",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("beco.Beco", "UpdateOtherNames"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn update_last_name(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ModifyNameRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/beco.Beco/UpdateLastName");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "UpdateLastName"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn add_account(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddAccountRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/beco.Beco/AddAccount");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "AddAccount"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn remove_account(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RemoveAccountRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/beco.Beco/RemoveAccount");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "RemoveAccount"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn sign_with_account(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SignWithAccountRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SignWithAccountResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/beco.Beco/SignWithAccount",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "SignWithAccount"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn set_attribute(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetAttributeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/beco.Beco/SetAttribute");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "SetAttribute"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn clear_attribute(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ClearAttributeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/beco.Beco/ClearAttribute");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "ClearAttribute"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_user_diff(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UserDiffRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UserDiffResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/beco.Beco/GetUserDiff");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "GetUserDiff"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn create_organisation(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateOrganisationRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::OrganisationResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/beco.Beco/CreateOrganisation",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("beco.Beco", "CreateOrganisation"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn add_member(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddMemberRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::OrganisationResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/beco.Beco/AddMember");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "AddMember"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn end_membership(
+            &mut self,
+            request: impl tonic::IntoRequest<super::EndMembershipRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::OrganisationResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/beco.Beco/EndMembership");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "EndMembership"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_memberships(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListMembershipsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListMembershipsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/beco.Beco/ListMemberships",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "ListMemberships"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn request_access(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RequestAccessRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/beco.Beco/RequestAccess");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "RequestAccess"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_pending_requests(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListPendingRequestsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPendingRequestsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/beco.Beco/ListPendingRequests",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("beco.Beco", "ListPendingRequests"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn respond_to_access_request(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RespondAccessRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/beco.Beco/RespondToAccessRequest",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("beco.Beco", "RespondToAccessRequest"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn create_group(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateGroupRequest>,
+        ) -> std::result::Result<tonic::Response<super::GroupResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/beco.Beco/CreateGroup");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "CreateGroup"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn add_group_member(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GroupMemberRequest>,
+        ) -> std::result::Result<tonic::Response<super::GroupResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/beco.Beco/AddGroupMember");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "AddGroupMember"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn remove_group_member(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GroupMemberRequest>,
+        ) -> std::result::Result<tonic::Response<super::GroupResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/beco.Beco/RemoveGroupMember",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("beco.Beco", "RemoveGroupMember"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn grant_group_access(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GroupAccessRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/beco.Beco/GrantGroupAccess",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("beco.Beco", "GrantGroupAccess"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn revoke_group_access(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GroupAccessRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/beco.Beco/RevokeGroupAccess",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("beco.Beco", "RevokeGroupAccess"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn revoke_grant(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RevokeGrantRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/beco.Beco/RevokeGrant");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "RevokeGrant"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn set_field_policy(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetFieldPolicyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/beco.Beco/SetFieldPolicy");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "SetFieldPolicy"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn set_field_visibility(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetFieldVisibilityRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/beco.Beco/SetFieldVisibility",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("beco.Beco", "SetFieldVisibility"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_access_log(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListAccessLogRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListAccessLogResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/beco.Beco/ListAccessLog");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "ListAccessLog"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn set_guardian(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetGuardianRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/beco.Beco/SetGuardian");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "SetGuardian"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn set_auth_key(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetAuthKeyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/beco.Beco/SetAuthKey");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "SetAuthKey"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn set_recovery_guardians(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetRecoveryGuardiansRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/beco.Beco/SetRecoveryGuardians",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("beco.Beco", "SetRecoveryGuardians"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn approve_recovery(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ApproveRecoveryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/beco.Beco/ApproveRecovery",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "ApproveRecovery"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn cancel_recovery(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CancelRecoveryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/beco.Beco/CancelRecovery");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "CancelRecovery"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn set_approval_policy(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetApprovalPolicyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/beco.Beco/SetApprovalPolicy",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("beco.Beco", "SetApprovalPolicy"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_pending_approvals(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListPendingApprovalsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPendingApprovalsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/beco.Beco/ListPendingApprovals",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("beco.Beco", "ListPendingApprovals"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn approve_change(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ApproveChangeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/beco.Beco/ApproveChange");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "ApproveChange"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn attest_field(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AttestFieldRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/beco.Beco/AttestField");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "AttestField"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn issue_credential(
+            &mut self,
+            request: impl tonic::IntoRequest<super::IssueCredentialRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::IssueCredentialResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/beco.Beco/IssueCredential",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("beco.Beco", "IssueCredential"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn verify_credential(
+            &mut self,
+            request: impl tonic::IntoRequest<super::VerifyCredentialRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::VerifyCredentialResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/beco.Beco/VerifyCredential",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("beco.Beco", "VerifyCredential"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn revoke_credential(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RevokeCredentialRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetUserResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/beco.Beco/RevokeCredential",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("beco.Beco", "RevokeCredential"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_credential_status_list(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CredentialStatusListRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CredentialStatusListResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/beco.Beco/GetCredentialStatusList",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("beco.Beco", "GetCredentialStatusList"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod beco_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with BecoServer.
+    #[async_trait]
+    pub trait Beco: Send + Sync + 'static {
+        async fn list_user(
+            &self,
+            request: tonic::Request<super::ListUserRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListUserResponse>,
+            tonic::Status,
+        >;
+        async fn add_user(
+            &self,
+            request: tonic::Request<super::AddUserRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn add_linked_user(
+            &self,
+            request: tonic::Request<super::ModifyLinkedUserRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn remove_linked_user(
+            &self,
+            request: tonic::Request<super::ModifyLinkedUserRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn update_first_name(
+            &self,
+            request: tonic::Request<super::ModifyNameRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn update_other_names(
+            &self,
+            request: tonic::Request<super::ModifyOtherNamesRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn update_last_name(
+            &self,
+            request: tonic::Request<super::ModifyNameRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn add_account(
+            &self,
+            request: tonic::Request<super::AddAccountRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn remove_account(
+            &self,
+            request: tonic::Request<super::RemoveAccountRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn sign_with_account(
+            &self,
+            request: tonic::Request<super::SignWithAccountRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SignWithAccountResponse>,
+            tonic::Status,
+        >;
+        async fn set_attribute(
+            &self,
+            request: tonic::Request<super::SetAttributeRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn clear_attribute(
+            &self,
+            request: tonic::Request<super::ClearAttributeRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn get_user_diff(
+            &self,
+            request: tonic::Request<super::UserDiffRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UserDiffResponse>,
+            tonic::Status,
+        >;
+        async fn create_organisation(
+            &self,
+            request: tonic::Request<super::CreateOrganisationRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::OrganisationResponse>,
+            tonic::Status,
+        >;
+        async fn add_member(
+            &self,
+            request: tonic::Request<super::AddMemberRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::OrganisationResponse>,
+            tonic::Status,
+        >;
+        async fn end_membership(
+            &self,
+            request: tonic::Request<super::EndMembershipRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::OrganisationResponse>,
+            tonic::Status,
+        >;
+        async fn list_memberships(
+            &self,
+            request: tonic::Request<super::ListMembershipsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListMembershipsResponse>,
+            tonic::Status,
+        >;
+        async fn request_access(
+            &self,
+            request: tonic::Request<super::RequestAccessRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn list_pending_requests(
+            &self,
+            request: tonic::Request<super::ListPendingRequestsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPendingRequestsResponse>,
+            tonic::Status,
+        >;
+        async fn respond_to_access_request(
+            &self,
+            request: tonic::Request<super::RespondAccessRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn create_group(
+            &self,
+            request: tonic::Request<super::CreateGroupRequest>,
+        ) -> std::result::Result<tonic::Response<super::GroupResponse>, tonic::Status>;
+        async fn add_group_member(
+            &self,
+            request: tonic::Request<super::GroupMemberRequest>,
+        ) -> std::result::Result<tonic::Response<super::GroupResponse>, tonic::Status>;
+        async fn remove_group_member(
+            &self,
+            request: tonic::Request<super::GroupMemberRequest>,
+        ) -> std::result::Result<tonic::Response<super::GroupResponse>, tonic::Status>;
+        async fn grant_group_access(
+            &self,
+            request: tonic::Request<super::GroupAccessRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn revoke_group_access(
+            &self,
+            request: tonic::Request<super::GroupAccessRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn revoke_grant(
+            &self,
+            request: tonic::Request<super::RevokeGrantRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn set_field_policy(
+            &self,
+            request: tonic::Request<super::SetFieldPolicyRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn set_field_visibility(
+            &self,
+            request: tonic::Request<super::SetFieldVisibilityRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn list_access_log(
+            &self,
+            request: tonic::Request<super::ListAccessLogRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListAccessLogResponse>,
+            tonic::Status,
+        >;
+        async fn set_guardian(
+            &self,
+            request: tonic::Request<super::SetGuardianRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn set_auth_key(
+            &self,
+            request: tonic::Request<super::SetAuthKeyRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn set_recovery_guardians(
+            &self,
+            request: tonic::Request<super::SetRecoveryGuardiansRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn approve_recovery(
+            &self,
+            request: tonic::Request<super::ApproveRecoveryRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn cancel_recovery(
+            &self,
+            request: tonic::Request<super::CancelRecoveryRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn set_approval_policy(
+            &self,
+            request: tonic::Request<super::SetApprovalPolicyRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn list_pending_approvals(
+            &self,
+            request: tonic::Request<super::ListPendingApprovalsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPendingApprovalsResponse>,
+            tonic::Status,
+        >;
+        async fn approve_change(
+            &self,
+            request: tonic::Request<super::ApproveChangeRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn attest_field(
+            &self,
+            request: tonic::Request<super::AttestFieldRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn issue_credential(
+            &self,
+            request: tonic::Request<super::IssueCredentialRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::IssueCredentialResponse>,
+            tonic::Status,
+        >;
+        async fn verify_credential(
+            &self,
+            request: tonic::Request<super::VerifyCredentialRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::VerifyCredentialResponse>,
+            tonic::Status,
+        >;
+        async fn revoke_credential(
+            &self,
+            request: tonic::Request<super::RevokeCredentialRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetUserResponse>, tonic::Status>;
+        async fn get_credential_status_list(
+            &self,
+            request: tonic::Request<super::CredentialStatusListRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CredentialStatusListResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct BecoServer<T: Beco> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: Beco> BecoServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for BecoServer<T>
+    where
+        T: Beco,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
         }
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
             let inner = self.inner.clone();
             match req.uri().path() {
                 "/beco.Beco/ListUser" => {
                     #[allow(non_camel_case_types)]
-                    struct ListUserSvc<T: Beco>(pub Arc<T>);
-                    impl<T: Beco> tonic::server::UnaryService<super::ListUserRequest>
-                    for ListUserSvc<T> {
-                        type Response = super::ListUserResponse;
+                    struct ListUserSvc<T: Beco>(pub Arc<T>);
+                    impl<T: Beco> tonic::server::UnaryService<super::ListUserRequest>
+                    for ListUserSvc<T> {
+                        type Response = super::ListUserResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListUserRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::list_user(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListUserSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/AddUser" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddUserSvc<T: Beco>(pub Arc<T>);
+                    impl<T: Beco> tonic::server::UnaryService<super::AddUserRequest>
+                    for AddUserSvc<T> {
+                        type Response = super::GetUserResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddUserRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::add_user(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AddUserSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/AddLinkedUser" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddLinkedUserSvc<T: Beco>(pub Arc<T>);
+                    impl<
+                        T: Beco,
+                    > tonic::server::UnaryService<super::ModifyLinkedUserRequest>
+                    for AddLinkedUserSvc<T> {
+                        type Response = super::GetUserResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ModifyLinkedUserRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::add_linked_user(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AddLinkedUserSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/RemoveLinkedUser" => {
+                    #[allow(non_camel_case_types)]
+                    struct RemoveLinkedUserSvc<T: Beco>(pub Arc<T>);
+                    impl<
+                        T: Beco,
+                    > tonic::server::UnaryService<super::ModifyLinkedUserRequest>
+                    for RemoveLinkedUserSvc<T> {
+                        type Response = super::GetUserResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ModifyLinkedUserRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::remove_linked_user(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RemoveLinkedUserSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/UpdateFirstName" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateFirstNameSvc<T: Beco>(pub Arc<T>);
+                    impl<T: Beco> tonic::server::UnaryService<super::ModifyNameRequest>
+                    for UpdateFirstNameSvc<T> {
+                        type Response = super::GetUserResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ModifyNameRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::update_first_name(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UpdateFirstNameSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/UpdateOtherNames" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateOtherNamesSvc<T: Beco>(pub Arc<T>);
+                    impl<
+                        T: Beco,
+                    > tonic::server::UnaryService<super::ModifyOtherNamesRequest>
+                    for UpdateOtherNamesSvc<T> {
+                        type Response = super::GetUserResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ModifyOtherNamesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::update_other_names(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UpdateOtherNamesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/UpdateLastName" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateLastNameSvc<T: Beco>(pub Arc<T>);
+                    impl<T: Beco> tonic::server::UnaryService<super::ModifyNameRequest>
+                    for UpdateLastNameSvc<T> {
+                        type Response = super::GetUserResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ModifyNameRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::update_last_name(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UpdateLastNameSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/AddAccount" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddAccountSvc<T: Beco>(pub Arc<T>);
+                    impl<T: Beco> tonic::server::UnaryService<super::AddAccountRequest>
+                    for AddAccountSvc<T> {
+                        type Response = super::GetUserResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddAccountRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::add_account(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AddAccountSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/RemoveAccount" => {
+                    #[allow(non_camel_case_types)]
+                    struct RemoveAccountSvc<T: Beco>(pub Arc<T>);
+                    impl<
+                        T: Beco,
+                    > tonic::server::UnaryService<super::RemoveAccountRequest>
+                    for RemoveAccountSvc<T> {
+                        type Response = super::GetUserResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RemoveAccountRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::remove_account(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RemoveAccountSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/SignWithAccount" => {
+                    #[allow(non_camel_case_types)]
+                    struct SignWithAccountSvc<T: Beco>(pub Arc<T>);
+                    impl<
+                        T: Beco,
+                    > tonic::server::UnaryService<super::SignWithAccountRequest>
+                    for SignWithAccountSvc<T> {
+                        type Response = super::SignWithAccountResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SignWithAccountRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::sign_with_account(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SignWithAccountSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/SetAttribute" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetAttributeSvc<T: Beco>(pub Arc<T>);
+                    impl<T: Beco> tonic::server::UnaryService<super::SetAttributeRequest>
+                    for SetAttributeSvc<T> {
+                        type Response = super::GetUserResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetAttributeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::set_attribute(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SetAttributeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/ClearAttribute" => {
+                    #[allow(non_camel_case_types)]
+                    struct ClearAttributeSvc<T: Beco>(pub Arc<T>);
+                    impl<
+                        T: Beco,
+                    > tonic::server::UnaryService<super::ClearAttributeRequest>
+                    for ClearAttributeSvc<T> {
+                        type Response = super::GetUserResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ClearAttributeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::clear_attribute(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ClearAttributeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/GetUserDiff" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetUserDiffSvc<T: Beco>(pub Arc<T>);
+                    impl<T: Beco> tonic::server::UnaryService<super::UserDiffRequest>
+                    for GetUserDiffSvc<T> {
+                        type Response = super::UserDiffResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UserDiffRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::get_user_diff(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetUserDiffSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/CreateOrganisation" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateOrganisationSvc<T: Beco>(pub Arc<T>);
+                    impl<
+                        T: Beco,
+                    > tonic::server::UnaryService<super::CreateOrganisationRequest>
+                    for CreateOrganisationSvc<T> {
+                        type Response = super::OrganisationResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateOrganisationRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::create_organisation(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CreateOrganisationSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/AddMember" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddMemberSvc<T: Beco>(pub Arc<T>);
+                    impl<T: Beco> tonic::server::UnaryService<super::AddMemberRequest>
+                    for AddMemberSvc<T> {
+                        type Response = super::OrganisationResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddMemberRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::add_member(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AddMemberSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/EndMembership" => {
+                    #[allow(non_camel_case_types)]
+                    struct EndMembershipSvc<T: Beco>(pub Arc<T>);
+                    impl<
+                        T: Beco,
+                    > tonic::server::UnaryService<super::EndMembershipRequest>
+                    for EndMembershipSvc<T> {
+                        type Response = super::OrganisationResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EndMembershipRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::end_membership(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = EndMembershipSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/ListMemberships" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListMembershipsSvc<T: Beco>(pub Arc<T>);
+                    impl<
+                        T: Beco,
+                    > tonic::server::UnaryService<super::ListMembershipsRequest>
+                    for ListMembershipsSvc<T> {
+                        type Response = super::ListMembershipsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListMembershipsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::list_memberships(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListMembershipsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/RequestAccess" => {
+                    #[allow(non_camel_case_types)]
+                    struct RequestAccessSvc<T: Beco>(pub Arc<T>);
+                    impl<
+                        T: Beco,
+                    > tonic::server::UnaryService<super::RequestAccessRequest>
+                    for RequestAccessSvc<T> {
+                        type Response = super::GetUserResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RequestAccessRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::request_access(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RequestAccessSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/ListPendingRequests" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListPendingRequestsSvc<T: Beco>(pub Arc<T>);
+                    impl<
+                        T: Beco,
+                    > tonic::server::UnaryService<super::ListPendingRequestsRequest>
+                    for ListPendingRequestsSvc<T> {
+                        type Response = super::ListPendingRequestsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListPendingRequestsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::list_pending_requests(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListPendingRequestsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/RespondToAccessRequest" => {
+                    #[allow(non_camel_case_types)]
+                    struct RespondToAccessRequestSvc<T: Beco>(pub Arc<T>);
+                    impl<
+                        T: Beco,
+                    > tonic::server::UnaryService<super::RespondAccessRequest>
+                    for RespondToAccessRequestSvc<T> {
+                        type Response = super::GetUserResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RespondAccessRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::respond_to_access_request(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RespondToAccessRequestSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/CreateGroup" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateGroupSvc<T: Beco>(pub Arc<T>);
+                    impl<T: Beco> tonic::server::UnaryService<super::CreateGroupRequest>
+                    for CreateGroupSvc<T> {
+                        type Response = super::GroupResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateGroupRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::create_group(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CreateGroupSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/AddGroupMember" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddGroupMemberSvc<T: Beco>(pub Arc<T>);
+                    impl<T: Beco> tonic::server::UnaryService<super::GroupMemberRequest>
+                    for AddGroupMemberSvc<T> {
+                        type Response = super::GroupResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GroupMemberRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::add_group_member(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AddGroupMemberSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/RemoveGroupMember" => {
+                    #[allow(non_camel_case_types)]
+                    struct RemoveGroupMemberSvc<T: Beco>(pub Arc<T>);
+                    impl<T: Beco> tonic::server::UnaryService<super::GroupMemberRequest>
+                    for RemoveGroupMemberSvc<T> {
+                        type Response = super::GroupResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GroupMemberRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::remove_group_member(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RemoveGroupMemberSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/GrantGroupAccess" => {
+                    #[allow(non_camel_case_types)]
+                    struct GrantGroupAccessSvc<T: Beco>(pub Arc<T>);
+                    impl<T: Beco> tonic::server::UnaryService<super::GroupAccessRequest>
+                    for GrantGroupAccessSvc<T> {
+                        type Response = super::GetUserResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GroupAccessRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::grant_group_access(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GrantGroupAccessSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/RevokeGroupAccess" => {
+                    #[allow(non_camel_case_types)]
+                    struct RevokeGroupAccessSvc<T: Beco>(pub Arc<T>);
+                    impl<T: Beco> tonic::server::UnaryService<super::GroupAccessRequest>
+                    for RevokeGroupAccessSvc<T> {
+                        type Response = super::GetUserResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GroupAccessRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::revoke_group_access(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RevokeGroupAccessSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/RevokeGrant" => {
+                    #[allow(non_camel_case_types)]
+                    struct RevokeGrantSvc<T: Beco>(pub Arc<T>);
+                    impl<T: Beco> tonic::server::UnaryService<super::RevokeGrantRequest>
+                    for RevokeGrantSvc<T> {
+                        type Response = super::GetUserResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RevokeGrantRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::revoke_grant(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RevokeGrantSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/SetFieldPolicy" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetFieldPolicySvc<T: Beco>(pub Arc<T>);
+                    impl<
+                        T: Beco,
+                    > tonic::server::UnaryService<super::SetFieldPolicyRequest>
+                    for SetFieldPolicySvc<T> {
+                        type Response = super::GetUserResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetFieldPolicyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::set_field_policy(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SetFieldPolicySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/SetFieldVisibility" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetFieldVisibilitySvc<T: Beco>(pub Arc<T>);
+                    impl<
+                        T: Beco,
+                    > tonic::server::UnaryService<super::SetFieldVisibilityRequest>
+                    for SetFieldVisibilitySvc<T> {
+                        type Response = super::GetUserResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetFieldVisibilityRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::set_field_visibility(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SetFieldVisibilitySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/ListAccessLog" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListAccessLogSvc<T: Beco>(pub Arc<T>);
+                    impl<
+                        T: Beco,
+                    > tonic::server::UnaryService<super::ListAccessLogRequest>
+                    for ListAccessLogSvc<T> {
+                        type Response = super::ListAccessLogResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListAccessLogRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::list_access_log(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListAccessLogSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/SetGuardian" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetGuardianSvc<T: Beco>(pub Arc<T>);
+                    impl<T: Beco> tonic::server::UnaryService<super::SetGuardianRequest>
+                    for SetGuardianSvc<T> {
+                        type Response = super::GetUserResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetGuardianRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::set_guardian(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SetGuardianSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/SetAuthKey" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetAuthKeySvc<T: Beco>(pub Arc<T>);
+                    impl<T: Beco> tonic::server::UnaryService<super::SetAuthKeyRequest>
+                    for SetAuthKeySvc<T> {
+                        type Response = super::GetUserResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetAuthKeyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::set_auth_key(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SetAuthKeySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/SetRecoveryGuardians" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetRecoveryGuardiansSvc<T: Beco>(pub Arc<T>);
+                    impl<
+                        T: Beco,
+                    > tonic::server::UnaryService<super::SetRecoveryGuardiansRequest>
+                    for SetRecoveryGuardiansSvc<T> {
+                        type Response = super::GetUserResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ListUserRequest>,
+                            request: tonic::Request<super::SetRecoveryGuardiansRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Beco>::list_user(&inner, request).await
+                                <T as Beco>::set_recovery_guardians(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -497,7 +3959,7 @@ pub mod beco_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = ListUserSvc(inner);
+                        let method = SetRecoveryGuardiansSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -513,11 +3975,13 @@ pub mod beco_server {
                     };
                     Box::pin(fut)
                 }
-                "/beco.Beco/AddUser" => {
+                "/beco.Beco/ApproveRecovery" => {
                     #[allow(non_camel_case_types)]
-                    struct AddUserSvc<T: Beco>(pub Arc<T>);
-                    impl<T: Beco> tonic::server::UnaryService<super::AddUserRequest>
-                    for AddUserSvc<T> {
+                    struct ApproveRecoverySvc<T: Beco>(pub Arc<T>);
+                    impl<
+                        T: Beco,
+                    > tonic::server::UnaryService<super::ApproveRecoveryRequest>
+                    for ApproveRecoverySvc<T> {
                         type Response = super::GetUserResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
@@ -525,11 +3989,11 @@ pub mod beco_server {
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::AddUserRequest>,
+                            request: tonic::Request<super::ApproveRecoveryRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Beco>::add_user(&inner, request).await
+                                <T as Beco>::approve_recovery(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -541,7 +4005,7 @@ pub mod beco_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = AddUserSvc(inner);
+                        let method = ApproveRecoverySvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -557,11 +4021,13 @@ pub mod beco_server {
                     };
                     Box::pin(fut)
                 }
-                "/beco.Beco/UpdateFirstName" => {
+                "/beco.Beco/CancelRecovery" => {
                     #[allow(non_camel_case_types)]
-                    struct UpdateFirstNameSvc<T: Beco>(pub Arc<T>);
-                    impl<T: Beco> tonic::server::UnaryService<super::ModifyNameRequest>
-                    for UpdateFirstNameSvc<T> {
+                    struct CancelRecoverySvc<T: Beco>(pub Arc<T>);
+                    impl<
+                        T: Beco,
+                    > tonic::server::UnaryService<super::CancelRecoveryRequest>
+                    for CancelRecoverySvc<T> {
                         type Response = super::GetUserResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
@@ -569,11 +4035,11 @@ pub mod beco_server {
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ModifyNameRequest>,
+                            request: tonic::Request<super::CancelRecoveryRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Beco>::update_first_name(&inner, request).await
+                                <T as Beco>::cancel_recovery(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -585,7 +4051,7 @@ pub mod beco_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = UpdateFirstNameSvc(inner);
+                        let method = CancelRecoverySvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -601,13 +4067,13 @@ pub mod beco_server {
                     };
                     Box::pin(fut)
                 }
-                "/beco.Beco/UpdateOtherNames" => {
+                "/beco.Beco/SetApprovalPolicy" => {
                     #[allow(non_camel_case_types)]
-                    struct UpdateOtherNamesSvc<T: Beco>(pub Arc<T>);
+                    struct SetApprovalPolicySvc<T: Beco>(pub Arc<T>);
                     impl<
                         T: Beco,
-                    > tonic::server::UnaryService<super::ModifyOtherNamesRequest>
-                    for UpdateOtherNamesSvc<T> {
+                    > tonic::server::UnaryService<super::SetApprovalPolicyRequest>
+                    for SetApprovalPolicySvc<T> {
                         type Response = super::GetUserResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
@@ -615,11 +4081,11 @@ pub mod beco_server {
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ModifyOtherNamesRequest>,
+                            request: tonic::Request<super::SetApprovalPolicyRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Beco>::update_other_names(&inner, request).await
+                                <T as Beco>::set_approval_policy(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -631,7 +4097,7 @@ pub mod beco_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = UpdateOtherNamesSvc(inner);
+                        let method = SetApprovalPolicySvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -647,11 +4113,59 @@ pub mod beco_server {
                     };
                     Box::pin(fut)
                 }
-                "/beco.Beco/UpdateLastName" => {
+                "/beco.Beco/ListPendingApprovals" => {
                     #[allow(non_camel_case_types)]
-                    struct UpdateLastNameSvc<T: Beco>(pub Arc<T>);
-                    impl<T: Beco> tonic::server::UnaryService<super::ModifyNameRequest>
-                    for UpdateLastNameSvc<T> {
+                    struct ListPendingApprovalsSvc<T: Beco>(pub Arc<T>);
+                    impl<
+                        T: Beco,
+                    > tonic::server::UnaryService<super::ListPendingApprovalsRequest>
+                    for ListPendingApprovalsSvc<T> {
+                        type Response = super::ListPendingApprovalsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListPendingApprovalsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::list_pending_approvals(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListPendingApprovalsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/ApproveChange" => {
+                    #[allow(non_camel_case_types)]
+                    struct ApproveChangeSvc<T: Beco>(pub Arc<T>);
+                    impl<
+                        T: Beco,
+                    > tonic::server::UnaryService<super::ApproveChangeRequest>
+                    for ApproveChangeSvc<T> {
                         type Response = super::GetUserResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
@@ -659,11 +4173,11 @@ pub mod beco_server {
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ModifyNameRequest>,
+                            request: tonic::Request<super::ApproveChangeRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Beco>::update_last_name(&inner, request).await
+                                <T as Beco>::approve_change(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -675,7 +4189,7 @@ pub mod beco_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = UpdateLastNameSvc(inner);
+                        let method = ApproveChangeSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -691,11 +4205,11 @@ pub mod beco_server {
                     };
                     Box::pin(fut)
                 }
-                "/beco.Beco/AddAccount" => {
+                "/beco.Beco/AttestField" => {
                     #[allow(non_camel_case_types)]
-                    struct AddAccountSvc<T: Beco>(pub Arc<T>);
-                    impl<T: Beco> tonic::server::UnaryService<super::AddAccountRequest>
-                    for AddAccountSvc<T> {
+                    struct AttestFieldSvc<T: Beco>(pub Arc<T>);
+                    impl<T: Beco> tonic::server::UnaryService<super::AttestFieldRequest>
+                    for AttestFieldSvc<T> {
                         type Response = super::GetUserResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
@@ -703,11 +4217,11 @@ pub mod beco_server {
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::AddAccountRequest>,
+                            request: tonic::Request<super::AttestFieldRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as Beco>::add_account(&inner, request).await
+                                <T as Beco>::attest_field(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -719,7 +4233,192 @@ pub mod beco_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = AddAccountSvc(inner);
+                        let method = AttestFieldSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/IssueCredential" => {
+                    #[allow(non_camel_case_types)]
+                    struct IssueCredentialSvc<T: Beco>(pub Arc<T>);
+                    impl<
+                        T: Beco,
+                    > tonic::server::UnaryService<super::IssueCredentialRequest>
+                    for IssueCredentialSvc<T> {
+                        type Response = super::IssueCredentialResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::IssueCredentialRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::issue_credential(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = IssueCredentialSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/VerifyCredential" => {
+                    #[allow(non_camel_case_types)]
+                    struct VerifyCredentialSvc<T: Beco>(pub Arc<T>);
+                    impl<
+                        T: Beco,
+                    > tonic::server::UnaryService<super::VerifyCredentialRequest>
+                    for VerifyCredentialSvc<T> {
+                        type Response = super::VerifyCredentialResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::VerifyCredentialRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::verify_credential(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = VerifyCredentialSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/RevokeCredential" => {
+                    #[allow(non_camel_case_types)]
+                    struct RevokeCredentialSvc<T: Beco>(pub Arc<T>);
+                    impl<
+                        T: Beco,
+                    > tonic::server::UnaryService<super::RevokeCredentialRequest>
+                    for RevokeCredentialSvc<T> {
+                        type Response = super::GetUserResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RevokeCredentialRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::revoke_credential(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RevokeCredentialSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/beco.Beco/GetCredentialStatusList" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetCredentialStatusListSvc<T: Beco>(pub Arc<T>);
+                    impl<
+                        T: Beco,
+                    > tonic::server::UnaryService<super::CredentialStatusListRequest>
+                    for GetCredentialStatusListSvc<T> {
+                        type Response = super::CredentialStatusListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CredentialStatusListRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Beco>::get_credential_status_list(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetCredentialStatusListSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
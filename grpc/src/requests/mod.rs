@@ -1 +1,3 @@
-pub mod user_request;
\ No newline at end of file
+pub mod user_request;
+
+mod tests;
\ No newline at end of file
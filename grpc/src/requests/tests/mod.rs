@@ -0,0 +1 @@
+mod user_request;
\ No newline at end of file
@@ -0,0 +1,43 @@
+#![cfg(test)]
+
+use tonic::Code;
+
+use crate::{
+    enums::value_reference::ValueReference,
+    proto::beco::{FieldReference as FieldReferenceMessage, ValueReference as ProtoValueReference},
+    requests::user_request::FieldReference,
+};
+
+#[test]
+fn attribute_reference_needs_a_key() {
+    let result = FieldReference::try_from(FieldReferenceMessage {
+        reference: ProtoValueReference::DetailAttribute.into(),
+        key: None,
+    });
+    assert_eq!(result.unwrap_err().status, Code::InvalidArgument);
+
+    let field = FieldReference::try_from(FieldReferenceMessage {
+        reference: ProtoValueReference::DetailAttribute.into(),
+        key: Some("email".into()),
+    })
+    .unwrap();
+    assert_eq!(field.reference, ValueReference::DETAIL_ATTRIBUTE);
+}
+
+#[test]
+fn only_attribute_references_take_a_key() {
+    let result = FieldReference::try_from(FieldReferenceMessage {
+        reference: ProtoValueReference::DetailFirstName.into(),
+        key: Some("email".into()),
+    });
+    assert_eq!(result.unwrap_err().status, Code::InvalidArgument);
+}
+
+#[test]
+fn unspecified_reference_is_rejected() {
+    let result = FieldReference::try_from(FieldReferenceMessage {
+        reference: ProtoValueReference::Unspecified.into(),
+        key: None,
+    });
+    assert_eq!(result.unwrap_err().status, Code::InvalidArgument);
+}
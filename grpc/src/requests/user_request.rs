@@ -1,4 +1,167 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use tonic::Code;
+use uuid::Uuid;
 
+use crate::{
+    enums::{
+        oragnisaton_relation::OrganisationRelation, user_request_type::UserRequestType,
+        value_reference::ValueReference,
+    },
+    errors::BecoError,
+    organisation::organisation::{parse_date, Organisation},
+    proto::beco::{
+        AccessRequestResponse, FieldReference as FieldReferenceMessage, RequestAccessRequest,
+    },
+    user::attribute::validate_key,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct FieldReference {
+    pub reference: ValueReference,
+    // the attribute name for DETAIL_ATTRIBUTE, or a single key alias for CHAIN_HEYS
+    pub key: Option<String>,
+}
+
+impl TryFrom<FieldReferenceMessage> for FieldReference {
+    type Error = BecoError;
+
+    fn try_from(value: FieldReferenceMessage) -> Result<Self, Self::Error> {
+        let reference_result = ValueReference::try_from(value.reference);
+        if let Err(err) = reference_result {
+            return Err(err);
+        }
+        let reference = reference_result.unwrap();
+        match (&reference, &value.key) {
+            (ValueReference::DETAIL_ATTRIBUTE, Some(key)) => {
+                validate_key(key)?;
+            }
+            (ValueReference::DETAIL_ATTRIBUTE, None) => {
+                return Err(BecoError {
+                    message: "An attribute reference needs a key".into(),
+                    status: Code::InvalidArgument,
+                });
+            }
+            (ValueReference::CHAIN_HEYS, Some(alias)) if alias.is_empty() => {
+                return Err(BecoError {
+                    message: "A key alias cannot be empty".into(),
+                    status: Code::InvalidArgument,
+                });
+            }
+            (ValueReference::CHAIN_HEYS, Some(_)) => {}
+            (_, Some(_)) => {
+                return Err(BecoError {
+                    message: "Only attribute and chain key references take a key".into(),
+                    status: Code::InvalidArgument,
+                });
+            }
+            (_, None) => {}
+        }
+        Ok(Self {
+            reference,
+            key: value.key,
+        })
+    }
+}
+
+impl From<FieldReference> for FieldReferenceMessage {
+    fn from(val: FieldReference) -> Self {
+        FieldReferenceMessage {
+            reference: val.reference.into(),
+            key: val.key,
+        }
+    }
+}
+
+// at least one field, duplicates are dropped
+pub fn field_references(
+    messages: Vec<FieldReferenceMessage>,
+) -> Result<Vec<FieldReference>, BecoError> {
+    if messages.is_empty() {
+        return Err(BecoError {
+            message: "No fields requested".into(),
+            status: Code::InvalidArgument,
+        });
+    }
+    let mut fields: Vec<FieldReference> = vec![];
+    for message in messages {
+        let field_result = FieldReference::try_from(message);
+        if let Err(err) = field_result {
+            return Err(err);
+        }
+        let field = field_result.unwrap();
+        if !fields.contains(&field) {
+            fields.push(field);
+        }
+    }
+    Ok(fields)
+}
+
+// an organisation asking a user for view access to some of their fields, kept on the user until
+// they approve or deny it
 #[derive(Debug, Hash, Clone, Serialize, Deserialize, PartialEq, Eq)]
-pub struct UserRequests {}
+pub struct UserRequests {
+    pub id: String,
+    pub user_id: String,
+    pub organisation_id: String,
+    pub organisation_name: String,
+    pub request_type: UserRequestType,
+    pub requested_by: String,
+    pub fields: Vec<FieldReference>,
+    pub created: DateTime<Utc>,
+    // when the granted access lapses, None grants it until revoked
+    #[serde(default)]
+    pub expires: Option<DateTime<Utc>>,
+}
+
+impl UserRequests {
+    pub fn new(
+        request: RequestAccessRequest,
+        organisation: &Organisation,
+    ) -> Result<Self, BecoError> {
+        let fields_result = field_references(request.fields);
+        if let Err(err) = fields_result {
+            return Err(err);
+        }
+        let fields = fields_result.unwrap();
+        let created = Utc::now();
+        let expires_result = parse_date(&request.expires_at, None);
+        if let Err(err) = expires_result {
+            return Err(err);
+        }
+        let expires = expires_result.unwrap();
+        if expires.is_some_and(|expires| expires <= created) {
+            return Err(BecoError {
+                message: "expires_at must be in the future".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        Ok(Self {
+            id: Uuid::new_v4().to_string(),
+            user_id: request.user_id,
+            organisation_id: organisation.id.clone(),
+            organisation_name: organisation.name.clone(),
+            request_type: organisation.relation.into(),
+            requested_by: request.calling_user,
+            fields,
+            created,
+            expires,
+        })
+    }
+}
+
+impl From<UserRequests> for AccessRequestResponse {
+    fn from(val: UserRequests) -> Self {
+        let relation: OrganisationRelation = val.request_type.into();
+        AccessRequestResponse {
+            id: val.id,
+            organisation_id: val.organisation_id,
+            organisation_name: val.organisation_name,
+            relation: relation.into(),
+            requested_by: val.requested_by,
+            fields: val.fields.into_iter().map(|field| field.into()).collect(),
+            created: val.created.to_rfc3339(),
+            expires_at: val.expires.map(|expires| expires.to_rfc3339()),
+        }
+    }
+}
@@ -2,12 +2,34 @@ use std::sync::Arc;
 
 use tonic::{Request, Response, Status};
 
+use crate::audit::access_record::ListAccessLog;
+use crate::chain::chain_custody::RemoveAccount;
 use crate::entry::Entry;
 use crate::enums::data_value::DataRequests;
+use crate::permissions::grant::{GroupAccess, RevokeGrant};
+use crate::permissions::policy::SetFieldPolicy;
+use crate::permissions::visibility::SetFieldVisibility;
 use crate::proto::beco::beco_server::Beco;
 use crate::proto::beco::{
-    AddAccountRequest, ModifyNameRequest, ModifyOtherNamesRequest,
+    AddAccountRequest, AddMemberRequest, ApproveChangeRequest, ApproveRecoveryRequest,
+    AttestFieldRequest, CancelRecoveryRequest, ClearAttributeRequest, CreateGroupRequest,
+    CreateOrganisationRequest, CredentialStatusListRequest, CredentialStatusListResponse,
+    EndMembershipRequest, GroupAccessRequest, GroupMemberRequest, GroupResponse,
+    IssueCredentialRequest, IssueCredentialResponse, ListAccessLogRequest, ListAccessLogResponse,
+    ListMembershipsRequest, ListMembershipsResponse, ListPendingApprovalsRequest,
+    ListPendingApprovalsResponse, ListPendingRequestsRequest, ListPendingRequestsResponse,
+    ModifyLinkedUserRequest, ModifyNameRequest, ModifyOtherNamesRequest, OrganisationResponse,
+    RemoveAccountRequest, RequestAccessRequest, RespondAccessRequest, RevokeCredentialRequest,
+    RevokeGrantRequest, SetApprovalPolicyRequest, SetAttributeRequest, SetAuthKeyRequest, SetFieldPolicyRequest,
+    SetFieldVisibilityRequest, SetGuardianRequest, SetRecoveryGuardiansRequest,
+    SignWithAccountRequest, SignWithAccountResponse, UserDiffRequest, UserDiffResponse,
+    VerifyCredentialRequest, VerifyCredentialResponse,
 };
+use crate::user::approval::SetApprovalPolicy;
+use crate::user::attribute::{validate_key, SetAttribute};
+use crate::user::guardian::SetGuardian;
+use crate::user::linked_user::ModifyLinkedUser;
+use crate::user::recovery::{ApproveRecovery, SetAuthKey, SetRecoveryGuardians};
 use crate::proto::beco::{AddUserRequest, GetUserResponse, ListUserRequest, ListUserResponse};
 
 #[derive(Debug)]
@@ -19,6 +41,58 @@ impl BecoImplementation {
     pub fn new(entry: Arc<Entry>) -> Self {
         Self { entry }
     }
+
+    async fn organisation_request(
+        &self,
+        data_request: DataRequests,
+        calling_user: String,
+    ) -> Result<Response<OrganisationResponse>, Status> {
+        let result = self
+            .entry
+            .organisation_request(data_request, calling_user.clone())
+            .await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap().as_response(&calling_user)))
+    }
+
+    async fn group_request(
+        &self,
+        data_request: DataRequests,
+        calling_user: String,
+    ) -> Result<Response<GroupResponse>, Status> {
+        let result = self.entry.group_request(data_request, calling_user).await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap().into()))
+    }
+
+    async fn group_access(
+        &self,
+        request: GroupAccessRequest,
+        grant: bool,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        let group_access_result = GroupAccess::try_from(request.clone());
+        if let Err(err) = group_access_result {
+            return Err(Status::new(err.status, err.message));
+        }
+        let group_access = group_access_result.unwrap();
+        let data_request = if grant {
+            DataRequests::GrantGroupAccess(group_access)
+        } else {
+            DataRequests::RevokeGroupAccess(group_access)
+        };
+        let result = self
+            .entry
+            .propose(data_request, request.calling_user, request.user_id)
+            .await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap()))
+    }
 }
 
 #[tonic::async_trait]
@@ -66,6 +140,41 @@ impl Beco for BecoImplementation {
         Ok(Response::new(result.unwrap()))
     }
 
+    async fn remove_account(
+        &self,
+        request: Request<RemoveAccountRequest>,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        let inner_request = request.into_inner();
+        let remove_account_result = RemoveAccount::try_from(inner_request.clone());
+        if let Err(err) = remove_account_result {
+            return Err(Status::new(err.status, err.message));
+        }
+        let result = self
+            .entry
+            .propose(
+                DataRequests::RemoveAccount(remove_account_result.unwrap()),
+                inner_request.calling_user.clone(),
+                inner_request.user_id.clone(),
+            )
+            .await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap()))
+    }
+
+    async fn sign_with_account(
+        &self,
+        request: Request<SignWithAccountRequest>,
+    ) -> Result<Response<SignWithAccountResponse>, Status> {
+        let inner_request = request.into_inner();
+        let result = self.entry.sign_with_account(inner_request).await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap()))
+    }
+
     async fn update_first_name(
         &self,
         request: Request<ModifyNameRequest>,
@@ -123,27 +232,576 @@ impl Beco for BecoImplementation {
         Ok(Response::new(result.unwrap()))
     }
 
-    // async fn add_linked_user(
-    //     &self,
-    //     request: Request<ModifyLinkedUserRequest>,
-    // ) -> Result<Response<GetUserResponse>, Status> {
-    //     let inner_request = request.into_inner();
-    //     let result = self.entry.add_linked_user(inner_request).await;
-    //     if let Err(err) = result {
-    //         return Err(Status::new(err.status, err.message));
-    //     }
-    //     Ok(Response::new(result.unwrap()))
-    // }
+    async fn set_attribute(
+        &self,
+        request: Request<SetAttributeRequest>,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        let inner_request = request.into_inner();
+        let set_attribute_result = SetAttribute::try_from(inner_request.clone());
+        if let Err(err) = set_attribute_result {
+            return Err(Status::new(err.status, err.message));
+        }
+        let result = self
+            .entry
+            .propose(
+                DataRequests::SetAttribute(set_attribute_result.unwrap()),
+                inner_request.calling_user.clone(),
+                inner_request.user_id.clone(),
+            )
+            .await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap()))
+    }
+
+    async fn clear_attribute(
+        &self,
+        request: Request<ClearAttributeRequest>,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        let inner_request = request.into_inner();
+        if let Err(err) = validate_key(&inner_request.key) {
+            return Err(Status::new(err.status, err.message));
+        }
+        let result = self
+            .entry
+            .propose(
+                DataRequests::ClearAttribute(inner_request.clone()),
+                inner_request.calling_user.clone(),
+                inner_request.user_id.clone(),
+            )
+            .await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap()))
+    }
+
+    async fn get_user_diff(
+        &self,
+        request: Request<UserDiffRequest>,
+    ) -> Result<Response<UserDiffResponse>, Status> {
+        let inner_request = request.into_inner();
+        let result = self.entry.user_diff(inner_request).await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap()))
+    }
+
+    async fn create_organisation(
+        &self,
+        request: Request<CreateOrganisationRequest>,
+    ) -> Result<Response<OrganisationResponse>, Status> {
+        let inner_request = request.into_inner();
+        let calling_user = inner_request.calling_user.clone();
+        self.organisation_request(DataRequests::CreateOrganisation(inner_request), calling_user)
+            .await
+    }
+
+    async fn add_member(
+        &self,
+        request: Request<AddMemberRequest>,
+    ) -> Result<Response<OrganisationResponse>, Status> {
+        let inner_request = request.into_inner();
+        let calling_user = inner_request.calling_user.clone();
+        self.organisation_request(DataRequests::AddMember(inner_request), calling_user)
+            .await
+    }
+
+    async fn end_membership(
+        &self,
+        request: Request<EndMembershipRequest>,
+    ) -> Result<Response<OrganisationResponse>, Status> {
+        let inner_request = request.into_inner();
+        let calling_user = inner_request.calling_user.clone();
+        self.organisation_request(DataRequests::EndMembership(inner_request), calling_user)
+            .await
+    }
+
+    async fn list_memberships(
+        &self,
+        request: Request<ListMembershipsRequest>,
+    ) -> Result<Response<ListMembershipsResponse>, Status> {
+        let inner_request = request.into_inner();
+        let result = self.entry.list_memberships(inner_request).await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(ListMembershipsResponse {
+            memberships: result
+                .unwrap()
+                .into_iter()
+                .map(|membership| membership.into())
+                .collect(),
+        }))
+    }
+
+    async fn request_access(
+        &self,
+        request: Request<RequestAccessRequest>,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        let inner_request = request.into_inner();
+        let result = self.entry.request_access(inner_request).await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap()))
+    }
+
+    async fn list_pending_requests(
+        &self,
+        request: Request<ListPendingRequestsRequest>,
+    ) -> Result<Response<ListPendingRequestsResponse>, Status> {
+        let inner_request = request.into_inner();
+        let result = self.entry.list_pending_requests(inner_request).await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(ListPendingRequestsResponse {
+            requests: result
+                .unwrap()
+                .into_iter()
+                .map(|request| request.into())
+                .collect(),
+        }))
+    }
+
+    async fn respond_to_access_request(
+        &self,
+        request: Request<RespondAccessRequest>,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        let inner_request = request.into_inner();
+        let result = self
+            .entry
+            .propose(
+                DataRequests::RespondAccessRequest(inner_request.clone()),
+                inner_request.calling_user.clone(),
+                inner_request.user_id.clone(),
+            )
+            .await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap()))
+    }
+
+    async fn create_group(
+        &self,
+        request: Request<CreateGroupRequest>,
+    ) -> Result<Response<GroupResponse>, Status> {
+        let inner_request = request.into_inner();
+        let calling_user = inner_request.calling_user.clone();
+        self.group_request(DataRequests::CreateGroup(inner_request), calling_user)
+            .await
+    }
+
+    async fn add_group_member(
+        &self,
+        request: Request<GroupMemberRequest>,
+    ) -> Result<Response<GroupResponse>, Status> {
+        let inner_request = request.into_inner();
+        let calling_user = inner_request.calling_user.clone();
+        self.group_request(DataRequests::AddGroupMember(inner_request), calling_user)
+            .await
+    }
+
+    async fn remove_group_member(
+        &self,
+        request: Request<GroupMemberRequest>,
+    ) -> Result<Response<GroupResponse>, Status> {
+        let inner_request = request.into_inner();
+        let calling_user = inner_request.calling_user.clone();
+        self.group_request(DataRequests::RemoveGroupMember(inner_request), calling_user)
+            .await
+    }
+
+    async fn grant_group_access(
+        &self,
+        request: Request<GroupAccessRequest>,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        self.group_access(request.into_inner(), true).await
+    }
+
+    async fn revoke_group_access(
+        &self,
+        request: Request<GroupAccessRequest>,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        self.group_access(request.into_inner(), false).await
+    }
+
+    async fn revoke_grant(
+        &self,
+        request: Request<RevokeGrantRequest>,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        let inner_request = request.into_inner();
+        let revoke_grant_result = RevokeGrant::try_from(inner_request.clone());
+        if let Err(err) = revoke_grant_result {
+            return Err(Status::new(err.status, err.message));
+        }
+        let result = self
+            .entry
+            .propose(
+                DataRequests::RevokeGrant(revoke_grant_result.unwrap()),
+                inner_request.calling_user.clone(),
+                inner_request.user_id.clone(),
+            )
+            .await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap()))
+    }
+
+    async fn set_field_policy(
+        &self,
+        request: Request<SetFieldPolicyRequest>,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        let inner_request = request.into_inner();
+        let set_field_policy_result = SetFieldPolicy::try_from(inner_request.clone());
+        if let Err(err) = set_field_policy_result {
+            return Err(Status::new(err.status, err.message));
+        }
+        let result = self
+            .entry
+            .propose(
+                DataRequests::SetFieldPolicy(set_field_policy_result.unwrap()),
+                inner_request.calling_user.clone(),
+                inner_request.user_id.clone(),
+            )
+            .await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap()))
+    }
+
+    async fn set_field_visibility(
+        &self,
+        request: Request<SetFieldVisibilityRequest>,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        let inner_request = request.into_inner();
+        let set_field_visibility_result = SetFieldVisibility::try_from(inner_request.clone());
+        if let Err(err) = set_field_visibility_result {
+            return Err(Status::new(err.status, err.message));
+        }
+        let result = self
+            .entry
+            .propose(
+                DataRequests::SetFieldVisibility(set_field_visibility_result.unwrap()),
+                inner_request.calling_user.clone(),
+                inner_request.user_id.clone(),
+            )
+            .await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap()))
+    }
+
+    async fn list_access_log(
+        &self,
+        request: Request<ListAccessLogRequest>,
+    ) -> Result<Response<ListAccessLogResponse>, Status> {
+        let list_access_log_result = ListAccessLog::try_from(request.into_inner());
+        if let Err(err) = list_access_log_result {
+            return Err(Status::new(err.status, err.message));
+        }
+        let result = self.entry.list_access_log(list_access_log_result.unwrap()).await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(ListAccessLogResponse {
+            records: result
+                .unwrap()
+                .into_iter()
+                .map(|record| record.into())
+                .collect(),
+        }))
+    }
+
+    // records the calling user's consent, the link shows once the other user consents too
+    async fn add_linked_user(
+        &self,
+        request: Request<ModifyLinkedUserRequest>,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        let inner_request = request.into_inner();
+        let linked_user_result = ModifyLinkedUser::try_from(inner_request.clone());
+        if let Err(err) = linked_user_result {
+            return Err(Status::new(err.status, err.message));
+        }
+        let result = self
+            .entry
+            .propose(
+                DataRequests::AddLinkedUser(linked_user_result.unwrap()),
+                inner_request.calling_user.clone(),
+                inner_request.calling_user.clone(),
+            )
+            .await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap()))
+    }
+
+    async fn remove_linked_user(
+        &self,
+        request: Request<ModifyLinkedUserRequest>,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        let inner_request = request.into_inner();
+        let linked_user_result = ModifyLinkedUser::try_from(inner_request.clone());
+        if let Err(err) = linked_user_result {
+            return Err(Status::new(err.status, err.message));
+        }
+        let result = self
+            .entry
+            .propose(
+                DataRequests::RemoveLinkedUser(linked_user_result.unwrap()),
+                inner_request.calling_user.clone(),
+                inner_request.calling_user.clone(),
+            )
+            .await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap()))
+    }
 
-    // async fn remove_linked_user(
-    //     &self,
-    //     request: Request<ModifyLinkedUserRequest>,
-    // ) -> Result<Response<GetUserResponse>, Status> {
-    //     let inner_request = request.into_inner();
-    //     let result = self.entry.remove_linked_user(inner_request).await;
-    //     if let Err(err) = result {
-    //         return Err(Status::new(err.status, err.message));
-    //     }
-    //     Ok(Response::new(result.unwrap()))
-    // }
+    async fn set_guardian(
+        &self,
+        request: Request<SetGuardianRequest>,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        let inner_request = request.into_inner();
+        let set_guardian_result = SetGuardian::try_from(inner_request.clone());
+        if let Err(err) = set_guardian_result {
+            return Err(Status::new(err.status, err.message));
+        }
+        let result = self
+            .entry
+            .propose(
+                DataRequests::SetGuardian(set_guardian_result.unwrap()),
+                inner_request.calling_user.clone(),
+                inner_request.user_id.clone(),
+            )
+            .await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap()))
+    }
+
+    async fn set_auth_key(
+        &self,
+        request: Request<SetAuthKeyRequest>,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        let inner_request = request.into_inner();
+        let set_auth_key_result = SetAuthKey::try_from(inner_request.clone());
+        if let Err(err) = set_auth_key_result {
+            return Err(Status::new(err.status, err.message));
+        }
+        let result = self
+            .entry
+            .propose(
+                DataRequests::SetAuthKey(set_auth_key_result.unwrap()),
+                inner_request.calling_user.clone(),
+                inner_request.user_id.clone(),
+            )
+            .await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap()))
+    }
+
+    async fn set_recovery_guardians(
+        &self,
+        request: Request<SetRecoveryGuardiansRequest>,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        let inner_request = request.into_inner();
+        let guardians_result = SetRecoveryGuardians::try_from(inner_request.clone());
+        if let Err(err) = guardians_result {
+            return Err(Status::new(err.status, err.message));
+        }
+        let result = self
+            .entry
+            .propose(
+                DataRequests::SetRecoveryGuardians(guardians_result.unwrap()),
+                inner_request.calling_user.clone(),
+                inner_request.user_id.clone(),
+            )
+            .await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap()))
+    }
+
+    async fn approve_recovery(
+        &self,
+        request: Request<ApproveRecoveryRequest>,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        let inner_request = request.into_inner();
+        let approve_recovery_result = ApproveRecovery::try_from(inner_request.clone());
+        if let Err(err) = approve_recovery_result {
+            return Err(Status::new(err.status, err.message));
+        }
+        let result = self
+            .entry
+            .propose(
+                DataRequests::ApproveRecovery(approve_recovery_result.unwrap()),
+                inner_request.calling_user.clone(),
+                inner_request.user_id.clone(),
+            )
+            .await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap()))
+    }
+
+    async fn cancel_recovery(
+        &self,
+        request: Request<CancelRecoveryRequest>,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        let inner_request = request.into_inner();
+        let result = self
+            .entry
+            .propose(
+                DataRequests::CancelRecovery(inner_request.clone().into()),
+                inner_request.calling_user.clone(),
+                inner_request.user_id.clone(),
+            )
+            .await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap()))
+    }
+
+    async fn set_approval_policy(
+        &self,
+        request: Request<SetApprovalPolicyRequest>,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        let inner_request = request.into_inner();
+        let set_approval_policy_result = SetApprovalPolicy::try_from(inner_request.clone());
+        if let Err(err) = set_approval_policy_result {
+            return Err(Status::new(err.status, err.message));
+        }
+        let result = self
+            .entry
+            .propose(
+                DataRequests::SetApprovalPolicy(set_approval_policy_result.unwrap()),
+                inner_request.calling_user.clone(),
+                inner_request.user_id.clone(),
+            )
+            .await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap()))
+    }
+
+    async fn list_pending_approvals(
+        &self,
+        request: Request<ListPendingApprovalsRequest>,
+    ) -> Result<Response<ListPendingApprovalsResponse>, Status> {
+        let inner_request = request.into_inner();
+        let result = self.entry.list_pending_approvals(inner_request).await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(ListPendingApprovalsResponse {
+            approvals: result
+                .unwrap()
+                .into_iter()
+                .map(|approval| approval.into())
+                .collect(),
+        }))
+    }
+
+    async fn approve_change(
+        &self,
+        request: Request<ApproveChangeRequest>,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        let inner_request = request.into_inner();
+        let result = self
+            .entry
+            .propose(
+                DataRequests::ApproveChange(inner_request.clone().into()),
+                inner_request.calling_user.clone(),
+                inner_request.user_id.clone(),
+            )
+            .await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap()))
+    }
+
+    async fn attest_field(
+        &self,
+        request: Request<AttestFieldRequest>,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        let inner_request = request.into_inner();
+        let result = self.entry.attest_field(inner_request).await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap()))
+    }
+
+    async fn issue_credential(
+        &self,
+        request: Request<IssueCredentialRequest>,
+    ) -> Result<Response<IssueCredentialResponse>, Status> {
+        let inner_request = request.into_inner();
+        let result = self.entry.issue_credential(inner_request).await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap()))
+    }
+
+    async fn verify_credential(
+        &self,
+        request: Request<VerifyCredentialRequest>,
+    ) -> Result<Response<VerifyCredentialResponse>, Status> {
+        let inner_request = request.into_inner();
+        let result = self.entry.verify_credential(inner_request).await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap().into()))
+    }
+
+    async fn revoke_credential(
+        &self,
+        request: Request<RevokeCredentialRequest>,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        let inner_request = request.into_inner();
+        let result = self
+            .entry
+            .propose(
+                DataRequests::RevokeCredential(inner_request.clone().into()),
+                inner_request.calling_user.clone(),
+                inner_request.user_id.clone(),
+            )
+            .await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap()))
+    }
+
+    async fn get_credential_status_list(
+        &self,
+        request: Request<CredentialStatusListRequest>,
+    ) -> Result<Response<CredentialStatusListResponse>, Status> {
+        let inner_request = request.into_inner();
+        let result = self.entry.credential_status_list(inner_request).await;
+        if let Err(err) = result {
+            return Err(Status::new(err.status, err.message));
+        }
+        Ok(Response::new(result.unwrap()))
+    }
 }
@@ -2,12 +2,14 @@
 #![allow(unused_variables)]
 #![allow(non_snake_case)]
 
+mod audit;
 mod chain;
 mod db;
 mod entry;
 mod enums;
 mod errors;
 mod evm;
+mod group;
 mod implement;
 mod merkle;
 mod organisation;
@@ -20,12 +22,67 @@ mod user;
 mod utils;
 mod xrpl;
 
+use db::DB;
+use merkle::SST;
 use p2p::P2P;
 
+const USAGE: &str = "Usage: sst [rebuild [--apply] [<user_id>]]";
+
 #[cfg(feature = "sst")]
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    P2P::new().loop_swarm().await;
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let command = args.first().map(|arg| arg.as_str());
+    if command.is_some_and(|command| command != "rebuild") {
+        eprintln!("{USAGE}");
+        std::process::exit(2);
+    }
+
+    let sst_result = SST::new();
+    if let Err(err) = sst_result {
+        eprintln!("{}", err.message);
+        std::process::exit(1);
+    }
+    let sst = sst_result.unwrap();
+
+    let applied = DB::new().migrate().await?;
+    for version in applied {
+        println!("Applied migration {version}");
+    }
+
+    match command {
+        Some("rebuild") => rebuild(sst, &args[1..]).await?,
+        _ => P2P::new(sst).loop_swarm().await,
+    }
 
     Ok(())
 }
+
+// replays the transaction log for one or all users and reports where it diverges from the snapshot
+#[cfg(feature = "sst")]
+async fn rebuild(sst: SST, args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let apply = args.iter().any(|arg| arg == "--apply");
+    let user_id = args.iter().find(|arg| !arg.starts_with("--")).cloned();
+    let reports = sst.rebuild(user_id, apply).await?;
+    let mut diverged = 0;
+    for report in reports.iter() {
+        if report.diverged() {
+            diverged += 1;
+        }
+        println!(
+            "{} transactions={} replayed_sequence={} stored_sequence={:?} first_divergent_sequence={:?} snapshot_diverged={} applied={}",
+            report.user_id,
+            report.transactions,
+            report.replayed_sequence,
+            report.stored_sequence,
+            report.first_divergent_sequence,
+            report.snapshot_diverged,
+            report.applied,
+        );
+    }
+    println!("{} users rebuilt, {} diverged", reports.len(), diverged);
+    if diverged > 0 && !apply {
+        std::process::exit(1);
+    }
+    Ok(())
+}
@@ -1,3 +1,5 @@
+use chrono::{DateTime, Utc};
+
 use crate::{errors::BecoError, user::public_user::PublicUser, proto::beco::AddAccountRequest};
 
 pub trait Key<A> {
@@ -6,5 +8,15 @@ pub trait Key<A> {
         algorithm: Option<A>,
         request: AddAccountRequest,
         public_user: &PublicUser,
+        at: &DateTime<Utc>,
     ) -> Result<(), BecoError>;
+
+    // returns the public key and the signature over message
+    fn sign(
+        &self,
+        alias: &str,
+        message: &[u8],
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(String, String), BecoError>;
 }
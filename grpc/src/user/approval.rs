@@ -0,0 +1,262 @@
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use tonic::Code;
+
+use crate::{
+    enums::{data_value::DataRequests, value_reference::ValueReference},
+    errors::BecoError,
+    proto::beco::{
+        approver::Kind, ApproveChangeRequest, Approver as ApproverMessage, PendingApprovalResponse,
+        SetApprovalPolicyRequest,
+    },
+    requests::user_request::FieldReference,
+    user::public_user::PublicUser,
+};
+
+// A change to a field with an approval policy is stored on the user instead of applied, and only
+// applied once enough approvers other than the requester have signed off on it.
+
+const APPROVAL_EXPIRY_HOURS: i64 = 168;
+const MAX_APPROVERS: usize = 16;
+
+pub fn approval_expiry() -> Duration {
+    Duration::hours(APPROVAL_EXPIRY_HOURS)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub enum Approver {
+    User(String),
+    // whoever is the user's guardian when the change is approved
+    Guardian,
+    // any admin of the organisation, the sst checks this as the user nodes cannot
+    OrganisationAdmin(String),
+}
+
+impl TryFrom<ApproverMessage> for Approver {
+    type Error = BecoError;
+
+    fn try_from(message: ApproverMessage) -> Result<Self, Self::Error> {
+        match message.kind {
+            Some(Kind::UserId(user_id)) if !user_id.is_empty() => Ok(Approver::User(user_id)),
+            Some(Kind::Guardian(true)) => Ok(Approver::Guardian),
+            Some(Kind::OrganisationAdmin(organisation_id)) if !organisation_id.is_empty() => {
+                Ok(Approver::OrganisationAdmin(organisation_id))
+            }
+            _ => Err(BecoError {
+                message: "Invalid approver".into(),
+                status: Code::InvalidArgument,
+            }),
+        }
+    }
+}
+
+impl From<Approver> for ApproverMessage {
+    fn from(val: Approver) -> Self {
+        let kind = match val {
+            Approver::User(user_id) => Kind::UserId(user_id),
+            Approver::Guardian => Kind::Guardian(true),
+            Approver::OrganisationAdmin(organisation_id) => {
+                Kind::OrganisationAdmin(organisation_id)
+            }
+        };
+        ApproverMessage { kind: Some(kind) }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct ApprovalPolicy {
+    pub field: FieldReference,
+    pub approvers: Vec<Approver>,
+    pub required: u32,
+}
+
+impl ApprovalPolicy {
+    // a policy on the chain keys without a key covers every key
+    pub fn covers(&self, field: &FieldReference) -> bool {
+        self.field.reference == field.reference
+            && (self.field.key.is_none() || self.field.key == field.key)
+    }
+}
+
+// the field a change touches, for the changes that can be put behind an approval policy
+pub fn guarded_field(request: &DataRequests) -> Option<FieldReference> {
+    let field = |reference: ValueReference, key: Option<String>| FieldReference { reference, key };
+    match request {
+        DataRequests::FirstName(_) => Some(field(ValueReference::DETAIL_FIRST_NAME, None)),
+        DataRequests::OtherNames(_) => Some(field(ValueReference::DETAIL_OTHER_NAMES, None)),
+        DataRequests::LastName(_) => Some(field(ValueReference::DETAIL_LAST_NAME, None)),
+        DataRequests::SetAttribute(request) => Some(field(
+            ValueReference::DETAIL_ATTRIBUTE,
+            Some(request.key.clone()),
+        )),
+        DataRequests::ClearAttribute(request) => Some(field(
+            ValueReference::DETAIL_ATTRIBUTE,
+            Some(request.key.clone()),
+        )),
+        DataRequests::AddCryptoAccount(request) => Some(field(
+            ValueReference::CHAIN_HEYS,
+            Some(request.alias.clone()),
+        )),
+        DataRequests::RemoveAccount(request) => Some(field(
+            ValueReference::CHAIN_HEYS,
+            Some(request.alias.clone()),
+        )),
+        // loosening a policy needs the approvals the policy asks for
+        DataRequests::SetApprovalPolicy(request) => Some(request.field.clone()),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Hash)]
+pub struct PendingApproval {
+    pub id: String,
+    pub field: FieldReference,
+    pub change: Box<DataRequests>,
+    // the requester's id, groups and memberships as checked when the change was requested, the
+    // change is applied with them
+    pub requested_by: PublicUser,
+    // copied from the policy so changing it does not affect changes already waiting
+    pub approvers: Vec<Approver>,
+    pub required: u32,
+    pub approvals: BTreeSet<String>,
+    pub created: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl PendingApproval {
+    pub fn is_expired(&self, at: &DateTime<Utc>) -> bool {
+        &self.expires_at <= at
+    }
+
+    pub fn organisation_ids(&self) -> Vec<String> {
+        self.approvers
+            .iter()
+            .filter_map(|approver| match approver {
+                Approver::OrganisationAdmin(organisation_id) => Some(organisation_id.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl From<PendingApproval> for PendingApprovalResponse {
+    fn from(val: PendingApproval) -> Self {
+        PendingApprovalResponse {
+            id: val.id,
+            field: Some(val.field.into()),
+            requested_by: val.requested_by.id,
+            approvers: val
+                .approvers
+                .into_iter()
+                .map(|approver| approver.into())
+                .collect(),
+            required: val.required,
+            approvals: val.approvals.into_iter().collect(),
+            created: val.created.to_rfc3339(),
+            expires_at: val.expires_at.to_rfc3339(),
+        }
+    }
+}
+
+// the validated form of SetApprovalPolicyRequest, no approvers removes the policy
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct SetApprovalPolicy {
+    pub user_id: String,
+    pub calling_user: String,
+    pub field: FieldReference,
+    pub approvers: Vec<Approver>,
+    pub required: u32,
+    pub expected_sequence: Option<u64>,
+}
+
+impl TryFrom<SetApprovalPolicyRequest> for SetApprovalPolicy {
+    type Error = BecoError;
+
+    fn try_from(request: SetApprovalPolicyRequest) -> Result<Self, Self::Error> {
+        if request.field.is_none() {
+            return Err(BecoError {
+                message: "No field provided".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        let field_result = FieldReference::try_from(request.field.unwrap());
+        if let Err(err) = field_result {
+            return Err(err);
+        }
+        if request.approvers.len() > MAX_APPROVERS {
+            return Err(BecoError {
+                message: format!("A policy can have at most {MAX_APPROVERS} approvers"),
+                status: Code::InvalidArgument,
+            });
+        }
+        let mut approvers: Vec<Approver> = vec![];
+        for message in request.approvers {
+            let approver_result = Approver::try_from(message);
+            if let Err(err) = approver_result {
+                return Err(err);
+            }
+            let approver = approver_result.unwrap();
+            if approvers.contains(&approver) {
+                return Err(BecoError {
+                    message: format!("Duplicate approver: {approver:?}"),
+                    status: Code::InvalidArgument,
+                });
+            }
+            approvers.push(approver);
+        }
+        if !approvers.is_empty()
+            && (request.required == 0 || request.required as usize > approvers.len())
+        {
+            return Err(BecoError {
+                message: format!(
+                    "Required approvals must be between 1 and {}, got {}",
+                    approvers.len(),
+                    request.required
+                ),
+                status: Code::InvalidArgument,
+            });
+        }
+        Ok(Self {
+            user_id: request.user_id,
+            calling_user: request.calling_user,
+            field: field_result.unwrap(),
+            approvers,
+            required: request.required,
+            expected_sequence: request.expected_sequence,
+        })
+    }
+}
+
+// wraps a change to a guarded field, the id is assigned by the node that proposes it
+#[derive(Debug, Clone, Serialize, Deserialize, Hash)]
+pub struct RequestApproval {
+    pub id: String,
+    pub change: Box<DataRequests>,
+}
+
+// the validated form of ApproveChangeRequest
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct ApproveChange {
+    pub user_id: String,
+    pub calling_user: String,
+    pub approval_id: String,
+}
+
+impl From<ApproveChangeRequest> for ApproveChange {
+    fn from(request: ApproveChangeRequest) -> Self {
+        Self {
+            user_id: request.user_id,
+            calling_user: request.calling_user,
+            approval_id: request.approval_id,
+        }
+    }
+}
+
+// emitted by the sst sweeper for changes that were not approved in time
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct ExpireApprovals {
+    pub user_id: String,
+    pub approval_ids: Vec<String>,
+}
@@ -0,0 +1,147 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tonic::Code;
+
+use crate::{
+    enums::{oragnisaton_relation::OrganisationRelation, value_reference::ValueReference},
+    errors::BecoError,
+    organisation::organisation::Organisation,
+    proto::beco::{AttestFieldRequest, AttestationResponse, AttestationStatus},
+    requests::user_request::FieldReference,
+};
+
+// An organisation vouches for a field's value, not for the user. The attestation is bound to the
+// hash of the value it was made against and is invalidated by any change to that value.
+
+const MAX_CLAIM_LENGTH: usize = 256;
+
+// the hex encoded sha256 of the JSON encoding of a value
+pub fn value_hash<T: Serialize>(value: &T) -> String {
+    let json = serde_json::to_string(value).unwrap_or_default();
+    hex::encode(Sha256::digest(json.as_bytes()))
+}
+
+// what the organisation admin signs with their auth key
+pub fn attestation_message(
+    user_id: &String,
+    organisation_id: &String,
+    field: &FieldReference,
+    value_hash: &String,
+    claim: &String,
+) -> String {
+    format!(
+        "attest:{user_id}:{organisation_id}:{:?}:{}:{value_hash}:{claim}",
+        field.reference,
+        field.key.clone().unwrap_or_default()
+    )
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct Attestation {
+    pub user_id: String,
+    pub organisation_id: String,
+    pub organisation_name: String,
+    pub relation: OrganisationRelation,
+    pub field: FieldReference,
+    pub value_hash: String,
+    pub claim: String,
+    pub attested_by: String,
+    // the admin's auth key when they attested, checked by the sst
+    pub public_key: String,
+    pub signature: String,
+    pub attested_at: DateTime<Utc>,
+    // set once the value no longer matches, an attestation is never valid again after that
+    pub invalidated_at: Option<DateTime<Utc>>,
+}
+
+impl Attestation {
+    // public_key is the calling admin's auth key
+    pub fn new(
+        request: AttestFieldRequest,
+        organisation: &Organisation,
+        public_key: String,
+    ) -> Result<Self, BecoError> {
+        if request.field.is_none() {
+            return Err(BecoError {
+                message: "No field provided".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        let field_result = FieldReference::try_from(request.field.unwrap());
+        if let Err(err) = field_result {
+            return Err(err);
+        }
+        let field = field_result.unwrap();
+        if field.reference == ValueReference::CHAIN_HEYS {
+            return Err(BecoError {
+                message: "Chain keys cannot be attested".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        if request.claim.is_empty() || request.claim.len() > MAX_CLAIM_LENGTH {
+            return Err(BecoError {
+                message: format!("A claim must be between 1 and {MAX_CLAIM_LENGTH} bytes"),
+                status: Code::InvalidArgument,
+            });
+        }
+        if request.value_hash.is_empty() || request.signature.is_empty() {
+            return Err(BecoError {
+                message: "An attestation needs a value hash and a signature".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        Ok(Self {
+            user_id: request.user_id,
+            organisation_id: organisation.id.clone(),
+            organisation_name: organisation.name.clone(),
+            relation: organisation.relation,
+            field,
+            value_hash: request.value_hash,
+            claim: request.claim,
+            attested_by: request.calling_user,
+            public_key,
+            signature: request.signature,
+            // replaced with the validator time when the attestation is applied
+            attested_at: Utc::now(),
+            invalidated_at: None,
+        })
+    }
+
+    pub fn message(&self) -> String {
+        attestation_message(
+            &self.user_id,
+            &self.organisation_id,
+            &self.field,
+            &self.value_hash,
+            &self.claim,
+        )
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.invalidated_at.is_none()
+    }
+}
+
+impl From<Attestation> for AttestationResponse {
+    fn from(val: Attestation) -> Self {
+        let status = if val.is_valid() {
+            AttestationStatus::Valid
+        } else {
+            AttestationStatus::Invalidated
+        };
+        AttestationResponse {
+            organisation_id: val.organisation_id,
+            organisation_name: val.organisation_name,
+            relation: val.relation.into(),
+            field: Some(val.field.into()),
+            value_hash: val.value_hash,
+            claim: val.claim,
+            attested_by: val.attested_by,
+            public_key: val.public_key,
+            signature: val.signature,
+            attested_at: val.attested_at.to_rfc3339(),
+            status: status.into(),
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::hash::Hash;
+use tonic::Code;
+
+use crate::{
+    errors::BecoError,
+    proto::beco::{
+        attribute_value::Value, AttributeValue as AttributeValueMessage, PostalAddress,
+        SetAttributeRequest,
+    },
+};
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+const MAX_KEY_LENGTH: usize = 64;
+// these have their own fields on UserDetails
+const RESERVED_KEYS: [&str; 3] = ["first_name", "other_names", "last_name"];
+
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq)]
+pub struct Address {
+    pub lines: Vec<String>,
+    pub locality: String,
+    pub region: Option<String>,
+    pub postal_code: String,
+    pub country: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq)]
+pub enum AttributeValue {
+    String(String),
+    Date(NaiveDate),
+    Address(Address),
+}
+
+impl AttributeValue {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            AttributeValue::String(_) => "string",
+            AttributeValue::Date(_) => "date",
+            AttributeValue::Address(_) => "address",
+        }
+    }
+
+    pub fn is_same_type(&self, other: &AttributeValue) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+}
+
+impl TryFrom<AttributeValueMessage> for AttributeValue {
+    type Error = BecoError;
+
+    fn try_from(value: AttributeValueMessage) -> Result<Self, Self::Error> {
+        match value.value {
+            Some(Value::Text(text)) => Ok(AttributeValue::String(text)),
+            Some(Value::Date(date)) => NaiveDate::parse_from_str(&date, DATE_FORMAT)
+                .map(AttributeValue::Date)
+                .map_err(|_| BecoError {
+                    message: format!("Invalid date, expected YYYY-MM-DD: {date}"),
+                    status: Code::InvalidArgument,
+                }),
+            Some(Value::Address(address)) => Ok(AttributeValue::Address(Address {
+                lines: address.lines,
+                locality: address.locality,
+                region: address.region,
+                postal_code: address.postal_code,
+                country: address.country,
+            })),
+            None => Err(BecoError {
+                message: "No attribute value provided".into(),
+                status: Code::InvalidArgument,
+            }),
+        }
+    }
+}
+
+impl From<AttributeValue> for AttributeValueMessage {
+    fn from(val: AttributeValue) -> Self {
+        let value = match val {
+            AttributeValue::String(text) => Value::Text(text),
+            AttributeValue::Date(date) => Value::Date(date.format(DATE_FORMAT).to_string()),
+            AttributeValue::Address(address) => Value::Address(PostalAddress {
+                lines: address.lines,
+                locality: address.locality,
+                region: address.region,
+                postal_code: address.postal_code,
+                country: address.country,
+            }),
+        };
+        AttributeValueMessage { value: Some(value) }
+    }
+}
+
+// the validated form of SetAttributeRequest that is gossiped and stored in the transaction log
+#[derive(Debug, Clone, Serialize, Deserialize, Hash)]
+pub struct SetAttribute {
+    pub user_id: String,
+    pub calling_user: String,
+    pub key: String,
+    pub value: AttributeValue,
+    pub expected_sequence: Option<u64>,
+}
+
+impl TryFrom<SetAttributeRequest> for SetAttribute {
+    type Error = BecoError;
+
+    fn try_from(request: SetAttributeRequest) -> Result<Self, Self::Error> {
+        validate_key(&request.key)?;
+        if request.value.is_none() {
+            return Err(BecoError {
+                message: "No attribute value provided".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        let value_result = AttributeValue::try_from(request.value.unwrap());
+        if let Err(err) = value_result {
+            return Err(err);
+        }
+        Ok(Self {
+            user_id: request.user_id,
+            calling_user: request.calling_user,
+            key: request.key,
+            value: value_result.unwrap(),
+            expected_sequence: request.expected_sequence,
+        })
+    }
+}
+
+pub fn validate_key(key: &String) -> Result<(), BecoError> {
+    let is_valid = !key.is_empty()
+        && key.len() <= MAX_KEY_LENGTH
+        && key
+            .chars()
+            .all(|character| character.is_ascii_lowercase() || character.is_ascii_digit() || character == '_');
+    if !is_valid {
+        return Err(BecoError {
+            message: format!("Invalid attribute key: {key}"),
+            status: Code::InvalidArgument,
+        });
+    }
+    if RESERVED_KEYS.contains(&key.as_str()) {
+        return Err(BecoError {
+            message: format!("Attribute key is reserved: {key}"),
+            status: Code::InvalidArgument,
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,456 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use k256::ecdsa::{
+    signature::{Signer, Verifier},
+    Signature, SigningKey,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use tonic::Code;
+use uuid::Uuid;
+
+use crate::{
+    enums::value_reference::ValueReference,
+    errors::BecoError,
+    proto::beco::{
+        CredentialStatus as ProtoCredentialStatus, IssueCredentialRequest, RevokeCredentialRequest,
+        VerifyCredentialResponse,
+    },
+    requests::user_request::FieldReference,
+    user::{
+        attestation::value_hash, attribute::AttributeValue, public_user::PublicUser, user::User,
+    },
+};
+
+// A credential is a W3C verifiable credential about the user, encoded as a JWT and signed by the
+// sst's issuer key. The user only keeps a record of what was issued, the claims are rebuilt from
+// their fields when the sst signs, and revoking sets the credential's bit in their status list.
+
+const CREDENTIAL_EXPIRY_DAYS: i64 = 365;
+const CREDENTIAL_CONTEXT: &str = "https://www.w3.org/2018/credentials/v1";
+pub const CREDENTIAL_ISSUER: &str = "urn:beco:sst";
+
+pub fn credential_expiry() -> Duration {
+    Duration::days(CREDENTIAL_EXPIRY_DAYS)
+}
+
+pub fn subject_id(user_id: &String) -> String {
+    format!("urn:beco:user:{user_id}")
+}
+
+pub fn status_list_id(user_id: &String) -> String {
+    format!("urn:beco:status:{user_id}")
+}
+
+// a date is written as YYYY-MM-DD and an address as an object
+fn attribute_claim(value: &AttributeValue) -> Value {
+    match value {
+        AttributeValue::String(text) => json!(text),
+        AttributeValue::Date(date) => json!(date.format("%Y-%m-%d").to_string()),
+        AttributeValue::Address(address) => json!({
+            "lines": address.lines,
+            "locality": address.locality,
+            "region": address.region,
+            "postalCode": address.postal_code,
+            "country": address.country,
+        }),
+    }
+}
+
+// the claims about the user, taken from what calling_user sees of them
+pub fn credential_subject(
+    user: &User,
+    calling_user: &PublicUser,
+    fields: &Vec<FieldReference>,
+) -> Result<Value, BecoError> {
+    let public_user = user.as_public_user(calling_user);
+    let missing = |field: &FieldReference| BecoError {
+        message: format!("No value to put in the credential: {:?}", field.reference),
+        status: Code::FailedPrecondition,
+    };
+    let mut subject = Map::new();
+    subject.insert("id".into(), json!(subject_id(&public_user.id)));
+    let mut chain_keys = vec![];
+    let mut attributes = Map::new();
+    for field in fields {
+        match field.reference {
+            ValueReference::DETAIL_FIRST_NAME => {
+                if public_user.first_name.is_none() {
+                    return Err(missing(field));
+                }
+                subject.insert("firstName".into(), json!(public_user.first_name));
+            }
+            ValueReference::DETAIL_OTHER_NAMES => {
+                if public_user.other_names.is_none() {
+                    return Err(missing(field));
+                }
+                subject.insert("otherNames".into(), json!(public_user.other_names));
+            }
+            ValueReference::DETAIL_LAST_NAME => {
+                if public_user.last_name.is_none() {
+                    return Err(missing(field));
+                }
+                subject.insert("lastName".into(), json!(public_user.last_name));
+            }
+            ValueReference::CHAIN_HEYS => {
+                let key_option = public_user.chain_accounts.iter().find_map(|chain_account| {
+                    chain_account
+                        .keys
+                        .iter()
+                        .find(|key| Some(&key.alias) == field.key.as_ref())
+                        .map(|key| (chain_account.chain, key))
+                });
+                if key_option.is_none() {
+                    return Err(missing(field));
+                }
+                let (chain, key) = key_option.unwrap();
+                chain_keys.push(json!({
+                    "chain": chain.to_string(),
+                    "alias": key.alias,
+                    "address": key.address,
+                }));
+            }
+            ValueReference::DETAIL_ATTRIBUTE => {
+                let value_option = field
+                    .key
+                    .as_ref()
+                    .and_then(|key| user.user_details.attribute(key, calling_user));
+                if value_option.is_none() {
+                    return Err(missing(field));
+                }
+                attributes.insert(
+                    field.key.clone().unwrap(),
+                    attribute_claim(&value_option.unwrap()),
+                );
+            }
+        }
+    }
+    if !chain_keys.is_empty() {
+        subject.insert("chainKeys".into(), Value::Array(chain_keys));
+    }
+    if !attributes.is_empty() {
+        subject.insert("attributes".into(), Value::Object(attributes));
+    }
+    Ok(Value::Object(subject))
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub enum CredentialStatus {
+    Active,
+    Revoked,
+    Expired,
+}
+
+impl From<CredentialStatus> for i32 {
+    fn from(val: CredentialStatus) -> Self {
+        match val {
+            CredentialStatus::Active => ProtoCredentialStatus::Active as i32,
+            CredentialStatus::Revoked => ProtoCredentialStatus::Revoked as i32,
+            CredentialStatus::Expired => ProtoCredentialStatus::Expired as i32,
+        }
+    }
+}
+
+// what the user keeps of a credential, enough to rebuild and check it but not the claims
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct IssuedCredential {
+    pub id: String,
+    pub fields: Vec<FieldReference>,
+    // the hash of the credential subject, the sst will not sign once the fields have changed
+    pub claims_hash: String,
+    // the credential's bit in the user's status list
+    pub status_index: u32,
+    pub issued_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl IssuedCredential {
+    pub fn status(&self, at: &DateTime<Utc>) -> CredentialStatus {
+        if self.revoked_at.is_some() {
+            CredentialStatus::Revoked
+        } else if &self.expires_at <= at {
+            CredentialStatus::Expired
+        } else {
+            CredentialStatus::Active
+        }
+    }
+
+    // the JWT claims, the vc claim holds the credential itself
+    pub fn claims(&self, user_id: &String, subject: Value) -> Value {
+        let status_index = self.status_index.to_string();
+        json!({
+            "iss": CREDENTIAL_ISSUER,
+            "sub": subject_id(user_id),
+            "jti": format!("urn:uuid:{}", self.id),
+            "nbf": self.issued_at.timestamp(),
+            "exp": self.expires_at.timestamp(),
+            "vc": {
+                "@context": [CREDENTIAL_CONTEXT],
+                "type": ["VerifiableCredential"],
+                "issuer": CREDENTIAL_ISSUER,
+                "issuanceDate": self.issued_at.to_rfc3339(),
+                "expirationDate": self.expires_at.to_rfc3339(),
+                "credentialSubject": subject,
+                "credentialStatus": {
+                    "id": format!("{}#{status_index}", status_list_id(user_id)),
+                    "type": "BecoStatusListEntry",
+                    "statusPurpose": "revocation",
+                    "statusListIndex": status_index,
+                    "statusListCredential": status_list_id(user_id),
+                },
+            },
+        })
+    }
+}
+
+// bit n is set when credential n is revoked, most significant bit first
+pub fn encode_status_list(credentials: &[IssuedCredential]) -> String {
+    let mut bits = vec![0u8; credentials.len().div_ceil(8)];
+    for credential in credentials.iter() {
+        if credential.revoked_at.is_some() {
+            let index = credential.status_index as usize;
+            bits[index / 8] |= 0x80 >> (index % 8);
+        }
+    }
+    URL_SAFE_NO_PAD.encode(bits)
+}
+
+// the validated form of IssueCredentialRequest, the id and hash are set by the node that proposes
+// it so every node records the same credential, the dates come from the validator time
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct IssueCredential {
+    pub user_id: String,
+    pub calling_user: String,
+    pub id: String,
+    pub fields: Vec<FieldReference>,
+    pub claims_hash: String,
+    pub expected_sequence: Option<u64>,
+}
+
+impl IssueCredential {
+    pub fn new(
+        request: IssueCredentialRequest,
+        user: &User,
+        calling_user: &PublicUser,
+    ) -> Result<Self, BecoError> {
+        if request.fields.is_empty() {
+            return Err(BecoError {
+                message: "No fields provided".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        let mut fields: Vec<FieldReference> = vec![];
+        for message in request.fields {
+            let field_result = FieldReference::try_from(message);
+            if let Err(err) = field_result {
+                return Err(err);
+            }
+            let field = field_result.unwrap();
+            if fields.contains(&field) {
+                return Err(BecoError {
+                    message: format!("Duplicate field: {:?}", field.reference),
+                    status: Code::InvalidArgument,
+                });
+            }
+            fields.push(field);
+        }
+        let subject_result = credential_subject(user, calling_user, &fields);
+        if let Err(err) = subject_result {
+            return Err(err);
+        }
+        Ok(Self {
+            user_id: request.user_id,
+            calling_user: request.calling_user,
+            id: Uuid::new_v4().to_string(),
+            fields,
+            claims_hash: value_hash(&subject_result.unwrap()),
+            expected_sequence: request.expected_sequence,
+        })
+    }
+}
+
+// the validated form of RevokeCredentialRequest
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct RevokeCredential {
+    pub user_id: String,
+    pub calling_user: String,
+    pub credential_id: String,
+    pub expected_sequence: Option<u64>,
+}
+
+impl From<RevokeCredentialRequest> for RevokeCredential {
+    fn from(request: RevokeCredentialRequest) -> Self {
+        Self {
+            user_id: request.user_id,
+            calling_user: request.calling_user,
+            credential_id: request.credential_id,
+            expected_sequence: request.expected_sequence,
+        }
+    }
+}
+
+// asks the sst to sign a credential once its issuance is committed
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct SignCredential {
+    pub user_id: String,
+    pub credential_id: String,
+}
+
+// what the sst found when checking a credential
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct VerifiedCredential {
+    pub credential_id: String,
+    pub user_id: String,
+    pub status: CredentialStatus,
+    // JSON encoded
+    pub credential_subject: String,
+    pub issued_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl From<VerifiedCredential> for VerifyCredentialResponse {
+    fn from(val: VerifiedCredential) -> Self {
+        VerifyCredentialResponse {
+            credential_id: val.credential_id,
+            user_id: val.user_id,
+            status: val.status.into(),
+            credential_subject: val.credential_subject,
+            issued_at: val.issued_at.to_rfc3339(),
+            expires_at: val.expires_at.to_rfc3339(),
+        }
+    }
+}
+
+// the claims of a JWT whose signature was checked
+#[derive(Debug, Clone)]
+pub struct CredentialClaims {
+    pub credential_id: String,
+    pub user_id: String,
+    pub credential_subject: Value,
+    pub issued_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl TryFrom<Value> for CredentialClaims {
+    type Error = BecoError;
+
+    fn try_from(claims: Value) -> Result<Self, Self::Error> {
+        let credential_id = claims["jti"]
+            .as_str()
+            .and_then(|jti| jti.strip_prefix("urn:uuid:"));
+        let user_id = claims["sub"]
+            .as_str()
+            .and_then(|sub| sub.strip_prefix("urn:beco:user:"));
+        let issued_at = claims["nbf"]
+            .as_i64()
+            .and_then(|nbf| Utc.timestamp_opt(nbf, 0).single());
+        let expires_at = claims["exp"]
+            .as_i64()
+            .and_then(|exp| Utc.timestamp_opt(exp, 0).single());
+        let credential_subject = &claims["vc"]["credentialSubject"];
+        if credential_id.is_none()
+            || user_id.is_none()
+            || issued_at.is_none()
+            || expires_at.is_none()
+            || !credential_subject.is_object()
+        {
+            return Err(BecoError {
+                message: "The credential is missing claims".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        Ok(Self {
+            credential_id: credential_id.unwrap().into(),
+            user_id: user_id.unwrap().into(),
+            credential_subject: credential_subject.clone(),
+            issued_at: issued_at.unwrap(),
+            expires_at: expires_at.unwrap(),
+        })
+    }
+}
+
+// signs credentials as ES256K JWTs
+#[derive(Debug)]
+pub struct Issuer {
+    signing_key: SigningKey,
+}
+
+impl Issuer {
+    pub fn new(secret_key: &str) -> Result<Self, BecoError> {
+        let signing_key_option = hex::decode(secret_key)
+            .ok()
+            .and_then(|bytes| SigningKey::from_slice(&bytes).ok());
+        if signing_key_option.is_none() {
+            return Err(BecoError {
+                message: "Invalid issuer secret key".into(),
+                status: Code::FailedPrecondition,
+            });
+        }
+        Ok(Self {
+            signing_key: signing_key_option.unwrap(),
+        })
+    }
+
+    // set as the kid of every credential so verifiers can check it without asking the sst
+    pub fn public_key(&self) -> String {
+        hex::encode(self.signing_key.verifying_key().to_sec1_bytes())
+    }
+
+    pub fn sign(&self, claims: &Value) -> String {
+        let header = json!({
+            "alg": "ES256K",
+            "typ": "JWT",
+            "kid": self.public_key(),
+        });
+        let signing_input = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(header.to_string()),
+            URL_SAFE_NO_PAD.encode(claims.to_string())
+        );
+        let signature: Signature = self.signing_key.sign(signing_input.as_bytes());
+        format!(
+            "{signing_input}.{}",
+            URL_SAFE_NO_PAD.encode(signature.to_bytes())
+        )
+    }
+
+    // the claims of a JWT signed by this issuer
+    pub fn verify(&self, jwt: &str) -> Result<Value, BecoError> {
+        let invalid = BecoError {
+            message: "Invalid credential".into(),
+            status: Code::InvalidArgument,
+        };
+        let parts: Vec<&str> = jwt.split('.').collect();
+        if parts.len() != 3 {
+            return Err(invalid);
+        }
+        let signature_option = URL_SAFE_NO_PAD
+            .decode(parts[2])
+            .ok()
+            .and_then(|bytes| Signature::from_slice(&bytes).ok());
+        if signature_option.is_none() {
+            return Err(invalid);
+        }
+        let signing_input = format!("{}.{}", parts[0], parts[1]);
+        if self
+            .signing_key
+            .verifying_key()
+            .verify(signing_input.as_bytes(), &signature_option.unwrap())
+            .is_err()
+        {
+            return Err(BecoError {
+                message: "The credential was not signed by this issuer".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        let claims_option = URL_SAFE_NO_PAD
+            .decode(parts[1])
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<Value>(&bytes).ok());
+        if claims_option.is_none() {
+            return Err(invalid);
+        }
+        Ok(claims_option.unwrap())
+    }
+}
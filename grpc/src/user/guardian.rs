@@ -0,0 +1,80 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tonic::Code;
+
+use crate::{
+    errors::BecoError,
+    proto::beco::{GuardianResponse, SetGuardianRequest},
+};
+
+// the guardian edits every field of the dependant until the end date, the dependant stays owner
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct Guardianship {
+    pub guardian_id: String,
+    pub until: DateTime<Utc>,
+}
+
+impl Guardianship {
+    pub fn is_active(&self, at: &DateTime<Utc>) -> bool {
+        &self.until > at
+    }
+}
+
+impl From<Guardianship> for GuardianResponse {
+    fn from(val: Guardianship) -> Self {
+        GuardianResponse {
+            guardian_id: val.guardian_id,
+            until: val.until.to_rfc3339(),
+        }
+    }
+}
+
+// the validated form of SetGuardianRequest
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct SetGuardian {
+    pub user_id: String,
+    pub calling_user: String,
+    pub guardianship: Guardianship,
+    pub expected_sequence: Option<u64>,
+}
+
+impl TryFrom<SetGuardianRequest> for SetGuardian {
+    type Error = BecoError;
+
+    fn try_from(request: SetGuardianRequest) -> Result<Self, Self::Error> {
+        if request.guardian_id.is_empty() {
+            return Err(BecoError {
+                message: "No guardian provided".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        if request.guardian_id == request.user_id {
+            return Err(BecoError {
+                message: "A user cannot be their own guardian".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        let until_result = DateTime::parse_from_rfc3339(&request.until);
+        if until_result.is_err() {
+            return Err(BecoError {
+                message: format!("Invalid until: {}", request.until),
+                status: Code::InvalidArgument,
+            });
+        }
+        Ok(Self {
+            user_id: request.user_id,
+            calling_user: request.calling_user,
+            guardianship: Guardianship {
+                guardian_id: request.guardian_id,
+                until: until_result.unwrap().with_timezone(&Utc),
+            },
+            expected_sequence: request.expected_sequence,
+        })
+    }
+}
+
+// emitted by the sst sweeper once the end date has passed, the guardian's grants lapse with it
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct EndGuardianship {
+    pub user_id: String,
+}
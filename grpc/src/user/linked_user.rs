@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use tonic::Code;
+
+use crate::{errors::BecoError, proto::beco::ModifyLinkedUserRequest};
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub enum LinkStatus {
+    // this user consented, waiting on the other user
+    PROPOSED,
+    // both users consented
+    LINKED,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct LinkedUser {
+    pub user_id: String,
+    pub status: LinkStatus,
+}
+
+// the validated form of ModifyLinkedUserRequest, user_id is the other side of the link
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct ModifyLinkedUser {
+    pub user_id: String,
+    pub calling_user: String,
+    pub expected_sequence: Option<u64>,
+}
+
+impl TryFrom<ModifyLinkedUserRequest> for ModifyLinkedUser {
+    type Error = BecoError;
+
+    fn try_from(request: ModifyLinkedUserRequest) -> Result<Self, Self::Error> {
+        if request.user_id.is_empty() {
+            return Err(BecoError {
+                message: "No linked user provided".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        if request.user_id == request.calling_user {
+            return Err(BecoError {
+                message: "A user cannot be linked to themselves".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        Ok(Self {
+            user_id: request.user_id,
+            calling_user: request.calling_user,
+            expected_sequence: request.expected_sequence,
+        })
+    }
+}
+
+// emitted by the sst once both users consented, one for each side of the link
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct ConfirmLinkedUser {
+    pub user_id: String,
+    pub linked_user_id: String,
+}
@@ -1,5 +1,14 @@
 pub mod user_details;
 pub mod public_user;
 pub mod user;
+pub mod national_identifier;
+pub mod user_diff;
+pub mod attribute;
+pub mod linked_user;
+pub mod guardian;
+pub mod recovery;
+pub mod approval;
+pub mod attestation;
+pub mod credential;
 
 mod tests;
\ No newline at end of file
@@ -0,0 +1,171 @@
+use chacha20poly1305::{
+    aead::{Aead, KeyInit},
+    ChaCha20Poly1305, Key, Nonce,
+};
+use hmac::{Hmac, Mac};
+use k256::{
+    ecdh::{diffie_hellman, EphemeralSecret},
+    elliptic_curve::{
+        rand_core::{OsRng, RngCore},
+        sec1::ToEncodedPoint,
+    },
+    PublicKey, SecretKey,
+};
+use sha2::{Digest, Sha256};
+use tonic::Code;
+
+use crate::errors::BecoError;
+
+// A national identifier only leaves the grpc node sealed to the sst's public key. The sst opens
+// it, turns it into a keyed blind index and discards it, so the raw value is never gossiped or
+// persisted.
+
+const PUBLIC_KEY_LENGTH: usize = 33;
+const NONCE_LENGTH: usize = 12;
+
+// formatting differences such as spaces or lower case must not hide a duplicate
+pub fn normalise(national_identifier: &str) -> String {
+    national_identifier
+        .chars()
+        .filter(|character| character.is_ascii_alphanumeric())
+        .map(|character| character.to_ascii_uppercase())
+        .collect()
+}
+
+// ephemeral ecdh with the sst key, output is hex(ephemeral public key | nonce | ciphertext)
+pub fn seal(national_identifier: &str, sst_public_key: &str) -> Result<String, BecoError> {
+    let public_key_result = hex::decode(sst_public_key)
+        .ok()
+        .and_then(|bytes| PublicKey::from_sec1_bytes(&bytes).ok());
+    if public_key_result.is_none() {
+        return Err(BecoError {
+            message: "Invalid sst identity public key".into(),
+            status: Code::FailedPrecondition,
+        });
+    }
+    let public_key = public_key_result.unwrap();
+    let normalised = normalise(national_identifier);
+    if normalised.is_empty() {
+        return Err(BecoError {
+            message: "Invalid national identifier".into(),
+            status: Code::InvalidArgument,
+        });
+    }
+
+    let ephemeral_secret = EphemeralSecret::random(&mut OsRng);
+    let ephemeral_public = ephemeral_secret.public_key().to_encoded_point(true);
+    let shared_secret = ephemeral_secret.diffie_hellman(&public_key);
+    let key = symmetric_key(
+        shared_secret.raw_secret_bytes().as_slice(),
+        ephemeral_public.as_bytes(),
+    );
+    let mut nonce = [0u8; NONCE_LENGTH];
+    OsRng.fill_bytes(&mut nonce);
+    let ciphertext_result =
+        ChaCha20Poly1305::new(&key).encrypt(Nonce::from_slice(&nonce), normalised.as_bytes());
+    if ciphertext_result.is_err() {
+        return Err(BecoError {
+            message: "Failed to seal the national identifier".into(),
+            status: Code::Internal,
+        });
+    }
+
+    let mut sealed = ephemeral_public.as_bytes().to_vec();
+    sealed.extend_from_slice(&nonce);
+    sealed.extend_from_slice(&ciphertext_result.unwrap());
+    Ok(hex::encode(sealed))
+}
+
+fn symmetric_key(shared_secret: &[u8], ephemeral_public: &[u8]) -> Key {
+    let mut hasher = Sha256::new();
+    hasher.update(shared_secret);
+    hasher.update(ephemeral_public);
+    hasher.finalize()
+}
+
+pub struct BlindIndex {
+    secret_key: SecretKey,
+    index_key: Vec<u8>,
+    salt: Vec<u8>,
+}
+
+impl BlindIndex {
+    pub fn new(secret_key: &str, index_key: &str, salt: &str) -> Result<Self, BecoError> {
+        let secret_key_result = hex::decode(secret_key)
+            .ok()
+            .and_then(|bytes| SecretKey::from_slice(&bytes).ok());
+        if secret_key_result.is_none() {
+            return Err(BecoError {
+                message: "Invalid identity secret key".into(),
+                status: Code::FailedPrecondition,
+            });
+        }
+        let index_key_result = hex::decode(index_key);
+        if index_key_result.is_err() || index_key_result.as_ref().unwrap().len() < 32 {
+            return Err(BecoError {
+                message: "The blind index key must be at least 32 hex encoded bytes".into(),
+                status: Code::FailedPrecondition,
+            });
+        }
+        Ok(Self {
+            secret_key: secret_key_result.unwrap(),
+            index_key: index_key_result.unwrap(),
+            salt: salt.as_bytes().to_vec(),
+        })
+    }
+
+    // configured on the grpc nodes so they can seal identifiers for this sst
+    pub fn public_key(&self) -> String {
+        hex::encode(self.secret_key.public_key().to_encoded_point(true).as_bytes())
+    }
+
+    pub fn index_sealed(&self, sealed: &str) -> Result<String, BecoError> {
+        let national_identifier_result = self.open(sealed);
+        if let Err(err) = national_identifier_result {
+            return Err(err);
+        }
+        Ok(self.index(&national_identifier_result.unwrap()))
+    }
+
+    pub fn index(&self, national_identifier: &str) -> String {
+        let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(&self.index_key).unwrap();
+        mac.update(&self.salt);
+        mac.update(normalise(national_identifier).as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    fn open(&self, sealed: &str) -> Result<String, BecoError> {
+        let invalid = BecoError {
+            message: "Invalid sealed national identifier".into(),
+            status: Code::InvalidArgument,
+        };
+        let sealed_result = hex::decode(sealed);
+        if sealed_result.is_err() {
+            return Err(invalid);
+        }
+        let sealed = sealed_result.unwrap();
+        if sealed.len() <= PUBLIC_KEY_LENGTH + NONCE_LENGTH {
+            return Err(invalid);
+        }
+        let (ephemeral_public, rest) = sealed.split_at(PUBLIC_KEY_LENGTH);
+        let (nonce, ciphertext) = rest.split_at(NONCE_LENGTH);
+        let ephemeral_public_result = PublicKey::from_sec1_bytes(ephemeral_public);
+        if ephemeral_public_result.is_err() {
+            return Err(invalid);
+        }
+        let shared_secret = diffie_hellman(
+            self.secret_key.to_nonzero_scalar(),
+            ephemeral_public_result.unwrap().as_affine(),
+        );
+        let key = symmetric_key(
+            shared_secret.raw_secret_bytes().as_slice(),
+            ephemeral_public,
+        );
+        let plaintext_result =
+            ChaCha20Poly1305::new(&key).decrypt(Nonce::from_slice(nonce), ciphertext);
+        if plaintext_result.is_err() {
+            return Err(invalid);
+        }
+        String::from_utf8(plaintext_result.unwrap()).map_err(|_| invalid)
+    }
+}
@@ -1,6 +1,9 @@
 use serde::{Serialize, Deserialize};
 
-use crate::{chain::chain_custody::PublicChainCustody, proto::beco::GetUserResponse};
+use crate::{
+    chain::chain_custody::PublicChainCustody,
+    organisation::user_organisation::UserOrganisation, proto::beco::GetUserResponse,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize, Hash)]
 pub struct PublicUser {
@@ -9,6 +12,12 @@ pub struct PublicUser {
     pub other_names: Option<Vec<String>>,
     pub last_name: Option<String>,
     pub chain_accounts: Vec<PublicChainCustody>,
+    // only set on a calling user, the groups its grants are checked against
+    #[serde(default)]
+    pub groups: Vec<String>,
+    // only set on a calling user, the memberships policies are evaluated against
+    #[serde(default)]
+    pub memberships: Vec<UserOrganisation>,
 }
 
 impl PublicUser {
@@ -25,6 +34,8 @@ impl PublicUser {
             other_names,
             last_name,
             chain_accounts,
+            groups: vec![],
+            memberships: vec![],
         }
     }
 }
@@ -47,6 +58,14 @@ impl Into<GetUserResponse> for PublicUser {
             },
             last_name: self.last_name,
             chain_accounts: self.chain_accounts.iter().map(|chain_accounts| chain_accounts.clone().into()).collect(),
+            sequence: None,
+            attributes: vec![],
+            linked_users: vec![],
+            guardian: None,
+            auth_key: None,
+            recovery: None,
+            pending_approvals: vec![],
+            attestations: vec![],
         }
     }
 }
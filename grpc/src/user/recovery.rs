@@ -0,0 +1,304 @@
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Duration, Utc};
+use k256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use tonic::Code;
+
+use crate::{
+    errors::BecoError,
+    proto::beco::{
+        ApproveRecoveryRequest, CancelRecoveryRequest, RecoveryResponse, SetAuthKeyRequest,
+        SetRecoveryGuardiansRequest,
+    },
+};
+
+// Recovery only replaces the key a user authenticates with, wallet keys are never touched. A
+// recovery that reaches its threshold waits out the delay so the owner can still cancel it.
+
+const RECOVERY_DELAY_HOURS: i64 = 72;
+
+pub fn recovery_delay() -> Duration {
+    Duration::hours(RECOVERY_DELAY_HOURS)
+}
+
+// what a guardian signs to approve moving the user to a new key, the epoch ties the approval to
+// the current attempt
+pub fn recovery_message(user_id: &String, epoch: u64, public_key: &String) -> String {
+    format!("recover:{user_id}:{epoch}:{public_key}")
+}
+
+// hex encoded SEC1 secp256k1 keys
+pub fn validate_public_key(public_key: &String) -> Result<VerifyingKey, BecoError> {
+    let invalid = BecoError {
+        message: format!("Invalid public key: {public_key}"),
+        status: Code::InvalidArgument,
+    };
+    let bytes_result = hex::decode(public_key);
+    if bytes_result.is_err() {
+        return Err(invalid);
+    }
+    let key_result = VerifyingKey::from_sec1_bytes(&bytes_result.unwrap());
+    if key_result.is_err() {
+        return Err(invalid);
+    }
+    Ok(key_result.unwrap())
+}
+
+// a hex encoded 64 byte ECDSA signature over the sha256 of the message
+pub fn verify_signature(
+    public_key: &String,
+    message: &String,
+    signature: &String,
+) -> Result<(), BecoError> {
+    let key_result = validate_public_key(public_key);
+    if let Err(err) = key_result {
+        return Err(err);
+    }
+    let invalid = BecoError {
+        message: "Invalid signature".into(),
+        status: Code::PermissionDenied,
+    };
+    let signature_bytes_result = hex::decode(signature);
+    if signature_bytes_result.is_err() {
+        return Err(invalid);
+    }
+    let signature_result = Signature::from_slice(&signature_bytes_result.unwrap());
+    if signature_result.is_err() {
+        return Err(invalid);
+    }
+    if key_result
+        .unwrap()
+        .verify(message.as_bytes(), &signature_result.unwrap())
+        .is_err()
+    {
+        return Err(invalid);
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct RecoveryGuardian {
+    pub user_id: String,
+    // the guardian's auth key when they were nominated, approvals are checked against it
+    pub public_key: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct PendingRecovery {
+    pub public_key: String,
+    pub approvals: BTreeSet<String>,
+    // set once the threshold is met
+    pub ready_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct Recovery {
+    pub guardians: Vec<RecoveryGuardian>,
+    pub threshold: u32,
+    pub pending: Option<PendingRecovery>,
+}
+
+impl Recovery {
+    pub fn guardian(&self, user_id: &String) -> Option<&RecoveryGuardian> {
+        self.guardians
+            .iter()
+            .find(|guardian| &guardian.user_id == user_id)
+    }
+
+    pub fn as_response(&self, epoch: u64) -> RecoveryResponse {
+        RecoveryResponse {
+            epoch,
+            guardian_ids: self
+                .guardians
+                .iter()
+                .map(|guardian| guardian.user_id.clone())
+                .collect(),
+            threshold: self.threshold,
+            pending_public_key: self.pending.as_ref().map(|pending| pending.public_key.clone()),
+            approvals: self
+                .pending
+                .as_ref()
+                .map(|pending| pending.approvals.iter().cloned().collect())
+                .unwrap_or_default(),
+            ready_at: self
+                .pending
+                .as_ref()
+                .and_then(|pending| pending.ready_at)
+                .map(|ready_at| ready_at.to_rfc3339()),
+        }
+    }
+}
+
+// the validated form of SetAuthKeyRequest
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct SetAuthKey {
+    pub user_id: String,
+    pub calling_user: String,
+    pub public_key: String,
+    pub expected_sequence: Option<u64>,
+    // by the current auth key, empty while the user has none
+    #[serde(default)]
+    pub signature: String,
+}
+
+impl SetAuthKey {
+    // sequence is the user's sequence before the change, so a signature is only good once
+    pub fn message(&self, sequence: u64) -> String {
+        format!("auth-key:{}:{sequence}:{}", self.user_id, self.public_key)
+    }
+}
+
+impl TryFrom<SetAuthKeyRequest> for SetAuthKey {
+    type Error = BecoError;
+
+    fn try_from(request: SetAuthKeyRequest) -> Result<Self, Self::Error> {
+        validate_public_key(&request.public_key)?;
+        Ok(Self {
+            user_id: request.user_id,
+            calling_user: request.calling_user,
+            public_key: request.public_key,
+            expected_sequence: request.expected_sequence,
+            signature: request.signature,
+        })
+    }
+}
+
+// the validated form of SetRecoveryGuardiansRequest, no guardians turns recovery off
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct SetRecoveryGuardians {
+    pub user_id: String,
+    pub calling_user: String,
+    pub guardians: Vec<RecoveryGuardian>,
+    pub threshold: u32,
+    pub expected_sequence: Option<u64>,
+    #[serde(default)]
+    pub signature: String,
+}
+
+impl SetRecoveryGuardians {
+    pub fn message(&self, sequence: u64) -> String {
+        let guardians: Vec<String> = self
+            .guardians
+            .iter()
+            .map(|guardian| format!("{}={}", guardian.user_id, guardian.public_key))
+            .collect();
+        format!(
+            "recovery-guardians:{}:{sequence}:{}:{}",
+            self.user_id,
+            self.threshold,
+            guardians.join(",")
+        )
+    }
+}
+
+impl TryFrom<SetRecoveryGuardiansRequest> for SetRecoveryGuardians {
+    type Error = BecoError;
+
+    fn try_from(request: SetRecoveryGuardiansRequest) -> Result<Self, Self::Error> {
+        let mut user_ids: BTreeSet<String> = BTreeSet::new();
+        let mut guardians = vec![];
+        for guardian in request.guardians {
+            if guardian.user_id.is_empty() || guardian.user_id == request.user_id {
+                return Err(BecoError {
+                    message: format!("Invalid recovery guardian: {}", guardian.user_id),
+                    status: Code::InvalidArgument,
+                });
+            }
+            if !user_ids.insert(guardian.user_id.clone()) {
+                return Err(BecoError {
+                    message: format!("Duplicate recovery guardian: {}", guardian.user_id),
+                    status: Code::InvalidArgument,
+                });
+            }
+            validate_public_key(&guardian.public_key)?;
+            guardians.push(RecoveryGuardian {
+                user_id: guardian.user_id,
+                public_key: guardian.public_key,
+            });
+        }
+        let has_guardians = !guardians.is_empty();
+        if has_guardians && (request.threshold == 0 || request.threshold as usize > guardians.len())
+        {
+            return Err(BecoError {
+                message: format!(
+                    "Threshold must be between 1 and {}, got {}",
+                    guardians.len(),
+                    request.threshold
+                ),
+                status: Code::InvalidArgument,
+            });
+        }
+        Ok(Self {
+            user_id: request.user_id,
+            calling_user: request.calling_user,
+            guardians,
+            threshold: request.threshold,
+            expected_sequence: request.expected_sequence,
+            signature: request.signature,
+        })
+    }
+}
+
+// the validated form of ApproveRecoveryRequest
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct ApproveRecovery {
+    pub user_id: String,
+    pub calling_user: String,
+    pub public_key: String,
+    pub signature: String,
+}
+
+impl TryFrom<ApproveRecoveryRequest> for ApproveRecovery {
+    type Error = BecoError;
+
+    fn try_from(request: ApproveRecoveryRequest) -> Result<Self, Self::Error> {
+        validate_public_key(&request.public_key)?;
+        if request.signature.is_empty() {
+            return Err(BecoError {
+                message: "No signature provided".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        Ok(Self {
+            user_id: request.user_id,
+            calling_user: request.calling_user,
+            public_key: request.public_key,
+            signature: request.signature,
+        })
+    }
+}
+
+// the validated form of CancelRecoveryRequest
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct CancelRecovery {
+    pub user_id: String,
+    pub calling_user: String,
+    pub expected_sequence: Option<u64>,
+    #[serde(default)]
+    pub signature: String,
+}
+
+impl CancelRecovery {
+    pub fn message(&self, sequence: u64) -> String {
+        format!("cancel-recovery:{}:{sequence}", self.user_id)
+    }
+}
+
+impl From<CancelRecoveryRequest> for CancelRecovery {
+    fn from(request: CancelRecoveryRequest) -> Self {
+        Self {
+            user_id: request.user_id,
+            calling_user: request.calling_user,
+            expected_sequence: request.expected_sequence,
+            signature: request.signature,
+        }
+    }
+}
+
+// emitted by the sst sweeper once the delay has passed
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub struct CompleteRecovery {
+    pub user_id: String,
+    pub public_key: String,
+}
@@ -0,0 +1,89 @@
+#![cfg(test)]
+
+use chrono::NaiveDate;
+use tonic::Code;
+
+use crate::{
+    proto::beco::{
+        attribute_value::Value, AttributeValue as AttributeValueMessage, PostalAddress,
+        SetAttributeRequest,
+    },
+    user::attribute::{validate_key, Address, AttributeValue, SetAttribute},
+};
+
+fn set_attribute_request(key: &str, value: Option<Value>) -> SetAttributeRequest {
+    SetAttributeRequest {
+        user_id: "user".into(),
+        calling_user: "user".into(),
+        key: key.into(),
+        value: value.map(|value| AttributeValueMessage { value: Some(value) }),
+        expected_sequence: None,
+    }
+}
+
+#[test]
+fn parses_date() {
+    let request = set_attribute_request("date_of_birth", Some(Value::Date("1990-01-31".into())));
+
+    let set_attribute = SetAttribute::try_from(request).unwrap();
+
+    assert_eq!(
+        set_attribute.value,
+        AttributeValue::Date(NaiveDate::from_ymd_opt(1990, 1, 31).unwrap())
+    );
+}
+
+#[test]
+fn rejects_invalid_date() {
+    let request = set_attribute_request("date_of_birth", Some(Value::Date("31/01/1990".into())));
+
+    let err = SetAttribute::try_from(request).unwrap_err();
+
+    assert_eq!(err.status, Code::InvalidArgument);
+}
+
+#[test]
+fn rejects_missing_value() {
+    let err = SetAttribute::try_from(set_attribute_request("email", None)).unwrap_err();
+
+    assert_eq!(err.message, "No attribute value provided");
+}
+
+#[test]
+fn address_round_trips() {
+    let address = PostalAddress {
+        lines: vec!["1 Street".into()],
+        locality: "Town".into(),
+        region: None,
+        postal_code: "AB1 2CD".into(),
+        country: "GB".into(),
+    };
+    let request = set_attribute_request("address", Some(Value::Address(address.clone())));
+
+    let set_attribute = SetAttribute::try_from(request).unwrap();
+    let message: AttributeValueMessage = set_attribute.value.clone().into();
+
+    assert_eq!(
+        set_attribute.value,
+        AttributeValue::Address(Address {
+            lines: vec!["1 Street".into()],
+            locality: "Town".into(),
+            region: None,
+            postal_code: "AB1 2CD".into(),
+            country: "GB".into(),
+        })
+    );
+    assert_eq!(message.value, Some(Value::Address(address)));
+}
+
+#[test]
+fn validates_keys() {
+    assert!(validate_key(&"phone_number_2".into()).is_ok());
+    assert!(validate_key(&"".into()).is_err());
+    assert!(validate_key(&"Email".into()).is_err());
+    assert!(validate_key(&"a".repeat(65)).is_err());
+    assert_eq!(
+        validate_key(&"last_name".into()).unwrap_err().message,
+        "Attribute key is reserved: last_name"
+    );
+}
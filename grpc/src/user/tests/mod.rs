@@ -1,2 +1,5 @@
+mod attribute;
+mod national_identifier;
 mod user;
-mod user_details;
\ No newline at end of file
+mod user_details;
+mod user_diff;
\ No newline at end of file
@@ -0,0 +1,69 @@
+#![cfg(test)]
+
+use tonic::Code;
+
+use crate::user::national_identifier::{normalise, seal, BlindIndex};
+
+const SECRET_KEY: &str = "0101010101010101010101010101010101010101010101010101010101010101";
+const OTHER_SECRET_KEY: &str = "0202020202020202020202020202020202020202020202020202020202020202";
+const INDEX_KEY: &str = "0303030303030303030303030303030303030303030303030303030303030303";
+
+fn blind_index(secret_key: &str) -> BlindIndex {
+    BlindIndex::new(secret_key, INDEX_KEY, "salt").unwrap()
+}
+
+#[test]
+fn normalise_ignores_formatting() {
+    assert_eq!(normalise("ab 12 34 56 c"), "AB123456C");
+    assert_eq!(normalise("AB-12-34-56-C"), "AB123456C");
+}
+
+#[test]
+fn sealed_identifier_indexes_like_the_raw_value() {
+    let blind_index = blind_index(SECRET_KEY);
+
+    let sealed = seal("AB 12 34 56 C", &blind_index.public_key()).unwrap();
+
+    assert!(!sealed.contains("AB123456C"));
+    assert_eq!(
+        blind_index.index_sealed(&sealed).unwrap(),
+        blind_index.index("ab123456c")
+    );
+}
+
+#[test]
+fn sealing_twice_differs_but_indexes_the_same() {
+    let blind_index = blind_index(SECRET_KEY);
+
+    let first = seal("AB123456C", &blind_index.public_key()).unwrap();
+    let second = seal("AB123456C", &blind_index.public_key()).unwrap();
+
+    assert_ne!(first, second);
+    assert_eq!(
+        blind_index.index_sealed(&first).unwrap(),
+        blind_index.index_sealed(&second).unwrap()
+    );
+}
+
+#[test]
+fn index_depends_on_salt() {
+    let salted = BlindIndex::new(SECRET_KEY, INDEX_KEY, "other").unwrap();
+
+    assert_ne!(blind_index(SECRET_KEY).index("AB123456C"), salted.index("AB123456C"));
+}
+
+#[test]
+fn sealed_for_another_key_is_rejected() {
+    let sealed = seal("AB123456C", &blind_index(OTHER_SECRET_KEY).public_key()).unwrap();
+
+    let err = blind_index(SECRET_KEY).index_sealed(&sealed).unwrap_err();
+
+    assert_eq!(err.status, Code::InvalidArgument);
+}
+
+#[test]
+fn short_index_key_is_rejected() {
+    let result = BlindIndex::new(SECRET_KEY, "0303", "salt");
+
+    assert_eq!(result.err().unwrap().status, Code::FailedPrecondition);
+}
@@ -1,14 +1,21 @@
-#![cfg(test)]
+#![cfg(all(test, feature = "sst"))]
+
+use uuid::Uuid;
+
+use crate::user::{
+    linked_user::{ConfirmLinkedUser, ModifyLinkedUser},
+    public_user::PublicUser,
+    user::User,
+};
 
 // use tokio::sync::mpsc::Sender;
-// use uuid::Uuid;
 
 // use crate::{enums::blockchain::Blockchain, user::{user::User, public_user::PublicUser}};
 
 // #[test]
 // fn create_new_user() {
 //     let user: User = User::new(Some("hjdsj-98d8-oops".into(), Sender::));
-//     let public_user = PublicUser { id: user.id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+//     let public_user = PublicUser { id: user.id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
 //     let accounts = user.get_chain_accounts(Blockchain::XRPL, &public_user);
 //     assert_eq!(accounts, vec![]);
 // }
@@ -16,7 +23,7 @@
 // #[test]
 // fn add_account() {
 //     let mut user: User = User::new(Some("hjdsj-98d8-oops".into()));
-//     let public_user = PublicUser { id: user.id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+//     let public_user = PublicUser { id: user.id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
 //     let result = user.add_account(Blockchain::XRPL, "test".into(), &public_user).unwrap();
 //     let accounts = user.get_chain_accounts(Blockchain::XRPL, &public_user);
 //     let expected_response = vec![WalletResponse {
@@ -30,99 +37,129 @@
 // #[test]
 // fn add_account_invalid_blockchain() {
 //     let mut user: User = User::new(Some("hjdsj-98d8-oops".into()));
-//     let public_user = PublicUser { id: user.id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+//     let public_user = PublicUser { id: user.id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
 //     let result = user.add_account(Blockchain::UNSPECIFIED, "test".into(), &public_user);
 //     let expected_error = "No blockchain set";
 //     assert_eq!(result.unwrap_err().message, expected_error);
 // }
 
-// #[test]
-// fn add_linked_user() {
-//     let mut user: User = User::new(Some("hjdsj-98d8-oops".into()));
-//     let id = Uuid::new_v4();
-//     let public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-//     user.add_linked_user(&public_user);
-//     let linked_users = user.linked_users();
-//     assert_eq!(linked_users.len(), 1);
-//     assert_eq!(linked_users.get(&id.to_string()).unwrap(), &public_user);
-// }
-
-// #[test]
-// fn remove_linked_user() {
-//     let mut user: User = User::new(Some("hjdsj-98d8-oops".into()));
-//     let id = Uuid::new_v4();
-//     let calling_user = PublicUser { id: user.id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-//     let public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-//     user.add_linked_user(&public_user);
-//     let linked_users = user.linked_users();
-
-//     assert_eq!(linked_users.len(), 1);
-//     assert_eq!(linked_users.get(&id.to_string()).unwrap(), &public_user);
-    
-//     let result = user.remove_linked_user(&public_user, &calling_user);
-
-//     assert_eq!(result.err(), None);
-//     let updated_linked_users = user.linked_users();
-//     assert_eq!(updated_linked_users.len(), 0);
-//     assert_eq!(updated_linked_users.get(&id.to_string()), None);
-// }
-
-// #[test]
-// fn remove_linked_user_as_linked_user() {
-//     let mut user: User = User::new(Some("hjdsj-98d8-oops".into()));
-//     let id = Uuid::new_v4();
-//     let public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-//     user.add_linked_user(&public_user);
-//     let linked_users = user.linked_users();
-
-//     assert_eq!(linked_users.len(), 1);
-//     assert_eq!(linked_users.get(&id.to_string()).unwrap(), &public_user);
-    
-//     let result = user.remove_linked_user(&public_user, &public_user);
-
-//     assert_eq!(result.err(), None);
-//     let updated_linked_users = user.linked_users();
-//     assert_eq!(updated_linked_users.len(), 0);
-//     assert_eq!(updated_linked_users.get(&id.to_string()), None);
-// }
-
-// #[test]
-// fn remove_linked_user_invalid_calling_user() {
-//     let mut user: User = User::new(Some("hjdsj-98d8-oops".into()));
-//     let id = Uuid::new_v4();
-//     let calling_user = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-//     let public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-//     user.add_linked_user(&public_user);
-//     let linked_users = user.linked_users();
-
-//     assert_eq!(linked_users.len(), 1);
-//     assert_eq!(linked_users.get(&id.to_string()).unwrap(), &public_user);
-    
-//     let result = user.remove_linked_user(&public_user, &calling_user);
-
-//     assert_eq!(result.err().unwrap().message, "User does not have permission to remove this linked account");
-//     let updated_linked_users = user.linked_users();
-//     assert_eq!(updated_linked_users.len(), 1);
-//     assert_eq!(updated_linked_users.get(&id.to_string()).unwrap(), &public_user);
-// }
-
-// #[test]
-// fn remove_linked_user_does_not_exist() {
-//     let mut user: User = User::new(Some("hjdsj-98d8-oops".into()));
-//     let id = Uuid::new_v4();
-//     let calling_user = PublicUser { id: user.id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-//     let public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-//     let non_existant_public_user = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-//     user.add_linked_user(&public_user);
-//     let linked_users = user.linked_users();
-
-//     assert_eq!(linked_users.len(), 1);
-//     assert_eq!(linked_users.get(&id.to_string()).unwrap(), &public_user);
-    
-//     let result = user.remove_linked_user(&non_existant_public_user, &calling_user);
-
-//     assert_eq!(result.err().unwrap().message, "User does not exist as linked account");
-//     let updated_linked_users = user.linked_users();
-//     assert_eq!(updated_linked_users.len(), 1);
-//     assert_eq!(updated_linked_users.get(&id.to_string()).unwrap(), &public_user);
-// }
\ No newline at end of file
+fn modify_linked_user(user_id: &str, calling_user: &str) -> ModifyLinkedUser {
+    ModifyLinkedUser {
+        user_id: user_id.to_string(),
+        calling_user: calling_user.to_string(),
+        expected_sequence: None,
+    }
+}
+
+fn linked_user(linked_user_id: &str) -> (User, PublicUser) {
+    let mut user: User = User::new(Some("hjdsj-98d8-oops".into()));
+    let owner = PublicUser::new(user.id.clone(), None, None, None, vec![]);
+    user.add_linked_user(modify_linked_user(linked_user_id, &user.id), &owner)
+        .unwrap();
+    user.confirm_linked_user(ConfirmLinkedUser {
+        user_id: user.id.clone(),
+        linked_user_id: linked_user_id.to_string(),
+    })
+    .unwrap();
+    (user, owner)
+}
+
+#[test]
+fn add_linked_user_waits_for_confirmation() {
+    let mut user: User = User::new(Some("hjdsj-98d8-oops".into()));
+    let owner = PublicUser::new(user.id.clone(), None, None, None, vec![]);
+    let id = Uuid::new_v4().to_string();
+
+    let result = user.add_linked_user(modify_linked_user(&id, &user.id), &owner);
+
+    assert_eq!(result.err(), None);
+    assert!(user.consents_to_link(&id));
+    assert_eq!(user.linked_users(&owner), Vec::<String>::new());
+}
+
+#[test]
+fn add_linked_user_for_another_user() {
+    let mut user: User = User::new(Some("hjdsj-98d8-oops".into()));
+    let id = Uuid::new_v4().to_string();
+    let calling_user = PublicUser::new(id.clone(), None, None, None, vec![]);
+
+    let result = user.add_linked_user(modify_linked_user(&id, &id), &calling_user);
+
+    assert_eq!(result.err().unwrap().message, "Only the user can consent to a link");
+    assert!(!user.consents_to_link(&id));
+}
+
+#[test]
+fn confirm_linked_user_without_consent() {
+    let mut user: User = User::new(Some("hjdsj-98d8-oops".into()));
+
+    let result = user.confirm_linked_user(ConfirmLinkedUser {
+        user_id: user.id.clone(),
+        linked_user_id: Uuid::new_v4().to_string(),
+    });
+
+    assert_eq!(result.err().unwrap().message, "User has not consented to this link");
+}
+
+#[test]
+fn linked_users_shown_to_user_and_linked_user() {
+    let id = Uuid::new_v4().to_string();
+    let (user, owner) = linked_user(&id);
+    let linked = PublicUser::new(id.clone(), None, None, None, vec![]);
+    let other = PublicUser::new(Uuid::new_v4().to_string(), None, None, None, vec![]);
+
+    assert_eq!(user.linked_users(&owner), vec![id.clone()]);
+    assert_eq!(user.as_response(&linked).linked_users, vec![id]);
+    assert_eq!(user.linked_users(&other), Vec::<String>::new());
+}
+
+#[test]
+fn remove_linked_user() {
+    let id = Uuid::new_v4().to_string();
+    let (mut user, owner) = linked_user(&id);
+
+    let result = user.remove_linked_user(modify_linked_user(&id, &user.id), &owner);
+
+    assert_eq!(result.err(), None);
+    assert!(!user.consents_to_link(&id));
+    assert_eq!(user.linked_users(&owner), Vec::<String>::new());
+}
+
+#[test]
+fn remove_linked_user_as_linked_user() {
+    let id = Uuid::new_v4().to_string();
+    let (mut user, owner) = linked_user(&id);
+    let linked = PublicUser::new(id.clone(), None, None, None, vec![]);
+
+    let result = user.remove_linked_user(modify_linked_user(&id, &id), &linked);
+
+    assert_eq!(result.err(), None);
+    assert_eq!(user.linked_users(&owner), Vec::<String>::new());
+}
+
+#[test]
+fn remove_linked_user_invalid_calling_user() {
+    let id = Uuid::new_v4().to_string();
+    let (mut user, owner) = linked_user(&id);
+    let calling_user = PublicUser::new(Uuid::new_v4().to_string(), None, None, None, vec![]);
+
+    let result = user.remove_linked_user(modify_linked_user(&id, &calling_user.id), &calling_user);
+
+    assert_eq!(
+        result.err().unwrap().message,
+        "User does not have permission to remove this linked account"
+    );
+    assert_eq!(user.linked_users(&owner), vec![id]);
+}
+
+#[test]
+fn remove_linked_user_does_not_exist() {
+    let id = Uuid::new_v4().to_string();
+    let (mut user, owner) = linked_user(&id);
+    let non_existant_id = Uuid::new_v4().to_string();
+
+    let result = user.remove_linked_user(modify_linked_user(&non_existant_id, &user.id), &owner);
+
+    assert_eq!(result.err().unwrap().message, "User does not exist as linked account");
+    assert_eq!(user.linked_users(&owner), vec![id]);
+}
@@ -1,14 +1,21 @@
 #![cfg(test)]
 
+use chrono::{NaiveDate, Utc};
+use tonic::Code;
 use uuid::Uuid;
 
-use crate::user::{public_user::PublicUser, user_details::UserDetails};
+use crate::{
+    enums::value_reference::ValueReference,
+    permissions::visibility::Visibility,
+    requests::user_request::FieldReference,
+    user::{attribute::AttributeValue, public_user::PublicUser, user_details::UserDetails},
+};
 
 #[test]
 fn create_new_user_details() {
     let id = Uuid::new_v4();
     let user_details = UserDetails::new(id.to_string(), None);
-    let public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+    let public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
     
     assert_eq!(user_details.first_name.value(&public_user).unwrap(), None);
 }
@@ -17,13 +24,13 @@ fn create_new_user_details() {
 async fn update_first_name() {
     let id = Uuid::new_v4();
     let mut user_details = UserDetails::new(id.to_string(), None);
-    let public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+    let public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
     
     assert_eq!(user_details.first_name.value(&public_user).unwrap(), None);
 
     let first_name: String = "boop".into();
 
-    let result = user_details.first_name.update(Some(first_name.clone()), &public_user).await;
+    let result = user_details.first_name.update(Some(first_name.clone()), &public_user, &Utc::now()).await;
 
     assert!(result.is_ok());
     assert_eq!(user_details.first_name.value(&public_user).unwrap(), Some(first_name));
@@ -33,14 +40,14 @@ async fn update_first_name() {
 async fn update_first_name_fail_permission() {
     let id = Uuid::new_v4();
     let mut user_details = UserDetails::new(id.to_string(), None);
-    let owner_public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-    let public_user = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+    let owner_public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let public_user = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
     
     assert_eq!(user_details.first_name.value(&owner_public_user).unwrap(), None);
 
     let first_name: String = "boop".into();
 
-    let result = user_details.first_name.update(Some(first_name.clone()), &public_user).await;
+    let result = user_details.first_name.update(Some(first_name.clone()), &public_user, &Utc::now()).await;
 
     assert_eq!(result.unwrap_err().message, format!("User does not have permission to update this value: first_name"));
     assert_eq!(user_details.first_name.value(&owner_public_user).unwrap(), None);
@@ -50,13 +57,13 @@ async fn update_first_name_fail_permission() {
 async fn update_other_names() {
     let id = Uuid::new_v4();
     let mut user_details = UserDetails::new(id.to_string(), None);
-    let public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+    let public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
     
     assert_eq!(user_details.other_names.value(&public_user).unwrap(), None);
 
     let other_names: Vec<String> = vec!["boop".into()];
 
-    let result = user_details.other_names.update(Some(other_names.clone()), &public_user).await;
+    let result = user_details.other_names.update(Some(other_names.clone()), &public_user, &Utc::now()).await;
 
     assert!(result.is_ok());
     assert_eq!(user_details.other_names.value(&public_user).unwrap(), Some(other_names));
@@ -66,14 +73,14 @@ async fn update_other_names() {
 async fn update_other_names_fail_permission() {
     let id = Uuid::new_v4();
     let mut user_details = UserDetails::new(id.to_string(), None);
-    let owner_public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-    let public_user = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+    let owner_public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let public_user = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
     
     assert_eq!(user_details.other_names.value(&owner_public_user).unwrap(), None);
 
     let other_names: Vec<String> = vec!["boop".into()];
 
-    let result = user_details.other_names.update(Some(other_names.clone()), &public_user).await;
+    let result = user_details.other_names.update(Some(other_names.clone()), &public_user, &Utc::now()).await;
 
     assert_eq!(result.unwrap_err().message, format!("User does not have permission to update this value: other_names"));
     assert_eq!(user_details.other_names.value(&owner_public_user).unwrap(), None);
@@ -83,13 +90,13 @@ async fn update_other_names_fail_permission() {
 async fn update_last_name() {
     let id = Uuid::new_v4();
     let mut user_details = UserDetails::new(id.to_string(), None);
-    let public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+    let public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
     
     assert_eq!(user_details.last_name.value(&public_user).unwrap(), None);
 
     let last_name: String = "boop".into();
 
-    let result = user_details.last_name.update(Some(last_name.clone()), &public_user).await;
+    let result = user_details.last_name.update(Some(last_name.clone()), &public_user, &Utc::now()).await;
 
     assert!(result.is_ok());
     assert_eq!(user_details.last_name.value(&public_user).unwrap(), Some(last_name));
@@ -99,15 +106,125 @@ async fn update_last_name() {
 async fn update_last_name_fail_permission() {
     let id = Uuid::new_v4();
     let mut user_details = UserDetails::new(id.to_string(), None);
-    let owner_public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
-    let public_user = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![] };
+    let owner_public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let public_user = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
     
     assert_eq!(user_details.last_name.value(&owner_public_user).unwrap(), None);
 
     let last_name: String = "boop".into();
 
-    let result = user_details.last_name.update(Some(last_name.clone()), &public_user).await;
+    let result = user_details.last_name.update(Some(last_name.clone()), &public_user, &Utc::now()).await;
 
     assert_eq!(result.unwrap_err().message, format!("User does not have permission to update this value: last_name"));
     assert_eq!(user_details.last_name.value(&owner_public_user).unwrap(), None);
-}
\ No newline at end of file
+}
+#[tokio::test]
+async fn set_attribute() {
+    let id = Uuid::new_v4();
+    let mut user_details = UserDetails::new(id.to_string(), None);
+    let public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let email = AttributeValue::String("boop@example.com".into());
+
+    let result = user_details.set_attribute("email".into(), email.clone(), &public_user, &Utc::now()).await;
+
+    assert!(result.is_ok());
+    assert_eq!(user_details.attributes.get("email").unwrap().value(&public_user).unwrap(), Some(email));
+}
+
+#[tokio::test]
+async fn set_attribute_fail_permission() {
+    let id = Uuid::new_v4();
+    let mut user_details = UserDetails::new(id.to_string(), None);
+    let public_user = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+
+    let result = user_details.set_attribute("email".into(), AttributeValue::String("boop@example.com".into()), &public_user, &Utc::now()).await;
+
+    assert_eq!(result.unwrap_err().message, "User does not have permission to add this attribute: email");
+    assert!(user_details.attributes.is_empty());
+}
+
+#[tokio::test]
+async fn set_attribute_keeps_type() {
+    let id = Uuid::new_v4();
+    let mut user_details = UserDetails::new(id.to_string(), None);
+    let public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let date_of_birth = AttributeValue::Date(NaiveDate::from_ymd_opt(1990, 1, 31).unwrap());
+    let _ = user_details.set_attribute("date_of_birth".into(), date_of_birth, &public_user, &Utc::now()).await;
+
+    let result = user_details.set_attribute("date_of_birth".into(), AttributeValue::String("31/01/1990".into()), &public_user, &Utc::now()).await;
+
+    assert_eq!(result.unwrap_err().message, "Attribute date_of_birth holds a date, not a string");
+}
+
+#[tokio::test]
+async fn clear_attribute() {
+    let id = Uuid::new_v4();
+    let mut user_details = UserDetails::new(id.to_string(), None);
+    let public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let _ = user_details.set_attribute("email".into(), AttributeValue::String("boop@example.com".into()), &public_user, &Utc::now()).await;
+
+    let result = user_details.clear_attribute(&"email".into(), &public_user, &Utc::now()).await;
+
+    assert!(result.is_ok());
+    assert!(user_details.public_attributes(&public_user).is_empty());
+    assert_eq!(user_details.clear_attribute(&"email".into(), &public_user, &Utc::now()).await.unwrap_err().status, Code::NotFound);
+}
+
+#[tokio::test]
+async fn public_attributes_hidden_from_others() {
+    let id = Uuid::new_v4();
+    let mut user_details = UserDetails::new(id.to_string(), None);
+    let owner_public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let public_user = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let _ = user_details.set_attribute("email".into(), AttributeValue::String("boop@example.com".into()), &owner_public_user, &Utc::now()).await;
+
+    assert_eq!(user_details.public_attributes(&owner_public_user).len(), 1);
+    assert!(user_details.public_attributes(&public_user).is_empty());
+}
+
+#[test]
+fn public_field_in_public_user() {
+    let id = Uuid::new_v4();
+    let mut user_details = UserDetails::new(id.to_string(), Some("boop".into()));
+    let owner_public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let public_user = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let first_name = FieldReference { reference: ValueReference::DETAIL_FIRST_NAME, key: None };
+
+    assert_eq!(user_details.as_public_user(&public_user, vec![]).first_name, None);
+    assert_eq!(user_details.set_visibility(&first_name, Visibility::PUBLIC, &public_user).unwrap_err().status, Code::PermissionDenied);
+    user_details.set_visibility(&first_name, Visibility::PUBLIC, &owner_public_user).unwrap();
+
+    assert_eq!(user_details.as_public_user(&public_user, vec![]).first_name, Some("boop".into()));
+    assert_eq!(user_details.as_public_user(&public_user, vec![]).last_name, None);
+}
+
+#[test]
+fn private_field_hidden_from_viewers() {
+    let id = Uuid::new_v4();
+    let mut user_details = UserDetails::new(id.to_string(), Some("boop".into()));
+    let owner_public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let viewer = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let first_name = FieldReference { reference: ValueReference::DETAIL_FIRST_NAME, key: None };
+    user_details.first_name.add_viewer(viewer.id.clone(), &owner_public_user, &Utc::now()).unwrap();
+
+    user_details.set_visibility(&first_name, Visibility::PRIVATE, &owner_public_user).unwrap();
+    assert_eq!(user_details.as_public_user(&viewer, vec![]).first_name, None);
+    assert_eq!(user_details.as_public_user(&owner_public_user, vec![]).first_name, Some("boop".into()));
+
+    user_details.set_visibility(&first_name, Visibility::SHARED, &owner_public_user).unwrap();
+    assert_eq!(user_details.as_public_user(&viewer, vec![]).first_name, Some("boop".into()));
+}
+
+#[test]
+fn readable_fields_skip_hidden_and_unset() {
+    let id = Uuid::new_v4();
+    let mut user_details = UserDetails::new(id.to_string(), Some("boop".into()));
+    let owner_public_user = PublicUser { id: id.to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    let viewer = PublicUser { id: Uuid::new_v4().to_string(), first_name: None, other_names: None, last_name: None, chain_accounts: vec![], groups: vec![], memberships: vec![] };
+    user_details.first_name.add_viewer(viewer.id.clone(), &owner_public_user, &Utc::now()).unwrap();
+    user_details.last_name.add_viewer(viewer.id.clone(), &owner_public_user, &Utc::now()).unwrap();
+
+    let fields = user_details.readable_fields(&viewer, &Utc::now());
+
+    assert_eq!(fields, vec![FieldReference { reference: ValueReference::DETAIL_FIRST_NAME, key: None }]);
+}
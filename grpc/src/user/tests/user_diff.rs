@@ -0,0 +1,58 @@
+#![cfg(all(test, feature = "sst"))]
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::{
+    enums::data_value::DataValue,
+    user::{public_user::PublicUser, user::User, user_diff::FieldDiff},
+};
+
+#[tokio::test]
+async fn diff_changed_field() {
+    let previous = User::new(Some("boop".into()));
+    let owner = PublicUser::new(previous.id.clone(), None, None, None, vec![]);
+    let mut current = previous.clone();
+    let _ = current.user_details.last_name.update(Some("beep".into()), &owner, &Utc::now()).await;
+    current.increase_sequence();
+
+    let changes = FieldDiff::between(&previous, &current, &owner.id, None, &owner);
+
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].field, DataValue::LastName.to_string());
+    assert_eq!(changes[0].sequence, 2);
+    assert_eq!(changes[0].old_value, None);
+    assert_eq!(changes[0].new_value, Some("beep".into()));
+    assert_eq!(changes[0].calling_user, owner.id);
+    assert!(!changes[0].redacted);
+}
+
+#[tokio::test]
+async fn diff_no_changes() {
+    let previous = User::new(Some("boop".into()));
+    let owner = PublicUser::new(previous.id.clone(), None, None, None, vec![]);
+    let mut current = previous.clone();
+    current.increase_sequence();
+
+    let changes = FieldDiff::between(&previous, &current, &owner.id, None, &owner);
+
+    assert!(changes.is_empty());
+}
+
+#[tokio::test]
+async fn diff_redacted_for_non_viewer() {
+    let previous = User::new(Some("boop".into()));
+    let owner = PublicUser::new(previous.id.clone(), None, None, None, vec![]);
+    let stranger = PublicUser::new(Uuid::new_v4().to_string(), None, None, None, vec![]);
+    let mut current = previous.clone();
+    let _ = current.user_details.first_name.update(Some("beep".into()), &owner, &Utc::now()).await;
+    current.increase_sequence();
+
+    let changes = FieldDiff::between(&previous, &current, &owner.id, None, &stranger);
+
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].field, DataValue::FirstName.to_string());
+    assert_eq!(changes[0].old_value, None);
+    assert_eq!(changes[0].new_value, None);
+    assert!(changes[0].redacted);
+}
@@ -1,16 +1,49 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::hash::Hash;
 use tonic::Code;
 
 use crate::{
-    chain::chain_custody::{ChainCustody, PublicChainCustody},
-    enums::blockchain::{Blockchain, BlockchainCustody},
+    chain::chain_custody::{ChainCustody, PublicChainCustody, RemoveAccount},
+    enums::{
+        blockchain::{Blockchain, BlockchainCustody},
+        data_value::DataRequests,
+        value_reference::ValueReference,
+    },
     errors::BecoError,
-    proto::beco::AddAccountRequest,
+    permissions::{
+        grant::{ExpiredGrant, GroupAccess, RevokeExpiredGrants, RevokeGrant},
+        model::GrantRole,
+        policy::{AccessPolicy, SetFieldPolicy},
+        visibility::{SetFieldVisibility, Visibility},
+    },
+    proto::beco::{
+        AddAccountRequest, AttestationResponse, GetUserResponse, GuardianResponse,
+        RecoveryResponse, RespondAccessRequest, SignWithAccountRequest, SignWithAccountResponse,
+    },
+    requests::user_request::{FieldReference, UserRequests},
     traits::key::Key,
-    user::{public_user::PublicUser, user_details::UserDetails},
+    user::{
+        approval::{
+            approval_expiry, guarded_field, ApprovalPolicy, ApproveChange, Approver,
+            ExpireApprovals, PendingApproval, RequestApproval, SetApprovalPolicy,
+        },
+        attestation::{value_hash, Attestation},
+        credential::{
+            credential_expiry, credential_subject, encode_status_list, IssueCredential,
+            IssuedCredential, RevokeCredential,
+        },
+        guardian::{EndGuardianship, SetGuardian},
+        linked_user::{ConfirmLinkedUser, LinkStatus, LinkedUser, ModifyLinkedUser},
+        recovery::{
+            recovery_delay, recovery_message, verify_signature, ApproveRecovery, CancelRecovery,
+            CompleteRecovery, PendingRecovery, Recovery, SetAuthKey, SetRecoveryGuardians,
+        },
+        public_user::PublicUser,
+        user_details::UserDetails,
+    },
 };
 
 #[cfg(feature = "sst")]
@@ -24,8 +57,31 @@ pub struct User {
     sequence: u64,
     chain_accounts: HashMap<Blockchain, BlockchainCustody>,
     // organisations: HashMap<String, UserOrganisation>,
-    // requests: HashMap<UserRequestType, HashSet<UserRequests>>
-    // linked_users: HashMap<String, PublicUser>,
+    // pending access requests keyed by request id
+    #[serde(default)]
+    requests: BTreeMap<String, UserRequests>,
+    // this user's side of each link keyed by the other user's id
+    #[serde(default)]
+    linked_users: BTreeMap<String, LinkedUser>,
+    // hex encoded secp256k1 key the user authenticates with, replaced by a recovery
+    #[serde(default)]
+    auth_key: Option<String>,
+    #[serde(default)]
+    recovery: Option<Recovery>,
+    // part of what guardians sign, bumped whenever a recovery is dropped so its approvals cannot
+    // be replayed
+    #[serde(default)]
+    recovery_epoch: u64,
+    #[serde(default)]
+    approval_policies: Vec<ApprovalPolicy>,
+    // changes waiting on approval keyed by approval id
+    #[serde(default)]
+    pending_approvals: BTreeMap<String, PendingApproval>,
+    #[serde(default)]
+    attestations: Vec<Attestation>,
+    // credentials issued about this user, the position is the credential's status index
+    #[serde(default)]
+    credentials: Vec<IssuedCredential>,
 }
 
 #[cfg(feature = "sst")]
@@ -35,7 +91,31 @@ pub struct User {
     pub user_details: UserDetails,
     sequence: u64,
     chain_accounts: HashMap<Blockchain, BlockchainCustody>,
-    // linked_users: HashMap<String, PublicUser>,
+    // pending access requests keyed by request id
+    #[serde(default)]
+    requests: BTreeMap<String, UserRequests>,
+    // this user's side of each link keyed by the other user's id
+    #[serde(default)]
+    linked_users: BTreeMap<String, LinkedUser>,
+    // hex encoded secp256k1 key the user authenticates with, replaced by a recovery
+    #[serde(default)]
+    auth_key: Option<String>,
+    #[serde(default)]
+    recovery: Option<Recovery>,
+    // part of what guardians sign, bumped whenever a recovery is dropped so its approvals cannot
+    // be replayed
+    #[serde(default)]
+    recovery_epoch: u64,
+    #[serde(default)]
+    approval_policies: Vec<ApprovalPolicy>,
+    // changes waiting on approval keyed by approval id
+    #[serde(default)]
+    pending_approvals: BTreeMap<String, PendingApproval>,
+    #[serde(default)]
+    attestations: Vec<Attestation>,
+    // credentials issued about this user, the position is the credential's status index
+    #[serde(default)]
+    credentials: Vec<IssuedCredential>,
 }
 
 impl Hash for User {
@@ -49,10 +129,24 @@ impl Hash for User {
                 blockchain.hash(state);
                 custody.hash(state);
             });
-        // self.linked_users.iter().for_each(|(user_id, public_user)| {
-        //     user_id.hash(state);
-        //     public_user.hash(state);
-        // });
+        self.requests.iter().for_each(|(request_id, request)| {
+            request_id.hash(state);
+            request.hash(state);
+        });
+        self.linked_users.iter().for_each(|(user_id, linked_user)| {
+            user_id.hash(state);
+            linked_user.hash(state);
+        });
+        self.auth_key.hash(state);
+        self.recovery.hash(state);
+        self.recovery_epoch.hash(state);
+        self.approval_policies.hash(state);
+        self.pending_approvals.iter().for_each(|(approval_id, approval)| {
+            approval_id.hash(state);
+            approval.hash(state);
+        });
+        self.attestations.hash(state);
+        self.credentials.hash(state);
     }
 }
 
@@ -79,7 +173,15 @@ impl User {
             chain_accounts: User::generate_default_chain_accounts(id.to_string()),
             sequence: 1,
             user_details: UserDetails::new(id.to_string(), first_name),
-            // linked_users: HashMap::new(),
+            requests: BTreeMap::new(),
+            linked_users: BTreeMap::new(),
+            auth_key: None,
+            recovery: None,
+            recovery_epoch: 0,
+            approval_policies: vec![],
+            pending_approvals: BTreeMap::new(),
+            attestations: vec![],
+            credentials: vec![],
         }
     }
 
@@ -92,6 +194,23 @@ impl User {
         self.sequence.clone()
     }
 
+    // optimistic concurrency, a change made against an older sequence is rejected so the client
+    // can reload and retry
+    pub fn check_sequence(&self, expected_sequence: Option<u64>) -> Result<(), BecoError> {
+        if let Some(expected_sequence) = expected_sequence {
+            if expected_sequence != self.sequence {
+                return Err(BecoError {
+                    message: format!(
+                        "Expected sequence {expected_sequence} but the current sequence is {}",
+                        self.sequence
+                    ),
+                    status: Code::Aborted,
+                });
+            }
+        }
+        Ok(())
+    }
+
     pub fn as_public_user(&self, calling_user: &PublicUser) -> PublicUser {
         let chain_accounts: Vec<PublicChainCustody> = self
             .chain_accounts
@@ -102,10 +221,64 @@ impl User {
             .as_public_user(calling_user, chain_accounts)
     }
 
+    pub fn as_response(&self, calling_user: &PublicUser) -> GetUserResponse {
+        let mut response: GetUserResponse = self.as_public_user(calling_user).into();
+        response.sequence = Some(self.sequence);
+        response.attributes = self.user_details.public_attributes(calling_user);
+        response.linked_users = self.linked_users(calling_user);
+        response.guardian = self.guardian(calling_user);
+        response.auth_key = self.auth_key.clone();
+        response.recovery = self.recovery(calling_user);
+        response.pending_approvals = self
+            .pending_approvals(calling_user, &[], &Utc::now())
+            .into_iter()
+            .map(|approval| approval.id)
+            .collect();
+        response.attestations = self.attestations(calling_user, &Utc::now());
+        response
+    }
+
+    pub fn sorted_public_chain_accounts(&self, calling_user: &PublicUser) -> Vec<PublicChainCustody> {
+        let mut chain_accounts: Vec<PublicChainCustody> = self
+            .chain_accounts
+            .values()
+            .map(|chain_account| chain_account.clone().as_public(calling_user))
+            .collect();
+        chain_accounts.sort_by_key(|chain_account| chain_account.chain.to_string());
+        chain_accounts
+    }
+
+    // what a read hands to someone other than the owner, each key is listed by its alias
+    pub fn fields_read_by(&self, calling_user: &PublicUser, at: &DateTime<Utc>) -> Vec<FieldReference> {
+        if calling_user.id == self.id {
+            return vec![];
+        }
+        let mut fields = self.user_details.readable_fields(calling_user, at);
+        for chain_account in self.sorted_public_chain_accounts(calling_user) {
+            for key in chain_account.keys {
+                let field = FieldReference {
+                    reference: ValueReference::CHAIN_HEYS,
+                    key: Some(key.alias),
+                };
+                if !fields.contains(&field) {
+                    fields.push(field);
+                }
+            }
+        }
+        fields
+    }
+
+    pub fn can_view_chain_accounts(&self, calling_user: &PublicUser) -> bool {
+        self.chain_accounts
+            .iter()
+            .all(|(_, chain_account)| chain_account.can_view(calling_user))
+    }
+
     pub fn add_account(
         &mut self,
         request: AddAccountRequest,
         calling_user: &PublicUser,
+        at: &DateTime<Utc>,
     ) -> Result<(), BecoError> {
         let blockchain: Blockchain = request.blockchain.into();
         let accounts_option = self.chain_accounts.get_mut(&blockchain);
@@ -117,18 +290,91 @@ impl User {
         }
         match accounts_option.unwrap() {
             BlockchainCustody::XRPL(xrpl_accounts) => {
-                xrpl_accounts.create(None, request, calling_user)
+                xrpl_accounts.create(None, request, calling_user, at)
             }
             BlockchainCustody::EVM(evm_accounts) => {
-                evm_accounts.create(None, request, calling_user)
+                evm_accounts.create(None, request, calling_user, at)
+            }
+        }
+    }
+
+    pub fn replace_chain_accounts(&mut self, other: &User) {
+        self.chain_accounts = other.chain_accounts.clone();
+    }
+
+    pub fn propose_remove_account(
+        &self,
+        request: &RemoveAccount,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        self.clone().remove_account(request.clone(), calling_user, at)
+    }
+
+    pub fn remove_account(
+        &mut self,
+        request: RemoveAccount,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        if request.user_id != self.id {
+            return Err(BecoError {
+                message: "Invalid remove account request".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        let accounts_option = self.chain_accounts.get_mut(&request.blockchain);
+        if accounts_option.is_none() {
+            return Err(BecoError {
+                message: format!("No blockchain set: {}", request.blockchain),
+                status: Code::OutOfRange,
+            });
+        }
+        match accounts_option.unwrap() {
+            BlockchainCustody::XRPL(account) => account.remove(&request.alias, calling_user, at),
+            BlockchainCustody::EVM(account) => account.remove(&request.alias, calling_user, at),
+        }
+    }
+
+    // signing does not change the user, so it is answered locally without consensus
+    pub fn sign_with_account(
+        &self,
+        request: SignWithAccountRequest,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<SignWithAccountResponse, BecoError> {
+        let blockchain: Blockchain = request.blockchain.into();
+        let accounts_option = self.chain_accounts.get(&blockchain);
+        if accounts_option.is_none() {
+            return Err(BecoError {
+                message: format!("No blockchain set: {}", blockchain),
+                status: Code::OutOfRange,
+            });
+        }
+        let signature_result = match accounts_option.unwrap() {
+            BlockchainCustody::XRPL(account) => {
+                account.sign(&request.alias, &request.message, calling_user, at)
             }
+            BlockchainCustody::EVM(account) => {
+                account.sign(&request.alias, &request.message, calling_user, at)
+            }
+        };
+        if let Err(err) = signature_result {
+            return Err(err);
         }
+        let (public_key, signature) = signature_result.unwrap();
+        Ok(SignWithAccountResponse {
+            alias: request.alias,
+            public_key,
+            signature,
+        })
     }
 
     pub fn propose_account(
         &self,
         request: AddAccountRequest,
         calling_user: &PublicUser,
+        at: &DateTime<Utc>,
     ) -> Result<(), BecoError> {
         let blockchain: Blockchain = request.blockchain.into();
         let accounts_option = self.chain_accounts.get(&blockchain);
@@ -139,60 +385,1546 @@ impl User {
             });
         }
         match accounts_option.unwrap() {
-            BlockchainCustody::XRPL(xrpl_accounts) => xrpl_accounts.propose(request, calling_user),
-            BlockchainCustody::EVM(evm_accounts) => evm_accounts.propose(request, calling_user),
-        }
-    }
-
-    // pub fn add_linked_user(&mut self, user: &PublicUser) {
-    //     self.linked_users.insert(user.id.clone(), user.clone());
-    // }
-
-    // pub fn linked_users(&self) -> &HashMap<String, PublicUser> {
-    //     &self.linked_users
-    // }
-
-    // pub fn remove_linked_user(
-    //     &mut self,
-    //     user: &PublicUser,
-    //     calling_user: &PublicUser,
-    // ) -> Result<(), BecoError> {
-    //     if user != calling_user && calling_user.id != self.id.to_string() {
-    //         return Err(BecoError {
-    //             message: "User does not have permission to remove this linked account".into(),
-    //             status: Code::PermissionDenied,
-    //         });
-    //     }
-    //     let removed_account = self.linked_users.remove(&user.id);
-    //     if removed_account.is_none() {
-    //         return Err(BecoError {
-    //             message: "User does not exist as linked account".into(),
-    //             status: Code::NotFound,
-    //         });
-    //     }
-    //     Ok(())
-    // }
-
-    // pub fn propose_alter_linked_user(
-    //     &self,
-    //     user: &PublicUser,
-    //     calling_user: &PublicUser,
-    // ) -> Result<(), BecoError> {
-    //     if user != calling_user && calling_user.id != self.id.to_string() {
-    //         return Err(BecoError {
-    //             message: "User does not have permission to alter this linked account".into(),
-    //             status: Code::PermissionDenied,
-    //         });
-    //     }
-    //     let altered_account = self.linked_users.get(&user.id);
-    //     if altered_account.is_none() {
-    //         return Err(BecoError {
-    //             message: "User does not exist as linked account".into(),
-    //             status: Code::NotFound,
-    //         });
-    //     }
-    //     Ok(())
-    // }
+            BlockchainCustody::XRPL(xrpl_accounts) => {
+                xrpl_accounts.propose(request, calling_user, at)
+            }
+            BlockchainCustody::EVM(evm_accounts) => evm_accounts.propose(request, calling_user, at),
+        }
+    }
+
+    pub fn pending_requests(
+        &self,
+        calling_user: &PublicUser,
+    ) -> Result<Vec<UserRequests>, BecoError> {
+        if calling_user.id != self.id {
+            return Err(BecoError {
+                message: "User does not have permission to view these requests".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        Ok(self.requests.values().cloned().collect())
+    }
+
+    fn has_field(&self, field: &FieldReference) -> bool {
+        match field.reference {
+            ValueReference::CHAIN_HEYS => self
+                .chain_accounts
+                .values()
+                .any(|chain_account| chain_account.has_key(&field.key)),
+            _ => self.user_details.has_field(field),
+        }
+    }
+
+    // the sst checks that the caller is an admin of the organisation, the user nodes cannot
+    pub fn propose_access_request(
+        &self,
+        request: &UserRequests,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        if request.requested_by != calling_user.id || request.user_id != self.id {
+            return Err(BecoError {
+                message: "Invalid access request".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        if calling_user.id == self.id {
+            return Err(BecoError {
+                message: "Users cannot request access to their own fields".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        if self.requests.contains_key(&request.id) {
+            return Err(BecoError {
+                message: format!("Access request already exists: {}", request.id),
+                status: Code::AlreadyExists,
+            });
+        }
+        let missing_field = request.fields.iter().find(|field| !self.has_field(field));
+        if let Some(field) = missing_field {
+            return Err(BecoError {
+                message: format!(
+                    "Field not found: {:?} {}",
+                    field.reference,
+                    field.key.clone().unwrap_or_default()
+                ),
+                status: Code::NotFound,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn add_access_request(
+        &mut self,
+        request: UserRequests,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        self.propose_access_request(&request, calling_user)?;
+        self.requests.insert(request.id.clone(), request);
+        Ok(())
+    }
+
+    pub fn propose_respond_access_request(
+        &self,
+        request: &RespondAccessRequest,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        if calling_user.id != self.id || request.user_id != self.id {
+            return Err(BecoError {
+                message: "User does not have permission to respond to this request".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        let access_request_option = self.requests.get(&request.request_id);
+        if access_request_option.is_none() {
+            return Err(BecoError {
+                message: format!("Access request not found: {}", request.request_id),
+                status: Code::NotFound,
+            });
+        }
+        let expires = access_request_option.unwrap().expires;
+        if request.approve && expires.is_some_and(|expires| &expires <= at) {
+            return Err(BecoError {
+                message: format!("Access request has expired: {}", request.request_id),
+                status: Code::FailedPrecondition,
+            });
+        }
+        Ok(())
+    }
+
+    // approving adds the organisation as a viewer of each requested field that still exists
+    pub fn respond_access_request(
+        &mut self,
+        request: RespondAccessRequest,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        self.propose_respond_access_request(&request, calling_user, at)?;
+        let access_request = self.requests.remove(&request.request_id).unwrap();
+        if !request.approve {
+            return Ok(());
+        }
+        for field in access_request.fields.iter() {
+            if !self.has_field(field) {
+                continue;
+            }
+            let result = match field.reference {
+                ValueReference::CHAIN_HEYS => {
+                    self.add_chain_viewer(
+                        &access_request.organisation_id,
+                        &field.key,
+                        calling_user,
+                        access_request.expires,
+                        at,
+                    )
+                }
+                _ => self.user_details.add_viewer(
+                    field,
+                    access_request.organisation_id.clone(),
+                    calling_user,
+                    access_request.expires,
+                    at,
+                ),
+            };
+            result?;
+        }
+        Ok(())
+    }
+
+    // an alias names the key on every chain that has one by that name
+    fn add_chain_viewer(
+        &mut self,
+        viewer_id: &str,
+        alias: &Option<String>,
+        calling_user: &PublicUser,
+        expires: Option<DateTime<Utc>>,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        for (_, chain_account) in self.chain_accounts.iter_mut() {
+            if !chain_account.has_key(alias) {
+                continue;
+            }
+            let result = match chain_account {
+                BlockchainCustody::XRPL(account) => account.add_viewer_until(
+                    alias,
+                    viewer_id.to_string(),
+                    calling_user,
+                    expires,
+                    at,
+                ),
+                BlockchainCustody::EVM(account) => account.add_viewer_until(
+                    alias,
+                    viewer_id.to_string(),
+                    calling_user,
+                    expires,
+                    at,
+                ),
+            };
+            match result {
+                Err(err) if err.status != Code::AlreadyExists => return Err(err),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    // checked on a copy so the rules stay in the permission model
+    pub fn propose_group_access(
+        &self,
+        request: &GroupAccess,
+        grant: bool,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        self.clone().set_group_access(request.clone(), grant, calling_user, at)
+    }
+
+    // grant is false to revoke, either way every field must exist
+    pub fn set_group_access(
+        &mut self,
+        request: GroupAccess,
+        grant: bool,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        if request.user_id != self.id {
+            return Err(BecoError {
+                message: "Invalid group access request".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        for field in request.fields.iter() {
+            if !self.has_field(field) {
+                return Err(BecoError {
+                    message: format!("Field not found: {:?}", field.reference),
+                    status: Code::NotFound,
+                });
+            }
+            let result = match field.reference {
+                ValueReference::CHAIN_HEYS => {
+                    self.set_chain_group_access(
+                        &field.key,
+                        &request.group_id,
+                        request.role,
+                        grant,
+                        calling_user,
+                        at,
+                    )
+                }
+                _ => self.user_details.set_group_access(
+                    field,
+                    &request.group_id,
+                    request.role,
+                    grant,
+                    calling_user,
+                    at,
+                ),
+            };
+            result?;
+        }
+        Ok(())
+    }
+
+    fn set_chain_group_access(
+        &mut self,
+        alias: &Option<String>,
+        group_id: &String,
+        role: GrantRole,
+        grant: bool,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        for (_, chain_account) in self.chain_accounts.iter_mut() {
+            if !chain_account.has_key(alias) {
+                continue;
+            }
+            let result = match chain_account {
+                BlockchainCustody::XRPL(account) => {
+                    account.set_group(alias, group_id, role, grant, calling_user, at)
+                }
+                BlockchainCustody::EVM(account) => {
+                    account.set_group(alias, group_id, role, grant, calling_user, at)
+                }
+            };
+            result?;
+        }
+        Ok(())
+    }
+
+    // checked on a copy so the rules stay in the permission model
+    pub fn propose_revoke_grant(
+        &self,
+        request: &RevokeGrant,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        self.clone().revoke_grant(request.clone(), calling_user, at)
+    }
+
+    // every field must hold the grant, an expiring grant can be revoked before it lapses
+    pub fn revoke_grant(
+        &mut self,
+        request: RevokeGrant,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        if request.user_id != self.id {
+            return Err(BecoError {
+                message: "Invalid revoke grant request".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        for field in request.fields.iter() {
+            if !self.has_field(field) {
+                return Err(BecoError {
+                    message: format!("Field not found: {:?}", field.reference),
+                    status: Code::NotFound,
+                });
+            }
+            let result = match field.reference {
+                ValueReference::CHAIN_HEYS => self.revoke_chain_grant(
+                    &field.key,
+                    &request.grantee_id,
+                    request.role,
+                    calling_user,
+                    at,
+                ),
+                _ => self.user_details.revoke_grant(
+                    field,
+                    &request.grantee_id,
+                    request.role,
+                    calling_user,
+                    at,
+                ),
+            };
+            result?;
+        }
+        Ok(())
+    }
+
+    // the grant only has to be held on one chain, a viewer of every key is one on each chain
+    fn revoke_chain_grant(
+        &mut self,
+        alias: &Option<String>,
+        grantee_id: &str,
+        role: GrantRole,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        let mut revoked = false;
+        for (_, chain_account) in self.chain_accounts.iter_mut() {
+            if !chain_account.has_key(alias) {
+                continue;
+            }
+            let result = match chain_account {
+                BlockchainCustody::XRPL(account) => {
+                    account.revoke(alias, grantee_id, role, calling_user, at)
+                }
+                BlockchainCustody::EVM(account) => {
+                    account.revoke(alias, grantee_id, role, calling_user, at)
+                }
+            };
+            match result {
+                Ok(()) => revoked = true,
+                Err(err) if err.status != Code::NotFound => return Err(err),
+                _ => {}
+            }
+        }
+        if !revoked {
+            return Err(BecoError {
+                message: "User has no such grant on the chain keys".into(),
+                status: Code::NotFound,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn propose_field_policy(
+        &self,
+        request: &SetFieldPolicy,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        self.clone().set_field_policy(request.clone(), calling_user)
+    }
+
+    pub fn set_field_policy(
+        &mut self,
+        request: SetFieldPolicy,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        if request.user_id != self.id {
+            return Err(BecoError {
+                message: "Invalid field policy request".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        if !self.has_field(&request.field) {
+            return Err(BecoError {
+                message: format!("Field not found: {:?}", request.field.reference),
+                status: Code::NotFound,
+            });
+        }
+        match request.field.reference {
+            ValueReference::CHAIN_HEYS => {
+                self.set_chain_policies(&request.field.key, request.policies, calling_user)
+            }
+            _ => self
+                .user_details
+                .set_policies(&request.field, request.policies, calling_user),
+        }
+    }
+
+    fn set_chain_policies(
+        &mut self,
+        alias: &Option<String>,
+        policies: Vec<AccessPolicy>,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        for (_, chain_account) in self.chain_accounts.iter_mut() {
+            if !chain_account.has_key(alias) {
+                continue;
+            }
+            let result = match chain_account {
+                BlockchainCustody::XRPL(account) => {
+                    account.set_policies(alias, policies.clone(), calling_user)
+                }
+                BlockchainCustody::EVM(account) => {
+                    account.set_policies(alias, policies.clone(), calling_user)
+                }
+            };
+            result?;
+        }
+        Ok(())
+    }
+
+    pub fn propose_field_visibility(
+        &self,
+        request: &SetFieldVisibility,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        self.clone().set_field_visibility(request.clone(), calling_user)
+    }
+
+    pub fn set_field_visibility(
+        &mut self,
+        request: SetFieldVisibility,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        if request.user_id != self.id {
+            return Err(BecoError {
+                message: "Invalid field visibility request".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        for field in request.fields.iter() {
+            if !self.has_field(field) {
+                return Err(BecoError {
+                    message: format!("Field not found: {:?}", field.reference),
+                    status: Code::NotFound,
+                });
+            }
+            let result = match field.reference {
+                ValueReference::CHAIN_HEYS => {
+                    self.set_chain_visibility(&field.key, request.visibility, calling_user)
+                }
+                _ => self
+                    .user_details
+                    .set_visibility(field, request.visibility, calling_user),
+            };
+            result?;
+        }
+        Ok(())
+    }
+
+    fn set_chain_visibility(
+        &mut self,
+        alias: &Option<String>,
+        visibility: Visibility,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        for (_, chain_account) in self.chain_accounts.iter_mut() {
+            if !chain_account.has_key(alias) {
+                continue;
+            }
+            let result = match chain_account {
+                BlockchainCustody::XRPL(account) => {
+                    account.set_visibility(alias, visibility, calling_user)
+                }
+                BlockchainCustody::EVM(account) => {
+                    account.set_visibility(alias, visibility, calling_user)
+                }
+            };
+            result?;
+        }
+        Ok(())
+    }
+
+    // the earliest time the sst has something to sweep on this user: a grant lapsing, the
+    // guardianship ending, a recovery becoming ready or a pending approval expiring
+    pub fn next_expiry(&self) -> Option<DateTime<Utc>> {
+        let chain_expiries = self
+            .chain_accounts
+            .values()
+            .map(|chain_account| match chain_account {
+                BlockchainCustody::XRPL(account) => account.next_expiry(),
+                BlockchainCustody::EVM(account) => account.next_expiry(),
+            });
+        let guardian_until = self.user_details.guardian.as_ref().map(|guardian| guardian.until);
+        let recovery_ready_at = self
+            .recovery
+            .as_ref()
+            .and_then(|recovery| recovery.pending.as_ref())
+            .and_then(|pending| pending.ready_at);
+        let approval_expiries = self
+            .pending_approvals
+            .values()
+            .map(|approval| Some(approval.expires_at));
+        std::iter::once(self.user_details.next_expiry())
+            .chain(chain_expiries)
+            .chain([guardian_until, recovery_ready_at])
+            .chain(approval_expiries)
+            .flatten()
+            .min()
+    }
+
+    // a grant on the chain keys of several chains is reported once
+    pub fn expired_grants(&self, at: &DateTime<Utc>) -> Vec<ExpiredGrant> {
+        let mut grants = self.user_details.expired_grants(at);
+        let mut blockchains: Vec<&Blockchain> = self.chain_accounts.keys().collect();
+        blockchains.sort_by_key(|blockchain| blockchain.to_string());
+        for blockchain in blockchains {
+            let expired = match self.chain_accounts.get(blockchain).unwrap() {
+                BlockchainCustody::XRPL(account) => account.expired_grants(at),
+                BlockchainCustody::EVM(account) => account.expired_grants(at),
+            };
+            for (alias, grantee_id, role) in expired {
+                let grant = ExpiredGrant {
+                    field: FieldReference {
+                        reference: ValueReference::CHAIN_HEYS,
+                        key: alias,
+                    },
+                    grantee_id,
+                    role,
+                };
+                if !grants.contains(&grant) {
+                    grants.push(grant);
+                }
+            }
+        }
+        grants
+    }
+
+    fn chain_grant_has_lapsed(&self, grant: &ExpiredGrant, at: &DateTime<Utc>) -> bool {
+        self.chain_accounts.values().any(|chain_account| {
+            let expires = match chain_account {
+                BlockchainCustody::XRPL(account) => {
+                    account.expires(&grant.field.key, &grant.grantee_id, grant.role)
+                }
+                BlockchainCustody::EVM(account) => {
+                    account.expires(&grant.field.key, &grant.grantee_id, grant.role)
+                }
+            };
+            expires.is_some_and(|expires| &expires <= at)
+        })
+    }
+
+    pub fn propose_revoke_expired_grants(
+        &self,
+        request: &RevokeExpiredGrants,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        if request.user_id != self.id || request.grants.is_empty() {
+            return Err(BecoError {
+                message: "Invalid revocation".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        for grant in request.grants.iter() {
+            let has_lapsed = match grant.field.reference {
+                ValueReference::CHAIN_HEYS => self.chain_grant_has_lapsed(grant, at),
+                _ => self
+                    .user_details
+                    .grant_expires(grant)
+                    .is_some_and(|expires| &expires <= at),
+            };
+            if !has_lapsed {
+                return Err(BecoError {
+                    message: format!("No expired grant for {}", grant.grantee_id),
+                    status: Code::FailedPrecondition,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn revoke_expired_grants(
+        &mut self,
+        request: RevokeExpiredGrants,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        self.propose_revoke_expired_grants(&request, at)?;
+        for grant in request.grants.iter() {
+            let result = match grant.field.reference {
+                ValueReference::CHAIN_HEYS => {
+                    for (_, chain_account) in self.chain_accounts.iter_mut() {
+                        // chains the grant has already gone from are skipped
+                        let _ = match chain_account {
+                            BlockchainCustody::XRPL(account) => account.revoke_expired(
+                                &grant.field.key,
+                                &grant.grantee_id,
+                                grant.role,
+                                at,
+                            ),
+                            BlockchainCustody::EVM(account) => account.revoke_expired(
+                                &grant.field.key,
+                                &grant.grantee_id,
+                                grant.role,
+                                at,
+                            ),
+                        };
+                    }
+                    Ok(())
+                }
+                _ => self.user_details.revoke_expired(grant, at),
+            };
+            result?;
+        }
+        Ok(())
+    }
+
+    // links both users consented to, only shown to the user and the users linked to them
+    pub fn linked_users(&self, calling_user: &PublicUser) -> Vec<String> {
+        let linked: Vec<String> = self
+            .linked_users
+            .values()
+            .filter(|linked_user| linked_user.status == LinkStatus::LINKED)
+            .map(|linked_user| linked_user.user_id.clone())
+            .collect();
+        if calling_user.id != self.id && !linked.contains(&calling_user.id) {
+            return vec![];
+        }
+        linked
+    }
+
+    // every user this user consented to a link with, confirmed or not
+    pub fn linked_user_ids(&self) -> Vec<String> {
+        self.linked_users.keys().cloned().collect()
+    }
+
+    // true once this user consented to the link, whether or not the other side has
+    pub fn consents_to_link(&self, user_id: &String) -> bool {
+        self.linked_users.contains_key(user_id)
+    }
+
+    pub fn is_linked_to(&self, user_id: &String) -> bool {
+        self.linked_users
+            .get(user_id)
+            .is_some_and(|linked_user| linked_user.status == LinkStatus::LINKED)
+    }
+
+    pub fn propose_add_linked_user(
+        &self,
+        request: &ModifyLinkedUser,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        self.clone().add_linked_user(request.clone(), calling_user)
+    }
+
+    // records this user's consent, the link is only confirmed once the other user consents too
+    pub fn add_linked_user(
+        &mut self,
+        request: ModifyLinkedUser,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        if calling_user.id != self.id || request.calling_user != self.id {
+            return Err(BecoError {
+                message: "Only the user can consent to a link".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        if request.user_id == self.id {
+            return Err(BecoError {
+                message: "A user cannot be linked to themselves".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        if self.linked_users.contains_key(&request.user_id) {
+            return Err(BecoError {
+                message: "User is already linked".into(),
+                status: Code::AlreadyExists,
+            });
+        }
+        self.linked_users.insert(
+            request.user_id.clone(),
+            LinkedUser {
+                user_id: request.user_id,
+                status: LinkStatus::PROPOSED,
+            },
+        );
+        Ok(())
+    }
+
+    pub fn propose_confirm_linked_user(&self, request: &ConfirmLinkedUser) -> Result<(), BecoError> {
+        self.clone().confirm_linked_user(request.clone())
+    }
+
+    pub fn confirm_linked_user(&mut self, request: ConfirmLinkedUser) -> Result<(), BecoError> {
+        if request.user_id != self.id {
+            return Err(BecoError {
+                message: "Invalid link confirmation".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        let linked_user_option = self.linked_users.get_mut(&request.linked_user_id);
+        if linked_user_option.is_none() {
+            return Err(BecoError {
+                message: "User has not consented to this link".into(),
+                status: Code::FailedPrecondition,
+            });
+        }
+        let linked_user = linked_user_option.unwrap();
+        if linked_user.status == LinkStatus::LINKED {
+            return Err(BecoError {
+                message: "User is already linked".into(),
+                status: Code::AlreadyExists,
+            });
+        }
+        linked_user.status = LinkStatus::LINKED;
+        Ok(())
+    }
+
+    pub fn propose_remove_linked_user(
+        &self,
+        request: &ModifyLinkedUser,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        self.clone().remove_linked_user(request.clone(), calling_user)
+    }
+
+    // either the user or the linked user can end the link
+    pub fn remove_linked_user(
+        &mut self,
+        request: ModifyLinkedUser,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        if calling_user.id != request.user_id && calling_user.id != self.id {
+            return Err(BecoError {
+                message: "User does not have permission to remove this linked account".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        let removed_account = self.linked_users.remove(&request.user_id);
+        if removed_account.is_none() {
+            return Err(BecoError {
+                message: "User does not exist as linked account".into(),
+                status: Code::NotFound,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn guardian(&self, calling_user: &PublicUser) -> Option<GuardianResponse> {
+        let guardian = self.user_details.guardian.clone()?;
+        if calling_user.id != self.id && calling_user.id != guardian.guardian_id {
+            return None;
+        }
+        Some(guardian.into())
+    }
+
+    pub fn propose_set_guardian(
+        &self,
+        request: &SetGuardian,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        self.clone().set_guardian(request.clone(), calling_user, at)
+    }
+
+    // only the dependant can name a guardian, and only one at a time
+    pub fn set_guardian(
+        &mut self,
+        request: SetGuardian,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        if request.user_id != self.id {
+            return Err(BecoError {
+                message: "Invalid guardian request".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        if calling_user.id != self.id {
+            return Err(BecoError {
+                message: "Only the user can name a guardian".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        if self.user_details.guardian.is_some() {
+            return Err(BecoError {
+                message: "User already has a guardian".into(),
+                status: Code::AlreadyExists,
+            });
+        }
+        if !request.guardianship.is_active(at) {
+            return Err(BecoError {
+                message: "Guardianship must end in the future".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        let guardian_id = request.guardianship.guardian_id.clone();
+        let until = Some(request.guardianship.until);
+        for (_, chain_account) in self.chain_accounts.iter_mut() {
+            let result = match chain_account {
+                BlockchainCustody::XRPL(account) => {
+                    account.keys.add_editor_until(guardian_id.clone(), calling_user, until, at)
+                }
+                BlockchainCustody::EVM(account) => {
+                    account.keys.add_editor_until(guardian_id.clone(), calling_user, until, at)
+                }
+            };
+            match result {
+                Err(err) if err.status != Code::AlreadyExists => return Err(err),
+                _ => {}
+            }
+        }
+        self.user_details.set_guardian(request.guardianship, calling_user, at)
+    }
+
+    // the sweeper proposes this once the end date has passed
+    pub fn ended_guardianship(&self, at: &DateTime<Utc>) -> Option<EndGuardianship> {
+        let guardian = self.user_details.guardian.as_ref()?;
+        if guardian.is_active(at) {
+            return None;
+        }
+        Some(EndGuardianship {
+            user_id: self.id.clone(),
+        })
+    }
+
+    pub fn propose_end_guardianship(
+        &self,
+        request: &EndGuardianship,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        self.clone().end_guardianship(request.clone(), at)
+    }
+
+    // the guardian's grants lapse on the same date and are revoked by the sweeper like any other
+    pub fn end_guardianship(
+        &mut self,
+        request: EndGuardianship,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        if request.user_id != self.id {
+            return Err(BecoError {
+                message: "Invalid guardianship end".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        if self.ended_guardianship(at).is_none() {
+            return Err(BecoError {
+                message: "No ended guardianship".into(),
+                status: Code::FailedPrecondition,
+            });
+        }
+        self.user_details.guardian = None;
+        Ok(())
+    }
+
+    pub fn auth_key(&self) -> Option<&String> {
+        self.auth_key.as_ref()
+    }
+
+    pub fn recovery_epoch(&self) -> u64 {
+        self.recovery_epoch
+    }
+
+    // once the user has an auth key, changes to it or to their recovery must be signed with it
+    fn verify_owner_signature(&self, message: &String, signature: &String) -> Result<(), BecoError> {
+        if self.auth_key.is_none() {
+            return Ok(());
+        }
+        if signature.is_empty() {
+            return Err(BecoError {
+                message: "A signature by the user's auth key is required".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        verify_signature(self.auth_key.as_ref().unwrap(), message, signature)
+    }
+
+    pub fn recovery(&self, calling_user: &PublicUser) -> Option<RecoveryResponse> {
+        let recovery = self.recovery.as_ref()?;
+        if calling_user.id != self.id && recovery.guardian(&calling_user.id).is_none() {
+            return None;
+        }
+        Some(recovery.as_response(self.recovery_epoch))
+    }
+
+    pub fn propose_set_auth_key(
+        &self,
+        request: &SetAuthKey,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        self.clone().set_auth_key(request.clone(), calling_user)
+    }
+
+    pub fn set_auth_key(
+        &mut self,
+        request: SetAuthKey,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        if request.user_id != self.id {
+            return Err(BecoError {
+                message: "Invalid auth key request".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        if calling_user.id != self.id {
+            return Err(BecoError {
+                message: "Only the user can set their auth key".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        self.verify_owner_signature(&request.message(self.sequence), &request.signature)?;
+        // a recovery in progress was for the old key, it must not complete over the new one
+        let pending_option = self
+            .recovery
+            .as_mut()
+            .and_then(|recovery| recovery.pending.take());
+        if pending_option.is_some() {
+            self.recovery_epoch += 1;
+        }
+        self.auth_key = Some(request.public_key);
+        Ok(())
+    }
+
+    pub fn propose_set_recovery_guardians(
+        &self,
+        request: &SetRecoveryGuardians,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        self.clone().set_recovery_guardians(request.clone(), calling_user)
+    }
+
+    // replacing the guardians drops a recovery that is in progress
+    pub fn set_recovery_guardians(
+        &mut self,
+        request: SetRecoveryGuardians,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        if request.user_id != self.id {
+            return Err(BecoError {
+                message: "Invalid recovery guardians request".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        if calling_user.id != self.id {
+            return Err(BecoError {
+                message: "Only the user can nominate recovery guardians".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        self.verify_owner_signature(&request.message(self.sequence), &request.signature)?;
+        self.recovery_epoch += 1;
+        if request.guardians.is_empty() {
+            self.recovery = None;
+            return Ok(());
+        }
+        self.recovery = Some(Recovery {
+            guardians: request.guardians,
+            threshold: request.threshold,
+            pending: None,
+        });
+        Ok(())
+    }
+
+    pub fn propose_approve_recovery(
+        &self,
+        request: &ApproveRecovery,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        self.clone().approve_recovery(request.clone(), calling_user, at)
+    }
+
+    // the first approval names the new key, the delay starts once the threshold is met
+    pub fn approve_recovery(
+        &mut self,
+        request: ApproveRecovery,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        if request.user_id != self.id {
+            return Err(BecoError {
+                message: "Invalid recovery approval".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        let guardian_option = self
+            .recovery
+            .as_ref()
+            .and_then(|recovery| recovery.guardian(&calling_user.id));
+        if guardian_option.is_none() {
+            return Err(BecoError {
+                message: "User is not a recovery guardian".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        verify_signature(
+            &guardian_option.unwrap().public_key,
+            &recovery_message(&self.id, self.recovery_epoch, &request.public_key),
+            &request.signature,
+        )?;
+        let recovery = self.recovery.as_mut().unwrap();
+        let pending = recovery.pending.get_or_insert_with(|| PendingRecovery {
+            public_key: request.public_key.clone(),
+            approvals: BTreeSet::new(),
+            ready_at: None,
+        });
+        if pending.public_key != request.public_key {
+            return Err(BecoError {
+                message: "Another recovery is in progress".into(),
+                status: Code::FailedPrecondition,
+            });
+        }
+        if !pending.approvals.insert(calling_user.id.clone()) {
+            return Err(BecoError {
+                message: "Recovery already approved".into(),
+                status: Code::AlreadyExists,
+            });
+        }
+        if pending.ready_at.is_none() && pending.approvals.len() >= recovery.threshold as usize {
+            pending.ready_at = Some(*at + recovery_delay());
+        }
+        Ok(())
+    }
+
+    pub fn propose_cancel_recovery(
+        &self,
+        request: &CancelRecovery,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        self.clone().cancel_recovery(request.clone(), calling_user)
+    }
+
+    pub fn cancel_recovery(
+        &mut self,
+        request: CancelRecovery,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        if request.user_id != self.id || calling_user.id != self.id {
+            return Err(BecoError {
+                message: "Only the user can cancel a recovery".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        self.verify_owner_signature(&request.message(self.sequence), &request.signature)?;
+        let pending_option = self
+            .recovery
+            .as_mut()
+            .and_then(|recovery| recovery.pending.take());
+        if pending_option.is_none() {
+            return Err(BecoError {
+                message: "No recovery in progress".into(),
+                status: Code::NotFound,
+            });
+        }
+        self.recovery_epoch += 1;
+        Ok(())
+    }
+
+    // the sweeper proposes this once the delay has passed
+    pub fn completed_recovery(&self, at: &DateTime<Utc>) -> Option<CompleteRecovery> {
+        let pending = self.recovery.as_ref()?.pending.as_ref()?;
+        if pending.ready_at.is_none_or(|ready_at| &ready_at > at) {
+            return None;
+        }
+        Some(CompleteRecovery {
+            user_id: self.id.clone(),
+            public_key: pending.public_key.clone(),
+        })
+    }
+
+    pub fn propose_complete_recovery(
+        &self,
+        request: &CompleteRecovery,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        self.clone().complete_recovery(request.clone(), at)
+    }
+
+    pub fn complete_recovery(
+        &mut self,
+        request: CompleteRecovery,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        if self.completed_recovery(at).as_ref() != Some(&request) {
+            return Err(BecoError {
+                message: "No recovery ready to complete".into(),
+                status: Code::FailedPrecondition,
+            });
+        }
+        self.auth_key = Some(request.public_key);
+        self.recovery.as_mut().unwrap().pending = None;
+        self.recovery_epoch += 1;
+        Ok(())
+    }
+
+    // an exact policy on the field wins over one covering every chain key
+    pub fn approval_policy(&self, field: &FieldReference) -> Option<&ApprovalPolicy> {
+        self.approval_policies
+            .iter()
+            .find(|policy| &policy.field == field)
+            .or_else(|| self.approval_policies.iter().find(|policy| policy.covers(field)))
+    }
+
+    // such a change has to be wrapped in a RequestApproval
+    pub fn needs_approval(&self, request: &DataRequests) -> bool {
+        guarded_field(request).is_some_and(|field| self.approval_policy(&field).is_some())
+    }
+
+    fn is_named_approver(
+        &self,
+        approvers: &[Approver],
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> bool {
+        approvers.iter().any(|approver| match approver {
+            Approver::User(user_id) => user_id == &calling_user.id,
+            Approver::Guardian => self.user_details.is_guardian(calling_user, at),
+            Approver::OrganisationAdmin(_) => false,
+        })
+    }
+
+    // the organisations the sst must find the caller to be an admin of, empty when the caller is
+    // named directly or there is nothing to check
+    pub fn approval_organisations(
+        &self,
+        approval_id: &String,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Vec<String> {
+        let approval_option = self.pending_approvals.get(approval_id);
+        if approval_option.is_none() {
+            return vec![];
+        }
+        let approval = approval_option.unwrap();
+        if self.is_named_approver(&approval.approvers, calling_user, at) {
+            return vec![];
+        }
+        approval.organisation_ids()
+    }
+
+    // the organisations named on any pending approval
+    pub fn pending_approval_organisations(&self) -> BTreeSet<String> {
+        self.pending_approvals
+            .values()
+            .flat_map(|approval| approval.organisation_ids())
+            .collect()
+    }
+
+    // shown to the user, the requester and anyone who may approve, admin_of lists the
+    // organisations the caller is known to be an admin of
+    pub fn pending_approvals(
+        &self,
+        calling_user: &PublicUser,
+        admin_of: &[String],
+        at: &DateTime<Utc>,
+    ) -> Vec<PendingApproval> {
+        self.pending_approvals
+            .values()
+            .filter(|approval| !approval.is_expired(at))
+            .filter(|approval| {
+                calling_user.id == self.id
+                    || approval.requested_by.id == calling_user.id
+                    || self.is_named_approver(&approval.approvers, calling_user, at)
+                    || approval
+                        .organisation_ids()
+                        .iter()
+                        .any(|organisation_id| admin_of.contains(organisation_id))
+            })
+            .cloned()
+            .collect()
+    }
+
+    pub fn propose_approval_policy(
+        &self,
+        request: &SetApprovalPolicy,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        self.clone().set_approval_policy(request.clone(), calling_user)
+    }
+
+    // changes already waiting keep the approvers they were requested with
+    pub fn set_approval_policy(
+        &mut self,
+        request: SetApprovalPolicy,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        if request.user_id != self.id {
+            return Err(BecoError {
+                message: "Invalid approval policy request".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        if calling_user.id != self.id {
+            return Err(BecoError {
+                message: "Only the user can set approval policies".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        self.approval_policies
+            .retain(|policy| policy.field != request.field);
+        if !request.approvers.is_empty() {
+            self.approval_policies.push(ApprovalPolicy {
+                field: request.field,
+                approvers: request.approvers,
+                required: request.required,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn propose_request_approval(
+        &self,
+        request: &RequestApproval,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        self.clone().request_approval(request.clone(), calling_user, at)
+    }
+
+    // the caller's right to make the change is checked by the caller of this, against the change
+    pub fn request_approval(
+        &mut self,
+        request: RequestApproval,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        let field_option = guarded_field(&request.change);
+        let policy_option = field_option
+            .as_ref()
+            .and_then(|field| self.approval_policy(field));
+        if policy_option.is_none() {
+            return Err(BecoError {
+                message: "Change does not need approval".into(),
+                status: Code::FailedPrecondition,
+            });
+        }
+        if self.pending_approvals.contains_key(&request.id) {
+            return Err(BecoError {
+                message: format!("Approval already exists: {}", request.id),
+                status: Code::AlreadyExists,
+            });
+        }
+        let policy = policy_option.unwrap().clone();
+        let mut requested_by = PublicUser::new(calling_user.id.clone(), None, None, None, vec![]);
+        requested_by.groups = calling_user.groups.clone();
+        requested_by.memberships = calling_user.memberships.clone();
+        self.pending_approvals.insert(
+            request.id.clone(),
+            PendingApproval {
+                id: request.id,
+                field: field_option.unwrap(),
+                change: request.change,
+                requested_by,
+                approvers: policy.approvers,
+                required: policy.required,
+                approvals: BTreeSet::new(),
+                created: *at,
+                expires_at: *at + approval_expiry(),
+            },
+        );
+        Ok(())
+    }
+
+    // returns the approval once it has enough approvals, the caller applies its change. Admins of
+    // an approving organisation are checked by the sst before this runs
+    pub fn approve_change(
+        &mut self,
+        request: ApproveChange,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<Option<PendingApproval>, BecoError> {
+        if request.user_id != self.id {
+            return Err(BecoError {
+                message: "Invalid approval".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        let approval_option = self.pending_approvals.get(&request.approval_id);
+        if approval_option.is_none() {
+            return Err(BecoError {
+                message: format!("Approval not found: {}", request.approval_id),
+                status: Code::NotFound,
+            });
+        }
+        let approval = approval_option.unwrap();
+        if approval.is_expired(at) {
+            return Err(BecoError {
+                message: format!("Approval has expired: {}", request.approval_id),
+                status: Code::FailedPrecondition,
+            });
+        }
+        if approval.requested_by.id == calling_user.id {
+            return Err(BecoError {
+                message: "Users cannot approve their own changes".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        if !self.is_named_approver(&approval.approvers, calling_user, at)
+            && approval.organisation_ids().is_empty()
+        {
+            return Err(BecoError {
+                message: "User is not an approver for this change".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        let approval = self.pending_approvals.get_mut(&request.approval_id).unwrap();
+        if !approval.approvals.insert(calling_user.id.clone()) {
+            return Err(BecoError {
+                message: "Change already approved".into(),
+                status: Code::AlreadyExists,
+            });
+        }
+        if approval.approvals.len() < approval.required as usize {
+            return Ok(None);
+        }
+        Ok(self.pending_approvals.remove(&request.approval_id))
+    }
+
+    // the sweeper proposes this for changes that were not approved in time
+    pub fn expired_approvals(&self, at: &DateTime<Utc>) -> Option<ExpireApprovals> {
+        let approval_ids: Vec<String> = self
+            .pending_approvals
+            .values()
+            .filter(|approval| approval.is_expired(at))
+            .map(|approval| approval.id.clone())
+            .collect();
+        if approval_ids.is_empty() {
+            return None;
+        }
+        Some(ExpireApprovals {
+            user_id: self.id.clone(),
+            approval_ids,
+        })
+    }
+
+    pub fn propose_expire_approvals(
+        &self,
+        request: &ExpireApprovals,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        self.clone().expire_approvals(request.clone(), at)
+    }
+
+    pub fn expire_approvals(
+        &mut self,
+        request: ExpireApprovals,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        if request.user_id != self.id {
+            return Err(BecoError {
+                message: "Invalid approval expiry".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        for approval_id in request.approval_ids.iter() {
+            let is_expired = self
+                .pending_approvals
+                .get(approval_id)
+                .is_some_and(|approval| approval.is_expired(at));
+            if !is_expired {
+                return Err(BecoError {
+                    message: format!("Approval has not expired: {approval_id}"),
+                    status: Code::FailedPrecondition,
+                });
+            }
+        }
+        for approval_id in request.approval_ids.iter() {
+            self.pending_approvals.remove(approval_id);
+        }
+        Ok(())
+    }
+
+    // only the attestations of fields the caller may view
+    pub fn attestations(
+        &self,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Vec<AttestationResponse> {
+        self.attestations
+            .iter()
+            .filter(|attestation| {
+                self.user_details
+                    .can_view_field(&attestation.field, calling_user, at)
+            })
+            .map(|attestation| attestation.clone().into())
+            .collect()
+    }
+
+    // the sst checks the organisation admin and their auth key, the user nodes cannot
+    pub fn propose_attest_field(
+        &self,
+        request: &Attestation,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        self.clone().attest_field(request.clone(), calling_user, at)
+    }
+
+    // replaces the organisation's earlier attestation of the same field, dated at the validator
+    // time rather than the proposing node's
+    pub fn attest_field(
+        &mut self,
+        mut request: Attestation,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        if request.user_id != self.id || request.attested_by != calling_user.id {
+            return Err(BecoError {
+                message: "Invalid attestation".into(),
+                status: Code::InvalidArgument,
+            });
+        }
+        if calling_user.id == self.id {
+            return Err(BecoError {
+                message: "Users cannot attest their own fields".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        if self.user_details.value_hash(&request.field).as_ref() != Some(&request.value_hash) {
+            return Err(BecoError {
+                message: "The value hash does not match the field's current value".into(),
+                status: Code::FailedPrecondition,
+            });
+        }
+        verify_signature(&request.public_key, &request.message(), &request.signature)?;
+        self.attestations.retain(|attestation| {
+            attestation.organisation_id != request.organisation_id
+                || attestation.field != request.field
+        });
+        request.attested_at = *at;
+        self.attestations.push(request);
+        Ok(())
+    }
+
+    // run after every change, so an attestation is invalidated with the change to its value
+    pub fn invalidate_attestations(&mut self, at: &DateTime<Utc>) {
+        for attestation in self.attestations.iter_mut() {
+            if attestation.is_valid()
+                && self.user_details.value_hash(&attestation.field).as_ref()
+                    != Some(&attestation.value_hash)
+            {
+                attestation.invalidated_at = Some(*at);
+            }
+        }
+    }
+
+    pub fn credential(&self, credential_id: &String) -> Option<&IssuedCredential> {
+        self.credentials
+            .iter()
+            .find(|credential| &credential.id == credential_id)
+    }
+
+    pub fn credential_status_list(&self) -> String {
+        encode_status_list(&self.credentials)
+    }
+
+    pub fn propose_issue_credential(
+        &self,
+        request: &IssueCredential,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        self.clone().issue_credential(request.clone(), calling_user, at)
+    }
+
+    // the claims are rebuilt from this node's copy of the user and must hash to what was proposed
+    pub fn issue_credential(
+        &mut self,
+        request: IssueCredential,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        if calling_user.id != self.id {
+            return Err(BecoError {
+                message: "Only the user can issue a credential about themselves".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        if self.credential(&request.id).is_some() {
+            return Err(BecoError {
+                message: format!("Credential already issued: {}", request.id),
+                status: Code::AlreadyExists,
+            });
+        }
+        let subject_result = credential_subject(self, calling_user, &request.fields);
+        if let Err(err) = subject_result {
+            return Err(err);
+        }
+        if value_hash(&subject_result.unwrap()) != request.claims_hash {
+            return Err(BecoError {
+                message: "The credential does not match the user's fields".into(),
+                status: Code::FailedPrecondition,
+            });
+        }
+        self.credentials.push(IssuedCredential {
+            id: request.id,
+            fields: request.fields,
+            claims_hash: request.claims_hash,
+            status_index: self.credentials.len() as u32,
+            issued_at: *at,
+            expires_at: *at + credential_expiry(),
+            revoked_at: None,
+        });
+        Ok(())
+    }
+
+    pub fn propose_revoke_credential(
+        &self,
+        request: &RevokeCredential,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        self.clone()
+            .revoke_credential(request.clone(), calling_user, at)
+    }
+
+    // the record is kept so the credential's bit stays set in the status list
+    pub fn revoke_credential(
+        &mut self,
+        request: RevokeCredential,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        if calling_user.id != self.id {
+            return Err(BecoError {
+                message: "Only the user can revoke their credentials".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        let credential_option = self
+            .credentials
+            .iter_mut()
+            .find(|credential| credential.id == request.credential_id);
+        if credential_option.is_none() {
+            return Err(BecoError {
+                message: format!("Credential not found: {}", request.credential_id),
+                status: Code::NotFound,
+            });
+        }
+        let credential = credential_option.unwrap();
+        if credential.revoked_at.is_some() {
+            return Err(BecoError {
+                message: format!("Credential already revoked: {}", request.credential_id),
+                status: Code::FailedPrecondition,
+            });
+        }
+        credential.revoked_at = Some(*at);
+        Ok(())
+    }
 
     pub fn can_access(&self, user: PublicUser) -> bool {
         unimplemented!()
@@ -1,12 +1,25 @@
+use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
-use std::hash::Hash;
+use std::{collections::BTreeMap, hash::Hash};
+use tonic::Code;
 
 use crate::{
     chain::chain_custody::PublicChainCustody, enums::value_reference::ValueReference,
-    permissions::model::PermissionModel,
+    errors::BecoError,
+    permissions::{
+        grant::ExpiredGrant,
+        model::{GrantRole, PermissionModel},
+        policy::AccessPolicy,
+        visibility::Visibility,
+    },
+    proto::beco::AttributeResponse,
+    requests::user_request::FieldReference,
 };
 
-use super::public_user::PublicUser;
+use super::{
+    attestation::value_hash, attribute::AttributeValue, guardian::Guardianship,
+    public_user::PublicUser,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserDetails {
@@ -14,6 +27,12 @@ pub struct UserDetails {
     pub first_name: PermissionModel<Option<String>>,
     pub other_names: PermissionModel<Option<Vec<String>>>,
     pub last_name: PermissionModel<Option<String>>,
+    // keyed by attribute name, details stored before attributes existed have none
+    #[serde(default)]
+    pub attributes: BTreeMap<String, PermissionModel<Option<AttributeValue>>>,
+    // kept here so attributes created during the guardianship are granted to the guardian too
+    #[serde(default)]
+    pub guardian: Option<Guardianship>,
 }
 
 impl Hash for UserDetails {
@@ -22,6 +41,11 @@ impl Hash for UserDetails {
         self.first_name.hash(state);
         self.other_names.hash(state);
         self.last_name.hash(state);
+        self.attributes.iter().for_each(|(key, attribute)| {
+            key.hash(state);
+            attribute.hash(state);
+        });
+        self.guardian.hash(state);
     }
 }
 
@@ -47,6 +71,8 @@ impl UserDetails {
                 "last_name".into(),
                 ValueReference::DETAIL_LAST_NAME,
             ),
+            attributes: BTreeMap::new(),
+            guardian: None,
         }
     }
 
@@ -61,6 +87,574 @@ impl UserDetails {
             other_names: self.other_names.value(user).unwrap_or(None),
             last_name: self.last_name.value(user).unwrap_or(None),
             chain_accounts,
+            groups: vec![],
+            memberships: vec![],
         }
     }
-}
+
+    // a new attribute can only be created by the owner or their guardian, an existing one by its
+    // editors too
+    pub fn propose_attribute(
+        &self,
+        key: &String,
+        value: &AttributeValue,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        let attribute_option = self.attributes.get(key);
+        if attribute_option.is_none() {
+            if calling_user.id != self.id && !self.is_guardian(calling_user, at) {
+                return Err(BecoError {
+                    message: format!("User does not have permission to add this attribute: {key}"),
+                    status: Code::PermissionDenied,
+                });
+            }
+            return Ok(());
+        }
+        let attribute = attribute_option.unwrap();
+        if !PermissionModel::is_owner_or_editor(attribute, calling_user, at) {
+            return Err(BecoError {
+                message: format!("User does not have permission to update this value: {key}"),
+                status: Code::PermissionDenied,
+            });
+        }
+        // a key keeps its type so consumers can rely on it, clear it first to change the type
+        let current_value = attribute.value_at(calling_user, at).unwrap();
+        if let Some(current_value) = current_value {
+            if !current_value.is_same_type(value) {
+                return Err(BecoError {
+                    message: format!(
+                        "Attribute {key} holds a {}, not a {}",
+                        current_value.type_name(),
+                        value.type_name()
+                    ),
+                    status: Code::InvalidArgument,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn set_attribute(
+        &mut self,
+        key: String,
+        value: AttributeValue,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        self.propose_attribute(&key, &value, calling_user, at)?;
+        let id = self.id.clone();
+        let guardian = self.guardian.clone().filter(|guardian| guardian.is_active(at));
+        let attribute = self.attributes.entry(key.clone()).or_insert_with(|| {
+            let mut attribute =
+                PermissionModel::new(id.clone(), None, key, ValueReference::DETAIL_ATTRIBUTE);
+            if let Some(guardian) = guardian {
+                let owner = PublicUser::new(id, None, None, None, vec![]);
+                attribute
+                    .add_editor_until(guardian.guardian_id, &owner, Some(guardian.until), at)
+                    .unwrap();
+            }
+            attribute
+        });
+        attribute.update(Some(value), calling_user, at).await
+    }
+
+    pub fn is_guardian(&self, user: &PublicUser, at: &DateTime<Utc>) -> bool {
+        self.guardian
+            .as_ref()
+            .is_some_and(|guardian| guardian.guardian_id == user.id && guardian.is_active(at))
+    }
+
+    // grants the guardian editor rights on every field until the guardianship ends, rights they
+    // already hold are left as they are
+    pub fn set_guardian(
+        &mut self,
+        guardianship: Guardianship,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        let guardian_id = guardianship.guardian_id.clone();
+        let until = Some(guardianship.until);
+        let mut results = vec![
+            self.first_name.add_editor_until(guardian_id.clone(), calling_user, until, at),
+            self.other_names.add_editor_until(guardian_id.clone(), calling_user, until, at),
+            self.last_name.add_editor_until(guardian_id.clone(), calling_user, until, at),
+        ];
+        for (_, attribute) in self.attributes.iter_mut() {
+            results.push(attribute.add_editor_until(guardian_id.clone(), calling_user, until, at));
+        }
+        for result in results {
+            match result {
+                Err(err) if err.status != Code::AlreadyExists => return Err(err),
+                _ => {}
+            }
+        }
+        self.guardian = Some(guardianship);
+        Ok(())
+    }
+
+    pub fn propose_clear_attribute(
+        &self,
+        key: &String,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        let attribute_option = self.attributes.get(key);
+        if attribute_option.is_none() {
+            return Err(BecoError {
+                message: format!("Attribute not found: {key}"),
+                status: Code::NotFound,
+            });
+        }
+        let attribute = attribute_option.unwrap();
+        if !PermissionModel::is_owner_or_editor(attribute, calling_user, at) {
+            return Err(BecoError {
+                message: format!("User does not have permission to update this value: {key}"),
+                status: Code::PermissionDenied,
+            });
+        }
+        if attribute.value_at(calling_user, at).unwrap().is_none() {
+            return Err(BecoError {
+                message: format!("Attribute not found: {key}"),
+                status: Code::NotFound,
+            });
+        }
+        Ok(())
+    }
+
+    // keeps the permissions so a value set again is shared with the same people
+    pub async fn clear_attribute(
+        &mut self,
+        key: &String,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        self.propose_clear_attribute(key, calling_user, at)?;
+        self.attributes
+            .get_mut(key)
+            .unwrap()
+            .update(None, calling_user, at)
+            .await
+    }
+
+    pub fn has_field(&self, field: &FieldReference) -> bool {
+        match field.reference {
+            ValueReference::DETAIL_FIRST_NAME
+            | ValueReference::DETAIL_OTHER_NAMES
+            | ValueReference::DETAIL_LAST_NAME => true,
+            ValueReference::DETAIL_ATTRIBUTE => field
+                .key
+                .as_ref()
+                .is_some_and(|key| self.attributes.contains_key(key)),
+            ValueReference::CHAIN_HEYS => false,
+        }
+    }
+
+    // the hash an attestation is bound to, None for fields without a value
+    pub fn value_hash(&self, field: &FieldReference) -> Option<String> {
+        let owner = PublicUser::new(self.id.clone(), None, None, None, vec![]);
+        match field.reference {
+            ValueReference::DETAIL_FIRST_NAME => {
+                self.first_name.value(&owner).ok()?.map(|value| value_hash(&value))
+            }
+            ValueReference::DETAIL_OTHER_NAMES => {
+                self.other_names.value(&owner).ok()?.map(|value| value_hash(&value))
+            }
+            ValueReference::DETAIL_LAST_NAME => {
+                self.last_name.value(&owner).ok()?.map(|value| value_hash(&value))
+            }
+            ValueReference::DETAIL_ATTRIBUTE => self
+                .attributes
+                .get(field.key.as_ref()?)?
+                .value(&owner)
+                .ok()?
+                .map(|value| value_hash(&value)),
+            ValueReference::CHAIN_HEYS => None,
+        }
+    }
+
+    pub fn can_view_field(
+        &self,
+        field: &FieldReference,
+        user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> bool {
+        match field.reference {
+            ValueReference::DETAIL_FIRST_NAME => self.first_name.can_view(user, at),
+            ValueReference::DETAIL_OTHER_NAMES => self.other_names.can_view(user, at),
+            ValueReference::DETAIL_LAST_NAME => self.last_name.can_view(user, at),
+            ValueReference::DETAIL_ATTRIBUTE => field
+                .key
+                .as_ref()
+                .and_then(|key| self.attributes.get(key))
+                .is_some_and(|attribute| attribute.can_view(user, at)),
+            ValueReference::CHAIN_HEYS => false,
+        }
+    }
+
+    // granting again is not an error, the viewer simply keeps access
+    pub fn add_viewer(
+        &mut self,
+        field: &FieldReference,
+        viewer_id: String,
+        calling_user: &PublicUser,
+        expires: Option<DateTime<Utc>>,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        let result = match field.reference {
+            ValueReference::DETAIL_FIRST_NAME => {
+                self.first_name.add_viewer_until(viewer_id, calling_user, expires, at)
+            }
+            ValueReference::DETAIL_OTHER_NAMES => {
+                self.other_names.add_viewer_until(viewer_id, calling_user, expires, at)
+            }
+            ValueReference::DETAIL_LAST_NAME => {
+                self.last_name.add_viewer_until(viewer_id, calling_user, expires, at)
+            }
+            ValueReference::DETAIL_ATTRIBUTE => {
+                let attribute_option = field
+                    .key
+                    .as_ref()
+                    .and_then(|key| self.attributes.get_mut(key));
+                if attribute_option.is_none() {
+                    return Err(BecoError {
+                        message: format!(
+                            "Attribute not found: {}",
+                            field.key.clone().unwrap_or_default()
+                        ),
+                        status: Code::NotFound,
+                    });
+                }
+                attribute_option
+                    .unwrap()
+                    .add_viewer_until(viewer_id, calling_user, expires, at)
+            }
+            ValueReference::CHAIN_HEYS => Err(BecoError {
+                message: "Chain keys are not part of the user details".into(),
+                status: Code::InvalidArgument,
+            }),
+        };
+        match result {
+            Err(err) if err.status == Code::AlreadyExists => Ok(()),
+            result => result,
+        }
+    }
+
+    // grant is false to revoke the group's role instead
+    pub fn set_group_access(
+        &mut self,
+        field: &FieldReference,
+        group_id: &String,
+        role: GrantRole,
+        grant: bool,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        match field.reference {
+            ValueReference::DETAIL_FIRST_NAME => {
+                self.first_name.set_group(group_id, role, grant, calling_user, at)
+            }
+            ValueReference::DETAIL_OTHER_NAMES => {
+                self.other_names.set_group(group_id, role, grant, calling_user, at)
+            }
+            ValueReference::DETAIL_LAST_NAME => {
+                self.last_name.set_group(group_id, role, grant, calling_user, at)
+            }
+            ValueReference::DETAIL_ATTRIBUTE => {
+                let attribute_option = field
+                    .key
+                    .as_ref()
+                    .and_then(|key| self.attributes.get_mut(key));
+                if attribute_option.is_none() {
+                    return Err(BecoError {
+                        message: format!(
+                            "Attribute not found: {}",
+                            field.key.clone().unwrap_or_default()
+                        ),
+                        status: Code::NotFound,
+                    });
+                }
+                attribute_option
+                    .unwrap()
+                    .set_group(group_id, role, grant, calling_user, at)
+            }
+            ValueReference::CHAIN_HEYS => Err(BecoError {
+                message: "Chain keys are not part of the user details".into(),
+                status: Code::InvalidArgument,
+            }),
+        }
+    }
+
+    pub fn revoke_grant(
+        &mut self,
+        field: &FieldReference,
+        grantee_id: &str,
+        role: GrantRole,
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        match field.reference {
+            ValueReference::DETAIL_FIRST_NAME => {
+                self.first_name.revoke(grantee_id, role, calling_user, at)
+            }
+            ValueReference::DETAIL_OTHER_NAMES => {
+                self.other_names.revoke(grantee_id, role, calling_user, at)
+            }
+            ValueReference::DETAIL_LAST_NAME => {
+                self.last_name.revoke(grantee_id, role, calling_user, at)
+            }
+            ValueReference::DETAIL_ATTRIBUTE => {
+                let attribute_option = field
+                    .key
+                    .as_ref()
+                    .and_then(|key| self.attributes.get_mut(key));
+                if attribute_option.is_none() {
+                    return Err(BecoError {
+                        message: format!(
+                            "Attribute not found: {}",
+                            field.key.clone().unwrap_or_default()
+                        ),
+                        status: Code::NotFound,
+                    });
+                }
+                attribute_option
+                    .unwrap()
+                    .revoke(grantee_id, role, calling_user, at)
+            }
+            ValueReference::CHAIN_HEYS => Err(BecoError {
+                message: "Chain keys are not part of the user details".into(),
+                status: Code::InvalidArgument,
+            }),
+        }
+    }
+
+    pub fn set_policies(
+        &mut self,
+        field: &FieldReference,
+        policies: Vec<AccessPolicy>,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        match field.reference {
+            ValueReference::DETAIL_FIRST_NAME => self.first_name.set_policies(policies, calling_user),
+            ValueReference::DETAIL_OTHER_NAMES => {
+                self.other_names.set_policies(policies, calling_user)
+            }
+            ValueReference::DETAIL_LAST_NAME => self.last_name.set_policies(policies, calling_user),
+            ValueReference::DETAIL_ATTRIBUTE => {
+                let attribute_option = field
+                    .key
+                    .as_ref()
+                    .and_then(|key| self.attributes.get_mut(key));
+                if attribute_option.is_none() {
+                    return Err(BecoError {
+                        message: format!(
+                            "Attribute not found: {}",
+                            field.key.clone().unwrap_or_default()
+                        ),
+                        status: Code::NotFound,
+                    });
+                }
+                attribute_option.unwrap().set_policies(policies, calling_user)
+            }
+            ValueReference::CHAIN_HEYS => Err(BecoError {
+                message: "Chain keys are not part of the user details".into(),
+                status: Code::InvalidArgument,
+            }),
+        }
+    }
+
+    pub fn set_visibility(
+        &mut self,
+        field: &FieldReference,
+        visibility: Visibility,
+        calling_user: &PublicUser,
+    ) -> Result<(), BecoError> {
+        match field.reference {
+            ValueReference::DETAIL_FIRST_NAME => {
+                self.first_name.set_visibility(visibility, calling_user)
+            }
+            ValueReference::DETAIL_OTHER_NAMES => {
+                self.other_names.set_visibility(visibility, calling_user)
+            }
+            ValueReference::DETAIL_LAST_NAME => {
+                self.last_name.set_visibility(visibility, calling_user)
+            }
+            ValueReference::DETAIL_ATTRIBUTE => {
+                let attribute_option = field
+                    .key
+                    .as_ref()
+                    .and_then(|key| self.attributes.get_mut(key));
+                if attribute_option.is_none() {
+                    return Err(BecoError {
+                        message: format!(
+                            "Attribute not found: {}",
+                            field.key.clone().unwrap_or_default()
+                        ),
+                        status: Code::NotFound,
+                    });
+                }
+                attribute_option.unwrap().set_visibility(visibility, calling_user)
+            }
+            ValueReference::CHAIN_HEYS => Err(BecoError {
+                message: "Chain keys are not part of the user details".into(),
+                status: Code::InvalidArgument,
+            }),
+        }
+    }
+
+    pub fn next_expiry(&self) -> Option<DateTime<Utc>> {
+        [
+            self.first_name.next_expiry(),
+            self.other_names.next_expiry(),
+            self.last_name.next_expiry(),
+        ]
+        .into_iter()
+        .chain(self.attributes.values().map(|attribute| attribute.next_expiry()))
+        .flatten()
+        .min()
+    }
+
+    pub fn expired_grants(&self, at: &DateTime<Utc>) -> Vec<ExpiredGrant> {
+        let field = |reference: ValueReference, key: Option<String>| FieldReference { reference, key };
+        let mut grants: Vec<ExpiredGrant> = vec![];
+        let mut collect = |field: FieldReference, expired: Vec<(String, GrantRole)>| {
+            for (grantee_id, role) in expired {
+                grants.push(ExpiredGrant {
+                    field: field.clone(),
+                    grantee_id,
+                    role,
+                });
+            }
+        };
+        collect(
+            field(ValueReference::DETAIL_FIRST_NAME, None),
+            self.first_name.expired_grants(at),
+        );
+        collect(
+            field(ValueReference::DETAIL_OTHER_NAMES, None),
+            self.other_names.expired_grants(at),
+        );
+        collect(
+            field(ValueReference::DETAIL_LAST_NAME, None),
+            self.last_name.expired_grants(at),
+        );
+        for (key, attribute) in self.attributes.iter() {
+            collect(
+                field(ValueReference::DETAIL_ATTRIBUTE, Some(key.clone())),
+                attribute.expired_grants(at),
+            );
+        }
+        grants
+    }
+
+    pub fn grant_expires(&self, grant: &ExpiredGrant) -> Option<DateTime<Utc>> {
+        match grant.field.reference {
+            ValueReference::DETAIL_FIRST_NAME => {
+                self.first_name.expires(&grant.grantee_id, grant.role)
+            }
+            ValueReference::DETAIL_OTHER_NAMES => {
+                self.other_names.expires(&grant.grantee_id, grant.role)
+            }
+            ValueReference::DETAIL_LAST_NAME => {
+                self.last_name.expires(&grant.grantee_id, grant.role)
+            }
+            ValueReference::DETAIL_ATTRIBUTE => grant
+                .field
+                .key
+                .as_ref()
+                .and_then(|key| self.attributes.get(key))
+                .and_then(|attribute| attribute.expires(&grant.grantee_id, grant.role)),
+            ValueReference::CHAIN_HEYS => None,
+        }
+    }
+
+    pub fn revoke_expired(
+        &mut self,
+        grant: &ExpiredGrant,
+        at: &DateTime<Utc>,
+    ) -> Result<(), BecoError> {
+        match grant.field.reference {
+            ValueReference::DETAIL_FIRST_NAME => {
+                self.first_name.revoke_expired(&grant.grantee_id, grant.role, at)
+            }
+            ValueReference::DETAIL_OTHER_NAMES => {
+                self.other_names.revoke_expired(&grant.grantee_id, grant.role, at)
+            }
+            ValueReference::DETAIL_LAST_NAME => {
+                self.last_name.revoke_expired(&grant.grantee_id, grant.role, at)
+            }
+            ValueReference::DETAIL_ATTRIBUTE => {
+                let attribute_option = grant
+                    .field
+                    .key
+                    .as_ref()
+                    .and_then(|key| self.attributes.get_mut(key));
+                if attribute_option.is_none() {
+                    return Err(BecoError {
+                        message: format!(
+                            "Attribute not found: {}",
+                            grant.field.key.clone().unwrap_or_default()
+                        ),
+                        status: Code::NotFound,
+                    });
+                }
+                attribute_option
+                    .unwrap()
+                    .revoke_expired(&grant.grantee_id, grant.role, at)
+            }
+            ValueReference::CHAIN_HEYS => Err(BecoError {
+                message: "Chain keys are not part of the user details".into(),
+                status: Code::InvalidArgument,
+            }),
+        }
+    }
+
+    // the fields holding a value the user can read
+    pub fn readable_fields(&self, user: &PublicUser, at: &DateTime<Utc>) -> Vec<FieldReference> {
+        let mut fields = vec![];
+        if self.first_name.value_at(user, at).is_ok_and(|value| value.is_some()) {
+            fields.push(FieldReference {
+                reference: ValueReference::DETAIL_FIRST_NAME,
+                key: None,
+            });
+        }
+        if self.other_names.value_at(user, at).is_ok_and(|value| value.is_some()) {
+            fields.push(FieldReference {
+                reference: ValueReference::DETAIL_OTHER_NAMES,
+                key: None,
+            });
+        }
+        if self.last_name.value_at(user, at).is_ok_and(|value| value.is_some()) {
+            fields.push(FieldReference {
+                reference: ValueReference::DETAIL_LAST_NAME,
+                key: None,
+            });
+        }
+        for (key, attribute) in self.attributes.iter() {
+            if attribute.value_at(user, at).is_ok_and(|value| value.is_some()) {
+                fields.push(FieldReference {
+                    reference: ValueReference::DETAIL_ATTRIBUTE,
+                    key: Some(key.clone()),
+                });
+            }
+        }
+        fields
+    }
+
+    pub fn attribute(&self, key: &String, user: &PublicUser) -> Option<AttributeValue> {
+        self.attributes.get(key)?.value(user).unwrap_or(None)
+    }
+
+    pub fn public_attributes(&self, user: &PublicUser) -> Vec<AttributeResponse> {
+        self.attributes
+            .iter()
+            .filter_map(|(key, attribute)| {
+                let value = attribute.value(user).unwrap_or(None)?;
+                Some(AttributeResponse {
+                    key: key.clone(),
+                    value: Some(value.into()),
+                })
+            })
+            .collect()
+    }
+}
\ No newline at end of file
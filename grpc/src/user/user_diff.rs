@@ -0,0 +1,146 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{collections::BTreeSet, hash::Hash};
+
+use crate::{
+    enums::data_value::DataValue,
+    errors::BecoError,
+    proto::beco::FieldDiffResponse,
+    user::{public_user::PublicUser, user::User},
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq)]
+pub struct FieldDiff {
+    pub field: String,
+    pub sequence: u64,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub calling_user: String,
+    pub datetime: Option<DateTime<Utc>>,
+    pub redacted: bool,
+}
+
+impl FieldDiff {
+    // compares two consecutive snapshots of a user and returns one entry per changed field
+    // values are compared as the owner sees them, but only returned if the viewer can see them
+    pub fn between(
+        previous: &User,
+        current: &User,
+        changed_by: &str,
+        datetime: Option<DateTime<Utc>>,
+        viewer: &PublicUser,
+    ) -> Vec<FieldDiff> {
+        let owner = PublicUser::new(current.id.clone(), None, None, None, vec![]);
+        let sequence = current.sequence();
+        let previous_details = &previous.user_details;
+        let current_details = &current.user_details;
+        let mut changes = vec![
+            FieldDiff::compare(
+                DataValue::FirstName.to_string(),
+                previous_details.first_name.value(&owner),
+                current_details.first_name.value(&owner),
+                previous_details.first_name.value(viewer).is_ok()
+                    && current_details.first_name.value(viewer).is_ok(),
+            ),
+            FieldDiff::compare(
+                DataValue::OtherNames.to_string(),
+                previous_details.other_names.value(&owner),
+                current_details.other_names.value(&owner),
+                previous_details.other_names.value(viewer).is_ok()
+                    && current_details.other_names.value(viewer).is_ok(),
+            ),
+            FieldDiff::compare(
+                DataValue::LastName.to_string(),
+                previous_details.last_name.value(&owner),
+                current_details.last_name.value(&owner),
+                previous_details.last_name.value(viewer).is_ok()
+                    && current_details.last_name.value(viewer).is_ok(),
+            ),
+            FieldDiff::compare(
+                DataValue::ChainAccounts.to_string(),
+                Ok(previous.sorted_public_chain_accounts(&owner)),
+                Ok(current.sorted_public_chain_accounts(&owner)),
+                previous.can_view_chain_accounts(viewer) && current.can_view_chain_accounts(viewer),
+            ),
+        ];
+        let attribute_keys: BTreeSet<&String> = previous_details
+            .attributes
+            .keys()
+            .chain(current_details.attributes.keys())
+            .collect();
+        for key in attribute_keys {
+            // an attribute that did not exist yet reads as unset and viewable
+            let previous_attribute = previous_details.attributes.get(key);
+            let current_attribute = current_details.attributes.get(key);
+            changes.push(FieldDiff::compare(
+                format!("{}.{key}", DataValue::Attribute),
+                previous_attribute.map_or(Ok(None), |attribute| attribute.value(&owner)),
+                current_attribute.map_or(Ok(None), |attribute| attribute.value(&owner)),
+                previous_attribute.is_none_or(|attribute| attribute.value(viewer).is_ok())
+                    && current_attribute.is_none_or(|attribute| attribute.value(viewer).is_ok()),
+            ));
+        }
+
+        changes
+            .into_iter()
+            .flatten()
+            .map(|mut change| {
+                change.sequence = sequence;
+                change.calling_user = changed_by.to_string();
+                change.datetime = datetime;
+                change
+            })
+            .collect()
+    }
+
+    fn compare<T: Serialize>(
+        field: String,
+        previous: Result<T, BecoError>,
+        current: Result<T, BecoError>,
+        can_view: bool,
+    ) -> Option<FieldDiff> {
+        let old_value = FieldDiff::render(previous);
+        let new_value = FieldDiff::render(current);
+        if old_value == new_value {
+            return None;
+        }
+        let (old_value, new_value) = if can_view {
+            (old_value, new_value)
+        } else {
+            (None, None)
+        };
+        Some(FieldDiff {
+            field,
+            sequence: 0,
+            old_value,
+            new_value,
+            calling_user: "".to_string(),
+            datetime: None,
+            redacted: !can_view,
+        })
+    }
+
+    fn render<T: Serialize>(value: Result<T, BecoError>) -> Option<String> {
+        let serialised = serde_json::to_value(value.ok()?).ok()?;
+        match serialised {
+            Value::Null => None,
+            Value::String(string) => Some(string),
+            other => Some(other.to_string()),
+        }
+    }
+}
+
+impl From<FieldDiff> for FieldDiffResponse {
+    fn from(val: FieldDiff) -> Self {
+        FieldDiffResponse {
+            field: val.field,
+            sequence: val.sequence,
+            old_value: val.old_value,
+            new_value: val.new_value,
+            calling_user: val.calling_user,
+            datetime: val.datetime.map(|datetime| datetime.to_rfc3339()),
+            redacted: val.redacted,
+        }
+    }
+}
@@ -2,12 +2,14 @@
 #![allow(unused_variables)]
 #![allow(non_snake_case)]
 
+mod audit;
 mod chain;
 mod config;
 mod entry;
 mod enums;
 mod errors;
 mod evm;
+mod group;
 mod implement;
 mod organisation;
 mod p2p;
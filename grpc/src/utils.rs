@@ -7,7 +7,7 @@ use std::{
 
 use futures::Future;
 
-use crate::enums::data_value::DataRequestType;
+use crate::enums::data_value::{DataRequestType, DataRequests};
 
 pub fn calculate_hash<T: Hash>(t: &T) -> u64 {
     let mut s = DefaultHasher::new();
@@ -19,6 +19,7 @@ pub fn calculate_hash<T: Hash>(t: &T) -> u64 {
 pub struct ProposeEvent {
     status: DataRequestType,
     user_id: Option<String>,
+    response: Option<DataRequests>,
     end: Instant,
 }
 
@@ -27,6 +28,7 @@ impl ProposeEvent {
         Self {
             status: status,
             user_id,
+            response: None,
             end: Instant::now() + duration,
         }
     }
@@ -37,6 +39,15 @@ impl ProposeEvent {
             self.user_id = user_id;
         }
     }
+
+    pub fn respond(&mut self, response: DataRequests) {
+        self.status = DataRequestType::RESPONSE;
+        self.response = Some(response);
+    }
+
+    pub fn response(&self) -> Option<DataRequests> {
+        self.response.clone()
+    }
 }
 
 impl Future for ProposeEvent {
@@ -45,7 +56,7 @@ impl Future for ProposeEvent {
         self: std::pin::Pin<&mut Self>,
         cx: &mut std::task::Context<'_>,
     ) -> std::task::Poll<Self::Output> {
-        if self.status == DataRequestType::VALIDATED
+        if self.status == DataRequestType::COMMITTED
             || self.status == DataRequestType::FAILED
             || self.status == DataRequestType::RESPONSE
             || self.status == DataRequestType::LOAD
@@ -65,7 +76,7 @@ impl Future for &ProposeEvent {
         self: std::pin::Pin<&mut Self>,
         cx: &mut std::task::Context<'_>,
     ) -> std::task::Poll<Self::Output> {
-        if self.status == DataRequestType::VALIDATED
+        if self.status == DataRequestType::COMMITTED
             || self.status == DataRequestType::FAILED
             || self.status == DataRequestType::RESPONSE
             || self.status == DataRequestType::LOAD
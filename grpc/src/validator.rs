@@ -2,10 +2,12 @@
 #![allow(unused_variables)]
 #![allow(non_snake_case)]
 
+mod audit;
 mod chain;
 mod enums;
 mod errors;
 mod evm;
+mod group;
 mod implement;
 mod organisation;
 mod p2p;
@@ -4,11 +4,12 @@ use crate::{
     traits::{key::Key, value::Values},
     user::public_user::PublicUser, proto::beco::AddAccountRequest,
 };
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use tonic::Code;
 use xrpl::{
     constants::CryptoAlgorithm,
-    core::keypairs::{derive_classic_address, derive_keypair, generate_seed},
+    core::keypairs::{derive_classic_address, derive_keypair, generate_seed, sign},
 };
 
 #[derive(Debug, Clone, Hash)]
@@ -82,10 +83,10 @@ impl Key<CryptoAlgorithm> for ChainCustody<XRPLKey, XRPLKeyValues> {
         algorithm: Option<CryptoAlgorithm>,
         request: AddAccountRequest,
         public_user: &PublicUser,
+        at: &DateTime<Utc>,
     ) -> Result<(), BecoError> {
         let alias = request.alias;
-        let does_alias_exist = self.does_alias_exist(alias.clone(), public_user);
-        if does_alias_exist {
+        if self.has_key(&alias) {
             return Err(BecoError {
                 message: "Alias already exists".into(),
                 status: Code::AlreadyExists,
@@ -102,7 +103,7 @@ impl Key<CryptoAlgorithm> for ChainCustody<XRPLKey, XRPLKeyValues> {
         let (public_key, private_key) = derive_keypair(&seed, false).unwrap();
         let classic_address = derive_classic_address(&public_key).unwrap();
         let key = XRPLKey::new(seed, public_key, private_key, classic_address, alias);
-        let keys_result = self.keys.value_mut(public_user);
+        let keys_result = self.keys.value_mut(public_user, at);
         if keys_result.is_err() {
             return Err(BecoError {
                 message: "User does not have permission to create a new key".into(),
@@ -113,4 +114,35 @@ impl Key<CryptoAlgorithm> for ChainCustody<XRPLKey, XRPLKeyValues> {
         keys.push(key.clone());
         Ok(())
     }
+    fn sign(
+        &self,
+        alias: &str,
+        message: &[u8],
+        calling_user: &PublicUser,
+        at: &DateTime<Utc>,
+    ) -> Result<(String, String), BecoError> {
+        // checked first so a caller without rights cannot tell which aliases exist
+        if !self.can_edit_key(alias, calling_user, at) {
+            return Err(BecoError {
+                message: "User does not have permission to sign with this key".into(),
+                status: Code::PermissionDenied,
+            });
+        }
+        let key_option = self.all_keys().into_iter().find(|key| key.alias == alias);
+        if key_option.is_none() {
+            return Err(BecoError {
+                message: format!("Key not found: {alias}"),
+                status: Code::NotFound,
+            });
+        }
+        let key = key_option.unwrap();
+        let signature_result = sign(message, &key.private_key);
+        if signature_result.is_err() {
+            return Err(BecoError {
+                message: "Error signing the message".into(),
+                status: Code::Internal,
+            });
+        }
+        Ok((key.public_key, signature_result.unwrap()))
+    }
 }